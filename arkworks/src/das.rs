@@ -1,6 +1,6 @@
 use crate::kzg_proofs::FFTSettings;
 use crate::kzg_types::FsFr as BlstFr;
-use kzg::{Fr, DAS};
+use kzg::{Error, Fr, DAS};
 use std::cmp::Ordering;
 
 impl FFTSettings {
@@ -57,15 +57,15 @@ impl FFTSettings {
 }
 
 impl DAS<BlstFr> for FFTSettings {
-    fn das_fft_extension(&self, vals: &[BlstFr]) -> Result<Vec<BlstFr>, String> {
+    fn das_fft_extension(&self, vals: &[BlstFr]) -> Result<Vec<BlstFr>, Error> {
         if vals.is_empty() {
-            return Err(String::from("vals can not be empty"));
+            return Err(Error::InvalidArgument("vals can not be empty"));
         }
         if !vals.len().is_power_of_two() {
-            return Err(String::from("vals lenght has to be power of 2"));
+            return Err(Error::NotPowerOfTwo("values length"));
         }
         if vals.len() * 2 > self.max_width {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "vals lenght * 2 has to equal or less than FFTSetings max width",
             ));
         }
@@ -78,9 +78,7 @@ impl DAS<BlstFr> for FFTSettings {
         let invlen = BlstFr::from_u64(vals.len() as u64);
         let invlen = invlen.inverse();
 
-        for val in &mut vals {
-            val.0 = val.mul(&invlen).0;
-        }
+        BlstFr::batch_mul_scalar(&mut vals, &invlen);
 
         Ok(vals)
     }
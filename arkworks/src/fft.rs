@@ -1,6 +1,6 @@
 use crate::kzg_proofs::FFTSettings;
 use crate::kzg_types::FsFr as BlstFr;
-use kzg::{FFTFr, Fr as FFr};
+use kzg::{Error, FFTFr, Fr as FFr};
 
 #[rustfmt::skip]
 pub const SCALE2_ROOT_OF_UNITY: [[u64; 4]; 32] = [
@@ -39,12 +39,14 @@ pub const SCALE2_ROOT_OF_UNITY: [[u64; 4]; 32] = [
 ];
 
 impl FFTFr<BlstFr> for FFTSettings {
-    fn fft_fr(&self, data: &[BlstFr], inverse: bool) -> Result<Vec<BlstFr>, String> {
+    fn fft_fr(&self, data: &[BlstFr], inverse: bool) -> Result<Vec<BlstFr>, Error> {
         if data.len() > self.max_width {
-            return Err(String::from("data length is longer than allowed max width"));
+            return Err(Error::InvalidArgument(
+                "data length is longer than allowed max width",
+            ));
         }
         if !data.len().is_power_of_two() {
-            return Err(String::from("data length is not power of 2"));
+            return Err(Error::NotPowerOfTwo("data length"));
         }
 
         let stride = self.max_width / data.len();
@@ -60,9 +62,7 @@ impl FFTFr<BlstFr> for FFTSettings {
 
         if inverse {
             let inv_fr_len = BlstFr::from_u64(data.len() as u64).inverse();
-            ret[..data.len()]
-                .iter_mut()
-                .for_each(|f| *f = BlstFr::mul(f, &inv_fr_len));
+            BlstFr::batch_mul_scalar(&mut ret[..data.len()], &inv_fr_len);
         }
 
         Ok(ret)
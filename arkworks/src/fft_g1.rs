@@ -8,7 +8,7 @@ use ark_ec::msm::VariableBaseMSM;
 use ark_ec::ProjectiveCurve;
 use ark_ff::{BigInteger256, PrimeField};
 use blst::{blst_fp, blst_p1};
-use kzg::{cfg_into_iter, G1Mul};
+use kzg::{cfg_into_iter, Error, G1Mul};
 use kzg::{Fr, FFTG1, G1};
 
 #[cfg(feature = "parallel")]
@@ -125,12 +125,14 @@ pub fn make_data(data: usize) -> Vec<ArkG1> {
 }
 
 impl FFTG1<ArkG1> for FFTSettings {
-    fn fft_g1(&self, data: &[ArkG1], inverse: bool) -> Result<Vec<ArkG1>, String> {
+    fn fft_g1(&self, data: &[ArkG1], inverse: bool) -> Result<Vec<ArkG1>, Error> {
         if data.len() > self.max_width {
-            return Err(String::from("data length is longer than allowed max width"));
+            return Err(Error::InvalidArgument(
+                "data length is longer than allowed max width",
+            ));
         }
         if !data.len().is_power_of_two() {
-            return Err(String::from("data length is not power of 2"));
+            return Err(Error::NotPowerOfTwo("data length"));
         }
 
         let stride: usize = self.max_width / data.len();
@@ -2,7 +2,8 @@ use crate::fft_g1::G1_IDENTITY;
 use crate::kzg_proofs::{FFTSettings, KZGSettings};
 use crate::kzg_types::{ArkG1, ArkG2, FsFr as BlstFr};
 use crate::utils::PolyData;
-use kzg::{FFTFr, FK20MultiSettings, FK20SingleSettings, Fr, G1Mul, Poly, FFTG1, G1};
+use kzg::trusted_setup::SetupBasis;
+use kzg::{Error, FFTFr, FK20MultiSettings, FK20SingleSettings, Fr, G1Mul, Poly, FFTG1, G1};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
@@ -42,20 +43,21 @@ where
 impl FK20SingleSettings<BlstFr, ArkG1, ArkG2, FFTSettings, PolyData, KZGSettings>
     for KzgFK20SingleSettings
 {
-    fn new(ks: &KZGSettings, n2: usize) -> Result<Self, String> {
+    fn new(ks: &KZGSettings, n2: usize) -> Result<Self, Error> {
         let n = n2 / 2;
 
         if n2 > ks.fs.max_width {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "n2 must be equal or less than kzg settings max width",
             ));
         }
         if !n2.is_power_of_two() {
-            return Err(String::from("n2 must be power of 2"));
+            return Err(Error::NotPowerOfTwo("n2"));
         }
         if n2 < 2 {
-            return Err(String::from("n2 must be equal or greater than 2"));
+            return Err(Error::InvalidArgument("n2 must be equal or greater than 2"));
         }
+        ks.check_basis(SetupBasis::Monomial)?;
 
         let mut x = Vec::new();
         for i in 0..(n - 1) {
@@ -75,17 +77,17 @@ impl FK20SingleSettings<BlstFr, ArkG1, ArkG2, FFTSettings, PolyData, KZGSettings
         })
     }
 
-    fn data_availability(&self, p: &PolyData) -> Result<Vec<ArkG1>, String> {
+    fn data_availability(&self, p: &PolyData) -> Result<Vec<ArkG1>, Error> {
         let n = p.len();
         let n2 = n * 2;
 
         if n2 > self.ks.fs.max_width {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "n2 must be equal or less than kzg settings max width",
             ));
         }
         if !n.is_power_of_two() {
-            return Err(String::from("n2 must be power of 2"));
+            return Err(Error::NotPowerOfTwo("n2"));
         }
 
         let mut out = fk20_single_da_opt(p, self).unwrap();
@@ -93,7 +95,7 @@ impl FK20SingleSettings<BlstFr, ArkG1, ArkG2, FFTSettings, PolyData, KZGSettings
         Ok(out)
     }
 
-    fn data_availability_optimized(&self, p: &PolyData) -> Result<Vec<ArkG1>, String> {
+    fn data_availability_optimized(&self, p: &PolyData) -> Result<Vec<ArkG1>, Error> {
         fk20_single_da_opt(p, self)
     }
 }
@@ -101,26 +103,29 @@ impl FK20SingleSettings<BlstFr, ArkG1, ArkG2, FFTSettings, PolyData, KZGSettings
 impl FK20MultiSettings<BlstFr, ArkG1, ArkG2, FFTSettings, PolyData, KZGSettings>
     for KzgFK20MultiSettings
 {
-    fn new(ks: &KZGSettings, n2: usize, chunk_len: usize) -> Result<Self, String> {
+    fn new(ks: &KZGSettings, n2: usize, chunk_len: usize) -> Result<Self, Error> {
         if n2 > ks.fs.max_width {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "n2 must be equal or less than kzg settings max width",
             ));
         }
         if !n2.is_power_of_two() {
-            return Err(String::from("n2 must be power of 2"));
+            return Err(Error::NotPowerOfTwo("n2"));
         }
         if n2 < 2 {
-            return Err(String::from("n2 must be equal or greater than 2"));
+            return Err(Error::InvalidArgument("n2 must be equal or greater than 2"));
         }
+        ks.check_basis(SetupBasis::Monomial)?;
         if chunk_len > n2 / 2 {
-            return Err(String::from("chunk_len must be equal or less than n2/2"));
+            return Err(Error::InvalidArgument(
+                "chunk_len must be equal or less than n2/2",
+            ));
         }
         if !chunk_len.is_power_of_two() {
-            return Err(String::from("chunk_len must be power of 2"));
+            return Err(Error::NotPowerOfTwo("chunk_len"));
         }
         if chunk_len == 0 {
-            return Err(String::from("chunk_len must be greater than 0"));
+            return Err(Error::InvalidArgument("chunk_len must be greater than 0"));
         }
 
         let n = n2 / 2;
@@ -161,17 +166,17 @@ impl FK20MultiSettings<BlstFr, ArkG1, ArkG2, FFTSettings, PolyData, KZGSettings>
         })
     }
 
-    fn data_availability(&self, p: &PolyData) -> Result<Vec<ArkG1>, String> {
+    fn data_availability(&self, p: &PolyData) -> Result<Vec<ArkG1>, Error> {
         let n = p.len();
         let n2 = n * 2;
 
         if n2 > self.ks.fs.max_width {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "n2 must be equal or less than kzg settings max width",
             ));
         }
         if !n.is_power_of_two() {
-            return Err(String::from("n2 must be power of 2"));
+            return Err(Error::NotPowerOfTwo("n2"));
         }
 
         let mut out = fk20_multi_da_opt(p, self).unwrap();
@@ -179,22 +184,22 @@ impl FK20MultiSettings<BlstFr, ArkG1, ArkG2, FFTSettings, PolyData, KZGSettings>
         Ok(out)
     }
 
-    fn data_availability_optimized(&self, p: &PolyData) -> Result<Vec<ArkG1>, String> {
+    fn data_availability_optimized(&self, p: &PolyData) -> Result<Vec<ArkG1>, Error> {
         fk20_multi_da_opt(p, self)
     }
 }
 
-fn fk20_single_da_opt(p: &PolyData, fk: &KzgFK20SingleSettings) -> Result<Vec<ArkG1>, String> {
+fn fk20_single_da_opt(p: &PolyData, fk: &KzgFK20SingleSettings) -> Result<Vec<ArkG1>, Error> {
     let n = p.len();
     let n2 = n * 2;
 
     if n2 > fk.ks.fs.max_width {
-        return Err(String::from(
+        return Err(Error::InvalidArgument(
             "n2 must be equal or less than kzg settings max width",
         ));
     }
     if !n.is_power_of_two() {
-        return Err(String::from("n2 must be power of 2"));
+        return Err(Error::NotPowerOfTwo("n2"));
     }
 
     let outlen = 2 * p.len();
@@ -205,17 +210,17 @@ fn fk20_single_da_opt(p: &PolyData, fk: &KzgFK20SingleSettings) -> Result<Vec<Ar
     fk.ks.fs.fft_g1(&h, false)
 }
 
-fn fk20_multi_da_opt(p: &PolyData, fk: &KzgFK20MultiSettings) -> Result<Vec<ArkG1>, String> {
+fn fk20_multi_da_opt(p: &PolyData, fk: &KzgFK20MultiSettings) -> Result<Vec<ArkG1>, Error> {
     let n = p.len();
     let n2 = n * 2;
 
     if n2 > fk.ks.fs.max_width {
-        return Err(String::from(
+        return Err(Error::InvalidArgument(
             "n2 must be equal or less than kzg settings max width",
         ));
     }
     if !n.is_power_of_two() {
-        return Err(String::from("n2 must be power of 2"));
+        return Err(Error::NotPowerOfTwo("n2"));
     }
 
     let n = n2 / 2;
@@ -249,7 +254,7 @@ fn fk20_multi_da_opt(p: &PolyData, fk: &KzgFK20MultiSettings) -> Result<Vec<ArkG
     fk.ks.fs.fft_g1(&h, false)
 }
 
-fn toeplitz_coeffs_step(p: &PolyData, outlen: usize) -> Result<PolyData, String> {
+fn toeplitz_coeffs_step(p: &PolyData, outlen: usize) -> Result<PolyData, Error> {
     toeplitz_coeffs_stride(p, 0, 1, outlen)
 }
 
@@ -258,18 +263,20 @@ fn toeplitz_coeffs_stride(
     offset: usize,
     stride: usize,
     outlen: usize,
-) -> Result<PolyData, String> {
+) -> Result<PolyData, Error> {
     let n = poly.len();
 
     if stride == 0 {
-        return Err(String::from("stride must be greater than 0"));
+        return Err(Error::InvalidArgument("stride must be greater than 0"));
     }
 
     let k = n / stride;
     let k2 = k * 2;
 
     if outlen < k2 {
-        return Err(String::from("outlen must be equal or greater than k2"));
+        return Err(Error::InvalidArgument(
+            "outlen must be equal or greater than k2",
+        ));
     }
 
     let mut out = PolyData::new(outlen).unwrap();
@@ -287,7 +294,7 @@ fn toeplitz_coeffs_stride(
     Ok(out)
 }
 
-fn toeplitz_part_1(x: &[ArkG1], fs: &FFTSettings) -> Result<Vec<ArkG1>, String> {
+fn toeplitz_part_1(x: &[ArkG1], fs: &FFTSettings) -> Result<Vec<ArkG1>, Error> {
     let n = x.len();
     let n2 = n * 2;
 
@@ -305,7 +312,7 @@ fn toeplitz_part_2(
     toeplitz_coeffs: &PolyData,
     x_ext_fft: &[ArkG1],
     fs: &FFTSettings,
-) -> Result<Vec<ArkG1>, String> {
+) -> Result<Vec<ArkG1>, Error> {
     let toeplitz_coeffs_fft = fs.fft_fr(&toeplitz_coeffs.coeffs, false).unwrap();
 
     #[cfg(feature = "parallel")]
@@ -327,7 +334,7 @@ fn toeplitz_part_2(
     }
 }
 
-fn toeplitz_part_3(h_ext_fft: &[ArkG1], fs: &FFTSettings) -> Result<Vec<ArkG1>, String> {
+fn toeplitz_part_3(h_ext_fft: &[ArkG1], fs: &FFTSettings) -> Result<Vec<ArkG1>, Error> {
     let n = h_ext_fft.len() / 2;
     let mut out = fs.fft_g1(h_ext_fft, true).unwrap();
 
@@ -30,7 +30,12 @@ use ark_ec::{
 use ark_ff::{BigInteger256, PrimeField};
 use ark_poly::univariate::DensePolynomial as DensePoly;
 use blst::{blst_fp, blst_fp2};
-use kzg::{FFTFr, Fr as FrTrait, Poly};
+use kzg::eip_4844::BlobPreset;
+use kzg::trusted_setup::{
+    lagrange_to_monomial, monomial_to_lagrange, verify_lagrange_setup, verify_monomial_setup,
+    SetupBasis,
+};
+use kzg::{Error, FFTFr, Fr as FrTrait, G1LinComb, Poly, PolyEval};
 use rand::rngs::StdRng;
 use std::collections::BTreeMap;
 use std::convert::TryInto;
@@ -285,12 +290,14 @@ pub struct FFTSettings {
     pub domain: Radix2EvaluationDomain<Fr>,
 }
 
-pub fn expand_root_of_unity(root: &BlstFr, width: usize) -> Result<Vec<BlstFr>, String> {
+pub fn expand_root_of_unity(root: &BlstFr, width: usize) -> Result<Vec<BlstFr>, Error> {
     let mut generated_powers = vec![BlstFr::one(), *root];
 
     while !(generated_powers.last().unwrap().is_one()) {
         if generated_powers.len() > width {
-            return Err(String::from("Root of unity multiplied for too long"));
+            return Err(Error::InvalidArgument(
+                "Root of unity multiplied for too long",
+            ));
         }
 
         generated_powers.push(generated_powers.last().unwrap().mul(root));
@@ -308,6 +315,9 @@ pub struct KZGSettings {
     pub params: UniversalParams<Bls12_381>,
     pub rand: StdRng,
     pub rand2: Randomness<Fr, UniPoly_381>,
+    pub blob_preset: BlobPreset,
+    /// Basis of `secret_g1`. `params` always holds the monomial powers.
+    pub basis: SetupBasis,
 }
 
 impl Default for KZGSettings {
@@ -320,8 +330,61 @@ impl Default for KZGSettings {
             params: KZG_Bls12_381::setup(1, false, &mut test_rng()).unwrap(),
             rand: test_rng(),
             rand2: Randomness::empty(),
+            blob_preset: BlobPreset::default(),
+            basis: SetupBasis::Monomial,
+        }
+    }
+}
+
+impl KZGSettings {
+    /// Checks that the points are powers of one secret, as described in [`kzg::trusted_setup`].
+    pub fn verify_trusted_setup(&self) -> Result<(), Error> {
+        match self.basis {
+            SetupBasis::Monomial => verify_monomial_setup(&self.secret_g1, &self.secret_g2),
+            SetupBasis::Lagrange => {
+                verify_lagrange_setup(&self.secret_g1, &self.secret_g2, &self.fs)
+            }
         }
     }
+
+    pub(crate) fn check_basis(&self, expected: SetupBasis) -> Result<(), Error> {
+        if self.basis != expected {
+            return Err(Error::SetupBasisMismatch {
+                expected,
+                actual: self.basis,
+            });
+        }
+        Ok(())
+    }
+}
+
+pub fn to_lagrange(ks: &KZGSettings) -> Result<KZGSettings, Error> {
+    if ks.basis == SetupBasis::Lagrange {
+        return Ok(ks.clone());
+    }
+    if ks.secret_g1.len() < ks.fs.max_width {
+        return Err(Error::SetupTooSmall);
+    }
+
+    Ok(KZGSettings {
+        secret_g1: monomial_to_lagrange(&ks.secret_g1[..ks.fs.max_width], &ks.fs)?,
+        length: ks.fs.max_width as u64,
+        basis: SetupBasis::Lagrange,
+        ..ks.clone()
+    })
+}
+
+pub fn to_monomial(ks: &KZGSettings) -> Result<KZGSettings, Error> {
+    if ks.basis == SetupBasis::Monomial {
+        return Ok(ks.clone());
+    }
+
+    Ok(KZGSettings {
+        secret_g1: lagrange_to_monomial(&ks.secret_g1, &ks.fs)?,
+        length: ks.secret_g1.len() as u64,
+        basis: SetupBasis::Monomial,
+        ..ks.clone()
+    })
 }
 
 fn read_be_u64(input: &mut &[u8]) -> u64 {
@@ -388,6 +451,7 @@ pub fn new_kzg_settings(
     secret_g1: &[ArkG1],
     _secret_g2: &[ArkG2],
     length: u64,
+    length_g2: usize,
     ffs: &FFTSettings,
 ) -> KZGSettings {
     let length = length + 1;
@@ -400,9 +464,11 @@ pub fn new_kzg_settings(
 
     for i in 0..length {
         temp.push(pc_g1projective_into_blst_p1(setup.g1_secret[i as usize]).unwrap());
-        temp2.push(pc_g2projective_into_blst_p2(setup.g2_secret[i as usize]).unwrap());
         temp3.push(setup.g1_secret[i as usize].into_affine());
     }
+    for i in 0..length_g2.min(length as usize) {
+        temp2.push(pc_g2projective_into_blst_p2(setup.g2_secret[i]).unwrap());
+    }
 
     setup.params.powers_of_g = temp3;
 
@@ -416,17 +482,23 @@ pub fn new_kzg_settings(
     }
 }
 
-pub fn commit_to_poly(p: &PolyData, ks: &KZGSettings) -> Result<ArkG1, String> {
+pub fn commit_to_poly(p: &PolyData, ks: &KZGSettings) -> Result<ArkG1, Error> {
+    ks.check_basis(SetupBasis::Monomial)?;
     if p.coeffs.len() > ks.length as usize {
-        Err(String::from("Poly given is too long"))
+        Err(Error::SetupTooSmall)
     } else if blst_poly_into_pc_poly(p).unwrap().is_zero() {
         Ok(G1_IDENTITY)
     } else {
-        let (powers, _) = trim(&ks.params, &ks.params.max_degree() - 1).unwrap();
-        let (com, _rand) =
-            KZG_Bls12_381::commit(&powers, &blst_poly_into_pc_poly(p).unwrap(), None, None)
-                .unwrap();
-        Ok(pc_g1projective_into_blst_p1(com.0.into_projective()).unwrap())
+        Ok(ArkG1::g1_lincomb(&ks.secret_g1, &p.coeffs, p.coeffs.len()))
+    }
+}
+
+pub fn commit_to_poly_eval(p: &PolyEval<BlstFr>, ks: &KZGSettings) -> Result<ArkG1, Error> {
+    ks.check_basis(SetupBasis::Lagrange)?;
+    if p.evals().len() > ks.secret_g1.len() {
+        Err(Error::SetupTooSmall)
+    } else {
+        Ok(ArkG1::g1_lincomb(&ks.secret_g1, p.evals(), p.evals().len()))
     }
 }
 
@@ -692,3 +764,19 @@ pub fn pairings_verify(a1: &ArkG1, a2: &ArkG2, b1: &ArkG1, b2: &ArkG2) -> bool {
     ])
     .is_one()
 }
+
+pub fn pairings_verify_multi(pairs: &[(ArkG1, ArkG2)]) -> bool {
+    let prepared: Vec<(
+        <Bls12_381 as PairingEngine>::G1Prepared,
+        <Bls12_381 as PairingEngine>::G2Prepared,
+    )> = pairs
+        .iter()
+        .map(|(p, q)| {
+            let p = blst_p1_into_pc_g1projective(&p.0).unwrap().into_affine();
+            let q = blst_p2_into_pc_g2projective(q).unwrap().into_affine();
+            (p.into(), q.into())
+        })
+        .collect();
+
+    Bls12_381::product_of_pairings(&prepared).is_one()
+}
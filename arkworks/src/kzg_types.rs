@@ -1,27 +1,33 @@
 use crate::fft::SCALE2_ROOT_OF_UNITY;
-use crate::fft_g1::{G1_GENERATOR, G1_IDENTITY, G1_NEGATIVE_GENERATOR};
+use crate::fft_g1::{g1_linear_combination, G1_GENERATOR, G1_IDENTITY, G1_NEGATIVE_GENERATOR};
 use crate::kzg_proofs::{
     check_proof_multi as check_multi, check_proof_single as check_single, commit_to_poly as commit,
-    compute_proof_multi as compute_multi, compute_proof_single as compute_single, eval_poly,
-    expand_root_of_unity, new_kzg_settings, FFTSettings as LFFTSettings,
-    KZGSettings as LKZGSettings, G2_GENERATOR, G2_NEGATIVE_GENERATOR,
+    commit_to_poly_eval as commit_eval, compute_proof_multi as compute_multi,
+    compute_proof_single as compute_single, eval_poly, expand_root_of_unity, new_kzg_settings,
+    pairings_verify, pairings_verify_multi, to_lagrange as settings_to_lagrange,
+    to_monomial as settings_to_monomial, FFTSettings as LFFTSettings, KZGSettings as LKZGSettings,
+    G2_GENERATOR, G2_NEGATIVE_GENERATOR,
 };
 use crate::poly::{poly_fast_div, poly_inverse, poly_long_div, poly_mul_direct, poly_mul_fft};
-use crate::recover::{scale_poly, unscale_poly};
 use crate::utils::PolyData as LPoly;
 use crate::utils::{
     blst_fr_into_pc_fr, blst_p1_into_pc_g1projective, blst_p2_into_pc_g2projective,
     pc_fr_into_blst_fr, pc_g1projective_into_blst_p1, pc_g2projective_into_blst_p2,
 };
-use ark_bls12_381::{g1, g2, Fr as ArkFr};
+use ark_bls12_381::{g1, g2, Fr as ArkFr, G2Affine};
 use ark_ec::models::short_weierstrass_jacobian::GroupProjective;
+use ark_ec::msm::VariableBaseMSM;
 use ark_ec::{AffineCurve, ProjectiveCurve};
-use ark_ff::{biginteger::BigInteger256, BigInteger, Field, PrimeField};
+use ark_ff::{batch_inversion, biginteger::BigInteger256, BigInteger, Field, PrimeField};
 use ark_poly::{EvaluationDomain, Radix2EvaluationDomain};
 use ark_std::{One, UniformRand, Zero};
 use blst::{blst_fr, blst_p1};
-use kzg::eip_4844::{BYTES_PER_FIELD_ELEMENT, BYTES_PER_G1, BYTES_PER_G2};
-use kzg::{FFTSettings, FFTSettingsPoly, Fr, G1Mul, G2Mul, KZGSettings, Poly, G1, G2};
+use kzg::eip_4844::{BlobPreset, BYTES_PER_FIELD_ELEMENT, BYTES_PER_G1, BYTES_PER_G2};
+use kzg::trusted_setup::SetupBasis;
+use kzg::{
+    Error, FFTSettings, FFTSettingsPoly, Fr, G1LinComb, G1Mul, G2LinComb, G2Mul, KZGSettings,
+    PairingVerify, Poly, PolyEval, G1, G2,
+};
 use kzg_bench::tests::fk20_proofs::reverse_bit_order;
 use std::ops::MulAssign;
 use std::ops::Neg;
@@ -57,15 +63,12 @@ impl G1 for ArkG1 {
     }
 
     #[allow(clippy::bind_instead_of_map)]
-    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         bytes
             .try_into()
-            .map_err(|_| {
-                format!(
-                    "Invalid byte length. Expected {}, got {}",
-                    BYTES_PER_G1,
-                    bytes.len()
-                )
+            .map_err(|_| Error::InvalidLength {
+                expected: BYTES_PER_G1,
+                actual: bytes.len(),
             })
             .and_then(|bytes: &[u8; BYTES_PER_G1]| {
                 let affine = g1::G1Affine::from_random_bytes(bytes.as_slice()).unwrap();
@@ -74,7 +77,7 @@ impl G1 for ArkG1 {
             })
     }
 
-    fn from_hex(hex: &str) -> Result<Self, String> {
+    fn from_hex(hex: &str) -> Result<Self, Error> {
         let bytes = hex::decode(&hex[2..]).unwrap();
         Self::from_bytes(&bytes)
     }
@@ -133,8 +136,59 @@ impl G1Mul<FsFr> for ArkG1 {
     }
 }
 
+impl G1LinComb<FsFr> for ArkG1 {
+    fn g1_lincomb(points: &[Self], scalars: &[FsFr], len: usize) -> Self {
+        let mut out = ArkG1::default();
+        g1_linear_combination(&mut out, &points[..len], &scalars[..len], len);
+        out
+    }
+}
+
+impl PairingVerify<ArkG1, ArkG2> for ArkG1 {
+    fn verify(a1: &ArkG1, a2: &ArkG2, b1: &ArkG1, b2: &ArkG2) -> bool {
+        pairings_verify(a1, a2, b1, b2)
+    }
+
+    fn verify_multi(pairs: &[(ArkG1, ArkG2)]) -> bool {
+        pairings_verify_multi(pairs)
+    }
+}
+
 impl Copy for ArkG1 {}
 
+// `G1::to_bytes` above does not produce the compressed encoding, so serde goes through blst
+#[cfg(feature = "serde")]
+fn g1_to_compressed(g1: &ArkG1) -> [u8; BYTES_PER_G1] {
+    let mut out = [0u8; BYTES_PER_G1];
+    unsafe {
+        blst::blst_p1_compress(out.as_mut_ptr(), &g1.0);
+    }
+    out
+}
+
+#[cfg(feature = "serde")]
+fn g1_from_compressed(bytes: &[u8]) -> Result<ArkG1, Error> {
+    let bytes: &[u8; BYTES_PER_G1] = bytes.try_into().map_err(|_| Error::InvalidLength {
+        expected: BYTES_PER_G1,
+        actual: bytes.len(),
+    })?;
+    let mut affine = blst::blst_p1_affine::default();
+    let mut g1 = blst_p1::default();
+    unsafe {
+        if blst::blst_p1_uncompress(&mut affine, bytes.as_ptr()) != blst::BLST_ERROR::BLST_SUCCESS {
+            return Err(Error::InvalidPoint);
+        }
+        if !blst::blst_p1_affine_in_g1(&affine) {
+            return Err(Error::PointNotInSubgroup);
+        }
+        blst::blst_p1_from_affine(&mut g1, &affine);
+    }
+    Ok(ArkG1(g1))
+}
+
+#[cfg(feature = "serde")]
+kzg::impl_serde_bytes!(ArkG1, g1_to_compressed, g1_from_compressed);
+
 #[derive(Debug, Default)]
 pub struct ArkG2(pub blst::blst_p2);
 
@@ -144,6 +198,38 @@ impl Clone for ArkG2 {
     }
 }
 
+#[cfg(feature = "serde")]
+fn g2_to_compressed(g2: &ArkG2) -> [u8; BYTES_PER_G2] {
+    let mut out = [0u8; BYTES_PER_G2];
+    unsafe {
+        blst::blst_p2_compress(out.as_mut_ptr(), &g2.0);
+    }
+    out
+}
+
+#[cfg(feature = "serde")]
+fn g2_from_compressed(bytes: &[u8]) -> Result<ArkG2, Error> {
+    let bytes: &[u8; BYTES_PER_G2] = bytes.try_into().map_err(|_| Error::InvalidLength {
+        expected: BYTES_PER_G2,
+        actual: bytes.len(),
+    })?;
+    let mut affine = blst::blst_p2_affine::default();
+    let mut g2 = blst::blst_p2::default();
+    unsafe {
+        if blst::blst_p2_uncompress(&mut affine, bytes.as_ptr()) != blst::BLST_ERROR::BLST_SUCCESS {
+            return Err(Error::InvalidPoint);
+        }
+        if !blst::blst_p2_affine_in_g2(&affine) {
+            return Err(Error::PointNotInSubgroup);
+        }
+        blst::blst_p2_from_affine(&mut g2, &affine);
+    }
+    Ok(ArkG2(g2))
+}
+
+#[cfg(feature = "serde")]
+kzg::impl_serde_bytes!(ArkG2, g2_to_compressed, g2_from_compressed);
+
 impl G2 for ArkG2 {
     fn generator() -> Self {
         G2_GENERATOR
@@ -154,15 +240,12 @@ impl G2 for ArkG2 {
     }
 
     #[allow(clippy::bind_instead_of_map)]
-    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         bytes
             .try_into()
-            .map_err(|_| {
-                format!(
-                    "Invalid byte length. Expected {}, got {}",
-                    BYTES_PER_G2,
-                    bytes.len()
-                )
+            .map_err(|_| Error::InvalidLength {
+                expected: BYTES_PER_G2,
+                actual: bytes.len(),
             })
             .and_then(|bytes: &[u8; BYTES_PER_G2]| {
                 let affine = g2::G2Affine::from_random_bytes(bytes.as_slice()).unwrap();
@@ -208,9 +291,32 @@ impl G2Mul<FsFr> for ArkG2 {
     }
 }
 
+impl G2LinComb<FsFr> for ArkG2 {
+    fn g2_lincomb(points: &[Self], scalars: &[FsFr], len: usize) -> Self {
+        let ark_points: Vec<G2Affine> = points[..len]
+            .iter()
+            .map(|point| blst_p2_into_pc_g2projective(point).unwrap().into_affine())
+            .collect();
+        let ark_scalars: Vec<BigInteger256> = scalars[..len]
+            .iter()
+            .map(|scalar| blst_fr_into_pc_fr(scalar).into_repr())
+            .collect();
+
+        let res = VariableBaseMSM::multi_scalar_mul(ark_points.as_slice(), ark_scalars.as_slice());
+        pc_g2projective_into_blst_p2(res).unwrap()
+    }
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
 pub struct FsFr(pub blst_fr);
 
+#[cfg(feature = "serde")]
+kzg::impl_serde_bytes!(
+    FsFr,
+    Fr::to_bytes,
+    kzg::serde_utils::fr_from_canonical_bytes::<FsFr>
+);
+
 impl Fr for FsFr {
     fn null() -> Self {
         FsFr(blst_fr {
@@ -237,15 +343,12 @@ impl Fr for FsFr {
     }
 
     #[allow(clippy::bind_instead_of_map)]
-    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         bytes
             .try_into()
-            .map_err(|_| {
-                format!(
-                    "Invalid byte length. Expected {}, got {}",
-                    BYTES_PER_FIELD_ELEMENT,
-                    bytes.len()
-                )
+            .map_err(|_| Error::InvalidLength {
+                expected: BYTES_PER_FIELD_ELEMENT,
+                actual: bytes.len(),
             })
             .and_then(|bytes: &[u8; BYTES_PER_FIELD_ELEMENT]| {
                 let ark_fr = ArkFr::from_random_bytes(bytes.as_slice());
@@ -257,7 +360,19 @@ impl Fr for FsFr {
             })
     }
 
-    fn from_hex(hex: &str) -> Result<Self, String> {
+    fn from_bytes_unchecked(bytes: &[u8]) -> Result<Self, Error> {
+        bytes
+            .try_into()
+            .map_err(|_| Error::InvalidLength {
+                expected: BYTES_PER_FIELD_ELEMENT,
+                actual: bytes.len(),
+            })
+            .map(|bytes: &[u8; BYTES_PER_FIELD_ELEMENT]| {
+                pc_fr_into_blst_fr(ArkFr::from_le_bytes_mod_order(bytes))
+            })
+    }
+
+    fn from_hex(hex: &str) -> Result<Self, Error> {
         let bytes = hex::decode(&hex[2..]).unwrap();
         Self::from_bytes(&bytes)
     }
@@ -343,7 +458,7 @@ impl Fr for FsFr {
         pc_fr_into_blst_fr(blst_fr_into_pc_fr(self).pow([n as u64]))
     }
 
-    fn div(&self, b: &Self) -> Result<Self, String> {
+    fn div(&self, b: &Self) -> Result<Self, Error> {
         let a = blst_fr_into_pc_fr(self);
         let b = blst_fr_into_pc_fr(b);
         let div = a / b;
@@ -357,10 +472,18 @@ impl Fr for FsFr {
     fn equals(&self, b: &Self) -> bool {
         blst_fr_into_pc_fr(self) == blst_fr_into_pc_fr(b)
     }
+
+    fn batch_inverse(values: &mut [Self]) {
+        let mut converted = values.iter().map(blst_fr_into_pc_fr).collect::<Vec<_>>();
+        batch_inversion(&mut converted);
+        for (value, inverse) in values.iter_mut().zip(converted) {
+            *value = pc_fr_into_blst_fr(inverse);
+        }
+    }
 }
 
 impl Poly<FsFr> for LPoly {
-    fn new(size: usize) -> Result<Self, String> {
+    fn new(size: usize) -> Result<Self, Error> {
         Ok(Self {
             coeffs: vec![FsFr::default(); size],
         })
@@ -387,18 +510,30 @@ impl Poly<FsFr> for LPoly {
     }
 
     fn scale(&mut self) {
-        scale_poly(self);
+        let inv_factor = FsFr::from_u64(SCALE_FACTOR).inverse();
+
+        let mut factor_power = FsFr::one();
+        for coeff in self.coeffs.iter_mut().skip(1) {
+            factor_power = factor_power.mul(&inv_factor);
+            *coeff = coeff.mul(&factor_power);
+        }
     }
 
     fn unscale(&mut self) {
-        unscale_poly(self);
+        let scale_factor = FsFr::from_u64(SCALE_FACTOR);
+
+        let mut factor_power = FsFr::one();
+        for coeff in self.coeffs.iter_mut().skip(1) {
+            factor_power = factor_power.mul(&scale_factor);
+            *coeff = coeff.mul(&factor_power);
+        }
     }
 
-    fn inverse(&mut self, new_len: usize) -> Result<Self, String> {
+    fn inverse(&mut self, new_len: usize) -> Result<Self, Error> {
         poly_inverse(self, new_len)
     }
 
-    fn div(&mut self, x: &Self) -> Result<Self, String> {
+    fn div(&mut self, x: &Self) -> Result<Self, Error> {
         if x.len() >= self.len() || x.len() < 128 {
             poly_long_div(self, x)
         } else {
@@ -406,15 +541,15 @@ impl Poly<FsFr> for LPoly {
         }
     }
 
-    fn long_div(&mut self, x: &Self) -> Result<Self, String> {
+    fn long_div(&mut self, x: &Self) -> Result<Self, Error> {
         poly_long_div(self, x)
     }
 
-    fn fast_div(&mut self, x: &Self) -> Result<Self, String> {
+    fn fast_div(&mut self, x: &Self) -> Result<Self, Error> {
         poly_fast_div(self, x)
     }
 
-    fn mul_direct(&mut self, x: &Self, len: usize) -> Result<Self, String> {
+    fn mul_direct(&mut self, x: &Self, len: usize) -> Result<Self, Error> {
         poly_mul_direct(self, x, len)
     }
 }
@@ -425,7 +560,7 @@ impl FFTSettingsPoly<FsFr, LPoly, LFFTSettings> for LFFTSettings {
         x: &LPoly,
         len: usize,
         fs: Option<&LFFTSettings>,
-    ) -> Result<LPoly, String> {
+    ) -> Result<LPoly, Error> {
         poly_mul_fft(a, x, fs, len)
     }
 }
@@ -444,9 +579,9 @@ impl Default for LFFTSettings {
 }
 
 impl FFTSettings<FsFr> for LFFTSettings {
-    fn new(scale: usize) -> Result<LFFTSettings, String> {
+    fn new(scale: usize) -> Result<LFFTSettings, Error> {
         if scale >= SCALE2_ROOT_OF_UNITY.len() {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "Scale is expected to be within root of unity matrix row size",
             ));
         }
@@ -507,17 +642,40 @@ impl KZGSettings<FsFr, ArkG1, ArkG2, LFFTSettings, LPoly> for LKZGSettings {
     fn new(
         secret_g1: &[ArkG1],
         secret_g2: &[ArkG2],
-        length: usize,
+        length_g1: usize,
+        length_g2: usize,
         fs: &LFFTSettings,
-    ) -> Result<LKZGSettings, String> {
-        Ok(new_kzg_settings(secret_g1, secret_g2, length as u64, fs))
+    ) -> Result<LKZGSettings, Error> {
+        if secret_g1.len() < length_g1 || secret_g2.len() < length_g2 {
+            return Err(Error::SetupTooSmall);
+        } else if length_g1 < fs.max_width {
+            return Err(Error::InvalidArgument(
+                "length_g1 must be equal to or greater than number of fft_settings roots",
+            ));
+        } else if length_g2 < 2 {
+            return Err(Error::InvalidArgument("length_g2 must be at least 2"));
+        }
+
+        Ok(new_kzg_settings(
+            secret_g1,
+            secret_g2,
+            length_g1 as u64,
+            length_g2,
+            fs,
+        ))
     }
 
-    fn commit_to_poly(&self, p: &LPoly) -> Result<ArkG1, String> {
+    fn commit_to_poly(&self, p: &LPoly) -> Result<ArkG1, Error> {
+        self.check_basis(SetupBasis::Monomial)?;
         Ok(commit(p, self).unwrap())
     }
 
-    fn compute_proof_single(&self, p: &LPoly, x: &FsFr) -> Result<ArkG1, String> {
+    fn commit_to_poly_eval(&self, p: &PolyEval<FsFr>) -> Result<ArkG1, Error> {
+        commit_eval(p, self)
+    }
+
+    fn compute_proof_single(&self, p: &LPoly, x: &FsFr) -> Result<ArkG1, Error> {
+        self.check_basis(SetupBasis::Monomial)?;
         Ok(compute_single(p, x, self))
     }
 
@@ -527,11 +685,12 @@ impl KZGSettings<FsFr, ArkG1, ArkG2, LFFTSettings, LPoly> for LKZGSettings {
         proof: &ArkG1,
         x: &FsFr,
         value: &FsFr,
-    ) -> Result<bool, String> {
+    ) -> Result<bool, Error> {
         Ok(check_single(com, proof, x, value, self))
     }
 
-    fn compute_proof_multi(&self, p: &LPoly, x: &FsFr, n: usize) -> Result<ArkG1, String> {
+    fn compute_proof_multi(&self, p: &LPoly, x: &FsFr, n: usize) -> Result<ArkG1, Error> {
+        self.check_basis(SetupBasis::Monomial)?;
         Ok(compute_multi(p, x, n, self))
     }
 
@@ -542,7 +701,12 @@ impl KZGSettings<FsFr, ArkG1, ArkG2, LFFTSettings, LPoly> for LKZGSettings {
         x: &FsFr,
         values: &[FsFr],
         n: usize,
-    ) -> Result<bool, String> {
+    ) -> Result<bool, Error> {
+        if n >= self.secret_g2.len() {
+            return Err(Error::SetupTooSmall);
+        }
+        self.check_basis(SetupBasis::Monomial)?;
+
         Ok(check_multi(com, proof, x, values, n, self))
     }
 
@@ -553,4 +717,32 @@ impl KZGSettings<FsFr, ArkG1, ArkG2, LFFTSettings, LPoly> for LKZGSettings {
     fn get_roots_of_unity_at(&self, i: usize) -> FsFr {
         self.fs.get_roots_of_unity_at(i)
     }
+
+    fn get_fft_settings(&self) -> &LFFTSettings {
+        &self.fs
+    }
+
+    fn get_g1_secret(&self) -> &[ArkG1] {
+        &self.secret_g1
+    }
+
+    fn get_g2_secret(&self) -> &[ArkG2] {
+        &self.secret_g2
+    }
+
+    fn get_blob_preset(&self) -> &BlobPreset {
+        &self.blob_preset
+    }
+
+    fn get_basis(&self) -> SetupBasis {
+        self.basis
+    }
+
+    fn to_lagrange(&self) -> Result<Self, Error> {
+        settings_to_lagrange(self)
+    }
+
+    fn to_monomial(&self) -> Result<Self, Error> {
+        settings_to_monomial(self)
+    }
 }
@@ -9,7 +9,7 @@ use ark_bls12_381::Fr;
 use ark_poly::univariate::DensePolynomial;
 use ark_poly::UVPolynomial;
 use ark_std::{log2, Zero};
-use kzg::{FFTFr, FFTSettings as FFTSettingsT, Fr as FrTrait, Poly};
+use kzg::{Error, FFTFr, FFTSettings as FFTSettingsT, Fr as FrTrait, Poly};
 use merkle_light::merkle::log2_pow2;
 use std::cmp::min;
 use std::ops::Neg;
@@ -18,13 +18,13 @@ fn neg(n: BlstFr) -> BlstFr {
     pc_fr_into_blst_fr(blst_fr_into_pc_fr(&n).neg())
 }
 
-pub fn poly_inverse(b: &PolyData, output_len: usize) -> Result<PolyData, String> {
+pub fn poly_inverse(b: &PolyData, output_len: usize) -> Result<PolyData, Error> {
     if b.coeffs.is_empty() {
-        return Err(String::from("b.coeffs is empty"));
+        return Err(Error::DivisionByZero);
     }
 
     if BlstFr::is_zero(&b.coeffs[0]) {
-        return Err(String::from("b.coeffs[0] is zero"));
+        return Err(Error::InvalidArgument("b.coeffs[0] is zero"));
     }
 
     let mut output = PolyData {
@@ -75,12 +75,12 @@ pub fn poly_inverse(b: &PolyData, output_len: usize) -> Result<PolyData, String>
         }
     }
     if d + 1 != output_len {
-        return Err(String::from("d + 1 is not equals to output_len"));
+        return Err(Error::Internal("d + 1 is not equals to output_len"));
     }
     Ok(output)
 }
 
-pub fn poly_mul_direct(p1: &PolyData, p2: &PolyData, len: usize) -> Result<PolyData, String> {
+pub fn poly_mul_direct(p1: &PolyData, p2: &PolyData, len: usize) -> Result<PolyData, Error> {
     let p1 = blst_poly_into_pc_poly(p1).unwrap();
     let p2 = blst_poly_into_pc_poly(p2).unwrap();
     if p1.is_zero() || p2.is_zero() {
@@ -102,7 +102,7 @@ pub fn poly_mul_direct(p1: &PolyData, p2: &PolyData, len: usize) -> Result<PolyD
     }
 }
 
-pub fn poly_long_div(p1: &PolyData, p2: &PolyData) -> Result<PolyData, String> {
+pub fn poly_long_div(p1: &PolyData, p2: &PolyData) -> Result<PolyData, Error> {
     pc_poly_into_blst_poly(
         &blst_poly_into_pc_poly(p1).unwrap() / &blst_poly_into_pc_poly(p2).unwrap(),
     )
@@ -113,7 +113,7 @@ pub fn poly_mul(
     b: &PolyData,
     fs: Option<&FFTSettings>,
     len: usize,
-) -> Result<PolyData, String> {
+) -> Result<PolyData, Error> {
     if a.coeffs.len() < 64 || b.coeffs.len() < 64 || len < 128 {
         poly_mul_direct(a, b, len)
     } else {
@@ -126,7 +126,7 @@ pub fn poly_mul_fft(
     b: &PolyData,
     fs: Option<&FFTSettings>,
     len: usize,
-) -> Result<PolyData, String> {
+) -> Result<PolyData, Error> {
     // Truncate a and b so as not to do excess work for the number of coefficients required.
     let a_len = min(a.len(), len);
     let b_len = min(b.len(), len);
@@ -141,7 +141,7 @@ pub fn poly_mul_fft(
     };
 
     if length > fs_p.max_width {
-        return Err(String::from(
+        return Err(Error::InvalidArgument(
             "length should be equals or less than FFTSettings max width",
         ));
     }
@@ -206,13 +206,13 @@ pub fn poly_mul_fft(
     Ok(out)
 }
 
-pub fn poly_fast_div(dividend: &PolyData, divisor: &PolyData) -> Result<PolyData, String> {
+pub fn poly_fast_div(dividend: &PolyData, divisor: &PolyData) -> Result<PolyData, Error> {
     if divisor.coeffs.is_empty() {
-        return Err(String::from("divisor coeffs are empty"));
+        return Err(Error::DivisionByZero);
     }
 
     if divisor.coeffs[divisor.coeffs.len() - 1].is_zero() {
-        return Err(String::from("divisor coeffs last member is zero"));
+        return Err(Error::InvalidArgument("divisor coeffs last member is zero"));
     }
 
     let m = dividend.coeffs.len() - 1;
@@ -223,7 +223,7 @@ pub fn poly_fast_div(dividend: &PolyData, divisor: &PolyData) -> Result<PolyData
     }
 
     if divisor.coeffs[divisor.coeffs.len() - 1].is_zero() {
-        return Err(String::from("divisor coeffs last member is zero"));
+        return Err(Error::InvalidArgument("divisor coeffs last member is zero"));
     }
 
     let mut out = PolyData::new(0).unwrap();
@@ -249,7 +249,7 @@ pub fn poly_fast_div(dividend: &PolyData, divisor: &PolyData) -> Result<PolyData
     })
 }
 
-pub fn poly_flip(input: &PolyData) -> Result<PolyData, String> {
+pub fn poly_flip(input: &PolyData) -> Result<PolyData, Error> {
     let mut output = PolyData::new(0).unwrap();
     for i in 0..input.len() {
         output.coeffs.push(input.coeffs[input.coeffs.len() - i - 1]);
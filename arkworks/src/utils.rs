@@ -14,7 +14,7 @@ pub struct PolyData {
     pub coeffs: Vec<BlstFr>,
 }
 
-pub fn pc_poly_into_blst_poly(poly: DensePoly<Fr>) -> Result<PolyData, String> {
+pub fn pc_poly_into_blst_poly(poly: DensePoly<Fr>) -> Result<PolyData, kzg::Error> {
     let mut bls_pol = PolyData { coeffs: Vec::new() };
     for x in poly.coeffs {
         bls_pol.coeffs.push(pc_fr_into_blst_fr(x));
@@ -22,7 +22,7 @@ pub fn pc_poly_into_blst_poly(poly: DensePoly<Fr>) -> Result<PolyData, String> {
     Ok(bls_pol)
 }
 
-pub fn blst_poly_into_pc_poly(pd: &PolyData) -> Result<DensePoly<Fr>, String> {
+pub fn blst_poly_into_pc_poly(pd: &PolyData) -> Result<DensePoly<Fr>, kzg::Error> {
     let mut poly = Vec::new();
     let x = pd.coeffs.clone();
     for x in x {
@@ -4,11 +4,11 @@ use super::utils::{
     PolyData,
 };
 use crate::kzg_types::FsFr as BlstFr;
-use kzg::{FFTFr, Fr as FrTrait, ZeroPoly};
+use kzg::{Error, FFTFr, Fr as FrTrait, ZeroPoly};
 use std::cmp::{min, Ordering};
 use std::ops::Neg;
 
-pub(crate) fn pad_poly(poly: &PolyData, new_length: usize) -> Result<Vec<BlstFr>, String> {
+pub(crate) fn pad_poly(poly: &PolyData, new_length: usize) -> Result<Vec<BlstFr>, Error> {
     if new_length <= poly.coeffs.len() {
         return Ok(poly.coeffs.clone());
     }
@@ -27,9 +27,9 @@ impl ZeroPoly<BlstFr, PolyData> for FFTSettings {
         &self,
         indices: &[usize],
         stride: usize,
-    ) -> Result<PolyData, String> {
+    ) -> Result<PolyData, Error> {
         if indices.is_empty() {
-            return Err(String::from("idx array must be non-zero"));
+            return Err(Error::InvalidArgument("idx array must be non-zero"));
         }
         let blstpoly = PolyData {
             coeffs: vec![BlstFr::one(); indices.len() + 1],
@@ -56,14 +56,14 @@ impl ZeroPoly<BlstFr, PolyData> for FFTSettings {
         Ok(pc_poly_into_blst_poly(poly).unwrap())
     }
 
-    fn reduce_partials(&self, len_out: usize, partials: &[PolyData]) -> Result<PolyData, String> {
+    fn reduce_partials(&self, len_out: usize, partials: &[PolyData]) -> Result<PolyData, Error> {
         let mut out_degree: usize = 0;
         for partial in partials {
             out_degree += partial.coeffs.len() - 1;
         }
 
         if out_degree + 1 > len_out {
-            return Err(String::from("Expected domain size to be a power of 2"));
+            return Err(Error::NotPowerOfTwo("domain size"));
         }
 
         let mut p_partial = pad_poly(&partials[0], len_out).unwrap();
@@ -93,7 +93,7 @@ impl ZeroPoly<BlstFr, PolyData> for FFTSettings {
         &self,
         length: usize,
         missing_indices: &[usize],
-    ) -> Result<(Vec<BlstFr>, PolyData), String> {
+    ) -> Result<(Vec<BlstFr>, PolyData), Error> {
         let zero_eval: Vec<BlstFr>;
         let mut zero_poly: PolyData;
 
@@ -104,13 +104,15 @@ impl ZeroPoly<BlstFr, PolyData> for FFTSettings {
         }
 
         if missing_indices.len() >= length {
-            return Err(String::from("Missing idxs greater than domain size"));
+            return Err(Error::InvalidArgument(
+                "Missing idxs greater than domain size",
+            ));
         } else if length > self.max_width as usize {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "Domain size greater than fft_settings.max_width",
             ));
         } else if !length.is_power_of_two() {
-            return Err(String::from("Domain size must be a power of 2"));
+            return Err(Error::NotPowerOfTwo("domain size"));
         }
 
         let degree_of_partial = 256;
@@ -56,6 +56,16 @@ mod tests {
         fr_uint64s_roundtrip::<FsFr>();
     }
 
+    #[test]
+    pub fn fr_batch_inverse_works_() {
+        fr_batch_inverse_works::<FsFr>();
+    }
+
+    #[test]
+    pub fn fr_batch_mul_and_inner_product_work_() {
+        fr_batch_mul_and_inner_product_work::<FsFr>();
+    }
+
     #[test]
     pub fn p1_mul_works_() {
         p1_mul_works::<FsFr, ArkG1>();
@@ -101,11 +111,26 @@ mod tests {
         g1_random_linear_combination::<FsFr, ArkG1>(&g1_linear_combination);
     }
 
+    #[test]
+    pub fn g1_lincomb_matches_naive_() {
+        g1_lincomb_matches_naive::<FsFr, ArkG1>();
+    }
+
+    #[test]
+    pub fn g2_lincomb_matches_naive_() {
+        g2_lincomb_matches_naive::<FsFr, ArkG2>();
+    }
+
     #[test]
     pub fn pairings_work_() {
         pairings_work::<FsFr, ArkG1, ArkG2>(&pairings_verify);
     }
 
+    #[test]
+    pub fn pairings_verify_multi_works_() {
+        pairings_verify_multi_works::<FsFr, ArkG1, ArkG2>();
+    }
+
     #[test]
     pub fn fr_is_null_works_() {
         fr_is_null_works::<FsFr>();
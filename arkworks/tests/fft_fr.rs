@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod tests {
-    use kzg_bench::tests::fft_fr::{compare_sft_fft, inverse_fft, roundtrip_fft, stride_fft};
+    use kzg_bench::tests::fft_fr::{
+        compare_sft_fft, coset_fft, inverse_fft, roundtrip_fft, stride_fft,
+    };
     use rust_kzg_arkworks::fft::{fft_fr_fast, fft_fr_slow};
     use rust_kzg_arkworks::kzg_proofs::FFTSettings;
     use rust_kzg_arkworks::kzg_types::FsFr;
@@ -24,4 +26,9 @@ mod tests {
     fn stride_fft_() {
         stride_fft::<FsFr, FFTSettings>();
     }
+
+    #[test]
+    fn coset_fft_() {
+        coset_fft::<FsFr, FFTSettings>();
+    }
 }
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use kzg_bench::tests::fft_g1::{compare_sft_fft, roundtrip_fft, stride_fft};
+    use kzg_bench::tests::fft_g1::{compare_sft_fft, coset_fft, roundtrip_fft, stride_fft};
     use rust_kzg_arkworks::fft_g1::{fft_g1_fast, fft_g1_slow, make_data};
     use rust_kzg_arkworks::kzg_proofs::FFTSettings;
     use rust_kzg_arkworks::kzg_types::{ArkG1, FsFr};
@@ -19,4 +19,9 @@ mod tests {
     fn compare_sft_fft_() {
         compare_sft_fft::<FsFr, ArkG1, FFTSettings>(&fft_g1_fast, &fft_g1_slow, &make_data);
     }
+
+    #[test]
+    fn coset_fft_() {
+        coset_fft::<FsFr, ArkG1, FFTSettings>();
+    }
 }
@@ -1,7 +1,8 @@
 #[cfg(test)]
 mod tests {
     use kzg_bench::tests::kzg_proofs::{
-        commit_to_nil_poly, commit_to_too_long_poly, proof_multi, proof_single,
+        commit_to_nil_poly, commit_to_too_long_poly, proof_multi, proof_multi_with_few_g2_points,
+        proof_single, setup_basis_conversion,
     };
     use rust_kzg_arkworks::kzg_proofs::{generate_trusted_setup, FFTSettings, KZGSettings};
     use rust_kzg_arkworks::kzg_types::{ArkG1, ArkG2, FsFr};
@@ -20,7 +21,7 @@ mod tests {
         );
     }
     #[test]
-    #[should_panic(expected = "Poly given is too long")]
+    #[should_panic(expected = "SetupTooSmall")]
     fn commit_to_too_long_poly_() {
         commit_to_too_long_poly::<FsFr, ArkG1, ArkG2, PolyData, FFTSettings, KZGSettings>(
             &generate_trusted_setup,
@@ -33,4 +34,17 @@ mod tests {
             &generate_trusted_setup,
         );
     }
+
+    #[test]
+    fn proof_multi_with_few_g2_points_() {
+        proof_multi_with_few_g2_points::<FsFr, ArkG1, ArkG2, PolyData, FFTSettings, KZGSettings>(
+            &generate_trusted_setup,
+        );
+    }
+    #[test]
+    fn setup_basis_conversion_() {
+        setup_basis_conversion::<FsFr, ArkG1, ArkG2, PolyData, FFTSettings, KZGSettings>(
+            &generate_trusted_setup,
+        );
+    }
 }
@@ -1,10 +1,12 @@
 #[cfg(test)]
 mod tests {
     use kzg_bench::tests::poly::{
-        create_poly_of_length_ten, poly_div_by_zero, poly_div_fast_test, poly_div_long_test,
-        poly_div_random, poly_eval_0_check, poly_eval_check, poly_eval_nil_check,
-        poly_inverse_simple_0, poly_inverse_simple_1, poly_mul_direct_test, poly_mul_fft_test,
-        poly_mul_random, poly_test_div,
+        create_poly_of_length_ten, poly_add_sub_test, poly_compose_x_pow_test,
+        poly_derivative_test, poly_div_by_zero, poly_div_fast_test, poly_div_long_test,
+        poly_div_random, poly_div_rem_test, poly_eval_0_check, poly_eval_check,
+        poly_eval_form_div_test, poly_eval_form_test, poly_eval_multi_test, poly_eval_nil_check,
+        poly_interpolate_test, poly_inverse_simple_0, poly_inverse_simple_1, poly_mul_direct_test,
+        poly_mul_fft_test, poly_mul_random, poly_test_div,
     };
     use rust_kzg_arkworks::kzg_proofs::FFTSettings;
     use rust_kzg_arkworks::kzg_types::FsFr;
@@ -80,4 +82,44 @@ mod tests {
     fn poly_div_fast_test_() {
         poly_div_fast_test::<FsFr, PolyData>()
     }
+
+    #[test]
+    fn poly_add_sub_test_() {
+        poly_add_sub_test::<FsFr, PolyData>();
+    }
+
+    #[test]
+    fn poly_derivative_test_() {
+        poly_derivative_test::<FsFr, PolyData>();
+    }
+
+    #[test]
+    fn poly_compose_x_pow_test_() {
+        poly_compose_x_pow_test::<FsFr, PolyData>();
+    }
+
+    #[test]
+    fn poly_div_rem_test_() {
+        poly_div_rem_test::<FsFr, PolyData>();
+    }
+
+    #[test]
+    fn poly_eval_multi_test_() {
+        poly_eval_multi_test::<FsFr, PolyData>();
+    }
+
+    #[test]
+    fn poly_interpolate_test_() {
+        poly_interpolate_test::<FsFr, PolyData>();
+    }
+
+    #[test]
+    fn poly_eval_form_test_() {
+        poly_eval_form_test::<FsFr, PolyData, FFTSettings>();
+    }
+
+    #[test]
+    fn poly_eval_form_div_test_() {
+        poly_eval_form_div_test::<FsFr, PolyData, FFTSettings>();
+    }
 }
@@ -20,4 +20,14 @@ mod recover_tests {
     fn more_than_half_missing_() {
         more_than_half_missing::<Fr, FFTSettings, PolyData, PolyData>();
     }
+
+    #[test]
+    fn correct_errors_simple_() {
+        correct_errors_simple::<Fr, FFTSettings, PolyData, PolyData>();
+    }
+
+    #[test]
+    fn correct_errors_too_many_() {
+        correct_errors_too_many::<Fr, FFTSettings, PolyData, PolyData>();
+    }
 }
@@ -0,0 +1,33 @@
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use kzg_bench::tests::serialization::{
+        fr_serde_rejects_invalid_test, fr_serde_round_trip_test, g1_serde_rejects_invalid_test,
+        g1_serde_round_trip_test, g2_serde_round_trip_test,
+    };
+    use rust_kzg_arkworks::kzg_types::{ArkG1, ArkG2, FsFr};
+
+    #[test]
+    fn fr_serde_round_trip_test_() {
+        fr_serde_round_trip_test::<FsFr>();
+    }
+
+    #[test]
+    fn fr_serde_rejects_invalid_test_() {
+        fr_serde_rejects_invalid_test::<FsFr>();
+    }
+
+    #[test]
+    fn g1_serde_round_trip_test_() {
+        g1_serde_round_trip_test::<ArkG1>();
+    }
+
+    #[test]
+    fn g1_serde_rejects_invalid_test_() {
+        g1_serde_rejects_invalid_test::<ArkG1>();
+    }
+
+    #[test]
+    fn g2_serde_round_trip_test_() {
+        g2_serde_round_trip_test::<ArkG2>();
+    }
+}
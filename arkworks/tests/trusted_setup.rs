@@ -0,0 +1,19 @@
+#[cfg(test)]
+mod tests {
+    use kzg_bench::tests::trusted_setup::{
+        powers_of_tau_ceremony_test, powers_of_tau_tampered_transcript_test,
+    };
+    use rust_kzg_arkworks::kzg_proofs::{FFTSettings, KZGSettings};
+    use rust_kzg_arkworks::kzg_types::{ArkG1, ArkG2, FsFr};
+    use rust_kzg_arkworks::utils::PolyData;
+
+    #[test]
+    fn powers_of_tau_ceremony_test_() {
+        powers_of_tau_ceremony_test::<FsFr, ArkG1, ArkG2, PolyData, FFTSettings, KZGSettings>();
+    }
+
+    #[test]
+    fn powers_of_tau_tampered_transcript_test_() {
+        powers_of_tau_tampered_transcript_test::<FsFr, ArkG1, ArkG2>();
+    }
+}
@@ -0,0 +1,29 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use kzg::{Fr, G1};
+use rust_kzg_blst::types::fixed_base_msm::FsFixedBaseMsm;
+use rust_kzg_blst::types::fr::FsFr;
+use rust_kzg_blst::types::g1::FsG1;
+
+fn bench_fixed_base_msm_(c: &mut Criterion) {
+    const NUM_POINTS: usize = 4096;
+
+    let points: Vec<FsG1> = (0..NUM_POINTS).map(|_| FsG1::rand()).collect();
+    let scalars: Vec<FsFr> = (0..NUM_POINTS).map(|_| FsFr::rand()).collect();
+
+    for wbits in [4, 8, 12, 13] {
+        let msm = FsFixedBaseMsm::new(&points, wbits).unwrap();
+        let id = format!(
+            "bench_fixed_base_msm points: '{}', window: '{}'",
+            NUM_POINTS, wbits
+        );
+        c.bench_function(&id, |b| b.iter(|| msm.multiply(&scalars).unwrap()));
+    }
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = bench_fixed_base_msm_
+}
+
+criterion_main!(benches);
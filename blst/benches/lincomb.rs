@@ -1,17 +1,21 @@
 use criterion::{criterion_group, criterion_main, Criterion};
-use kzg_bench::benches::lincomb::bench_g1_lincomb;
-use rust_kzg_blst::kzg_proofs::g1_linear_combination;
+use kzg_bench::benches::lincomb::{bench_g1_lincomb, bench_g2_lincomb};
 use rust_kzg_blst::types::fr::FsFr;
 use rust_kzg_blst::types::g1::FsG1;
+use rust_kzg_blst::types::g2::FsG2;
 
 fn bench_g1_lincomb_(c: &mut Criterion) {
-    bench_g1_lincomb::<FsFr, FsG1>(c, &g1_linear_combination);
+    bench_g1_lincomb::<FsFr, FsG1>(c);
+}
+
+fn bench_g2_lincomb_(c: &mut Criterion) {
+    bench_g2_lincomb::<FsFr, FsG2>(c);
 }
 
 criterion_group! {
     name = benches;
     config = Criterion::default().sample_size(10);
-    targets = bench_g1_lincomb_
+    targets = bench_g1_lincomb_, bench_g2_lincomb_
 }
 
 criterion_main!(benches);
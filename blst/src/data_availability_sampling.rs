@@ -1,10 +1,9 @@
 extern crate alloc;
 
-use alloc::string::String;
 use alloc::vec::Vec;
 use core::cmp::Ordering;
 
-use kzg::{Fr, DAS};
+use kzg::{Error, Fr, DAS};
 
 use crate::types::fft_settings::FsFFTSettings;
 use crate::types::fr::FsFr;
@@ -75,13 +74,13 @@ impl DAS<FsFr> for FsFFTSettings {
     /// Polynomial extension for data availability sampling. Given values of even indices, produce values of odd indices.
     /// FFTSettings must hold at least 2 times the roots of provided evens.
     /// The resulting odd indices make the right half of the coefficients of the inverse FFT of the combined indices zero.
-    fn das_fft_extension(&self, evens: &[FsFr]) -> Result<Vec<FsFr>, String> {
+    fn das_fft_extension(&self, evens: &[FsFr]) -> Result<Vec<FsFr>, Error> {
         if evens.is_empty() {
-            return Err(String::from("A non-zero list ab expected"));
+            return Err(Error::InvalidArgument("A non-zero list ab expected"));
         } else if !evens.len().is_power_of_two() {
-            return Err(String::from("A list with power-of-two length expected"));
+            return Err(Error::NotPowerOfTwo("list length"));
         } else if evens.len() * 2 > self.max_width {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "Supplied list is longer than the available max width",
             ));
         }
@@ -94,7 +93,7 @@ impl DAS<FsFr> for FsFFTSettings {
         // TODO: explain why each odd member is multiplied by euclidean inverse of length
         let mut inv_len = FsFr::from_u64(odds.len() as u64);
         inv_len = inv_len.eucl_inverse();
-        let odds = odds.iter().map(|f| f.mul(&inv_len)).collect();
+        FsFr::batch_mul_scalar(&mut odds, &inv_len);
 
         Ok(odds)
     }
@@ -1,9 +1,8 @@
 extern crate alloc;
 
+use alloc::borrow::Cow;
 use alloc::boxed::Box;
-use alloc::format;
 use alloc::string::String;
-use alloc::string::ToString;
 use alloc::vec;
 use alloc::vec::Vec;
 #[cfg(feature = "std")]
@@ -13,25 +12,29 @@ use std::fs::File;
 #[cfg(feature = "std")]
 use std::io::Read;
 
-use blst::{blst_fr, blst_fr_from_scalar, blst_p1, blst_p2, blst_scalar, blst_scalar_from_lendian};
-use kzg::{cfg_into_iter, FFTSettings, Fr, G1Mul, KZGSettings, Poly, FFTG1, G1, G2};
+use blst::{blst_fr, blst_p1, blst_p2};
+use core::ffi::c_void;
+use kzg::{
+    cfg_into_iter, Error, FFTFr, FFTSettings, FK20SingleSettings, Fr, KZGSettings, PolyEval, FFTG1,
+    G1, G2,
+};
 
 #[cfg(feature = "std")]
-use kzg::eip_4844::load_trusted_setup_string;
+use kzg::eip_4844::{load_trusted_setup_json, load_trusted_setup_string};
 
 use kzg::eip_4844::{
-    bytes_of_uint64, hash, Blob, Bytes32, Bytes48, CFFTSettings, CKZGSettings, KZGCommitment,
-    KZGProof, BYTES_PER_BLOB, BYTES_PER_COMMITMENT, BYTES_PER_FIELD_ELEMENT, BYTES_PER_G1,
-    BYTES_PER_G2, BYTES_PER_PROOF, CHALLENGE_INPUT_SIZE, C_KZG_RET, C_KZG_RET_BADARGS,
-    C_KZG_RET_OK, FIAT_SHAMIR_PROTOCOL_DOMAIN, FIELD_ELEMENTS_PER_BLOB,
-    RANDOM_CHALLENGE_KZG_BATCH_DOMAIN, TRUSTED_SETUP_NUM_G1_POINTS, TRUSTED_SETUP_NUM_G2_POINTS,
+    Blob, BlobPreset, Bytes32, Bytes48, CFFTSettings, CKZGSettings, KZGCommitment, KZGProof,
+    PointEvaluationError, ProofUpdateTerms, BYTES_PER_FIELD_ELEMENT, BYTES_PER_G1, BYTES_PER_G2,
+    CKZG_SETTINGS_VERSION, C_KZG_RET, C_KZG_RET_BADARGS, C_KZG_RET_OK,
+    POINT_EVALUATION_OUTPUT_LENGTH, TRUSTED_SETUP_NUM_G2_POINTS,
 };
+use kzg::trusted_setup::{lagrange_to_monomial, verify_monomial_setup, SetupBasis};
 
+use crate::eip_7594::{ext_fft_settings, monomial_settings};
 use crate::types::fft_settings::FsFFTSettings;
+use crate::types::fk20_single_settings::FsFK20SingleSettings;
 use crate::types::fr::FsFr;
 use crate::types::g1::FsG1;
-
-use crate::kzg_proofs::{g1_linear_combination, pairings_verify};
 use crate::types::g2::FsG2;
 use crate::types::kzg_settings::FsKZGSettings;
 use crate::types::poly::FsPoly;
@@ -41,121 +44,137 @@ use crate::utils::reverse_bit_order;
 use rayon::prelude::*;
 
 pub fn hash_to_bls_field(x: &[u8; BYTES_PER_FIELD_ELEMENT]) -> FsFr {
-    let mut tmp = blst_scalar::default();
-    let mut out = blst_fr::default();
-    unsafe {
-        blst_scalar_from_lendian(&mut tmp, x.as_ptr());
-        blst_fr_from_scalar(&mut out, &tmp);
+    kzg::eip_4844::hash_to_bls_field(x)
+}
+
+pub fn bytes_to_blob(bytes: &[u8]) -> Result<Vec<FsFr>, Error> {
+    kzg::eip_4844::bytes_to_blob(bytes)
+}
+
+pub fn bytes_to_blob_with_preset(bytes: &[u8], preset: &BlobPreset) -> Result<Vec<FsFr>, Error> {
+    kzg::eip_4844::bytes_to_blob_with_preset(bytes, preset)
+}
+
+/// Loads a trusted setup from the concatenated compressed G1 and G2 points, with the G1 points
+/// in monomial form. With `verify`, also checks that the points are powers of one secret with
+/// [`verify_monomial_setup`], which rejects a corrupted or altered setup at the cost of a few
+/// multi-scalar multiplications and pairings.
+pub fn load_trusted_setup_rust(
+    g1_bytes: &[u8],
+    g2_bytes: &[u8],
+    verify: bool,
+) -> Result<FsKZGSettings, Error> {
+    let (blob_preset, g1_monomial, g2_values, fs) = decode_trusted_setup(g1_bytes, g2_bytes)?;
+
+    if verify {
+        verify_monomial_setup(&g1_monomial, &g2_values)?;
     }
-    FsFr(out)
+
+    let mut g1_lagrange = fs.fft_g1(&g1_monomial, true)?;
+    reverse_bit_order(&mut g1_lagrange);
+
+    settings_from_points(blob_preset, g1_monomial, g1_lagrange, g2_values, &fs)
 }
 
-#[allow(clippy::useless_conversion)]
-pub fn bytes_to_blob(bytes: &[u8]) -> Result<Vec<FsFr>, String> {
-    if bytes.len() != BYTES_PER_BLOB {
-        return Err(format!(
-            "Invalid byte length. Expected {} got {}",
-            BYTES_PER_BLOB,
-            bytes.len(),
-        ));
+/// Loads a trusted setup whose G1 points are already in bit-reversed Lagrange form, like the
+/// `g1_lagrange` array of the ceremony's `trusted_setup.json`. The monomial points are recovered
+/// with [`lagrange_to_monomial`], and `verify` works as in [`load_trusted_setup_rust`].
+pub fn load_trusted_setup_lagrange_rust(
+    g1_bytes: &[u8],
+    g2_bytes: &[u8],
+    verify: bool,
+) -> Result<FsKZGSettings, Error> {
+    let (blob_preset, g1_lagrange, g2_values, fs) = decode_trusted_setup(g1_bytes, g2_bytes)?;
+    let g1_monomial = lagrange_to_monomial(&g1_lagrange, &fs)?;
+
+    if verify {
+        verify_monomial_setup(&g1_monomial, &g2_values)?;
     }
 
-    bytes
-        .chunks(BYTES_PER_FIELD_ELEMENT)
-        .map(|chunk| {
-            chunk
-                .try_into()
-                .map_err(|_| "Chunked into incorrect number of bytes".to_string())
-                .and_then(FsFr::from_bytes)
-        })
-        .collect()
+    settings_from_points(blob_preset, g1_monomial, g1_lagrange, g2_values, &fs)
 }
 
-#[allow(clippy::useless_conversion)]
-fn load_trusted_setup_rust(g1_bytes: &[u8], g2_bytes: &[u8]) -> FsKZGSettings {
+/// Decodes the points of a trusted setup and creates FFT settings wide enough for its G1 points.
+fn decode_trusted_setup(
+    g1_bytes: &[u8],
+    g2_bytes: &[u8],
+) -> Result<(BlobPreset, Vec<FsG1>, Vec<FsG2>, FsFFTSettings), Error> {
     let num_g1_points = g1_bytes.len() / BYTES_PER_G1;
 
-    assert_eq!(num_g1_points, FIELD_ELEMENTS_PER_BLOB);
-    assert_eq!(g2_bytes.len() / BYTES_PER_G2, TRUSTED_SETUP_NUM_G2_POINTS);
+    let blob_preset = BlobPreset::new(num_g1_points)?;
+    if g2_bytes.len() / BYTES_PER_G2 != TRUSTED_SETUP_NUM_G2_POINTS {
+        return Err(Error::InvalidLength {
+            expected: TRUSTED_SETUP_NUM_G2_POINTS,
+            actual: g2_bytes.len() / BYTES_PER_G2,
+        });
+    }
 
-    let g1_projectives: Vec<FsG1> = g1_bytes
+    let g1_values = g1_bytes
         .chunks(BYTES_PER_G1)
-        .map(|chunk| {
-            FsG1::from_bytes(
-                chunk
-                    .try_into()
-                    .expect("Chunked into incorrect number of bytes"),
-            )
-            .unwrap()
-        })
-        .collect();
-
-    let g2_values: Vec<FsG2> = g2_bytes
+        .map(FsG1::from_bytes)
+        .collect::<Result<Vec<FsG1>, Error>>()?;
+    let g2_values = g2_bytes
         .chunks(BYTES_PER_G2)
-        .map(|chunk| {
-            FsG2::from_bytes(
-                chunk
-                    .try_into()
-                    .expect("Chunked into incorrect number of bytes"),
-            )
-            .unwrap()
-        })
-        .collect();
+        .map(FsG2::from_bytes)
+        .collect::<Result<Vec<FsG2>, Error>>()?;
 
     let mut max_scale: usize = 0;
     while (1 << max_scale) < num_g1_points {
         max_scale += 1;
     }
 
-    let fs = FsFFTSettings::new(max_scale).unwrap();
-    let mut g1_values = fs.fft_g1(&g1_projectives, true).unwrap();
-    reverse_bit_order(&mut g1_values);
+    let fs = FsFFTSettings::new(max_scale)?;
+    Ok((blob_preset, g1_values, g2_values, fs))
+}
 
-    FsKZGSettings {
-        secret_g1: g1_values,
-        secret_g2: g2_values,
-        fs,
-    }
+fn settings_from_points(
+    blob_preset: BlobPreset,
+    g1_monomial: Vec<FsG1>,
+    g1_lagrange: Vec<FsG1>,
+    g2_values: Vec<FsG2>,
+    fs: &FsFFTSettings,
+) -> Result<FsKZGSettings, Error> {
+    Ok(FsKZGSettings {
+        secret_g1_monomial: g1_monomial,
+        blob_preset,
+        basis: SetupBasis::Lagrange,
+        ..FsKZGSettings::new(
+            &g1_lagrange,
+            &g2_values,
+            g1_lagrange.len(),
+            g2_values.len(),
+            fs,
+        )?
+    })
 }
 
 #[cfg(feature = "std")]
-pub fn load_trusted_setup_filename_rust(filepath: &str) -> FsKZGSettings {
-    let mut file = File::open(filepath).expect("Unable to open file");
+fn read_trusted_setup_file(filepath: &str) -> Result<String, Error> {
+    let mut file = File::open(filepath)?;
     let mut contents = String::new();
-    file.read_to_string(&mut contents)
-        .expect("Unable to read file");
-
-    let (g1_bytes, g2_bytes) = load_trusted_setup_string(&contents);
-    load_trusted_setup_rust(g1_bytes.as_slice(), g2_bytes.as_slice())
+    file.read_to_string(&mut contents)?;
+    Ok(contents)
 }
 
-fn fr_batch_inv(out: &mut [FsFr], a: &[FsFr], len: usize) {
-    assert!(len > 0);
-
-    let mut accumulator = FsFr::one();
-
-    for i in 0..len {
-        out[i] = accumulator;
-        accumulator = accumulator.mul(&a[i]);
-    }
-
-    accumulator = accumulator.eucl_inverse();
-
-    for i in (0..len).rev() {
-        out[i] = out[i].mul(&accumulator);
-        accumulator = accumulator.mul(&a[i]);
-    }
+#[cfg(feature = "std")]
+pub fn load_trusted_setup_filename_rust(filepath: &str) -> Result<FsKZGSettings, Error> {
+    let contents = read_trusted_setup_file(filepath)?;
+    let (g1_bytes, g2_bytes) = load_trusted_setup_string(&contents)?;
+    load_trusted_setup_rust(g1_bytes.as_slice(), g2_bytes.as_slice(), false)
 }
 
-fn g1_lincomb(points: &[FsG1], scalars: &[FsFr], length: usize) -> FsG1 {
-    let mut out = FsG1::default();
-    g1_linear_combination(&mut out, points, scalars, length);
-    out
+/// Loads a trusted setup from the `trusted_setup.json` file published by the KZG ceremony.
+#[cfg(feature = "std")]
+pub fn load_trusted_setup_json_filename_rust(filepath: &str) -> Result<FsKZGSettings, Error> {
+    let contents = read_trusted_setup_file(filepath)?;
+    let (g1_bytes, g2_bytes) = load_trusted_setup_json(&contents)?;
+    load_trusted_setup_lagrange_rust(g1_bytes.as_slice(), g2_bytes.as_slice(), false)
 }
 
 pub fn blob_to_kzg_commitment_rust(blob: &[FsFr], s: &FsKZGSettings) -> FsG1 {
-    let p = blob_to_polynomial_rust(blob);
-    poly_to_kzg_commitment(&p, s)
+    kzg::eip_4844::blob_to_kzg_commitment_rust::<FsFr, FsG1, FsG2, FsPoly, FsFFTSettings, _>(
+        blob, s,
+    )
 }
 
 pub fn verify_kzg_proof_rust(
@@ -164,16 +183,10 @@ pub fn verify_kzg_proof_rust(
     y: &FsFr,
     proof: &FsG1,
     s: &FsKZGSettings,
-) -> Result<bool, String> {
-    if !commitment.is_valid() {
-        return Err("Invalid commitment".to_string());
-    }
-    if !proof.is_valid() {
-        return Err("Invalid proof".to_string());
-    }
-
-    Ok(s.check_proof_single(commitment, proof, z, y)
-        .unwrap_or(false))
+) -> Result<bool, Error> {
+    kzg::eip_4844::verify_kzg_proof_rust::<FsFr, FsG1, FsG2, FsPoly, FsFFTSettings, _>(
+        commitment, z, y, proof, s,
+    )
 }
 
 pub fn verify_kzg_proof_batch(
@@ -183,259 +196,162 @@ pub fn verify_kzg_proof_batch(
     proofs_g1: &[FsG1],
     ts: &FsKZGSettings,
 ) -> bool {
-    let n = commitments_g1.len();
-    let mut c_minus_y: Vec<FsG1> = Vec::with_capacity(n);
-    let mut r_times_z: Vec<FsFr> = Vec::with_capacity(n);
-
-    // Compute the random lincomb challenges
-    let r_powers = compute_r_powers(commitments_g1, zs_fr, ys_fr, proofs_g1);
-
-    // Compute \sum r^i * Proof_i
-    let proof_lincomb = g1_lincomb(proofs_g1, &r_powers, n);
-
-    for i in 0..n {
-        // Get [y_i]
-        let ys_encrypted = FsG1::generator().mul(&ys_fr[i]);
-        // Get C_i - [y_i]
-        c_minus_y.push(commitments_g1[i].sub(&ys_encrypted));
-        // Get r^i * z_i
-        r_times_z.push(r_powers[i].mul(&zs_fr[i]));
-    }
-
-    // Get \sum r^i z_i Proof_i
-    let proof_z_lincomb = g1_lincomb(proofs_g1, &r_times_z, n);
-    // Get \sum r^i (C_i - [y_i])
-    let mut c_minus_y_lincomb = g1_lincomb(&c_minus_y, &r_powers, n);
-
-    // Get C_minus_y_lincomb + proof_z_lincomb
-    let rhs_g1 = c_minus_y_lincomb.add_or_dbl(&proof_z_lincomb);
-
-    // Do the pairing check!
-    pairings_verify(
-        &proof_lincomb,
-        &ts.secret_g2[1],
-        &rhs_g1,
-        &FsG2::generator(),
+    kzg::eip_4844::verify_kzg_proof_batch::<FsFr, FsG1, FsG2, FsPoly, FsFFTSettings, _>(
+        commitments_g1,
+        zs_fr,
+        ys_fr,
+        proofs_g1,
+        ts,
     )
 }
 
 pub fn compute_kzg_proof_rust(blob: &[FsFr], z: &FsFr, s: &FsKZGSettings) -> (FsG1, FsFr) {
-    assert_eq!(blob.len(), FIELD_ELEMENTS_PER_BLOB);
-
-    let polynomial = blob_to_polynomial_rust(blob);
-    let y = evaluate_polynomial_in_evaluation_form_rust(&polynomial, z, s);
-
-    let mut tmp: FsFr;
-    let roots_of_unity: &Vec<FsFr> = &s.fs.roots_of_unity;
-
-    let mut m: usize = 0;
-    let mut q: FsPoly = FsPoly::new(FIELD_ELEMENTS_PER_BLOB).unwrap();
-
-    let mut inverses_in: Vec<FsFr> = vec![FsFr::default(); FIELD_ELEMENTS_PER_BLOB];
-    let mut inverses: Vec<FsFr> = vec![FsFr::default(); FIELD_ELEMENTS_PER_BLOB];
-
-    for i in 0..FIELD_ELEMENTS_PER_BLOB {
-        if z.equals(&roots_of_unity[i]) {
-            // We are asked to compute a KZG proof inside the domain
-            m = i + 1;
-            inverses_in[i] = FsFr::one();
-            continue;
-        }
-        // (p_i - y) / (ω_i - z)
-        q.coeffs[i] = polynomial.coeffs[i].sub(&y);
-        inverses_in[i] = roots_of_unity[i].sub(z);
-    }
-
-    fr_batch_inv(&mut inverses, &inverses_in, FIELD_ELEMENTS_PER_BLOB);
-
-    for (i, inverse) in inverses.iter().enumerate().take(FIELD_ELEMENTS_PER_BLOB) {
-        q.coeffs[i] = q.coeffs[i].mul(inverse);
-    }
-
-    if m != 0 {
-        // ω_{m-1} == z
-        m -= 1;
-        q.coeffs[m] = FsFr::zero();
-        for i in 0..FIELD_ELEMENTS_PER_BLOB {
-            if i == m {
-                continue;
-            }
-            // Build denominator: z * (z - ω_i)
-            tmp = z.sub(&roots_of_unity[i]);
-            inverses_in[i] = tmp.mul(z);
-        }
-
-        fr_batch_inv(&mut inverses, &inverses_in, FIELD_ELEMENTS_PER_BLOB);
-
-        for i in 0..FIELD_ELEMENTS_PER_BLOB {
-            if i == m {
-                continue;
-            }
-            // Build numerator: ω_i * (p_i - y)
-            tmp = polynomial.coeffs[i].sub(&y);
-            tmp = tmp.mul(&roots_of_unity[i]);
-            // Do the division: (p_i - y) * ω_i / (z * (z - ω_i))
-            tmp = tmp.mul(&inverses[i]);
-            q.coeffs[m] = q.coeffs[m].add(&tmp);
-        }
-    }
+    kzg::eip_4844::compute_kzg_proof_rust::<FsFr, FsG1, FsG2, FsPoly, FsFFTSettings, _>(blob, z, s)
+}
 
-    let proof = g1_lincomb(&s.secret_g1, &q.coeffs, FIELD_ELEMENTS_PER_BLOB);
-    (proof, y)
+pub fn update_kzg_commitment_rust(
+    commitment: &FsG1,
+    changes: &[(usize, FsFr, FsFr)],
+    s: &FsKZGSettings,
+) -> Result<FsG1, Error> {
+    kzg::eip_4844::update_kzg_commitment_rust::<FsFr, FsG1, FsG2, FsPoly, FsFFTSettings, _>(
+        commitment, changes, s,
+    )
 }
 
-pub fn evaluate_polynomial_in_evaluation_form_rust(
-    p: &FsPoly,
-    x: &FsFr,
+pub fn precompute_proof_update_terms_rust(
+    z: &FsFr,
     s: &FsKZGSettings,
-) -> FsFr {
-    assert_eq!(p.coeffs.len(), FIELD_ELEMENTS_PER_BLOB);
+) -> Result<ProofUpdateTerms<FsFr, FsG1>, Error> {
+    kzg::eip_4844::precompute_proof_update_terms_rust::<FsFr, FsG1, FsG2, FsPoly, FsFFTSettings, _>(
+        z, s,
+    )
+}
 
-    let roots_of_unity: &Vec<FsFr> = &s.fs.roots_of_unity;
-    let mut inverses_in: Vec<FsFr> = vec![FsFr::default(); FIELD_ELEMENTS_PER_BLOB];
-    let mut inverses: Vec<FsFr> = vec![FsFr::default(); FIELD_ELEMENTS_PER_BLOB];
+pub fn update_kzg_proof_rust(
+    proof: &FsG1,
+    y: &FsFr,
+    changes: &[(usize, FsFr, FsFr)],
+    terms: &ProofUpdateTerms<FsFr, FsG1>,
+    s: &FsKZGSettings,
+) -> Result<(FsG1, FsFr), Error> {
+    kzg::eip_4844::update_kzg_proof_rust::<FsFr, FsG1, FsG2, FsPoly, FsFFTSettings, _>(
+        proof, y, changes, terms, s,
+    )
+}
 
-    for i in 0..FIELD_ELEMENTS_PER_BLOB {
-        if x.equals(&roots_of_unity[i]) {
-            return p.get_coeff_at(i);
-        }
-        inverses_in[i] = x.sub(&roots_of_unity[i]);
+/// Computes the opening proofs of a blob at every point of its domain, in the blob's bit-reversed
+/// order, so that `proofs[i]` opens the commitment to `blob[i]`.
+///
+/// Uses FK20 over the monomial setup points, which takes O(n log n) instead of the O(n^2) of
+/// calling [`compute_kzg_proof_rust`] for every point. FK20 computes the proofs over the domain
+/// of twice the blob size; the first half of its bit-reversed output is the blob's domain.
+pub fn compute_all_kzg_proofs_rust(blob: &[FsFr], s: &FsKZGSettings) -> Result<Vec<FsG1>, Error> {
+    let field_elements_per_blob = s.blob_preset.field_elements_per_blob;
+    if blob.len() != field_elements_per_blob {
+        return Err(Error::InvalidLength {
+            expected: field_elements_per_blob,
+            actual: blob.len(),
+        });
+    }
+    if s.secret_g1_monomial.len() < field_elements_per_blob {
+        return Err(Error::SetupTooSmall);
     }
 
-    fr_batch_inv(&mut inverses, &inverses_in, FIELD_ELEMENTS_PER_BLOB);
+    let mut evaluations = blob.to_vec();
+    reverse_bit_order(&mut evaluations);
+    let coeffs = s.fs.fft_fr(&evaluations, true)?;
 
-    let mut tmp: FsFr;
-    let mut out = FsFr::zero();
+    let mut proofs = single_proof_settings(s)?.data_availability(&FsPoly { coeffs })?;
+    proofs.truncate(field_elements_per_blob);
 
-    for i in 0..FIELD_ELEMENTS_PER_BLOB {
-        tmp = inverses[i].mul(&roots_of_unity[i]);
-        tmp = tmp.mul(&p.coeffs[i]);
-        out = out.add(&tmp);
-    }
+    Ok(proofs)
+}
 
-    tmp = FsFr::from_u64(FIELD_ELEMENTS_PER_BLOB as u64);
-    out = out.div(&tmp).unwrap();
-    tmp = x.pow(FIELD_ELEMENTS_PER_BLOB);
-    tmp = tmp.sub(&FsFr::one());
-    out = out.mul(&tmp);
-    out
+/// Returns the FK20 settings used by [`compute_all_kzg_proofs_rust`], computing them on first use.
+fn single_proof_settings(s: &FsKZGSettings) -> Result<&FsFK20SingleSettings, Error> {
+    s.single_proof_settings.get_or_try_init(|| {
+        let fs_ext = ext_fft_settings(s)?;
+        FsFK20SingleSettings::new(&monomial_settings(s, &fs_ext), fs_ext.max_width)
+    })
 }
 
-pub fn compute_powers(base: &FsFr, num_powers: usize) -> Vec<FsFr> {
-    let mut powers: Vec<FsFr> = vec![FsFr::default(); num_powers];
-    if num_powers == 0 {
-        return powers;
+/// Verifies proofs from [`compute_all_kzg_proofs_rust`] for any subset of a blob's elements
+/// with a single pairing check.
+///
+/// `values[k]` is the blob element at `indices[k]`, opened by `proofs[k]`.
+pub fn verify_all_kzg_proofs_batch_rust(
+    commitment: &FsG1,
+    indices: &[usize],
+    values: &[FsFr],
+    proofs: &[FsG1],
+    s: &FsKZGSettings,
+) -> Result<bool, Error> {
+    if values.len() != indices.len() {
+        return Err(Error::InvalidLength {
+            expected: indices.len(),
+            actual: values.len(),
+        });
+    }
+    if proofs.len() != indices.len() {
+        return Err(Error::InvalidLength {
+            expected: indices.len(),
+            actual: proofs.len(),
+        });
     }
-    powers[0] = FsFr::one();
-    for i in 1..num_powers {
-        powers[i] = powers[i - 1].mul(base);
+    if !commitment.is_valid() {
+        return Err(Error::InvalidArgument("Invalid commitment"));
     }
-
-    powers
-}
-
-fn compute_challenge(blob: &[FsFr], commitment: &FsG1) -> FsFr {
-    let mut bytes: Vec<u8> = vec![0; CHALLENGE_INPUT_SIZE];
-
-    // Copy domain separator
-    bytes[..16].copy_from_slice(&FIAT_SHAMIR_PROTOCOL_DOMAIN);
-    bytes_of_uint64(&mut bytes[16..24], FIELD_ELEMENTS_PER_BLOB as u64);
-    // Set all other bytes of this 16-byte (little-endian) field to zero
-    bytes_of_uint64(&mut bytes[24..32], 0);
-
-    // Copy blob
-    for i in 0..blob.len() {
-        let v = blob[i].to_bytes();
-        bytes[(32 + i * BYTES_PER_FIELD_ELEMENT)..(32 + (i + 1) * BYTES_PER_FIELD_ELEMENT)]
-            .copy_from_slice(&v);
+    if !proofs.iter().all(|proof| proof.is_valid()) {
+        return Err(Error::InvalidArgument("Invalid proof"));
     }
 
-    // Copy commitment
-    let v = commitment.to_bytes();
-    for i in 0..v.len() {
-        bytes[32 + BYTES_PER_BLOB + i] = v[i];
-    }
+    let field_elements_per_blob = s.blob_preset.field_elements_per_blob;
+    let zs = indices
+        .iter()
+        .map(|&index| {
+            if index >= field_elements_per_blob {
+                return Err(Error::InvalidArgument("Blob element index out of range"));
+            }
+            Ok(s.fs.roots_of_unity[index])
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let commitments = vec![*commitment; indices.len()];
 
-    // Now let's create the challenge!
-    let eval_challenge = hash(&bytes);
-    hash_to_bls_field(&eval_challenge)
+    Ok(verify_kzg_proof_batch(&commitments, &zs, values, proofs, s))
 }
 
-fn compute_r_powers(
-    commitments_g1: &[FsG1],
-    zs_fr: &[FsFr],
-    ys_fr: &[FsFr],
-    proofs_g1: &[FsG1],
-) -> Vec<FsFr> {
-    let n = commitments_g1.len();
-    let input_size =
-        32 + n * (BYTES_PER_COMMITMENT + 2 * BYTES_PER_FIELD_ELEMENT + BYTES_PER_PROOF);
-
-    #[allow(unused_assignments)]
-    let mut offset = 0;
-    let mut bytes: Vec<u8> = vec![0; input_size];
-
-    // Copy domain separator
-    bytes[..16].copy_from_slice(&RANDOM_CHALLENGE_KZG_BATCH_DOMAIN);
-    bytes_of_uint64(&mut bytes[16..24], FIELD_ELEMENTS_PER_BLOB as u64);
-    bytes_of_uint64(&mut bytes[24..32], n as u64);
-    offset = 32;
-
-    for i in 0..n {
-        // Copy commitment
-        let v = commitments_g1[i].to_bytes();
-        bytes[offset..(v.len() + offset)].copy_from_slice(&v[..]);
-        offset += BYTES_PER_COMMITMENT;
-
-        // Copy evaluation challenge
-        let v = zs_fr[i].to_bytes();
-        bytes[offset..(v.len() + offset)].copy_from_slice(&v[..]);
-        offset += BYTES_PER_FIELD_ELEMENT;
-
-        // Copy polynomial's evaluation value
-        let v = ys_fr[i].to_bytes();
-        bytes[offset..(v.len() + offset)].copy_from_slice(&v[..]);
-        offset += BYTES_PER_FIELD_ELEMENT;
-
-        // Copy proof
-        let v = proofs_g1[i].to_bytes();
-        bytes[offset..(v.len() + offset)].copy_from_slice(&v[..]);
-        offset += BYTES_PER_PROOF;
-    }
-
-    // Make sure we wrote the entire buffer
-    assert_eq!(offset, input_size);
-
-    // Now let's create the challenge!
-    let eval_challenge = hash(&bytes);
-    let r = hash_to_bls_field(&eval_challenge);
-    compute_powers(&r, n)
+pub fn evaluate_polynomial_in_evaluation_form_rust(
+    p: &PolyEval<FsFr>,
+    x: &FsFr,
+    s: &FsKZGSettings,
+) -> FsFr {
+    kzg::eip_4844::evaluate_polynomial_in_evaluation_form_rust::<
+        FsFr,
+        FsG1,
+        FsG2,
+        FsPoly,
+        FsFFTSettings,
+        _,
+    >(p, x, s)
 }
 
-pub fn blob_to_polynomial_rust(blob: &[FsFr]) -> FsPoly {
-    assert_eq!(blob.len(), FIELD_ELEMENTS_PER_BLOB);
-    let mut p: FsPoly = FsPoly::new(FIELD_ELEMENTS_PER_BLOB).unwrap();
-    p.coeffs = blob.to_vec();
-    p
+pub fn compute_powers(base: &FsFr, num_powers: usize) -> Vec<FsFr> {
+    kzg::eip_4844::compute_powers(base, num_powers)
 }
 
-fn poly_to_kzg_commitment(p: &FsPoly, s: &FsKZGSettings) -> FsG1 {
-    assert_eq!(p.coeffs.len(), FIELD_ELEMENTS_PER_BLOB);
-    g1_lincomb(&s.secret_g1, &p.coeffs, FIELD_ELEMENTS_PER_BLOB)
+pub fn blob_to_polynomial_rust(blob: &[FsFr]) -> PolyEval<FsFr> {
+    kzg::eip_4844::blob_to_polynomial_rust(blob)
 }
 
 pub fn compute_blob_kzg_proof_rust(
     blob: &[FsFr],
     commitment: &FsG1,
     ts: &FsKZGSettings,
-) -> Result<FsG1, String> {
-    if !commitment.is_valid() {
-        return Err("Invalid commitment".to_string());
-    }
-
-    let evaluation_challenge_fr = compute_challenge(blob, commitment);
-    let (proof, _) = compute_kzg_proof_rust(blob, &evaluation_challenge_fr, ts);
-    Ok(proof)
+) -> Result<FsG1, Error> {
+    kzg::eip_4844::compute_blob_kzg_proof_rust::<FsFr, FsG1, FsG2, FsPoly, FsFFTSettings, _>(
+        blob, commitment, ts,
+    )
 }
 
 pub fn verify_blob_kzg_proof_rust(
@@ -443,136 +359,40 @@ pub fn verify_blob_kzg_proof_rust(
     commitment_g1: &FsG1,
     proof_g1: &FsG1,
     ts: &FsKZGSettings,
-) -> Result<bool, String> {
-    if !commitment_g1.is_valid() {
-        return Err("Invalid commitment".to_string());
-    }
-    if !proof_g1.is_valid() {
-        return Err("Invalid proof".to_string());
-    }
-
-    let polynomial = blob_to_polynomial_rust(blob);
-    let evaluation_challenge_fr = compute_challenge(blob, commitment_g1);
-    let y_fr =
-        evaluate_polynomial_in_evaluation_form_rust(&polynomial, &evaluation_challenge_fr, ts);
-    verify_kzg_proof_rust(commitment_g1, &evaluation_challenge_fr, &y_fr, proof_g1, ts)
+) -> Result<bool, Error> {
+    kzg::eip_4844::verify_blob_kzg_proof_rust::<FsFr, FsG1, FsG2, FsPoly, FsFFTSettings, _>(
+        blob,
+        commitment_g1,
+        proof_g1,
+        ts,
+    )
 }
 
-fn compute_challenges_and_evaluate_polynomial(
+pub fn verify_blob_kzg_proof_batch_rust(
     blobs: &[Vec<FsFr>],
     commitments_g1: &[FsG1],
+    proofs_g1: &[FsG1],
     ts: &FsKZGSettings,
-) -> (Vec<FsFr>, Vec<FsFr>) {
-    let mut evaluation_challenges_fr = Vec::with_capacity(blobs.len());
-    let mut ys_fr = Vec::with_capacity(blobs.len());
-
-    for i in 0..blobs.len() {
-        let polynomial = blob_to_polynomial_rust(&blobs[i]);
-        let evaluation_challenge_fr = compute_challenge(&blobs[i], &commitments_g1[i]);
-        let y_fr =
-            evaluate_polynomial_in_evaluation_form_rust(&polynomial, &evaluation_challenge_fr, ts);
-
-        evaluation_challenges_fr.push(evaluation_challenge_fr);
-        ys_fr.push(y_fr);
-    }
-
-    (evaluation_challenges_fr, ys_fr)
+) -> Result<bool, Error> {
+    kzg::eip_4844::verify_blob_kzg_proof_batch_rust::<FsFr, FsG1, FsG2, FsPoly, FsFFTSettings, _>(
+        blobs,
+        commitments_g1,
+        proofs_g1,
+        ts,
+    )
 }
 
-fn validate_batched_input(commitments: &[FsG1], proofs: &[FsG1]) -> Result<(), String> {
-    let invalid_commitment = cfg_into_iter!(commitments).any(|&commitment| !commitment.is_valid());
-    let invalid_proof = cfg_into_iter!(proofs).any(|&proof| !proof.is_valid());
-
-    if invalid_commitment {
-        return Err("Invalid commitment".to_string());
-    }
-    if invalid_proof {
-        return Err("Invalid proof".to_string());
-    }
-
-    Ok(())
+pub fn kzg_to_versioned_hash_rust(commitment: &FsG1) -> [u8; 32] {
+    kzg::eip_4844::kzg_to_versioned_hash(&commitment.to_bytes())
 }
 
-pub fn verify_blob_kzg_proof_batch_rust(
-    blobs: &[Vec<FsFr>],
-    commitments_g1: &[FsG1],
-    proofs_g1: &[FsG1],
+pub fn point_evaluation_precompile_rust(
+    input: &[u8],
     ts: &FsKZGSettings,
-) -> Result<bool, String> {
-    // Exit early if we are given zero blobs
-    if blobs.is_empty() {
-        return Ok(true);
-    }
-
-    // For a single blob, just do a regular single verification
-    if blobs.len() == 1 {
-        return verify_blob_kzg_proof_rust(&blobs[0], &commitments_g1[0], &proofs_g1[0], ts);
-    }
-
-    if blobs.len() != commitments_g1.len() || blobs.len() != proofs_g1.len() {
-        return Err("Invalid amount of arguments".to_string());
-    }
-
-    #[cfg(feature = "parallel")]
-    {
-        let num_blobs = blobs.len();
-        let num_cores = num_cpus::get_physical();
-
-        return if num_blobs > num_cores {
-            validate_batched_input(commitments_g1, proofs_g1)?;
-
-            // Process blobs in parallel subgroups
-            let blobs_per_group = num_blobs / num_cores;
-
-            Ok(blobs
-                .par_chunks(blobs_per_group)
-                .enumerate()
-                .all(|(i, blob_group)| {
-                    let num_blobs_in_group = blob_group.len();
-                    let commitment_group = &commitments_g1
-                        [blobs_per_group * i..blobs_per_group * i + num_blobs_in_group];
-                    let proof_group =
-                        &proofs_g1[blobs_per_group * i..blobs_per_group * i + num_blobs_in_group];
-                    let (evaluation_challenges_fr, ys_fr) =
-                        compute_challenges_and_evaluate_polynomial(
-                            blob_group,
-                            commitment_group,
-                            ts,
-                        );
-
-                    verify_kzg_proof_batch(
-                        commitment_group,
-                        &evaluation_challenges_fr,
-                        &ys_fr,
-                        proof_group,
-                        ts,
-                    )
-                }))
-        } else {
-            // Each group contains either one or zero blobs, so iterate
-            // over the single blob verification function in parallel
-            Ok((blobs, commitments_g1, proofs_g1).into_par_iter().all(
-                |(blob, commitment, proof)| {
-                    verify_blob_kzg_proof_rust(blob, commitment, proof, ts).unwrap()
-                },
-            ))
-        };
-    }
-
-    #[cfg(not(feature = "parallel"))]
-    {
-        validate_batched_input(commitments_g1, proofs_g1)?;
-        let (evaluation_challenges_fr, ys_fr) =
-            compute_challenges_and_evaluate_polynomial(blobs, commitments_g1, ts);
-
-        Ok(verify_kzg_proof_batch(
-            commitments_g1,
-            &evaluation_challenges_fr,
-            &ys_fr,
-            proofs_g1,
-            ts,
-        ))
-    }
+) -> Result<[u8; POINT_EVALUATION_OUTPUT_LENGTH], PointEvaluationError> {
+    kzg::eip_4844::point_evaluation_precompile_rust::<FsFr, FsG1, FsG2, FsPoly, FsFFTSettings, _>(
+        input, ts,
+    )
 }
 
 fn fft_settings_to_rust(c_settings: *const CFFTSettings) -> FsFFTSettings {
@@ -645,15 +465,27 @@ fn fft_settings_to_c(rust_settings: &FsFFTSettings) -> *const CFFTSettings {
     Box::into_raw(b)
 }
 
-fn kzg_settings_to_rust(c_settings: &CKZGSettings) -> FsKZGSettings {
+/// Returns the settings behind `c_settings`. Settings loaded by this library are borrowed, so
+/// that tables computed on first use are kept between calls; settings assembled by the caller are
+/// copied from the C arrays.
+pub(crate) fn kzg_settings_to_rust(c_settings: &CKZGSettings) -> Cow<'_, FsKZGSettings> {
+    if !c_settings.backend_settings.is_null() {
+        return Cow::Borrowed(unsafe { &*(c_settings.backend_settings as *const FsKZGSettings) });
+    }
+
     let secret_g1 = unsafe {
-        core::slice::from_raw_parts(c_settings.g1_values, TRUSTED_SETUP_NUM_G1_POINTS)
+        core::slice::from_raw_parts(c_settings.g1_values, c_settings.num_g1_points)
             .iter()
             .map(|r| FsG1(*r))
             .collect::<Vec<FsG1>>()
     };
-    let res = FsKZGSettings {
-        fs: fft_settings_to_rust(c_settings.fs),
+    let fs = fft_settings_to_rust(c_settings.fs);
+    // Settings created by `load_trusted_setup` have exactly one root of unity per blob element
+    let blob_preset = BlobPreset {
+        field_elements_per_blob: fs.max_width,
+    };
+    Cow::Owned(FsKZGSettings {
+        fs,
         secret_g1,
         secret_g2: unsafe {
             core::slice::from_raw_parts(c_settings.g2_values, TRUSTED_SETUP_NUM_G2_POINTS)
@@ -661,11 +493,13 @@ fn kzg_settings_to_rust(c_settings: &CKZGSettings) -> FsKZGSettings {
                 .map(|r| FsG2(*r))
                 .collect::<Vec<FsG2>>()
         },
-    };
-    res
+        blob_preset,
+        basis: SetupBasis::Lagrange,
+        ..FsKZGSettings::default()
+    })
 }
 
-fn kzg_settings_to_c(rust_settings: &FsKZGSettings) -> CKZGSettings {
+fn kzg_settings_to_c(rust_settings: FsKZGSettings) -> CKZGSettings {
     let g1_val = rust_settings
         .secret_g1
         .iter()
@@ -685,22 +519,20 @@ fn kzg_settings_to_c(rust_settings: &FsKZGSettings) -> CKZGSettings {
         fs: fft_settings_to_c(&rust_settings.fs),
         g1_values: unsafe { (*v).as_mut_ptr() },
         g2_values: stat_ref.as_mut_ptr(),
+        num_g1_points: rust_settings.secret_g1.len(),
+        backend_settings: Box::into_raw(Box::new(rust_settings)) as *const c_void,
     }
 }
 
-unsafe fn deserialize_blob(blob: *const Blob) -> Result<Vec<FsFr>, C_KZG_RET> {
-    (*blob)
-        .bytes
+/// Returns the bytes of `n` consecutive blobs, sized according to the preset of the settings.
+pub(crate) unsafe fn blob_bytes<'a>(blobs: *const Blob, n: usize, preset: &BlobPreset) -> &'a [u8] {
+    core::slice::from_raw_parts(blobs as *const u8, n * preset.bytes_per_blob())
+}
+
+pub(crate) fn deserialize_blob(bytes: &[u8]) -> Result<Vec<FsFr>, C_KZG_RET> {
+    bytes
         .chunks(BYTES_PER_FIELD_ELEMENT)
-        .map(|chunk| {
-            let mut bytes = [0u8; BYTES_PER_FIELD_ELEMENT];
-            bytes.copy_from_slice(chunk);
-            if let Ok(result) = FsFr::from_bytes(&bytes) {
-                Ok(result)
-            } else {
-                Err(C_KZG_RET_BADARGS)
-            }
-        })
+        .map(|chunk| FsFr::from_bytes(chunk).map_err(C_KZG_RET::from))
         .collect::<Result<Vec<FsFr>, C_KZG_RET>>()
 }
 
@@ -711,9 +543,10 @@ pub unsafe extern "C" fn blob_to_kzg_commitment(
     blob: *const Blob,
     s: &CKZGSettings,
 ) -> C_KZG_RET {
-    let deserialized_blob = deserialize_blob(blob);
+    let settings = kzg_settings_to_rust(s);
+    let deserialized_blob = deserialize_blob(blob_bytes(blob, 1, &settings.blob_preset));
     if let Ok(blob_) = deserialized_blob {
-        let tmp = blob_to_kzg_commitment_rust(&blob_, &kzg_settings_to_rust(s));
+        let tmp = blob_to_kzg_commitment_rust(&blob_, &settings);
         (*out).bytes = tmp.to_bytes();
         C_KZG_RET_OK
     } else {
@@ -732,9 +565,14 @@ pub unsafe extern "C" fn load_trusted_setup(
 ) -> C_KZG_RET {
     let g1_bytes = core::slice::from_raw_parts(g1_bytes, n1 * BYTES_PER_G1);
     let g2_bytes = core::slice::from_raw_parts(g2_bytes, n2 * BYTES_PER_G2);
-    TRUSTED_SETUP_NUM_G1_POINTS = g1_bytes.len() / BYTES_PER_G1;
-    let settings = load_trusted_setup_rust(g1_bytes, g2_bytes);
-    *out = kzg_settings_to_c(&settings);
+    if BlobPreset::new(n1).is_err() {
+        return C_KZG_RET_BADARGS;
+    }
+    let settings = match load_trusted_setup_rust(g1_bytes, g2_bytes, false) {
+        Ok(settings) => settings,
+        Err(_) => return C_KZG_RET_BADARGS,
+    };
+    *out = kzg_settings_to_c(settings);
     C_KZG_RET_OK
 }
 
@@ -747,17 +585,24 @@ pub unsafe extern "C" fn load_trusted_setup_file(
 ) -> C_KZG_RET {
     let mut buf = vec![0u8; 1024 * 1024];
     let len: usize = libc::fread(buf.as_mut_ptr() as *mut libc::c_void, 1, buf.len(), in_);
-    let s = String::from_utf8(buf[..len].to_vec()).unwrap();
-    let (g1_bytes, g2_bytes) = load_trusted_setup_string(&s);
-    TRUSTED_SETUP_NUM_G1_POINTS = g1_bytes.len() / BYTES_PER_G1;
-    if TRUSTED_SETUP_NUM_G1_POINTS != FIELD_ELEMENTS_PER_BLOB {
+    let (g1_bytes, g2_bytes) = match String::from_utf8(buf[..len].to_vec()) {
+        Ok(s) => match load_trusted_setup_string(&s) {
+            Ok(bytes) => bytes,
+            Err(_) => return C_KZG_RET_BADARGS,
+        },
+        Err(_) => return C_KZG_RET_BADARGS,
+    };
+    if BlobPreset::new(g1_bytes.len() / BYTES_PER_G1).is_err() {
         // Helps pass the Java test "shouldThrowExceptionOnIncorrectTrustedSetupFromFile",
         // as well as 5 others that pass only if this one passes (likely because Java doesn't
         // deallocate its KZGSettings pointer when no exception is thrown).
         return C_KZG_RET_BADARGS;
     }
-    let settings = load_trusted_setup_rust(g1_bytes.as_slice(), g2_bytes.as_slice());
-    *out = kzg_settings_to_c(&settings);
+    let settings = match load_trusted_setup_rust(g1_bytes.as_slice(), g2_bytes.as_slice(), false) {
+        Ok(settings) => settings,
+        Err(_) => return C_KZG_RET_BADARGS,
+    };
+    *out = kzg_settings_to_c(settings);
     C_KZG_RET_OK
 }
 
@@ -769,7 +614,8 @@ pub unsafe extern "C" fn compute_blob_kzg_proof(
     commitment_bytes: *mut Bytes48,
     s: &CKZGSettings,
 ) -> C_KZG_RET {
-    let deserialized_blob = deserialize_blob(blob);
+    let settings = kzg_settings_to_rust(s);
+    let deserialized_blob = deserialize_blob(blob_bytes(blob, 1, &settings.blob_preset));
     if deserialized_blob.is_err() {
         return deserialized_blob.err().unwrap();
     }
@@ -780,17 +626,24 @@ pub unsafe extern "C" fn compute_blob_kzg_proof(
     let proof = compute_blob_kzg_proof_rust(
         &deserialized_blob.unwrap(),
         &commitment_g1.unwrap(),
-        &kzg_settings_to_rust(s),
+        &settings,
     );
 
-    if let Ok(proof) = proof {
-        (*out).bytes = proof.to_bytes();
-        C_KZG_RET_OK
-    } else {
-        C_KZG_RET_BADARGS
+    match proof {
+        Ok(proof) => {
+            (*out).bytes = proof.to_bytes();
+            C_KZG_RET_OK
+        }
+        Err(err) => err.into(),
     }
 }
 
+/// Returns the [`CKZG_SETTINGS_VERSION`] of the `CKZGSettings` this library reads and writes.
+#[no_mangle]
+pub extern "C" fn ckzg_settings_version() -> u32 {
+    CKZG_SETTINGS_VERSION
+}
+
 /// # Safety
 #[no_mangle]
 pub unsafe extern "C" fn free_trusted_setup(s: *mut CKZGSettings) {
@@ -812,9 +665,12 @@ pub unsafe extern "C" fn free_trusted_setup(s: *mut CKZGSettings) {
     drop(roots);
     let g1 = Box::from_raw(core::slice::from_raw_parts_mut(
         (*s).g1_values,
-        TRUSTED_SETUP_NUM_G1_POINTS,
+        (*s).num_g1_points,
     ));
     drop(g1);
+    if !(*s).backend_settings.is_null() {
+        drop(Box::from_raw((*s).backend_settings as *mut FsKZGSettings));
+    }
     let g2 = Box::from_raw(core::slice::from_raw_parts_mut(
         (*s).g2_values,
         TRUSTED_SETUP_NUM_G2_POINTS,
@@ -849,11 +705,12 @@ pub unsafe extern "C" fn verify_kzg_proof(
         &kzg_settings_to_rust(s),
     );
 
-    if let Ok(result) = result {
-        *ok = result;
-        C_KZG_RET_OK
-    } else {
-        C_KZG_RET_BADARGS
+    match result {
+        Ok(result) => {
+            *ok = result;
+            C_KZG_RET_OK
+        }
+        Err(err) => err.into(),
     }
 }
 
@@ -866,7 +723,8 @@ pub unsafe extern "C" fn verify_blob_kzg_proof(
     proof_bytes: *const Bytes48,
     s: &CKZGSettings,
 ) -> C_KZG_RET {
-    let deserialized_blob = deserialize_blob(blob);
+    let settings = kzg_settings_to_rust(s);
+    let deserialized_blob = deserialize_blob(blob_bytes(blob, 1, &settings.blob_preset));
     if deserialized_blob.is_err() {
         return deserialized_blob.err().unwrap();
     }
@@ -881,14 +739,15 @@ pub unsafe extern "C" fn verify_blob_kzg_proof(
         &deserialized_blob.unwrap(),
         &commitment_g1.unwrap(),
         &proof_g1.unwrap(),
-        &kzg_settings_to_rust(s),
+        &settings,
     );
 
-    if let Ok(result) = result {
-        *ok = result;
-        C_KZG_RET_OK
-    } else {
-        C_KZG_RET_BADARGS
+    match result {
+        Ok(result) => {
+            *ok = result;
+            C_KZG_RET_OK
+        }
+        Err(err) => err.into(),
     }
 }
 
@@ -902,39 +761,37 @@ pub unsafe extern "C" fn verify_blob_kzg_proof_batch(
     n: usize,
     s: &CKZGSettings,
 ) -> C_KZG_RET {
-    let raw_blobs = core::slice::from_raw_parts(blobs, n);
+    let settings = kzg_settings_to_rust(s);
+    let bytes_per_blob = settings.blob_preset.bytes_per_blob();
+    let raw_blobs = blob_bytes(blobs, n, &settings.blob_preset)
+        .chunks(bytes_per_blob)
+        .collect::<Vec<_>>();
     let raw_commitments = core::slice::from_raw_parts(commitments_bytes, n);
     let raw_proofs = core::slice::from_raw_parts(proofs_bytes, n);
 
-    let deserialized_blobs: Result<Vec<Vec<FsFr>>, C_KZG_RET> = cfg_into_iter!(raw_blobs)
-        .map(|raw_blob| deserialize_blob(raw_blob).map_err(|_| C_KZG_RET_BADARGS))
-        .collect();
+    let deserialized_blobs: Result<Vec<Vec<FsFr>>, C_KZG_RET> =
+        cfg_into_iter!(raw_blobs).map(deserialize_blob).collect();
 
     let commitments_g1: Result<Vec<FsG1>, C_KZG_RET> = cfg_into_iter!(raw_commitments)
-        .map(|raw_commitment| {
-            FsG1::from_bytes(&raw_commitment.bytes).map_err(|_| C_KZG_RET_BADARGS)
-        })
+        .map(|raw_commitment| FsG1::from_bytes(&raw_commitment.bytes).map_err(C_KZG_RET::from))
         .collect();
 
     let proofs_g1: Result<Vec<FsG1>, C_KZG_RET> = cfg_into_iter!(raw_proofs)
-        .map(|raw_proof| FsG1::from_bytes(&raw_proof.bytes).map_err(|_| C_KZG_RET_BADARGS))
+        .map(|raw_proof| FsG1::from_bytes(&raw_proof.bytes).map_err(C_KZG_RET::from))
         .collect();
 
     if let (Ok(blobs), Ok(commitments), Ok(proofs)) =
         (deserialized_blobs, commitments_g1, proofs_g1)
     {
-        let result = verify_blob_kzg_proof_batch_rust(
-            blobs.as_slice(),
-            &commitments,
-            &proofs,
-            &kzg_settings_to_rust(s),
-        );
-
-        if let Ok(result) = result {
-            *ok = result;
-            C_KZG_RET_OK
-        } else {
-            C_KZG_RET_BADARGS
+        let result =
+            verify_blob_kzg_proof_batch_rust(blobs.as_slice(), &commitments, &proofs, &settings);
+
+        match result {
+            Ok(result) => {
+                *ok = result;
+                C_KZG_RET_OK
+            }
+            Err(err) => err.into(),
         }
     } else {
         *ok = false;
@@ -951,7 +808,8 @@ pub unsafe extern "C" fn compute_kzg_proof(
     z_bytes: *const Bytes32,
     s: &CKZGSettings,
 ) -> C_KZG_RET {
-    let deserialized_blob = deserialize_blob(blob);
+    let settings = kzg_settings_to_rust(s);
+    let deserialized_blob = deserialize_blob(blob_bytes(blob, 1, &settings.blob_preset));
     if deserialized_blob.is_err() {
         return deserialized_blob.err().unwrap();
     }
@@ -959,12 +817,50 @@ pub unsafe extern "C" fn compute_kzg_proof(
     if frz.is_err() {
         return C_KZG_RET_BADARGS;
     }
-    let (proof_out_tmp, fry_tmp) = compute_kzg_proof_rust(
-        &deserialized_blob.unwrap(),
-        &frz.unwrap(),
-        &kzg_settings_to_rust(s),
-    );
+    let (proof_out_tmp, fry_tmp) =
+        compute_kzg_proof_rust(&deserialized_blob.unwrap(), &frz.unwrap(), &settings);
     (*proof_out).bytes = proof_out_tmp.to_bytes();
     (*y_out).bytes = fry_tmp.to_bytes();
     C_KZG_RET_OK
 }
+
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn kzg_to_versioned_hash(
+    versioned_hash_out: *mut Bytes32,
+    commitment: *const KZGCommitment,
+) -> C_KZG_RET {
+    (*versioned_hash_out).bytes = kzg::eip_4844::kzg_to_versioned_hash(&(*commitment).bytes);
+    C_KZG_RET_OK
+}
+
+/// Runs the point evaluation precompile on `input_len` bytes of `input`.
+///
+/// Malformed input, including a versioned hash that does not match the commitment, is reported
+/// as `C_KZG_RET_BADARGS`. A well-formed input with a wrong proof sets `ok` to `false`. The
+/// 64-byte output is only written when `ok` is `true`.
+///
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn point_evaluation_precompile(
+    ok: *mut bool,
+    output: *mut u8,
+    input: *const u8,
+    input_len: u64,
+    s: &CKZGSettings,
+) -> C_KZG_RET {
+    let input = core::slice::from_raw_parts(input, input_len as usize);
+
+    match point_evaluation_precompile_rust(input, &kzg_settings_to_rust(s)) {
+        Ok(result) => {
+            core::ptr::copy_nonoverlapping(result.as_ptr(), output, result.len());
+            *ok = true;
+            C_KZG_RET_OK
+        }
+        Err(PointEvaluationError::VerificationFailed) => {
+            *ok = false;
+            C_KZG_RET_OK
+        }
+        Err(_) => C_KZG_RET_BADARGS,
+    }
+}
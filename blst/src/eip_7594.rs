@@ -0,0 +1,465 @@
+extern crate alloc;
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use kzg::eip_4844::{
+    compute_powers, Blob, Bytes48, CKZGSettings, KZGProof, BYTES_PER_FIELD_ELEMENT, C_KZG_RET,
+    C_KZG_RET_BADARGS, C_KZG_RET_OK,
+};
+use kzg::eip_7594::{
+    compute_verify_cell_kzg_proof_batch_challenge, Cell, BYTES_PER_CELL, FIELD_ELEMENTS_PER_CELL,
+};
+use kzg::{Error, FFTFr, FFTSettings, FK20MultiSettings, Fr, G1LinComb, PolyRecover, G1};
+
+use crate::eip_4844::{blob_bytes, deserialize_blob, kzg_settings_to_rust};
+use crate::kzg_proofs::pairings_verify;
+use crate::types::fft_settings::FsFFTSettings;
+use crate::types::fk20_multi_settings::FsFK20MultiSettings;
+use crate::types::fr::FsFr;
+use crate::types::g1::FsG1;
+use crate::types::kzg_settings::FsKZGSettings;
+use crate::types::poly::FsPoly;
+use crate::utils::reverse_bit_order;
+
+/// The evaluations of an extended blob on one coset of the extended domain.
+pub type FsCell = [FsFr; FIELD_ELEMENTS_PER_CELL];
+
+pub fn bytes_to_cell(bytes: &[u8]) -> Result<FsCell, Error> {
+    if bytes.len() != BYTES_PER_CELL {
+        return Err(Error::InvalidLength {
+            expected: BYTES_PER_CELL,
+            actual: bytes.len(),
+        });
+    }
+
+    let mut cell = [FsFr::zero(); FIELD_ELEMENTS_PER_CELL];
+    for (element, chunk) in cell.iter_mut().zip(bytes.chunks(BYTES_PER_FIELD_ELEMENT)) {
+        *element = FsFr::from_bytes(chunk)?;
+    }
+    Ok(cell)
+}
+
+pub fn cell_to_bytes(cell: &FsCell) -> [u8; BYTES_PER_CELL] {
+    let mut bytes = [0u8; BYTES_PER_CELL];
+    for (chunk, element) in bytes.chunks_mut(BYTES_PER_FIELD_ELEMENT).zip(cell) {
+        chunk.copy_from_slice(&element.to_bytes());
+    }
+    bytes
+}
+
+/// Returns the number of cells in an extended blob of the settings' preset.
+fn cells_per_ext_blob(s: &FsKZGSettings) -> Result<usize, Error> {
+    let field_elements_per_blob = s.blob_preset.field_elements_per_blob;
+    if field_elements_per_blob < FIELD_ELEMENTS_PER_CELL {
+        return Err(Error::InvalidArgument(
+            "Blobs are too small to be split into cells",
+        ));
+    }
+    if s.secret_g1_monomial.len() < field_elements_per_blob
+        || s.secret_g2.len() <= FIELD_ELEMENTS_PER_CELL
+    {
+        return Err(Error::SetupTooSmall);
+    }
+
+    Ok(2 * field_elements_per_blob / FIELD_ELEMENTS_PER_CELL)
+}
+
+/// FFT settings over the extended domain, which is twice the size of a blob.
+pub(crate) fn ext_fft_settings(s: &FsKZGSettings) -> Result<FsFFTSettings, Error> {
+    let field_elements_per_ext_blob = 2 * s.blob_preset.field_elements_per_blob;
+    FsFFTSettings::new(field_elements_per_ext_blob.trailing_zeros() as usize)
+}
+
+/// Settings with the monomial setup points over the extended domain, as FK20 expects them.
+pub(crate) fn monomial_settings(s: &FsKZGSettings, fs_ext: &FsFFTSettings) -> FsKZGSettings {
+    FsKZGSettings {
+        fs: fs_ext.clone(),
+        secret_g1: s.secret_g1_monomial[..s.blob_preset.field_elements_per_blob].to_vec(),
+        ..FsKZGSettings::default()
+    }
+}
+
+/// Returns the FK20 tables of the settings, computing them on first use.
+pub(crate) fn cell_proof_tables(s: &FsKZGSettings) -> Result<&Vec<Vec<FsG1>>, Error> {
+    cells_per_ext_blob(s)?;
+    s.cell_proof_tables.get_or_try_init(|| {
+        let fs_ext = ext_fft_settings(s)?;
+        let fk = FsFK20MultiSettings::new(
+            &monomial_settings(s, &fs_ext),
+            fs_ext.max_width,
+            FIELD_ELEMENTS_PER_CELL,
+        )?;
+        Ok(fk.x_ext_fft_files)
+    })
+}
+
+/// Computes the cells and their proofs for a polynomial of at most `FIELD_ELEMENTS_PER_BLOB`
+/// coefficients.
+///
+/// The cells are the evaluations over the extended domain in bit-reversed order, split into
+/// chunks of `FIELD_ELEMENTS_PER_CELL`. Each proof is a multiproof for its cell's coset, computed
+/// for all cells at once with FK20.
+fn compute_cells_and_kzg_proofs_from_coeffs(
+    coeffs: &[FsFr],
+    fs_ext: &FsFFTSettings,
+    s: &FsKZGSettings,
+) -> Result<(Vec<FsCell>, Vec<FsG1>), Error> {
+    let field_elements_per_blob = s.blob_preset.field_elements_per_blob;
+    let field_elements_per_ext_blob = 2 * field_elements_per_blob;
+
+    let mut extended = coeffs.to_vec();
+    extended.resize(field_elements_per_ext_blob, FsFr::zero());
+    let mut evaluations = fs_ext.fft_fr(&extended, false)?;
+    reverse_bit_order(&mut evaluations);
+
+    let cells = evaluations
+        .chunks_exact(FIELD_ELEMENTS_PER_CELL)
+        .map(|chunk| chunk.try_into().unwrap())
+        .collect();
+
+    let fk = FsFK20MultiSettings {
+        kzg_settings: monomial_settings(s, fs_ext),
+        chunk_len: FIELD_ELEMENTS_PER_CELL,
+        x_ext_fft_files: cell_proof_tables(s)?.clone(),
+    };
+    let mut poly = FsPoly {
+        coeffs: coeffs.to_vec(),
+    };
+    poly.coeffs.resize(field_elements_per_blob, FsFr::zero());
+    let proofs = fk.data_availability(&poly)?;
+
+    Ok((cells, proofs))
+}
+
+pub fn compute_cells_and_kzg_proofs_rust(
+    blob: &[FsFr],
+    s: &FsKZGSettings,
+) -> Result<(Vec<FsCell>, Vec<FsG1>), Error> {
+    cells_per_ext_blob(s)?;
+    if blob.len() != s.blob_preset.field_elements_per_blob {
+        return Err(Error::InvalidLength {
+            expected: s.blob_preset.field_elements_per_blob,
+            actual: blob.len(),
+        });
+    }
+
+    // The blob holds evaluations over the roots of unity in bit-reversed order
+    let mut evaluations = blob.to_vec();
+    reverse_bit_order(&mut evaluations);
+    let fs_ext = ext_fft_settings(s)?;
+    let coeffs = fs_ext.fft_fr(&evaluations, true)?;
+
+    compute_cells_and_kzg_proofs_from_coeffs(&coeffs, &fs_ext, s)
+}
+
+/// Recovers all cells and proofs of an extended blob from at least half of its cells, given in
+/// any order.
+pub fn recover_cells_and_kzg_proofs_rust(
+    cell_indices: &[usize],
+    cells: &[FsCell],
+    s: &FsKZGSettings,
+) -> Result<(Vec<FsCell>, Vec<FsG1>), Error> {
+    let cells_per_ext_blob = cells_per_ext_blob(s)?;
+    if cells.len() != cell_indices.len() {
+        return Err(Error::InvalidLength {
+            expected: cell_indices.len(),
+            actual: cells.len(),
+        });
+    }
+    if cells.len() < cells_per_ext_blob / 2 {
+        return Err(Error::InvalidArgument(
+            "At least half of the cells are needed for recovery",
+        ));
+    }
+    if cells.len() > cells_per_ext_blob {
+        return Err(Error::InvalidArgument(
+            "More cells than in an extended blob",
+        ));
+    }
+
+    let mut samples = vec![None; cells_per_ext_blob * FIELD_ELEMENTS_PER_CELL];
+    for (&index, cell) in cell_indices.iter().zip(cells) {
+        if index >= cells_per_ext_blob {
+            return Err(Error::InvalidArgument("Cell index out of range"));
+        }
+        let chunk = &mut samples[index * FIELD_ELEMENTS_PER_CELL..][..FIELD_ELEMENTS_PER_CELL];
+        if chunk[0].is_some() {
+            return Err(Error::InvalidArgument("Duplicate cell index"));
+        }
+        for (sample, element) in chunk.iter_mut().zip(cell) {
+            *sample = Some(*element);
+        }
+    }
+    // Cells are in bit-reversed order, while recovery works over the domain in natural order
+    reverse_bit_order(&mut samples);
+
+    let fs_ext = ext_fft_settings(s)?;
+    let mut coeffs = if cells.len() == cells_per_ext_blob {
+        let evaluations = samples.into_iter().map(Option::unwrap).collect::<Vec<_>>();
+        fs_ext.fft_fr(&evaluations, true)?
+    } else {
+        FsPoly::recover_poly_coeffs_from_samples(&samples, &fs_ext)?.coeffs
+    };
+    coeffs.truncate(s.blob_preset.field_elements_per_blob);
+
+    compute_cells_and_kzg_proofs_from_coeffs(&coeffs, &fs_ext, s)
+}
+
+/// Verifies a batch of cell proofs, possibly for cells of different blobs, with a single pairing
+/// check over a random linear combination of the cells.
+pub fn verify_cell_kzg_proof_batch_rust(
+    commitments: &[FsG1],
+    cell_indices: &[usize],
+    cells: &[FsCell],
+    proofs: &[FsG1],
+    s: &FsKZGSettings,
+) -> Result<bool, Error> {
+    let cells_per_ext_blob = cells_per_ext_blob(s)?;
+    let num_cells = cells.len();
+    for len in [commitments.len(), cell_indices.len(), proofs.len()] {
+        if len != num_cells {
+            return Err(Error::InvalidLength {
+                expected: num_cells,
+                actual: len,
+            });
+        }
+    }
+    if cell_indices
+        .iter()
+        .any(|&index| index >= cells_per_ext_blob)
+    {
+        return Err(Error::InvalidArgument("Cell index out of range"));
+    }
+    if !commitments.iter().all(FsG1::is_valid) {
+        return Err(Error::InvalidArgument("Invalid commitment"));
+    }
+    if !proofs.iter().all(FsG1::is_valid) {
+        return Err(Error::InvalidArgument("Invalid proof"));
+    }
+    if num_cells == 0 {
+        return Ok(true);
+    }
+
+    // Deduplicate the commitments, keeping the order in which they first appear
+    let mut unique_commitments: Vec<FsG1> = Vec::new();
+    let commitment_indices = commitments
+        .iter()
+        .map(|commitment| {
+            match unique_commitments
+                .iter()
+                .position(|unique| unique.equals(commitment))
+            {
+                Some(position) => position,
+                None => {
+                    unique_commitments.push(*commitment);
+                    unique_commitments.len() - 1
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let r = compute_verify_cell_kzg_proof_batch_challenge(
+        s.blob_preset.field_elements_per_blob,
+        &unique_commitments,
+        &commitment_indices,
+        cell_indices,
+        cells,
+        proofs,
+    );
+    let r_powers = compute_powers(&r, num_cells);
+
+    // sum(r^k * proof_k)
+    let proof_lincomb = FsG1::g1_lincomb(proofs, &r_powers, num_cells);
+
+    // sum(r^k * commitment_k), with the powers of each unique commitment added up first
+    let mut commitment_weights = vec![FsFr::zero(); unique_commitments.len()];
+    for (&index, r_power) in commitment_indices.iter().zip(&r_powers) {
+        commitment_weights[index] = commitment_weights[index].add(r_power);
+    }
+    let commitment_lincomb = FsG1::g1_lincomb(
+        &unique_commitments,
+        &commitment_weights,
+        unique_commitments.len(),
+    );
+
+    // sum(r^k * I_k(s)), where I_k interpolates the k-th cell over its coset h_k * {w^j}
+    let fs_ext = ext_fft_settings(s)?;
+    let field_elements_per_ext_blob = fs_ext.max_width;
+    let mut coset_shifts = fs_ext.expanded_roots_of_unity[..field_elements_per_ext_blob].to_vec();
+    reverse_bit_order(&mut coset_shifts);
+    let mut inverse_coset_shifts =
+        fs_ext.reverse_roots_of_unity[..field_elements_per_ext_blob].to_vec();
+    reverse_bit_order(&mut inverse_coset_shifts);
+
+    let mut interpolation_lincomb = vec![FsFr::zero(); FIELD_ELEMENTS_PER_CELL];
+    let mut weighted_r_powers = Vec::with_capacity(num_cells);
+    for ((&index, cell), r_power) in cell_indices.iter().zip(cells).zip(&r_powers) {
+        let mut evaluations = cell.to_vec();
+        reverse_bit_order(&mut evaluations);
+        // The coefficients of I_k(h_k * x), which are scaled back by powers of 1 / h_k below
+        let coeffs = fs_ext.fft_fr(&evaluations, true)?;
+
+        let inverse_shift = inverse_coset_shifts[index * FIELD_ELEMENTS_PER_CELL];
+        let mut scale = *r_power;
+        for (sum, coeff) in interpolation_lincomb.iter_mut().zip(&coeffs) {
+            *sum = sum.add(&coeff.mul(&scale));
+            scale = scale.mul(&inverse_shift);
+        }
+
+        let shift = coset_shifts[index * FIELD_ELEMENTS_PER_CELL];
+        weighted_r_powers.push(r_power.mul(&shift.pow(FIELD_ELEMENTS_PER_CELL)));
+    }
+    let interpolation_commitment = FsG1::g1_lincomb(
+        &s.secret_g1_monomial,
+        &interpolation_lincomb,
+        FIELD_ELEMENTS_PER_CELL,
+    );
+
+    // sum(r^k * h_k^n * proof_k)
+    let weighted_proof_lincomb = FsG1::g1_lincomb(proofs, &weighted_r_powers, num_cells);
+
+    let rhs = commitment_lincomb
+        .sub(&interpolation_commitment)
+        .add_or_dbl(&weighted_proof_lincomb);
+
+    // e(sum(r^k * proof_k), [s^n]) == e(rhs, [1])
+    Ok(pairings_verify(
+        &proof_lincomb,
+        &s.secret_g2[FIELD_ELEMENTS_PER_CELL],
+        &rhs,
+        &s.secret_g2[0],
+    ))
+}
+
+unsafe fn deserialize_cells(cells: *const Cell, n: usize) -> Result<Vec<FsCell>, C_KZG_RET> {
+    core::slice::from_raw_parts(cells, n)
+        .iter()
+        .map(|cell| bytes_to_cell(&cell.bytes).map_err(C_KZG_RET::from))
+        .collect()
+}
+
+unsafe fn write_cells_and_proofs(
+    cells_out: *mut Cell,
+    proofs_out: *mut KZGProof,
+    cells: &[FsCell],
+    proofs: &[FsG1],
+) {
+    if !cells_out.is_null() {
+        for (out, cell) in core::slice::from_raw_parts_mut(cells_out, cells.len())
+            .iter_mut()
+            .zip(cells)
+        {
+            out.bytes = cell_to_bytes(cell);
+        }
+    }
+    if !proofs_out.is_null() {
+        for (out, proof) in core::slice::from_raw_parts_mut(proofs_out, proofs.len())
+            .iter_mut()
+            .zip(proofs)
+        {
+            out.bytes = proof.to_bytes();
+        }
+    }
+}
+
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn compute_cells_and_kzg_proofs(
+    cells: *mut Cell,
+    proofs: *mut KZGProof,
+    blob: *const Blob,
+    s: &CKZGSettings,
+) -> C_KZG_RET {
+    let settings = kzg_settings_to_rust(s);
+    let deserialized_blob = match deserialize_blob(blob_bytes(blob, 1, &settings.blob_preset)) {
+        Ok(blob) => blob,
+        Err(err) => return err,
+    };
+
+    match compute_cells_and_kzg_proofs_rust(&deserialized_blob, &settings) {
+        Ok((computed_cells, computed_proofs)) => {
+            write_cells_and_proofs(cells, proofs, &computed_cells, &computed_proofs);
+            C_KZG_RET_OK
+        }
+        Err(err) => err.into(),
+    }
+}
+
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn recover_cells_and_kzg_proofs(
+    recovered_cells: *mut Cell,
+    recovered_proofs: *mut KZGProof,
+    cell_indices: *const u64,
+    cells: *const Cell,
+    num_cells: u64,
+    s: &CKZGSettings,
+) -> C_KZG_RET {
+    let settings = kzg_settings_to_rust(s);
+    let num_cells = num_cells as usize;
+    let indices = core::slice::from_raw_parts(cell_indices, num_cells)
+        .iter()
+        .map(|&index| index as usize)
+        .collect::<Vec<_>>();
+    let deserialized_cells = match deserialize_cells(cells, num_cells) {
+        Ok(cells) => cells,
+        Err(err) => return err,
+    };
+
+    match recover_cells_and_kzg_proofs_rust(&indices, &deserialized_cells, &settings) {
+        Ok((all_cells, all_proofs)) => {
+            write_cells_and_proofs(recovered_cells, recovered_proofs, &all_cells, &all_proofs);
+            C_KZG_RET_OK
+        }
+        Err(err) => err.into(),
+    }
+}
+
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn verify_cell_kzg_proof_batch(
+    ok: *mut bool,
+    commitments_bytes: *const Bytes48,
+    cell_indices: *const u64,
+    cells: *const Cell,
+    proofs_bytes: *const Bytes48,
+    num_cells: u64,
+    s: &CKZGSettings,
+) -> C_KZG_RET {
+    *ok = false;
+    if num_cells == 0 {
+        *ok = true;
+        return C_KZG_RET_OK;
+    }
+    let settings = kzg_settings_to_rust(s);
+    let num_cells = num_cells as usize;
+
+    let commitments: Result<Vec<FsG1>, C_KZG_RET> =
+        core::slice::from_raw_parts(commitments_bytes, num_cells)
+            .iter()
+            .map(|raw| FsG1::from_bytes(&raw.bytes).map_err(C_KZG_RET::from))
+            .collect();
+    let proofs: Result<Vec<FsG1>, C_KZG_RET> = core::slice::from_raw_parts(proofs_bytes, num_cells)
+        .iter()
+        .map(|raw| FsG1::from_bytes(&raw.bytes).map_err(C_KZG_RET::from))
+        .collect();
+    let indices = core::slice::from_raw_parts(cell_indices, num_cells)
+        .iter()
+        .map(|&index| index as usize)
+        .collect::<Vec<_>>();
+
+    let (commitments, proofs, cells) =
+        match (commitments, proofs, deserialize_cells(cells, num_cells)) {
+            (Ok(commitments), Ok(proofs), Ok(cells)) => (commitments, proofs, cells),
+            _ => return C_KZG_RET_BADARGS,
+        };
+
+    match verify_cell_kzg_proof_batch_rust(&commitments, &indices, &cells, &proofs, &settings) {
+        Ok(result) => {
+            *ok = result;
+            C_KZG_RET_OK
+        }
+        Err(err) => err.into(),
+    }
+}
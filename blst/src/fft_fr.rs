@@ -1,11 +1,9 @@
 extern crate alloc;
 
-use alloc::format;
-use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
 
-use kzg::{FFTFr, Fr};
+use kzg::{Error, FFTFr, Fr};
 
 use crate::types::fft_settings::FsFFTSettings;
 use crate::types::fr::FsFr;
@@ -73,21 +71,20 @@ impl FsFFTSettings {
         data: &[FsFr],
         inverse: bool,
         output: &mut [FsFr],
-    ) -> Result<(), String> {
+    ) -> Result<(), Error> {
         if data.len() > self.max_width {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "Supplied list is longer than the available max width",
             ));
         }
         if data.len() != output.len() {
-            return Err(format!(
-                "Output length {} doesn't match data length {}",
-                data.len(),
-                output.len()
-            ));
+            return Err(Error::InvalidLength {
+                expected: data.len(),
+                actual: output.len(),
+            });
         }
         if !data.len().is_power_of_two() {
-            return Err(String::from("A list with power-of-two length expected"));
+            return Err(Error::NotPowerOfTwo("list length"));
         }
 
         // In case more roots are provided with fft_settings, use a larger stride
@@ -105,7 +102,7 @@ impl FsFFTSettings {
 
         if inverse {
             let inv_fr_len = FsFr::from_u64(data.len() as u64).inverse();
-            output.iter_mut().for_each(|f| *f = f.mul(&inv_fr_len));
+            FsFr::batch_mul_scalar(output, &inv_fr_len);
         }
 
         Ok(())
@@ -114,7 +111,7 @@ impl FsFFTSettings {
 
 impl FFTFr<FsFr> for FsFFTSettings {
     /// Fast Fourier Transform for finite field elements
-    fn fft_fr(&self, data: &[FsFr], inverse: bool) -> Result<Vec<FsFr>, String> {
+    fn fft_fr(&self, data: &[FsFr], inverse: bool) -> Result<Vec<FsFr>, Error> {
         let mut ret = vec![FsFr::default(); data.len()];
 
         self.fft_fr_output(data, inverse, &mut ret)?;
@@ -1,10 +1,9 @@
 extern crate alloc;
 
-use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
 
-use kzg::{Fr, G1Mul, FFTG1, G1};
+use kzg::{Error, Fr, G1Mul, FFTG1, G1};
 
 use crate::types::fft_settings::FsFFTSettings;
 use crate::types::fr::FsFr;
@@ -51,13 +50,13 @@ pub fn fft_g1_fast(
 }
 
 impl FFTG1<FsG1> for FsFFTSettings {
-    fn fft_g1(&self, data: &[FsG1], inverse: bool) -> Result<Vec<FsG1>, String> {
+    fn fft_g1(&self, data: &[FsG1], inverse: bool) -> Result<Vec<FsG1>, Error> {
         if data.len() > self.max_width {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "Supplied list is longer than the available max width",
             ));
         } else if !data.len().is_power_of_two() {
-            return Err(String::from("A list with power-of-two length expected"));
+            return Err(Error::NotPowerOfTwo("list length"));
         }
 
         let stride = self.max_width / data.len();
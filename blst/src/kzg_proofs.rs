@@ -6,20 +6,21 @@ use alloc::vec::Vec;
 #[cfg(not(feature = "parallel"))]
 use core::ptr;
 
-#[cfg(feature = "parallel")]
-use blst::p1_affines;
 #[cfg(not(feature = "parallel"))]
 use blst::{
     blst_p1s_mult_pippenger, blst_p1s_mult_pippenger_scratch_sizeof, blst_p1s_to_affine,
+    blst_p2s_mult_pippenger, blst_p2s_mult_pippenger_scratch_sizeof, blst_p2s_to_affine,
     blst_scalar, limb_t,
 };
+#[cfg(feature = "parallel")]
+use blst::{p1_affines, p2_affines};
 
 use blst::{
-    blst_fp12_is_one, blst_p1, blst_p1_affine, blst_p1_cneg, blst_p1_to_affine, blst_p2_affine,
-    blst_p2_to_affine, Pairing,
+    blst_fp12_is_one, blst_p1, blst_p1_affine, blst_p1_cneg, blst_p1_to_affine, blst_p2,
+    blst_p2_affine, blst_p2_is_inf, blst_p2_to_affine, Pairing,
 };
 
-use kzg::{Fr, G1Mul, G1};
+use kzg::{Fr, G1Mul, G2Mul, G1, G2};
 
 use crate::types::fr::FsFr;
 use crate::types::g1::FsG1;
@@ -85,6 +86,66 @@ pub fn g1_linear_combination(out: &mut FsG1, points: &[FsG1], scalars: &[FsFr],
     }
 }
 
+pub fn g2_linear_combination(out: &mut FsG2, points: &[FsG2], scalars: &[FsFr], len: usize) {
+    if len < 8 {
+        *out = FsG2::default();
+        for i in 0..len {
+            let tmp = points[i].mul(&scalars[i]);
+            *out = out.add_or_dbl(&tmp);
+        }
+        return;
+    }
+
+    #[cfg(feature = "parallel")]
+    {
+        let points = unsafe { core::slice::from_raw_parts(points.as_ptr() as *const blst_p2, len) };
+        let points = p2_affines::from(points);
+
+        let mut scalar_bytes: Vec<u8> = Vec::with_capacity(len * 32);
+        for bytes in scalars.iter().map(|b| b.to_bytes()) {
+            scalar_bytes.extend_from_slice(&bytes);
+        }
+
+        let res = points.mult(scalar_bytes.as_slice(), 255);
+        *out = FsG2(res)
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    {
+        let mut scratch: Vec<u8>;
+        unsafe {
+            scratch = vec![0u8; blst_p2s_mult_pippenger_scratch_sizeof(len) as usize];
+        }
+
+        let mut p_affine = vec![blst_p2_affine::default(); len];
+        let mut p_scalars = vec![blst_scalar::default(); len];
+
+        let p_arg: [*const blst_p2; 2] = [&points[0].0, ptr::null()];
+        unsafe {
+            blst_p2s_to_affine(p_affine.as_mut_ptr(), p_arg.as_ptr(), len);
+        }
+
+        for i in 0..len {
+            p_scalars[i] = blst_scalar {
+                b: scalars[i].to_bytes(),
+            };
+        }
+
+        let scalars_arg: [*const blst_scalar; 2] = [p_scalars.as_ptr(), ptr::null()];
+        let points_arg: [*const blst_p2_affine; 2] = [p_affine.as_ptr(), ptr::null()];
+        unsafe {
+            blst_p2s_mult_pippenger(
+                &mut out.0,
+                points_arg.as_ptr(),
+                len,
+                scalars_arg.as_ptr() as *const *const u8,
+                256,
+                scratch.as_mut_ptr() as *mut limb_t,
+            );
+        }
+    }
+}
+
 pub fn pairings_verify(a1: &FsG1, a2: &FsG2, b1: &FsG1, b2: &FsG2) -> bool {
     let mut aa1 = blst_p1_affine::default();
     let mut bb1 = blst_p1_affine::default();
@@ -112,3 +173,32 @@ pub fn pairings_verify(a1: &FsG1, a2: &FsG2, b1: &FsG1, b2: &FsG2) -> bool {
         blst_fp12_is_one(&gt_point)
     }
 }
+
+pub fn pairings_verify_multi(pairs: &[(FsG1, FsG2)]) -> bool {
+    let dst = [0u8; 3];
+    let mut pairing_blst = Pairing::new(false, &dst);
+    let mut aggregated = false;
+
+    for (p, q) in pairs {
+        // e(p, q) is the identity when either point is at infinity
+        if p.is_inf() || unsafe { blst_p2_is_inf(&q.0) } {
+            continue;
+        }
+
+        let mut p_affine = blst_p1_affine::default();
+        let mut q_affine = blst_p2_affine::default();
+        unsafe {
+            blst_p1_to_affine(&mut p_affine, &p.0);
+            blst_p2_to_affine(&mut q_affine, &q.0);
+        }
+        pairing_blst.raw_aggregate(&q_affine, &p_affine);
+        aggregated = true;
+    }
+
+    if !aggregated {
+        return true;
+    }
+
+    let gt_point = pairing_blst.as_fp12().final_exp();
+    unsafe { blst_fp12_is_one(&gt_point) }
+}
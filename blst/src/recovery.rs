@@ -1,74 +1,27 @@
 extern crate alloc;
 
-use alloc::string::String;
 use alloc::vec::Vec;
 
-use kzg::{FFTFr, Fr, PolyRecover, ZeroPoly};
+use kzg::reed_solomon::correct_errors;
+use kzg::{Error, FFTFr, Fr, PolyErrorCorrect, PolyRecover, ZeroPoly};
 
+use crate::consts::SCALE_FACTOR;
 use crate::types::fft_settings::FsFFTSettings;
 use crate::types::fr::FsFr;
 use crate::types::poly::FsPoly;
-use once_cell::sync::OnceCell;
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
-const SCALE_FACTOR: u64 = 5;
-static INVERSE_FACTORS: OnceCell<Vec<FsFr>> = OnceCell::new();
-static UNSCALE_FACTOR_POWERS: OnceCell<Vec<FsFr>> = OnceCell::new();
-
-pub fn scale_poly(p: &mut [FsFr], len_p: usize) {
-    let factors = INVERSE_FACTORS.get_or_init(|| {
-        let scale_factor = FsFr::from_u64(SCALE_FACTOR);
-        let inv_factor = FsFr::inverse(&scale_factor);
-        let mut temp = Vec::with_capacity(65536);
-        temp.push(FsFr::one());
-        for i in 1..65536 {
-            temp.push(temp[i - 1].mul(&inv_factor));
-        }
-        temp
-    });
-
-    p.iter_mut()
-        .zip(factors)
-        .take(len_p)
-        .skip(1)
-        .for_each(|(p, factor)| {
-            *p = p.mul(factor);
-        });
-}
-
-pub fn unscale_poly(p: &mut [FsFr], len_p: usize) {
-    let factors = UNSCALE_FACTOR_POWERS.get_or_init(|| {
-        let scale_factor = FsFr::from_u64(SCALE_FACTOR);
-        let mut temp = Vec::with_capacity(65536);
-        temp.push(FsFr::one());
-        for i in 1..65536 {
-            temp.push(temp[i - 1].mul(&scale_factor));
-        }
-        temp
-    });
-
-    p.iter_mut()
-        .zip(factors)
-        .take(len_p)
-        .skip(1)
-        .for_each(|(p, factor)| {
-            *p = p.mul(factor);
-        });
-}
-
 impl PolyRecover<FsFr, FsPoly, FsFFTSettings> for FsPoly {
     fn recover_poly_coeffs_from_samples(
         samples: &[Option<FsFr>],
         fs: &FsFFTSettings,
-    ) -> Result<Self, String> {
+    ) -> Result<Self, Error> {
         let len_samples = samples.len();
 
         if !len_samples.is_power_of_two() {
-            return Err(String::from(
-                "Samples must have a length that is a power of two",
-            ));
+            return Err(Error::NotPowerOfTwo("samples length"));
         }
 
         let mut missing = Vec::with_capacity(len_samples / 2);
@@ -80,13 +33,13 @@ impl PolyRecover<FsFr, FsPoly, FsFFTSettings> for FsPoly {
         }
 
         if missing.len() > len_samples / 2 {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "Impossible to recover, too many shards are missing",
             ));
         }
 
         // Calculate `Z_r,I`
-        let (zero_eval, mut zero_poly) = fs.zero_poly_via_multiplication(len_samples, &missing)?;
+        let (zero_eval, zero_poly) = fs.zero_poly_via_multiplication(len_samples, &missing)?;
 
         // Construct E * Z_r,I: the loop makes the evaluation polynomial
         let poly_evaluations_with_zero = samples
@@ -103,43 +56,36 @@ impl PolyRecover<FsFr, FsPoly, FsFFTSettings> for FsPoly {
             .collect::<Vec<_>>();
 
         // Now inverse FFT so that poly_with_zero is (E * Z_r,I)(x) = (D * Z_r,I)(x)
-        let mut poly_with_zero = fs.fft_fr(&poly_evaluations_with_zero, true).unwrap();
+        let poly_with_zero = fs.fft_fr(&poly_evaluations_with_zero, true).unwrap();
         drop(poly_evaluations_with_zero);
 
-        // x -> k * x
-        let len_zero_poly = zero_poly.coeffs.len();
-        scale_poly(&mut poly_with_zero, len_samples);
-        scale_poly(&mut zero_poly.coeffs, len_zero_poly);
-
-        // Q1 = (D * Z_r,I)(k * x)
-        let scaled_poly_with_zero = poly_with_zero;
-
-        // Q2 = Z_r,I(k * x)
-        let scaled_zero_poly = zero_poly.coeffs;
+        // Evaluate over the coset w^i / k, where Z_r,I has no roots, so that the division is defined
+        let shift = FsFr::from_u64(SCALE_FACTOR).inverse();
 
         // Polynomial division by convolution: Q3 = Q1 / Q2
         #[cfg(feature = "parallel")]
         let (eval_scaled_poly_with_zero, eval_scaled_zero_poly) = {
-            if len_zero_poly - 1 > 1024 {
+            if zero_poly.coeffs.len() - 1 > 1024 {
                 rayon::join(
-                    || fs.fft_fr(&scaled_poly_with_zero, false).unwrap(),
-                    || fs.fft_fr(&scaled_zero_poly, false).unwrap(),
+                    || fs.coset_fft_fr(&poly_with_zero, &shift).unwrap(),
+                    || fs.coset_fft_fr(&zero_poly.coeffs, &shift).unwrap(),
                 )
             } else {
                 (
-                    fs.fft_fr(&scaled_poly_with_zero, false).unwrap(),
-                    fs.fft_fr(&scaled_zero_poly, false).unwrap(),
+                    fs.coset_fft_fr(&poly_with_zero, &shift).unwrap(),
+                    fs.coset_fft_fr(&zero_poly.coeffs, &shift).unwrap(),
                 )
             }
         };
         #[cfg(not(feature = "parallel"))]
         let (eval_scaled_poly_with_zero, eval_scaled_zero_poly) = {
             (
-                fs.fft_fr(&scaled_poly_with_zero, false).unwrap(),
-                fs.fft_fr(&scaled_zero_poly, false).unwrap(),
+                fs.coset_fft_fr(&poly_with_zero, &shift).unwrap(),
+                fs.coset_fft_fr(&zero_poly.coeffs, &shift).unwrap(),
             )
         };
-        drop(scaled_zero_poly);
+        drop(poly_with_zero);
+        drop(zero_poly);
 
         let mut eval_scaled_reconstructed_poly = eval_scaled_poly_with_zero;
         #[cfg(not(feature = "parallel"))]
@@ -157,24 +103,22 @@ impl PolyRecover<FsFr, FsPoly, FsFFTSettings> for FsPoly {
                 },
             );
 
-        // The result of the division is D(k * x):
-        let mut scaled_reconstructed_poly =
-            fs.fft_fr(&eval_scaled_reconstructed_poly, true).unwrap();
+        // The result of the division is D over the coset, back to coefficients
+        let reconstructed_poly = fs
+            .coset_ifft_fr(&eval_scaled_reconstructed_poly, &shift)
+            .unwrap();
         drop(eval_scaled_reconstructed_poly);
 
-        // k * x -> x
-        unscale_poly(&mut scaled_reconstructed_poly, len_samples);
-
         // Finally we have D(x) which evaluates to our original data at the powers of roots of unity
         Ok(Self {
-            coeffs: scaled_reconstructed_poly,
+            coeffs: reconstructed_poly,
         })
     }
 
     fn recover_poly_from_samples(
         samples: &[Option<FsFr>],
         fs: &FsFFTSettings,
-    ) -> Result<Self, String> {
+    ) -> Result<Self, Error> {
         let reconstructed_poly = Self::recover_poly_coeffs_from_samples(samples, fs)?;
 
         // The evaluation polynomial for D(x) is the reconstructed data:
@@ -193,3 +137,14 @@ impl PolyRecover<FsFr, FsPoly, FsFFTSettings> for FsPoly {
         })
     }
 }
+
+impl PolyErrorCorrect<FsFr, FsPoly, FsFFTSettings> for FsPoly {
+    fn correct_poly_from_samples(
+        samples: &[Option<FsFr>],
+        data_len: usize,
+        fs: &FsFFTSettings,
+    ) -> Result<(Self, Vec<usize>), Error> {
+        let (coeffs, corrected) = correct_errors(samples, data_len, fs)?;
+        Ok((Self { coeffs }, corrected))
+    }
+}
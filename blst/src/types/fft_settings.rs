@@ -1,10 +1,9 @@
 extern crate alloc;
 
-use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
 
-use kzg::{FFTSettings, Fr};
+use kzg::{Error, FFTSettings, Fr};
 
 use crate::consts::SCALE2_ROOT_OF_UNITY;
 use crate::types::fr::FsFr;
@@ -25,11 +24,44 @@ impl Default for FsFFTSettings {
     }
 }
 
+impl FsFFTSettings {
+    /// Rebuilds the settings from previously computed expanded roots of unity, which must hold
+    /// `max_width + 1` powers of the root, starting and ending with one.
+    pub fn from_expanded_roots_of_unity(
+        expanded_roots_of_unity: Vec<FsFr>,
+    ) -> Result<FsFFTSettings, Error> {
+        let max_width = expanded_roots_of_unity.len().saturating_sub(1);
+        if !max_width.is_power_of_two() {
+            return Err(Error::NotPowerOfTwo("max width"));
+        }
+        if !expanded_roots_of_unity[0].is_one() || !expanded_roots_of_unity[max_width].is_one() {
+            return Err(Error::InvalidArgument(
+                "Expanded roots of unity must start and end with one",
+            ));
+        }
+
+        let root_of_unity = expanded_roots_of_unity[1];
+        let mut reverse_roots_of_unity = expanded_roots_of_unity.clone();
+        reverse_roots_of_unity.reverse();
+
+        let mut roots_of_unity = expanded_roots_of_unity.clone();
+        reverse_bit_order(&mut roots_of_unity);
+
+        Ok(FsFFTSettings {
+            max_width,
+            root_of_unity,
+            expanded_roots_of_unity,
+            reverse_roots_of_unity,
+            roots_of_unity,
+        })
+    }
+}
+
 impl FFTSettings<FsFr> for FsFFTSettings {
     /// Create FFTSettings with roots of unity for a selected scale. Resulting roots will have a magnitude of 2 ^ max_scale.
-    fn new(scale: usize) -> Result<FsFFTSettings, String> {
+    fn new(scale: usize) -> Result<FsFFTSettings, Error> {
         if scale >= SCALE2_ROOT_OF_UNITY.len() {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "Scale is expected to be within root of unity matrix row size",
             ));
         }
@@ -86,12 +118,14 @@ impl FFTSettings<FsFr> for FsFFTSettings {
 }
 
 /// Multiply a given root of unity by itself until it results in a 1 and result all multiplication values in a vector
-pub fn expand_root_of_unity(root: &FsFr, width: usize) -> Result<Vec<FsFr>, String> {
+pub fn expand_root_of_unity(root: &FsFr, width: usize) -> Result<Vec<FsFr>, Error> {
     let mut generated_powers = vec![FsFr::one(), *root];
 
     while !(generated_powers.last().unwrap().is_one()) {
         if generated_powers.len() > width {
-            return Err(String::from("Root of unity multiplied for too long"));
+            return Err(Error::InvalidArgument(
+                "Root of unity multiplied for too long",
+            ));
         }
 
         generated_powers.push(generated_powers.last().unwrap().mul(root));
@@ -1,10 +1,10 @@
 extern crate alloc;
 
-use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
 
-use kzg::{FK20MultiSettings, Poly, FFTG1, G1};
+use kzg::trusted_setup::SetupBasis;
+use kzg::{Error, FK20MultiSettings, Poly, FFTG1, G1};
 
 use crate::types::fft_settings::FsFFTSettings;
 use crate::types::fr::FsFr;
@@ -44,19 +44,28 @@ impl FK20MultiSettings<FsFr, FsG1, FsG2, FsFFTSettings, FsPoly, FsKZGSettings>
     for FsFK20MultiSettings
 {
     #[allow(clippy::many_single_char_names)]
-    fn new(ks: &FsKZGSettings, n2: usize, chunk_len: usize) -> Result<Self, String> {
+    fn new(ks: &FsKZGSettings, n2: usize, chunk_len: usize) -> Result<Self, Error> {
         if n2 > ks.fs.max_width {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "n2 must be less than or equal to kzg settings max width",
             ));
         } else if !n2.is_power_of_two() {
-            return Err(String::from("n2 must be a power of two"));
+            return Err(Error::NotPowerOfTwo("n2"));
         } else if n2 < 2 {
-            return Err(String::from("n2 must be greater than or equal to 2"));
+            return Err(Error::InvalidArgument(
+                "n2 must be greater than or equal to 2",
+            ));
+        } else if ks.basis != SetupBasis::Monomial {
+            return Err(Error::SetupBasisMismatch {
+                expected: SetupBasis::Monomial,
+                actual: ks.basis,
+            });
         } else if chunk_len > n2 / 2 {
-            return Err(String::from("chunk_len must be greater or equal to n2 / 2"));
+            return Err(Error::InvalidArgument(
+                "chunk_len must be greater or equal to n2 / 2",
+            ));
         } else if !chunk_len.is_power_of_two() {
-            return Err(String::from("chunk_len must be a power of two"));
+            return Err(Error::NotPowerOfTwo("chunk_len"));
         }
 
         let n = n2 / 2;
@@ -102,18 +111,18 @@ impl FK20MultiSettings<FsFr, FsG1, FsG2, FsFFTSettings, FsPoly, FsKZGSettings>
         Ok(ret)
     }
 
-    fn data_availability(&self, p: &FsPoly) -> Result<Vec<FsG1>, String> {
+    fn data_availability(&self, p: &FsPoly) -> Result<Vec<FsG1>, Error> {
         let n = p.len();
         let n2 = n * 2;
 
         if n2 > self.kzg_settings.fs.max_width {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "n2 must be less than or equal to kzg settings max width",
             ));
         }
 
         if !n2.is_power_of_two() {
-            return Err(String::from("n2 must be a power of two"));
+            return Err(Error::NotPowerOfTwo("n2"));
         }
 
         let mut ret = self.data_availability_optimized(p).unwrap();
@@ -122,16 +131,16 @@ impl FK20MultiSettings<FsFr, FsG1, FsG2, FsFFTSettings, FsPoly, FsKZGSettings>
         Ok(ret)
     }
 
-    fn data_availability_optimized(&self, p: &FsPoly) -> Result<Vec<FsG1>, String> {
+    fn data_availability_optimized(&self, p: &FsPoly) -> Result<Vec<FsG1>, Error> {
         let n = p.len();
         let n2 = n * 2;
 
         if n2 > self.kzg_settings.fs.max_width {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "n2 must be less than or equal to kzg settings max width",
             ));
         } else if !n2.is_power_of_two() {
-            return Err(String::from("n2 must be a power of two"));
+            return Err(Error::NotPowerOfTwo("n2"));
         }
 
         let n = n2 / 2;
@@ -1,9 +1,9 @@
 extern crate alloc;
 
-use alloc::string::String;
 use alloc::vec::Vec;
 
-use kzg::{FK20SingleSettings, Poly, FFTG1, G1};
+use kzg::trusted_setup::SetupBasis;
+use kzg::{Error, FK20SingleSettings, Poly, FFTG1, G1};
 
 use crate::types::fft_settings::FsFFTSettings;
 use crate::types::fr::FsFr;
@@ -22,17 +22,24 @@ pub struct FsFK20SingleSettings {
 impl FK20SingleSettings<FsFr, FsG1, FsG2, FsFFTSettings, FsPoly, FsKZGSettings>
     for FsFK20SingleSettings
 {
-    fn new(kzg_settings: &FsKZGSettings, n2: usize) -> Result<Self, String> {
+    fn new(kzg_settings: &FsKZGSettings, n2: usize) -> Result<Self, Error> {
         let n = n2 / 2;
 
         if n2 > kzg_settings.fs.max_width {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "n2 must be less than or equal to kzg settings max width",
             ));
         } else if !n2.is_power_of_two() {
-            return Err(String::from("n2 must be a power of two"));
+            return Err(Error::NotPowerOfTwo("n2"));
         } else if n2 < 2 {
-            return Err(String::from("n2 must be greater than or equal to 2"));
+            return Err(Error::InvalidArgument(
+                "n2 must be greater than or equal to 2",
+            ));
+        } else if kzg_settings.basis != SetupBasis::Monomial {
+            return Err(Error::SetupBasisMismatch {
+                expected: SetupBasis::Monomial,
+                actual: kzg_settings.basis,
+            });
         }
 
         let mut x = Vec::with_capacity(n);
@@ -53,16 +60,16 @@ impl FK20SingleSettings<FsFr, FsG1, FsG2, FsFFTSettings, FsPoly, FsKZGSettings>
         Ok(ret)
     }
 
-    fn data_availability(&self, p: &FsPoly) -> Result<Vec<FsG1>, String> {
+    fn data_availability(&self, p: &FsPoly) -> Result<Vec<FsG1>, Error> {
         let n = p.len();
         let n2 = n * 2;
 
         if n2 > self.kzg_settings.fs.max_width {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "n2 must be less than or equal to kzg settings max width",
             ));
         } else if !n2.is_power_of_two() {
-            return Err(String::from("n2 must be a power of two"));
+            return Err(Error::NotPowerOfTwo("n2"));
         }
 
         let mut ret = self.data_availability_optimized(p).unwrap();
@@ -71,16 +78,16 @@ impl FK20SingleSettings<FsFr, FsG1, FsG2, FsFFTSettings, FsPoly, FsKZGSettings>
         Ok(ret)
     }
 
-    fn data_availability_optimized(&self, p: &FsPoly) -> Result<Vec<FsG1>, String> {
+    fn data_availability_optimized(&self, p: &FsPoly) -> Result<Vec<FsG1>, Error> {
         let n = p.len();
         let n2 = n * 2;
 
         if n2 > self.kzg_settings.fs.max_width {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "n2 must be less than or equal to kzg settings max width",
             ));
         } else if !n2.is_power_of_two() {
-            return Err(String::from("n2 must be a power of two"));
+            return Err(Error::NotPowerOfTwo("n2"));
         }
 
         let toeplitz_coeffs = p.toeplitz_coeffs_step();
@@ -1,20 +1,28 @@
 extern crate alloc;
 
-use alloc::format;
-use alloc::string::String;
-use alloc::string::ToString;
-
+use alloc::vec::Vec;
 use blst::{
     blst_fr, blst_fr_add, blst_fr_cneg, blst_fr_eucl_inverse, blst_fr_from_scalar,
     blst_fr_from_uint64, blst_fr_inverse, blst_fr_mul, blst_fr_sqr, blst_fr_sub, blst_scalar,
     blst_scalar_fr_check, blst_scalar_from_fr, blst_scalar_from_lendian, blst_uint64_from_fr,
 };
 use kzg::eip_4844::BYTES_PER_FIELD_ELEMENT;
+use kzg::Error;
 use kzg::Fr;
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
 pub struct FsFr(pub blst_fr);
 
+#[cfg(feature = "serde")]
+kzg::impl_serde_bytes!(
+    FsFr,
+    Fr::to_bytes,
+    kzg::serde_utils::fr_from_canonical_bytes::<FsFr>
+);
+
 impl Fr for FsFr {
     fn null() -> Self {
         Self::from_u64_arr(&[u64::MAX, u64::MAX, u64::MAX, u64::MAX])
@@ -44,15 +52,12 @@ impl Fr for FsFr {
         ret
     }
 
-    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         bytes
             .try_into()
-            .map_err(|_| {
-                format!(
-                    "Invalid byte length. Expected {}, got {}",
-                    BYTES_PER_FIELD_ELEMENT,
-                    bytes.len()
-                )
+            .map_err(|_| Error::InvalidLength {
+                expected: BYTES_PER_FIELD_ELEMENT,
+                actual: bytes.len(),
             })
             .and_then(|bytes: &[u8; BYTES_PER_FIELD_ELEMENT]| {
                 let mut bls_scalar = blst_scalar::default();
@@ -60,7 +65,7 @@ impl Fr for FsFr {
                 unsafe {
                     blst_scalar_from_lendian(&mut bls_scalar, bytes.as_ptr());
                     if !blst_scalar_fr_check(&bls_scalar) {
-                        return Err("Invalid scalar".to_string());
+                        return Err(Error::NonCanonicalScalar);
                     }
                     blst_fr_from_scalar(&mut fr, &bls_scalar);
                 }
@@ -68,7 +73,25 @@ impl Fr for FsFr {
             })
     }
 
-    fn from_hex(hex: &str) -> Result<Self, String> {
+    fn from_bytes_unchecked(bytes: &[u8]) -> Result<Self, Error> {
+        bytes
+            .try_into()
+            .map_err(|_| Error::InvalidLength {
+                expected: BYTES_PER_FIELD_ELEMENT,
+                actual: bytes.len(),
+            })
+            .map(|bytes: &[u8; BYTES_PER_FIELD_ELEMENT]| {
+                let mut bls_scalar = blst_scalar::default();
+                let mut fr = blst_fr::default();
+                unsafe {
+                    blst_scalar_from_lendian(&mut bls_scalar, bytes.as_ptr());
+                    blst_fr_from_scalar(&mut fr, &bls_scalar);
+                }
+                Self(fr)
+            })
+    }
+
+    fn from_hex(hex: &str) -> Result<Self, Error> {
         let bytes = hex::decode(&hex[2..]).unwrap();
         Self::from_bytes(&bytes)
     }
@@ -209,7 +232,7 @@ impl Fr for FsFr {
         out
     }
 
-    fn div(&self, b: &Self) -> Result<Self, String> {
+    fn div(&self, b: &Self) -> Result<Self, Error> {
         let tmp = b.eucl_inverse();
         let out = self.mul(&tmp);
 
@@ -227,6 +250,46 @@ impl Fr for FsFr {
 
         val_a[0] == val_b[0] && val_a[1] == val_b[1] && val_a[2] == val_b[2] && val_a[3] == val_b[3]
     }
+
+    /// Inverts one chunk per thread, which costs an inversion per chunk but splits the
+    /// multiplications between the threads. Chunks are large enough for that to pay off.
+    fn batch_inverse(values: &mut [Self]) {
+        #[cfg(feature = "parallel")]
+        {
+            let num_threads = rayon::current_num_threads();
+            let chunk_len = ((values.len() + num_threads - 1) / num_threads).max(1024);
+            values
+                .par_chunks_mut(chunk_len)
+                .for_each(batch_inverse_sequential);
+        }
+
+        #[cfg(not(feature = "parallel"))]
+        {
+            batch_inverse_sequential(values);
+        }
+    }
+}
+
+/// Montgomery's trick with a single Euclidean inversion, skipping zeros.
+fn batch_inverse_sequential(values: &mut [FsFr]) {
+    let mut prefix = Vec::with_capacity(values.len());
+    let mut product = FsFr::one();
+    for value in values.iter() {
+        prefix.push(product);
+        if !value.is_zero() {
+            product = product.mul(value);
+        }
+    }
+
+    let mut inverse = product.eucl_inverse();
+    for (value, prefix) in values.iter_mut().zip(prefix).rev() {
+        if value.is_zero() {
+            continue;
+        }
+        let next = inverse.mul(value);
+        *value = inverse.mul(&prefix);
+        inverse = next;
+    }
 }
 
 impl FsFr {
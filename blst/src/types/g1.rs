@@ -1,29 +1,69 @@
 extern crate alloc;
 
-use alloc::format;
-use alloc::string::String;
-use alloc::string::ToString;
-
 use blst::{
     blst_fp, blst_p1, blst_p1_add, blst_p1_add_or_double, blst_p1_affine, blst_p1_cneg,
-    blst_p1_compress, blst_p1_double, blst_p1_from_affine, blst_p1_in_g1, blst_p1_is_equal,
-    blst_p1_is_inf, blst_p1_mult, blst_p1_uncompress, blst_scalar, blst_scalar_from_fr, BLST_ERROR,
+    blst_p1_compress, blst_p1_deserialize, blst_p1_double, blst_p1_from_affine, blst_p1_in_g1,
+    blst_p1_is_equal, blst_p1_is_inf, blst_p1_mult, blst_p1_serialize, blst_p1_uncompress,
+    blst_scalar, blst_scalar_from_fr, BLST_ERROR,
 };
 use kzg::eip_4844::BYTES_PER_G1;
-use kzg::{G1Mul, G1};
+use kzg::{Error, G1LinComb, G1Mul, PairingVerify, G1};
 
 use crate::consts::{G1_GENERATOR, G1_IDENTITY, G1_NEGATIVE_GENERATOR};
+use crate::kzg_proofs::{g1_linear_combination, pairings_verify, pairings_verify_multi};
 use crate::types::fr::FsFr;
+use crate::types::g2::FsG2;
 use crate::utils::log_2_byte;
 
+pub const BYTES_PER_G1_UNCOMPRESSED: usize = 2 * BYTES_PER_G1;
+
 #[repr(C)]
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
 pub struct FsG1(pub blst_p1);
 
+#[cfg(feature = "serde")]
+kzg::impl_serde_bytes!(
+    FsG1,
+    G1::to_bytes,
+    kzg::serde_utils::g1_from_canonical_bytes::<FsG1>
+);
+
 impl FsG1 {
     pub(crate) const fn from_xyz(x: blst_fp, y: blst_fp, z: blst_fp) -> Self {
         FsG1(blst_p1 { x, y, z })
     }
+
+    /// Serializes the point to its uncompressed affine form, skipping the square root that
+    /// [`G1::from_bytes`] needs when reading it back.
+    pub fn to_bytes_uncompressed(&self) -> [u8; BYTES_PER_G1_UNCOMPRESSED] {
+        let mut out = [0u8; BYTES_PER_G1_UNCOMPRESSED];
+        unsafe {
+            blst_p1_serialize(out.as_mut_ptr(), &self.0);
+        }
+        out
+    }
+
+    /// Reads a point written by [`FsG1::to_bytes_uncompressed`]. The point is checked to be on
+    /// the curve, but not to be in the subgroup; use [`G1::is_valid`] for that.
+    pub fn from_bytes_uncompressed(bytes: &[u8]) -> Result<Self, Error> {
+        bytes
+            .try_into()
+            .map_err(|_| Error::InvalidLength {
+                expected: BYTES_PER_G1_UNCOMPRESSED,
+                actual: bytes.len(),
+            })
+            .and_then(|bytes: &[u8; BYTES_PER_G1_UNCOMPRESSED]| {
+                let mut tmp = blst_p1_affine::default();
+                let mut g1 = blst_p1::default();
+                unsafe {
+                    if blst_p1_deserialize(&mut tmp, bytes.as_ptr()) != BLST_ERROR::BLST_SUCCESS {
+                        return Err(Error::InvalidPoint);
+                    }
+                    blst_p1_from_affine(&mut g1, &tmp);
+                }
+                Ok(FsG1(g1))
+            })
+    }
 }
 
 impl G1 for FsG1 {
@@ -45,15 +85,12 @@ impl G1 for FsG1 {
         result.mul(&kzg::Fr::rand())
     }
 
-    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         bytes
             .try_into()
-            .map_err(|_| {
-                format!(
-                    "Invalid byte length. Expected {}, got {}",
-                    BYTES_PER_G1,
-                    bytes.len()
-                )
+            .map_err(|_| Error::InvalidLength {
+                expected: BYTES_PER_G1,
+                actual: bytes.len(),
             })
             .and_then(|bytes: &[u8; BYTES_PER_G1]| {
                 let mut tmp = blst_p1_affine::default();
@@ -61,7 +98,7 @@ impl G1 for FsG1 {
                 unsafe {
                     // The uncompress routine also checks that the point is on the curve
                     if blst_p1_uncompress(&mut tmp, bytes.as_ptr()) != BLST_ERROR::BLST_SUCCESS {
-                        return Err("Failed to uncompress".to_string());
+                        return Err(Error::InvalidPoint);
                     }
                     blst_p1_from_affine(&mut g1, &tmp);
                 }
@@ -69,7 +106,7 @@ impl G1 for FsG1 {
             })
     }
 
-    fn from_hex(hex: &str) -> Result<Self, String> {
+    fn from_hex(hex: &str) -> Result<Self, Error> {
         let bytes = hex::decode(&hex[2..]).unwrap();
         Self::from_bytes(&bytes)
     }
@@ -164,3 +201,21 @@ impl G1Mul<FsFr> for FsG1 {
         result
     }
 }
+
+impl G1LinComb<FsFr> for FsG1 {
+    fn g1_lincomb(points: &[Self], scalars: &[FsFr], len: usize) -> Self {
+        let mut out = FsG1::default();
+        g1_linear_combination(&mut out, points, scalars, len);
+        out
+    }
+}
+
+impl PairingVerify<FsG1, FsG2> for FsG1 {
+    fn verify(a1: &FsG1, a2: &FsG2, b1: &FsG1, b2: &FsG2) -> bool {
+        pairings_verify(a1, a2, b1, b2)
+    }
+
+    fn verify_multi(pairs: &[(FsG1, FsG2)]) -> bool {
+        pairings_verify_multi(pairs)
+    }
+}
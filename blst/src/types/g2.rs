@@ -1,25 +1,73 @@
 extern crate alloc;
 
-use alloc::format;
-use alloc::string::String;
-use alloc::string::ToString;
-
 use blst::{
     blst_fp2, blst_p2, blst_p2_add_or_double, blst_p2_affine, blst_p2_cneg, blst_p2_compress,
-    blst_p2_double, blst_p2_from_affine, blst_p2_is_equal, blst_p2_mult, blst_p2_uncompress,
-    blst_scalar, blst_scalar_from_fr, BLST_ERROR,
+    blst_p2_deserialize, blst_p2_double, blst_p2_from_affine, blst_p2_in_g2, blst_p2_is_equal,
+    blst_p2_mult, blst_p2_serialize, blst_p2_uncompress, blst_scalar, blst_scalar_from_fr,
+    BLST_ERROR,
 };
 use kzg::eip_4844::BYTES_PER_G2;
 #[cfg(feature = "rand")]
 use kzg::Fr;
-use kzg::{G2Mul, G2};
+use kzg::{Error, G2LinComb, G2Mul, G2};
 
 use crate::consts::{G2_GENERATOR, G2_NEGATIVE_GENERATOR};
+use crate::kzg_proofs::g2_linear_combination;
 use crate::types::fr::FsFr;
 
+pub const BYTES_PER_G2_UNCOMPRESSED: usize = 2 * BYTES_PER_G2;
+
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
 pub struct FsG2(pub blst_p2);
 
+#[cfg(feature = "serde")]
+kzg::impl_serde_bytes!(FsG2, G2::to_bytes, |bytes: &[u8]| {
+    let g2 = <FsG2 as G2>::from_bytes(bytes)?;
+    if !g2.is_valid() {
+        return Err(Error::PointNotInSubgroup);
+    }
+    Ok(g2)
+});
+
+impl FsG2 {
+    /// Serializes the point to its uncompressed affine form, skipping the square root that
+    /// [`G2::from_bytes`] needs when reading it back.
+    pub fn to_bytes_uncompressed(&self) -> [u8; BYTES_PER_G2_UNCOMPRESSED] {
+        let mut out = [0u8; BYTES_PER_G2_UNCOMPRESSED];
+        unsafe {
+            blst_p2_serialize(out.as_mut_ptr(), &self.0);
+        }
+        out
+    }
+
+    /// Reads a point written by [`FsG2::to_bytes_uncompressed`]. The point is checked to be on
+    /// the curve, but not to be in the subgroup; use [`FsG2::is_valid`] for that.
+    pub fn from_bytes_uncompressed(bytes: &[u8]) -> Result<Self, Error> {
+        bytes
+            .try_into()
+            .map_err(|_| Error::InvalidLength {
+                expected: BYTES_PER_G2_UNCOMPRESSED,
+                actual: bytes.len(),
+            })
+            .and_then(|bytes: &[u8; BYTES_PER_G2_UNCOMPRESSED]| {
+                let mut tmp = blst_p2_affine::default();
+                let mut g2 = blst_p2::default();
+                unsafe {
+                    if blst_p2_deserialize(&mut tmp, bytes.as_ptr()) != BLST_ERROR::BLST_SUCCESS {
+                        return Err(Error::InvalidPoint);
+                    }
+                    blst_p2_from_affine(&mut g2, &tmp);
+                }
+                Ok(FsG2(g2))
+            })
+    }
+
+    /// Checks that the point is in the prime-order subgroup.
+    pub fn is_valid(&self) -> bool {
+        unsafe { blst_p2_in_g2(&self.0) }
+    }
+}
+
 impl G2Mul<FsFr> for FsG2 {
     fn mul(&self, b: &FsFr) -> Self {
         let mut result = blst_p2::default();
@@ -37,6 +85,14 @@ impl G2Mul<FsFr> for FsG2 {
     }
 }
 
+impl G2LinComb<FsFr> for FsG2 {
+    fn g2_lincomb(points: &[Self], scalars: &[FsFr], len: usize) -> Self {
+        let mut out = FsG2::default();
+        g2_linear_combination(&mut out, points, scalars, len);
+        out
+    }
+}
+
 impl G2 for FsG2 {
     fn generator() -> Self {
         G2_GENERATOR
@@ -46,15 +102,12 @@ impl G2 for FsG2 {
         G2_NEGATIVE_GENERATOR
     }
 
-    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         bytes
             .try_into()
-            .map_err(|_| {
-                format!(
-                    "Invalid byte length. Expected {}, got {}",
-                    BYTES_PER_G2,
-                    bytes.len()
-                )
+            .map_err(|_| Error::InvalidLength {
+                expected: BYTES_PER_G2,
+                actual: bytes.len(),
             })
             .and_then(|bytes: &[u8; BYTES_PER_G2]| {
                 let mut tmp = blst_p2_affine::default();
@@ -62,7 +115,7 @@ impl G2 for FsG2 {
                 unsafe {
                     // The uncompress routine also checks that the point is on the curve
                     if blst_p2_uncompress(&mut tmp, bytes.as_ptr()) != BLST_ERROR::BLST_SUCCESS {
-                        return Err("Failed to uncompress".to_string());
+                        return Err(Error::InvalidPoint);
                     }
                     blst_p2_from_affine(&mut g2, &tmp);
                 }
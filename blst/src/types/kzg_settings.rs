@@ -1,16 +1,25 @@
 extern crate alloc;
 
-use alloc::string::String;
+use alloc::sync::Arc;
 use alloc::vec::Vec;
 
-use kzg::{FFTFr, FFTSettings, Fr, G1Mul, G2Mul, KZGSettings, Poly, G1, G2};
+use kzg::eip_4844::{hash, BlobPreset, BYTES_PER_FIELD_ELEMENT};
+use kzg::trusted_setup::{
+    lagrange_to_monomial, monomial_to_lagrange, verify_monomial_setup, SetupBasis,
+};
+use kzg::{
+    Error, FFTFr, FFTSettings, Fr, G1LinComb, G1Mul, G2Mul, KZGSettings, Poly, PolyEval, G1, G2,
+};
+use once_cell::sync::OnceCell;
 
 use crate::consts::{G1_GENERATOR, G2_GENERATOR};
-use crate::kzg_proofs::{g1_linear_combination, pairings_verify};
+use crate::kzg_proofs::pairings_verify;
 use crate::types::fft_settings::FsFFTSettings;
+use crate::types::fixed_base_msm::FsFixedBaseMsm;
+use crate::types::fk20_single_settings::FsFK20SingleSettings;
 use crate::types::fr::FsFr;
-use crate::types::g1::FsG1;
-use crate::types::g2::FsG2;
+use crate::types::g1::{FsG1, BYTES_PER_G1_UNCOMPRESSED};
+use crate::types::g2::{FsG2, BYTES_PER_G2_UNCOMPRESSED};
 use crate::types::poly::FsPoly;
 
 #[derive(Debug, Clone, Default)]
@@ -18,54 +27,71 @@ pub struct FsKZGSettings {
     pub fs: FsFFTSettings,
     pub secret_g1: Vec<FsG1>,
     pub secret_g2: Vec<FsG2>,
+    /// The G1 points of a trusted setup in monomial form, as they appear in the setup file, while
+    /// `secret_g1` holds them in bit-reversed Lagrange form. Needed for EIP-7594 cell proofs and
+    /// empty for settings created with [`KZGSettings::new`].
+    pub secret_g1_monomial: Vec<FsG1>,
+    pub blob_preset: BlobPreset,
+    /// Basis of `secret_g1`.
+    pub basis: SetupBasis,
+    /// Fixed-base tables for `secret_g1`, set by [`FsKZGSettings::precompute`].
+    pub precomputation: Option<Arc<FsFixedBaseMsm>>,
+    /// FK20 tables for EIP-7594 cell proofs, computed from `secret_g1_monomial` on first use and
+    /// shared between clones of the settings.
+    pub cell_proof_tables: Arc<OnceCell<Vec<Vec<FsG1>>>>,
+    /// FK20 settings for the single proofs of [`compute_all_kzg_proofs_rust`], computed from
+    /// `secret_g1_monomial` on first use and shared between clones of the settings.
+    ///
+    /// [`compute_all_kzg_proofs_rust`]: crate::eip_4844::compute_all_kzg_proofs_rust
+    pub single_proof_settings: Arc<OnceCell<FsFK20SingleSettings>>,
 }
 
+#[cfg(feature = "serde")]
+kzg::impl_serde_bytes!(
+    FsKZGSettings,
+    FsKZGSettings::to_bytes,
+    FsKZGSettings::from_bytes
+);
+
 impl KZGSettings<FsFr, FsG1, FsG2, FsFFTSettings, FsPoly> for FsKZGSettings {
     fn new(
         secret_g1: &[FsG1],
         secret_g2: &[FsG2],
-        length: usize,
+        length_g1: usize,
+        length_g2: usize,
         fft_settings: &FsFFTSettings,
-    ) -> Result<Self, String> {
-        let mut kzg_settings = Self::default();
-
-        if secret_g1.len() < fft_settings.max_width {
-            return Err(String::from(
-                "secret_g1 must have a length equal to or greater than fft_settings roots",
-            ));
-        } else if secret_g2.len() < fft_settings.max_width {
-            return Err(String::from(
-                "secret_g2 must have a length equal to or greater than fft_settings roots",
+    ) -> Result<Self, Error> {
+        if secret_g1.len() < length_g1 || secret_g2.len() < length_g2 {
+            return Err(Error::SetupTooSmall);
+        } else if length_g1 < fft_settings.max_width {
+            return Err(Error::InvalidArgument(
+                "length_g1 must be equal to or greater than number of fft_settings roots",
             ));
-        } else if length < fft_settings.max_width {
-            return Err(String::from(
-                "length must be equal to or greater than number of fft_settings roots",
-            ));
-        }
-
-        for i in 0..length {
-            kzg_settings.secret_g1.push(secret_g1[i]);
-            kzg_settings.secret_g2.push(secret_g2[i]);
+        } else if length_g2 < 2 {
+            return Err(Error::InvalidArgument("length_g2 must be at least 2"));
         }
-        kzg_settings.fs = fft_settings.clone();
 
-        Ok(kzg_settings)
+        Ok(Self {
+            secret_g1: secret_g1[..length_g1].to_vec(),
+            secret_g2: secret_g2[..length_g2].to_vec(),
+            fs: fft_settings.clone(),
+            ..Self::default()
+        })
     }
 
-    fn commit_to_poly(&self, poly: &FsPoly) -> Result<FsG1, String> {
-        if poly.coeffs.len() > self.secret_g1.len() {
-            return Err(String::from("Polynomial is longer than secret g1"));
-        }
-
-        let mut out = FsG1::default();
-        g1_linear_combination(&mut out, &self.secret_g1, &poly.coeffs, poly.coeffs.len());
+    fn commit_to_poly(&self, poly: &FsPoly) -> Result<FsG1, Error> {
+        self.check_basis(SetupBasis::Monomial)?;
+        self.commit_to_scalars(&poly.coeffs)
+    }
 
-        Ok(out)
+    fn commit_to_poly_eval(&self, p: &PolyEval<FsFr>) -> Result<FsG1, Error> {
+        self.check_basis(SetupBasis::Lagrange)?;
+        self.commit_to_scalars(p.evals())
     }
 
-    fn compute_proof_single(&self, p: &FsPoly, x: &FsFr) -> Result<FsG1, String> {
+    fn compute_proof_single(&self, p: &FsPoly, x: &FsFr) -> Result<FsG1, Error> {
         if p.coeffs.is_empty() {
-            return Err(String::from("Polynomial must not be empty"));
+            return Err(Error::InvalidArgument("Polynomial must not be empty"));
         }
 
         // `-(x0^n)`, where `n` is `1`
@@ -92,7 +118,7 @@ impl KZGSettings<FsFr, FsG1, FsG2, FsFFTSettings, FsPoly> for FsKZGSettings {
         proof: &FsG1,
         x: &FsFr,
         y: &FsFr,
-    ) -> Result<bool, String> {
+    ) -> Result<bool, Error> {
         let x_g2: FsG2 = G2_GENERATOR.mul(x);
         let s_minus_x: FsG2 = self.secret_g2[1].sub(&x_g2);
         let y_g1 = G1_GENERATOR.mul(y);
@@ -106,13 +132,13 @@ impl KZGSettings<FsFr, FsG1, FsG2, FsFFTSettings, FsPoly> for FsKZGSettings {
         ))
     }
 
-    fn compute_proof_multi(&self, p: &FsPoly, x0: &FsFr, n: usize) -> Result<FsG1, String> {
+    fn compute_proof_multi(&self, p: &FsPoly, x0: &FsFr, n: usize) -> Result<FsG1, Error> {
         if p.coeffs.is_empty() {
-            return Err(String::from("Polynomial must not be empty"));
+            return Err(Error::InvalidArgument("Polynomial must not be empty"));
         }
 
         if !n.is_power_of_two() {
-            return Err(String::from("n must be a power of two"));
+            return Err(Error::NotPowerOfTwo("n"));
         }
 
         // Construct x^n - x0^n = (x - x0.w^0)(x - x0.w^1)...(x - x0.w^(n-1))
@@ -151,9 +177,11 @@ impl KZGSettings<FsFr, FsG1, FsG2, FsFFTSettings, FsPoly> for FsKZGSettings {
         x: &FsFr,
         ys: &[FsFr],
         n: usize,
-    ) -> Result<bool, String> {
+    ) -> Result<bool, Error> {
         if !n.is_power_of_two() {
-            return Err(String::from("n is not a power of two"));
+            return Err(Error::NotPowerOfTwo("n"));
+        } else if n >= self.secret_g2.len() {
+            return Err(Error::SetupTooSmall);
         }
 
         // Interpolate at a coset.
@@ -177,7 +205,7 @@ impl KZGSettings<FsFr, FsG1, FsG2, FsFFTSettings, FsPoly> for FsKZGSettings {
         let xn_minus_yn = self.secret_g2[n].sub(&xn2);
 
         // [interpolation_polynomial(s)]_1
-        let is1 = self.commit_to_poly(&interp).unwrap();
+        let is1 = self.commit_to_poly(&interp)?;
 
         // [commitment - interpolation_polynomial(s)]_1 = [commit]_1 - [interpolation_polynomial(s)]_1
         let commit_minus_interp = com.sub(&is1);
@@ -194,4 +222,316 @@ impl KZGSettings<FsFr, FsG1, FsG2, FsFFTSettings, FsPoly> for FsKZGSettings {
     fn get_roots_of_unity_at(&self, i: usize) -> FsFr {
         self.fs.get_roots_of_unity_at(i)
     }
+    fn get_fft_settings(&self) -> &FsFFTSettings {
+        &self.fs
+    }
+
+    fn get_g1_secret(&self) -> &[FsG1] {
+        &self.secret_g1
+    }
+
+    fn get_g2_secret(&self) -> &[FsG2] {
+        &self.secret_g2
+    }
+
+    fn get_blob_preset(&self) -> &BlobPreset {
+        &self.blob_preset
+    }
+
+    fn get_basis(&self) -> SetupBasis {
+        self.basis
+    }
+
+    fn to_lagrange(&self) -> Result<Self, Error> {
+        if self.basis == SetupBasis::Lagrange {
+            return Ok(self.clone());
+        }
+        if self.secret_g1.len() < self.fs.max_width {
+            return Err(Error::SetupTooSmall);
+        }
+
+        let secret_g1_monomial = self.secret_g1[..self.fs.max_width].to_vec();
+        Ok(Self {
+            fs: self.fs.clone(),
+            secret_g1: monomial_to_lagrange(&secret_g1_monomial, &self.fs)?,
+            secret_g2: self.secret_g2.clone(),
+            secret_g1_monomial,
+            blob_preset: self.blob_preset,
+            basis: SetupBasis::Lagrange,
+            precomputation: None,
+            cell_proof_tables: Default::default(),
+            single_proof_settings: Default::default(),
+        })
+    }
+
+    fn to_monomial(&self) -> Result<Self, Error> {
+        if self.basis == SetupBasis::Monomial {
+            return Ok(self.clone());
+        }
+
+        let secret_g1 = if self.secret_g1_monomial.is_empty() {
+            lagrange_to_monomial(&self.secret_g1, &self.fs)?
+        } else {
+            self.secret_g1_monomial.clone()
+        };
+        Ok(Self {
+            fs: self.fs.clone(),
+            secret_g1,
+            secret_g2: self.secret_g2.clone(),
+            blob_preset: self.blob_preset,
+            ..Self::default()
+        })
+    }
+}
+
+const SETTINGS_MAGIC: [u8; 4] = *b"KZGS";
+const SETTINGS_VERSION: u32 = 1;
+const SETTINGS_HEADER_SIZE: usize = 56;
+const SETTINGS_CHECKSUM_SIZE: usize = 32;
+
+/// Splits `count` items of `size` bytes off the front of `bytes`.
+fn take_items<'a>(bytes: &mut &'a [u8], count: u64, size: usize) -> Result<&'a [u8], Error> {
+    let len = usize::try_from(count)
+        .ok()
+        .and_then(|count| count.checked_mul(size))
+        .filter(|&len| len <= bytes.len())
+        .ok_or(Error::InvalidArgument("Settings are truncated"))?;
+    let (head, tail) = bytes.split_at(len);
+    *bytes = tail;
+    Ok(head)
+}
+
+fn take_u64(bytes: &mut &[u8]) -> Result<u64, Error> {
+    let head = take_items(bytes, 1, 8)?;
+    Ok(u64::from_le_bytes(head.try_into().unwrap()))
+}
+
+impl FsKZGSettings {
+    /// Precomputes fixed-base tables for `secret_g1` with a window of `wbits` bits. Commitments
+    /// and proofs computed with these settings then use the tables instead of Pippenger, which
+    /// pays off when committing to many polynomials with the same setup.
+    ///
+    /// See [`FsFixedBaseMsm`] for the memory cost of each window size. The tables are shared
+    /// between clones of the settings and are not included in [`FsKZGSettings::to_bytes`].
+    pub fn precompute(&mut self, wbits: usize) -> Result<(), Error> {
+        self.precomputation = Some(Arc::new(FsFixedBaseMsm::new(&self.secret_g1, wbits)?));
+        Ok(())
+    }
+
+    /// Checks that the points are powers of one secret, as described in [`kzg::trusted_setup`].
+    /// In Lagrange basis, `secret_g1` must also match `secret_g1_monomial` if that is set.
+    pub fn verify_trusted_setup(&self) -> Result<(), Error> {
+        if self.basis == SetupBasis::Monomial {
+            return verify_monomial_setup(&self.secret_g1, &self.secret_g2);
+        }
+
+        let monomial = lagrange_to_monomial(&self.secret_g1, &self.fs)?;
+        if !self.secret_g1_monomial.is_empty()
+            && (self.secret_g1_monomial.len() != monomial.len()
+                || !self
+                    .secret_g1_monomial
+                    .iter()
+                    .zip(&monomial)
+                    .all(|(a, b)| a.equals(b)))
+        {
+            return Err(Error::InconsistentTrustedSetup(
+                "Lagrange and monomial G1 points differ",
+            ));
+        }
+
+        verify_monomial_setup(&monomial, &self.secret_g2)
+    }
+
+    fn check_basis(&self, expected: SetupBasis) -> Result<(), Error> {
+        if self.basis != expected {
+            return Err(Error::SetupBasisMismatch {
+                expected,
+                actual: self.basis,
+            });
+        }
+        Ok(())
+    }
+
+    fn commit_to_scalars(&self, scalars: &[FsFr]) -> Result<FsG1, Error> {
+        if scalars.len() > self.secret_g1.len() {
+            return Err(Error::SetupTooSmall);
+        }
+
+        match &self.precomputation {
+            Some(precomputation) => precomputation.multiply(scalars),
+            None => Ok(FsG1::g1_lincomb(&self.secret_g1, scalars, scalars.len())),
+        }
+    }
+
+    /// Serializes the settings into a versioned binary format that can be loaded much faster
+    /// than a trusted setup file.
+    ///
+    /// The layout is the magic `KZGS`, a little-endian `u32` version, then the field elements
+    /// per blob, FFT max width, G1 count, G2 count, monomial G1 count and basis (0 for monomial,
+    /// 1 for Lagrange) as little-endian `u64`s.
+    /// These are followed by the expanded roots of unity, the uncompressed G1, G2 and monomial G1
+    /// points, and finally the SHA-256 of everything before it.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let roots = &self.fs.expanded_roots_of_unity;
+        let mut out = Vec::with_capacity(
+            SETTINGS_HEADER_SIZE
+                + roots.len() * BYTES_PER_FIELD_ELEMENT
+                + self.secret_g1.len() * BYTES_PER_G1_UNCOMPRESSED
+                + self.secret_g2.len() * BYTES_PER_G2_UNCOMPRESSED
+                + self.secret_g1_monomial.len() * BYTES_PER_G1_UNCOMPRESSED
+                + SETTINGS_CHECKSUM_SIZE,
+        );
+
+        out.extend_from_slice(&SETTINGS_MAGIC);
+        out.extend_from_slice(&SETTINGS_VERSION.to_le_bytes());
+        out.extend_from_slice(&(self.blob_preset.field_elements_per_blob as u64).to_le_bytes());
+        out.extend_from_slice(&(self.fs.max_width as u64).to_le_bytes());
+        out.extend_from_slice(&(self.secret_g1.len() as u64).to_le_bytes());
+        out.extend_from_slice(&(self.secret_g2.len() as u64).to_le_bytes());
+        out.extend_from_slice(&(self.secret_g1_monomial.len() as u64).to_le_bytes());
+        let basis: u64 = match self.basis {
+            SetupBasis::Monomial => 0,
+            SetupBasis::Lagrange => 1,
+        };
+        out.extend_from_slice(&basis.to_le_bytes());
+
+        for root in roots {
+            out.extend_from_slice(&root.to_bytes());
+        }
+        for point in &self.secret_g1 {
+            out.extend_from_slice(&point.to_bytes_uncompressed());
+        }
+        for point in &self.secret_g2 {
+            out.extend_from_slice(&point.to_bytes_uncompressed());
+        }
+        for point in &self.secret_g1_monomial {
+            out.extend_from_slice(&point.to_bytes_uncompressed());
+        }
+
+        let checksum = hash(&out);
+        out.extend_from_slice(&checksum);
+        out
+    }
+
+    /// Loads settings written by [`FsKZGSettings::to_bytes`], checking that the blob preset and
+    /// FFT width fit the number of G1 points, that every point is in the subgroup and that the
+    /// roots of unity match freshly computed ones.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Self::from_bytes_inner(bytes, true)
+    }
+
+    /// Same as [`FsKZGSettings::from_bytes`], but trusts the points and roots of unity beyond
+    /// the checksum and the on-curve checks. The header is still checked, so the settings are
+    /// consistent even if the file is not. Only use it for files this process or a trusted party
+    /// has written.
+    pub fn from_bytes_unchecked(bytes: &[u8]) -> Result<Self, Error> {
+        Self::from_bytes_inner(bytes, false)
+    }
+
+    fn from_bytes_inner(bytes: &[u8], validate: bool) -> Result<Self, Error> {
+        if bytes.len() < SETTINGS_HEADER_SIZE + SETTINGS_CHECKSUM_SIZE {
+            return Err(Error::InvalidArgument("Settings are truncated"));
+        }
+        let (mut body, checksum) = bytes.split_at(bytes.len() - SETTINGS_CHECKSUM_SIZE);
+        if hash(body) != checksum {
+            return Err(Error::InvalidArgument("Settings checksum does not match"));
+        }
+
+        if body[..4] != SETTINGS_MAGIC {
+            return Err(Error::InvalidArgument("Not a KZG settings file"));
+        }
+        if body[4..8] != SETTINGS_VERSION.to_le_bytes() {
+            return Err(Error::InvalidArgument("Unsupported KZG settings version"));
+        }
+        body = &body[8..];
+
+        let field_elements_per_blob = take_u64(&mut body)?;
+        let max_width = take_u64(&mut body)?;
+        let num_g1 = take_u64(&mut body)?;
+        let num_g2 = take_u64(&mut body)?;
+        let num_g1_monomial = take_u64(&mut body)?;
+        let basis = match take_u64(&mut body)? {
+            0 => SetupBasis::Monomial,
+            1 => SetupBasis::Lagrange,
+            _ => return Err(Error::InvalidArgument("Unknown setup basis")),
+        };
+        let blob_preset = usize::try_from(field_elements_per_blob)
+            .map_err(|_| Error::NotPowerOfTwo("field elements per blob"))
+            .and_then(BlobPreset::new)?;
+        if num_g1 != field_elements_per_blob {
+            return Err(Error::InvalidLength {
+                expected: blob_preset.field_elements_per_blob,
+                actual: num_g1 as usize,
+            });
+        }
+        if max_width < num_g1 {
+            return Err(Error::InvalidLength {
+                expected: num_g1 as usize,
+                actual: max_width as usize,
+            });
+        }
+        if num_g1_monomial != 0 && num_g1_monomial != num_g1 {
+            return Err(Error::InvalidLength {
+                expected: num_g1 as usize,
+                actual: num_g1_monomial as usize,
+            });
+        }
+
+        let roots = take_items(
+            &mut body,
+            max_width.saturating_add(1),
+            BYTES_PER_FIELD_ELEMENT,
+        )?
+        .chunks(BYTES_PER_FIELD_ELEMENT)
+        .map(FsFr::from_bytes)
+        .collect::<Result<Vec<_>, _>>()?;
+        let fs = FsFFTSettings::from_expanded_roots_of_unity(roots)?;
+
+        let secret_g1 = take_items(&mut body, num_g1, BYTES_PER_G1_UNCOMPRESSED)?
+            .chunks(BYTES_PER_G1_UNCOMPRESSED)
+            .map(FsG1::from_bytes_uncompressed)
+            .collect::<Result<Vec<_>, _>>()?;
+        let secret_g2 = take_items(&mut body, num_g2, BYTES_PER_G2_UNCOMPRESSED)?
+            .chunks(BYTES_PER_G2_UNCOMPRESSED)
+            .map(FsG2::from_bytes_uncompressed)
+            .collect::<Result<Vec<_>, _>>()?;
+        let secret_g1_monomial = take_items(&mut body, num_g1_monomial, BYTES_PER_G1_UNCOMPRESSED)?
+            .chunks(BYTES_PER_G1_UNCOMPRESSED)
+            .map(FsG1::from_bytes_uncompressed)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if !body.is_empty() {
+            return Err(Error::InvalidLength {
+                expected: bytes.len() - body.len(),
+                actual: bytes.len(),
+            });
+        }
+
+        if validate {
+            let scale = fs.max_width.trailing_zeros() as usize;
+            if FsFFTSettings::new(scale)?.expanded_roots_of_unity != fs.expanded_roots_of_unity {
+                return Err(Error::InvalidArgument(
+                    "Roots of unity do not match the max width",
+                ));
+            }
+            if !secret_g1.iter().all(FsG1::is_valid)
+                || !secret_g2.iter().all(FsG2::is_valid)
+                || !secret_g1_monomial.iter().all(FsG1::is_valid)
+            {
+                return Err(Error::PointNotInSubgroup);
+            }
+        }
+
+        Ok(FsKZGSettings {
+            fs,
+            secret_g1,
+            secret_g2,
+            secret_g1_monomial,
+            blob_preset,
+            basis,
+            precomputation: None,
+            cell_proof_tables: Default::default(),
+            single_proof_settings: Default::default(),
+        })
+    }
 }
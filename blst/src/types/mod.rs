@@ -1,4 +1,5 @@
 pub mod fft_settings;
+pub mod fixed_base_msm;
 pub mod fk20_multi_settings;
 pub mod fk20_single_settings;
 pub mod fr;
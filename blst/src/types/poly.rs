@@ -1,10 +1,9 @@
 extern crate alloc;
 
-use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
 
-use kzg::{FFTFr, FFTSettings, FFTSettingsPoly, Fr, Poly};
+use kzg::{Error, FFTFr, FFTSettings, FFTSettingsPoly, Fr, Poly};
 
 use crate::consts::SCALE_FACTOR;
 use crate::types::fft_settings::FsFFTSettings;
@@ -17,7 +16,7 @@ pub struct FsPoly {
 }
 
 impl Poly<FsFr> for FsPoly {
-    fn new(size: usize) -> Result<Self, String> {
+    fn new(size: usize) -> Result<Self, Error> {
         Ok(Self {
             coeffs: vec![FsFr::default(); size],
         })
@@ -83,13 +82,13 @@ impl Poly<FsFr> for FsPoly {
     }
 
     // TODO: analyze how algo works
-    fn inverse(&mut self, output_len: usize) -> Result<Self, String> {
+    fn inverse(&mut self, output_len: usize) -> Result<Self, Error> {
         if output_len == 0 {
-            return Err(String::from("Can't produce a zero-length result"));
+            return Err(Error::InvalidArgument("Can't produce a zero-length result"));
         } else if self.coeffs.is_empty() {
-            return Err(String::from("Can't inverse a zero-length poly"));
+            return Err(Error::InvalidArgument("Can't inverse a zero-length poly"));
         } else if self.coeffs[0].is_zero() {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "First coefficient of polynomial mustn't be zero",
             ));
         }
@@ -142,13 +141,13 @@ impl Poly<FsFr> for FsPoly {
         }
 
         if d + 1 != output_len {
-            return Err(String::from("D + 1 must be equal to output_len"));
+            return Err(Error::Internal("D + 1 must be equal to output_len"));
         }
 
         Ok(ret)
     }
 
-    fn div(&mut self, divisor: &Self) -> Result<Self, String> {
+    fn div(&mut self, divisor: &Self) -> Result<Self, Error> {
         if divisor.len() >= self.len() || divisor.len() < 128 {
             // Tunable parameter
             self.long_div(divisor)
@@ -157,11 +156,13 @@ impl Poly<FsFr> for FsPoly {
         }
     }
 
-    fn long_div(&mut self, divisor: &Self) -> Result<Self, String> {
+    fn long_div(&mut self, divisor: &Self) -> Result<Self, Error> {
         if divisor.coeffs.is_empty() {
-            return Err(String::from("Can't divide by zero"));
+            return Err(Error::DivisionByZero);
         } else if divisor.coeffs[divisor.coeffs.len() - 1].is_zero() {
-            return Err(String::from("Highest coefficient must be non-zero"));
+            return Err(Error::InvalidArgument(
+                "Highest coefficient must be non-zero",
+            ));
         }
 
         let out_length = self.poly_quotient_length(divisor);
@@ -213,11 +214,13 @@ impl Poly<FsFr> for FsPoly {
         }
     }
 
-    fn fast_div(&mut self, divisor: &Self) -> Result<Self, String> {
+    fn fast_div(&mut self, divisor: &Self) -> Result<Self, Error> {
         if divisor.coeffs.is_empty() {
-            return Err(String::from("Cant divide by zero"));
+            return Err(Error::DivisionByZero);
         } else if divisor.coeffs[divisor.coeffs.len() - 1].is_zero() {
-            return Err(String::from("Highest coefficient must be non-zero"));
+            return Err(Error::InvalidArgument(
+                "Highest coefficient must be non-zero",
+            ));
         }
 
         let m: usize = self.len() - 1;
@@ -249,7 +252,7 @@ impl Poly<FsFr> for FsPoly {
         Ok(out)
     }
 
-    fn mul_direct(&mut self, multiplier: &Self, output_len: usize) -> Result<Self, String> {
+    fn mul_direct(&mut self, multiplier: &Self, output_len: usize) -> Result<Self, Error> {
         if self.len() == 0 || multiplier.len() == 0 {
             return Ok(FsPoly::new(0).unwrap());
         }
@@ -283,7 +286,7 @@ impl FFTSettingsPoly<FsFr, FsPoly, FsFFTSettings> for FsFFTSettings {
         b: &FsPoly,
         len: usize,
         _fs: Option<&FsFFTSettings>,
-    ) -> Result<FsPoly, String> {
+    ) -> Result<FsPoly, Error> {
         b.mul_fft(a, len)
     }
 }
@@ -326,7 +329,7 @@ impl FsPoly {
         ret
     }
 
-    pub fn flip(&self) -> Result<FsPoly, String> {
+    pub fn flip(&self) -> Result<FsPoly, Error> {
         let mut ret = FsPoly {
             coeffs: vec![FsFr::default(); self.len()],
         };
@@ -337,7 +340,7 @@ impl FsPoly {
         Ok(ret)
     }
 
-    pub fn mul_fft(&self, multiplier: &Self, output_len: usize) -> Result<Self, String> {
+    pub fn mul_fft(&self, multiplier: &Self, output_len: usize) -> Result<Self, Error> {
         let length = (self.len() + multiplier.len() - 1).next_power_of_two();
 
         let scale = log2_pow2(length);
@@ -395,7 +398,7 @@ impl FsPoly {
         Ok(ret)
     }
 
-    pub fn mul(&mut self, multiplier: &Self, output_len: usize) -> Result<Self, String> {
+    pub fn mul(&mut self, multiplier: &Self, output_len: usize) -> Result<Self, Error> {
         if self.len() < 64 || multiplier.len() < 64 || output_len < 128 {
             // Tunable parameter
             self.mul_direct(multiplier, output_len)
@@ -1,11 +1,13 @@
 #[cfg(test)]
 mod tests {
     use kzg_bench::tests::bls12_381::{
-        fr_div_by_zero, fr_div_works, fr_equal_works, fr_from_uint64_works, fr_is_null_works,
-        fr_is_one_works, fr_is_zero_works, fr_negate_works, fr_pow_works, fr_uint64s_roundtrip,
-        g1_identity_is_identity, g1_identity_is_infinity, g1_make_linear_combination,
-        g1_random_linear_combination, log_2_byte_works, p1_mul_works, p1_sub_works,
-        p2_add_or_dbl_works, p2_mul_works, p2_sub_works, pairings_work,
+        fr_batch_inverse_works, fr_batch_mul_and_inner_product_work, fr_div_by_zero, fr_div_works,
+        fr_equal_works, fr_from_uint64_works, fr_is_null_works, fr_is_one_works, fr_is_zero_works,
+        fr_negate_works, fr_pow_works, fr_uint64s_roundtrip, g1_identity_is_identity,
+        g1_identity_is_infinity, g1_lincomb_matches_naive, g1_make_linear_combination,
+        g1_random_linear_combination, g2_lincomb_matches_naive, log_2_byte_works, p1_mul_works,
+        p1_sub_works, p2_add_or_dbl_works, p2_mul_works, p2_sub_works, pairings_verify_multi_works,
+        pairings_work,
     };
 
     use rust_kzg_blst::kzg_proofs::{g1_linear_combination, pairings_verify};
@@ -69,6 +71,16 @@ mod tests {
         fr_uint64s_roundtrip::<FsFr>()
     }
 
+    #[test]
+    fn fr_batch_inverse_works_() {
+        fr_batch_inverse_works::<FsFr>()
+    }
+
+    #[test]
+    fn fr_batch_mul_and_inner_product_work_() {
+        fr_batch_mul_and_inner_product_work::<FsFr>()
+    }
+
     #[test]
     fn p1_mul_works_() {
         p1_mul_works::<FsFr, FsG1>()
@@ -118,4 +130,19 @@ mod tests {
     fn pairings_work_() {
         pairings_work::<FsFr, FsG1, FsG2>(&pairings_verify)
     }
+
+    #[test]
+    fn pairings_verify_multi_works_() {
+        pairings_verify_multi_works::<FsFr, FsG1, FsG2>()
+    }
+
+    #[test]
+    fn g1_lincomb_matches_naive_() {
+        g1_lincomb_matches_naive::<FsFr, FsG1>()
+    }
+
+    #[test]
+    fn g2_lincomb_matches_naive_() {
+        g2_lincomb_matches_naive::<FsFr, FsG2>()
+    }
 }
@@ -0,0 +1,35 @@
+#[cfg(test)]
+mod tests {
+    use kzg_bench::tests::danksharding::*;
+    use rust_kzg_blst::types::fft_settings::FsFFTSettings;
+    use rust_kzg_blst::types::fk20_multi_settings::FsFK20MultiSettings;
+    use rust_kzg_blst::types::fr::FsFr;
+    use rust_kzg_blst::types::g1::FsG1;
+    use rust_kzg_blst::types::g2::FsG2;
+    use rust_kzg_blst::types::kzg_settings::FsKZGSettings;
+    use rust_kzg_blst::types::poly::FsPoly;
+    use rust_kzg_blst::utils::generate_trusted_setup;
+
+    #[test]
+    fn test_extend_matrix() {
+        extend_matrix_test::<FsFr, FsFFTSettings>();
+    }
+
+    #[test]
+    fn test_sample_proofs() {
+        sample_proofs_test::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+            FsFK20MultiSettings,
+        >(&generate_trusted_setup);
+    }
+
+    #[test]
+    fn test_recover_matrix() {
+        recover_matrix_test::<FsFr, FsPoly, FsFFTSettings>();
+    }
+}
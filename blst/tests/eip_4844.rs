@@ -1,25 +1,49 @@
 #[cfg(test)]
 mod tests {
+    use kzg::eip_4844::{
+        load_trusted_setup_string, Blob, BlobPreset, Bytes32, Bytes48, CKZGSettings, KZGCommitment,
+        KZGProof, BLS_MODULUS_BYTES_BE, BYTES_PER_G1, BYTES_PER_G2, C_KZG_RET_BADARGS,
+        C_KZG_RET_OK, FIELD_ELEMENTS_PER_BLOB, POINT_EVALUATION_INPUT_LENGTH,
+        POINT_EVALUATION_OUTPUT_LENGTH, TRUSTED_SETUP_PATH,
+    };
+    use kzg::{Fr, G1};
     use kzg_bench::tests::eip_4844::{
-        blob_to_kzg_commitment_test, bytes_to_bls_field_test,
+        blob_to_kzg_commitment_test, bytes_to_blob_rejects_invalid_length_test,
+        bytes_to_bls_field_test, compute_all_kzg_proofs_test,
         compute_and_verify_blob_kzg_proof_fails_with_incorrect_proof_test,
         compute_and_verify_blob_kzg_proof_test,
+        compute_and_verify_blob_kzg_proof_with_runtime_preset_test,
         compute_and_verify_kzg_proof_fails_with_incorrect_proof_test,
         compute_and_verify_kzg_proof_round_trip_test, compute_kzg_proof_test, compute_powers_test,
-        verify_kzg_proof_batch_fails_with_incorrect_proof_test, verify_kzg_proof_batch_test,
+        decode_payload_rejects_malformed_blobs_test, encode_payload_round_trip_test,
+        kzg_to_versioned_hash_test, load_trusted_setup_json_reports_line_test,
+        load_trusted_setup_json_test, load_trusted_setup_lagrange_test,
+        load_trusted_setup_string_reports_line_test, point_evaluation_precompile_reference_test,
+        point_evaluation_precompile_test, update_kzg_commitment_and_proof_test,
+        update_kzg_proof_cost_test, verify_kzg_proof_batch_fails_with_incorrect_proof_test,
+        verify_kzg_proof_batch_test, verify_trusted_setup_test,
     };
     #[cfg(not(feature = "minimal-spec"))]
     use kzg_bench::tests::eip_4844::{
         compute_and_verify_kzg_proof_within_domain_test, test_vectors_blob_to_kzg_commitment,
         test_vectors_compute_blob_kzg_proof, test_vectors_compute_kzg_proof,
-        test_vectors_verify_blob_kzg_proof, test_vectors_verify_blob_kzg_proof_batch,
-        test_vectors_verify_kzg_proof,
+        test_vectors_point_evaluation_precompile, test_vectors_verify_blob_kzg_proof,
+        test_vectors_verify_blob_kzg_proof_batch, test_vectors_verify_kzg_proof,
+    };
+    use rust_kzg_blst::eip_4844::{
+        blob_to_kzg_commitment, compute_blob_kzg_proof, free_trusted_setup, kzg_to_versioned_hash,
+        load_trusted_setup, point_evaluation_precompile, verify_blob_kzg_proof,
     };
     use rust_kzg_blst::eip_4844::{
         blob_to_kzg_commitment_rust, blob_to_polynomial_rust, bytes_to_blob,
-        compute_blob_kzg_proof_rust, compute_kzg_proof_rust, compute_powers,
-        evaluate_polynomial_in_evaluation_form_rust, load_trusted_setup_filename_rust,
-        verify_blob_kzg_proof_batch_rust, verify_blob_kzg_proof_rust, verify_kzg_proof_rust,
+        bytes_to_blob_with_preset, compute_all_kzg_proofs_rust, compute_blob_kzg_proof_rust,
+        compute_kzg_proof_rust, compute_powers, evaluate_polynomial_in_evaluation_form_rust,
+        kzg_to_versioned_hash_rust, load_trusted_setup_filename_rust,
+        load_trusted_setup_json_filename_rust, load_trusted_setup_lagrange_rust,
+        load_trusted_setup_rust, point_evaluation_precompile_rust,
+        precompute_proof_update_terms_rust, update_kzg_commitment_rust, update_kzg_proof_rust,
+        verify_all_kzg_proofs_batch_rust, verify_blob_kzg_proof_batch_rust,
+        verify_blob_kzg_proof_rust, verify_kzg_proof_rust,
     };
     use rust_kzg_blst::types::{
         fft_settings::FsFFTSettings, fr::FsFr, g1::FsG1, g2::FsG2, kzg_settings::FsKZGSettings,
@@ -31,6 +55,21 @@ mod tests {
         bytes_to_bls_field_test::<FsFr>();
     }
 
+    #[test]
+    pub fn encode_payload_round_trip_test_() {
+        encode_payload_round_trip_test::<FsFr>();
+    }
+
+    #[test]
+    pub fn decode_payload_rejects_malformed_blobs_test_() {
+        decode_payload_rejects_malformed_blobs_test::<FsFr>();
+    }
+
+    #[test]
+    pub fn bytes_to_blob_rejects_invalid_length_test_() {
+        bytes_to_blob_rejects_invalid_length_test::<FsFr>(&bytes_to_blob);
+    }
+
     #[test]
     pub fn compute_powers_test_() {
         compute_powers_test::<FsFr>(&compute_powers);
@@ -74,6 +113,37 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn kzg_to_versioned_hash_test_() {
+        kzg_to_versioned_hash_test::<FsG1>(&kzg_to_versioned_hash_rust);
+    }
+
+    #[test]
+    pub fn point_evaluation_precompile_test_() {
+        point_evaluation_precompile_test::<FsFr, FsG1, FsG2, FsPoly, FsFFTSettings, FsKZGSettings>(
+            &load_trusted_setup_filename_rust,
+            &blob_to_kzg_commitment_rust,
+            &bytes_to_blob,
+            &compute_kzg_proof_rust,
+            &point_evaluation_precompile_rust,
+        );
+    }
+
+    #[test]
+    pub fn point_evaluation_precompile_reference_test_() {
+        point_evaluation_precompile_reference_test::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+        >(
+            &load_trusted_setup_filename_rust,
+            &point_evaluation_precompile_rust,
+        );
+    }
+
     #[cfg(not(feature = "minimal-spec"))]
     #[test]
     pub fn compute_and_verify_kzg_proof_within_domain_test_() {
@@ -151,6 +221,101 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn compute_and_verify_blob_kzg_proof_with_runtime_preset_test_() {
+        compute_and_verify_blob_kzg_proof_with_runtime_preset_test::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+        >(
+            &load_trusted_setup_filename_rust,
+            &blob_to_kzg_commitment_rust,
+            &bytes_to_blob_with_preset,
+            &compute_blob_kzg_proof_rust,
+            &verify_blob_kzg_proof_rust,
+        );
+    }
+
+    #[test]
+    pub fn load_trusted_setup_string_reports_line_test_() {
+        load_trusted_setup_string_reports_line_test();
+    }
+
+    #[test]
+    pub fn load_trusted_setup_json_reports_line_test_() {
+        load_trusted_setup_json_reports_line_test();
+    }
+
+    #[test]
+    pub fn load_trusted_setup_json_test_() {
+        load_trusted_setup_json_test::<FsFr, FsG1, FsG2, FsPoly, FsFFTSettings, FsKZGSettings>(
+            &load_trusted_setup_filename_rust,
+            &load_trusted_setup_json_filename_rust,
+        );
+    }
+
+    #[test]
+    pub fn verify_trusted_setup_test_() {
+        verify_trusted_setup_test::<FsFr, FsG1, FsG2, FsPoly, FsFFTSettings, FsKZGSettings>(
+            &load_trusted_setup_rust,
+            &FsKZGSettings::verify_trusted_setup,
+        );
+    }
+
+    #[test]
+    pub fn load_trusted_setup_lagrange_test_() {
+        load_trusted_setup_lagrange_test::<FsFr, FsG1, FsG2, FsPoly, FsFFTSettings, FsKZGSettings>(
+            &load_trusted_setup_rust,
+            &load_trusted_setup_lagrange_rust,
+        );
+    }
+
+    #[test]
+    pub fn update_kzg_commitment_and_proof_test_() {
+        update_kzg_commitment_and_proof_test::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+        >(
+            &load_trusted_setup_filename_rust,
+            &blob_to_kzg_commitment_rust,
+            &bytes_to_blob,
+            &compute_kzg_proof_rust,
+            &update_kzg_commitment_rust,
+            &precompute_proof_update_terms_rust,
+            &update_kzg_proof_rust,
+        );
+    }
+
+    #[test]
+    pub fn update_kzg_proof_cost_test_() {
+        update_kzg_proof_cost_test::<FsFr, FsG1, FsG2, FsPoly, FsFFTSettings, FsKZGSettings>(
+            &load_trusted_setup_filename_rust,
+            &bytes_to_blob,
+            &compute_kzg_proof_rust,
+            &precompute_proof_update_terms_rust,
+            &update_kzg_proof_rust,
+        );
+    }
+
+    #[test]
+    pub fn compute_all_kzg_proofs_test_() {
+        compute_all_kzg_proofs_test::<FsFr, FsG1, FsG2, FsPoly, FsFFTSettings, FsKZGSettings>(
+            &load_trusted_setup_filename_rust,
+            &blob_to_kzg_commitment_rust,
+            &bytes_to_blob,
+            &compute_kzg_proof_rust,
+            &compute_all_kzg_proofs_rust,
+            &verify_all_kzg_proofs_batch_rust,
+        );
+    }
+
     #[test]
     pub fn verify_kzg_proof_batch_test_() {
         verify_kzg_proof_batch_test::<FsFr, FsG1, FsG2, FsPoly, FsFFTSettings, FsKZGSettings>(
@@ -219,6 +384,22 @@ mod tests {
         );
     }
 
+    #[cfg(not(feature = "minimal-spec"))]
+    #[test]
+    pub fn test_vectors_point_evaluation_precompile_() {
+        test_vectors_point_evaluation_precompile::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+        >(
+            &load_trusted_setup_filename_rust,
+            &point_evaluation_precompile_rust,
+        );
+    }
+
     #[cfg(not(feature = "minimal-spec"))]
     #[test]
     pub fn test_vectors_verify_blob_kzg_proof_() {
@@ -245,4 +426,159 @@ mod tests {
             &verify_blob_kzg_proof_batch_rust,
         );
     }
+
+    #[test]
+    pub fn c_api_point_evaluation_precompile() {
+        let contents = std::fs::read_to_string(format!(
+            "{}/../kzg-bench/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            TRUSTED_SETUP_PATH
+        ))
+        .unwrap();
+        let (g1_bytes, g2_bytes) = load_trusted_setup_string(&contents).unwrap();
+
+        // The zero blob commits to the identity, and so does every opening proof of it
+        let identity = FsG1::identity().to_bytes();
+        let commitment = KZGCommitment { bytes: identity };
+        let mut input = [0u8; POINT_EVALUATION_INPUT_LENGTH];
+        input[96..144].copy_from_slice(&identity);
+        input[144..].copy_from_slice(&identity);
+
+        unsafe {
+            let mut settings = core::mem::MaybeUninit::<CKZGSettings>::uninit();
+            assert_eq!(
+                load_trusted_setup(
+                    settings.as_mut_ptr(),
+                    g1_bytes.as_ptr(),
+                    g1_bytes.len() / BYTES_PER_G1,
+                    g2_bytes.as_ptr(),
+                    g2_bytes.len() / BYTES_PER_G2,
+                ),
+                C_KZG_RET_OK
+            );
+            let mut settings = settings.assume_init();
+
+            let mut versioned_hash = Bytes32 { bytes: [0; 32] };
+            assert_eq!(
+                kzg_to_versioned_hash(&mut versioned_hash, &commitment),
+                C_KZG_RET_OK
+            );
+            input[..32].copy_from_slice(&versioned_hash.bytes);
+
+            let mut ok = false;
+            let mut output = [0u8; POINT_EVALUATION_OUTPUT_LENGTH];
+            assert_eq!(
+                point_evaluation_precompile(
+                    &mut ok,
+                    output.as_mut_ptr(),
+                    input.as_ptr(),
+                    input.len() as u64,
+                    &settings
+                ),
+                C_KZG_RET_OK
+            );
+            assert!(ok);
+            assert_eq!(
+                output[24..32],
+                (FIELD_ELEMENTS_PER_BLOB as u64).to_be_bytes()
+            );
+            assert_eq!(output[32..], BLS_MODULUS_BYTES_BE);
+
+            // The zero polynomial does not evaluate to one
+            input[95] = 1;
+            assert_eq!(
+                point_evaluation_precompile(
+                    &mut ok,
+                    output.as_mut_ptr(),
+                    input.as_ptr(),
+                    input.len() as u64,
+                    &settings
+                ),
+                C_KZG_RET_OK
+            );
+            assert!(!ok);
+
+            assert_eq!(
+                point_evaluation_precompile(
+                    &mut ok,
+                    output.as_mut_ptr(),
+                    input.as_ptr(),
+                    input.len() as u64 - 1,
+                    &settings
+                ),
+                C_KZG_RET_BADARGS
+            );
+
+            free_trusted_setup(&mut settings);
+        }
+    }
+
+    #[test]
+    pub fn c_api_concurrent_setups_of_different_sizes() {
+        std::thread::scope(|scope| {
+            for preset in [
+                BlobPreset::MINIMAL,
+                BlobPreset::MAINNET,
+                BlobPreset::MINIMAL,
+                BlobPreset::MAINNET,
+            ] {
+                scope.spawn(move || c_api_load_use_and_free(&preset));
+            }
+        });
+    }
+
+    fn c_api_load_use_and_free(preset: &BlobPreset) {
+        let contents = std::fs::read_to_string(format!(
+            "{}/../kzg-bench/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            preset.trusted_setup_path().unwrap()
+        ))
+        .unwrap();
+        let (g1_bytes, g2_bytes) = load_trusted_setup_string(&contents).unwrap();
+
+        // Blobs are read according to the setup, so a buffer of the preset's size is enough
+        let blob = (0..preset.field_elements_per_blob)
+            .flat_map(|i| FsFr::from_u64(i as u64).to_bytes())
+            .collect::<Vec<u8>>();
+        let blob = blob.as_ptr() as *const Blob;
+
+        unsafe {
+            let mut settings = core::mem::MaybeUninit::<CKZGSettings>::uninit();
+            assert_eq!(
+                load_trusted_setup(
+                    settings.as_mut_ptr(),
+                    g1_bytes.as_ptr(),
+                    g1_bytes.len() / BYTES_PER_G1,
+                    g2_bytes.as_ptr(),
+                    g2_bytes.len() / BYTES_PER_G2,
+                ),
+                C_KZG_RET_OK
+            );
+            let mut settings = settings.assume_init();
+            assert_eq!(settings.num_g1_points, preset.field_elements_per_blob);
+
+            let mut commitment = KZGCommitment { bytes: [0; 48] };
+            assert_eq!(
+                blob_to_kzg_commitment(&mut commitment, blob, &settings),
+                C_KZG_RET_OK
+            );
+            let mut commitment = Bytes48 {
+                bytes: commitment.bytes,
+            };
+            let mut proof = KZGProof { bytes: [0; 48] };
+            assert_eq!(
+                compute_blob_kzg_proof(&mut proof, blob, &mut commitment, &settings),
+                C_KZG_RET_OK
+            );
+            let proof = Bytes48 { bytes: proof.bytes };
+            let mut ok = false;
+            assert_eq!(
+                verify_blob_kzg_proof(&mut ok, blob, &commitment, &proof, &settings),
+                C_KZG_RET_OK
+            );
+            assert!(ok);
+
+            free_trusted_setup(&mut settings);
+        }
+    }
 }
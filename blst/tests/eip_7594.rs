@@ -0,0 +1,371 @@
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "minimal-spec"))]
+    use kzg::eip_4844::{
+        load_trusted_setup_string, Blob, Bytes48, CKZGSettings, KZGCommitment, KZGProof,
+        BYTES_PER_G1, BYTES_PER_G2, C_KZG_RET_BADARGS, C_KZG_RET_OK,
+    };
+    use kzg::eip_7594::FIELD_ELEMENTS_PER_CELL;
+    #[cfg(not(feature = "minimal-spec"))]
+    use kzg::eip_7594::{Cell, CELLS_PER_EXT_BLOB};
+    use kzg::{FFTFr, FFTSettings, Fr, G1LinComb, G1};
+    #[cfg(not(feature = "minimal-spec"))]
+    use kzg_bench::tests::eip_7594::{
+        compute_cells_and_kzg_proofs_matches_spec_test, compute_cells_and_kzg_proofs_test,
+        recover_cells_and_kzg_proofs_test, test_vectors_compute_cells_and_kzg_proofs,
+        test_vectors_recover_cells_and_kzg_proofs, test_vectors_verify_cell_kzg_proof_batch,
+        verify_cell_kzg_proof_batch_challenge_test, verify_cell_kzg_proof_batch_test, NOT_IN_G1,
+    };
+    #[cfg(not(feature = "minimal-spec"))]
+    use rust_kzg_blst::eip_4844::{blob_to_kzg_commitment, free_trusted_setup, load_trusted_setup};
+    use rust_kzg_blst::eip_4844::{
+        blob_to_kzg_commitment_rust, bytes_to_blob, load_trusted_setup_filename_rust,
+    };
+    use rust_kzg_blst::eip_7594::{
+        bytes_to_cell, compute_cells_and_kzg_proofs_rust, recover_cells_and_kzg_proofs_rust,
+        verify_cell_kzg_proof_batch_rust,
+    };
+    #[cfg(not(feature = "minimal-spec"))]
+    use rust_kzg_blst::eip_7594::{
+        compute_cells_and_kzg_proofs, recover_cells_and_kzg_proofs, verify_cell_kzg_proof_batch,
+    };
+    use rust_kzg_blst::types::{
+        fft_settings::FsFFTSettings, fr::FsFr, g1::FsG1, g2::FsG2, kzg_settings::FsKZGSettings,
+        poly::FsPoly,
+    };
+    use rust_kzg_blst::utils::reverse_bit_order;
+
+    fn trusted_setup_path(name: &str) -> String {
+        format!(
+            "{}/../kzg-bench/src/trusted_setups/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            name
+        )
+    }
+
+    #[cfg(not(feature = "minimal-spec"))]
+    #[test]
+    pub fn compute_cells_and_kzg_proofs_test_() {
+        compute_cells_and_kzg_proofs_test::<FsFr, FsG1, FsG2, FsPoly, FsFFTSettings, FsKZGSettings>(
+            &load_trusted_setup_filename_rust,
+            &bytes_to_blob,
+            &blob_to_kzg_commitment_rust,
+            &compute_cells_and_kzg_proofs_rust,
+            &verify_cell_kzg_proof_batch_rust,
+        );
+    }
+
+    #[cfg(not(feature = "minimal-spec"))]
+    #[test]
+    pub fn compute_cells_and_kzg_proofs_matches_spec_test_() {
+        compute_cells_and_kzg_proofs_matches_spec_test::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+        >(
+            &load_trusted_setup_filename_rust,
+            &bytes_to_blob,
+            &compute_cells_and_kzg_proofs_rust,
+        );
+    }
+
+    #[cfg(not(feature = "minimal-spec"))]
+    #[test]
+    pub fn verify_cell_kzg_proof_batch_challenge_test_() {
+        verify_cell_kzg_proof_batch_challenge_test::<FsFr, FsG1>();
+    }
+
+    #[cfg(not(feature = "minimal-spec"))]
+    #[test]
+    pub fn verify_cell_kzg_proof_batch_test_() {
+        verify_cell_kzg_proof_batch_test::<FsFr, FsG1, FsG2, FsPoly, FsFFTSettings, FsKZGSettings>(
+            &load_trusted_setup_filename_rust,
+            &bytes_to_blob,
+            &blob_to_kzg_commitment_rust,
+            &compute_cells_and_kzg_proofs_rust,
+            &verify_cell_kzg_proof_batch_rust,
+        );
+    }
+
+    #[cfg(not(feature = "minimal-spec"))]
+    #[test]
+    pub fn recover_cells_and_kzg_proofs_test_() {
+        recover_cells_and_kzg_proofs_test::<FsFr, FsG1, FsG2, FsPoly, FsFFTSettings, FsKZGSettings>(
+            &load_trusted_setup_filename_rust,
+            &bytes_to_blob,
+            &compute_cells_and_kzg_proofs_rust,
+            &recover_cells_and_kzg_proofs_rust,
+        );
+    }
+
+    #[cfg(not(feature = "minimal-spec"))]
+    #[test]
+    #[ignore = "needs the consensus-specs PeerDAS vectors in kzg-bench/src/test_vectors"]
+    pub fn test_vectors_compute_cells_and_kzg_proofs_() {
+        test_vectors_compute_cells_and_kzg_proofs::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+        >(
+            &load_trusted_setup_filename_rust,
+            &bytes_to_blob,
+            &compute_cells_and_kzg_proofs_rust,
+        );
+    }
+
+    #[cfg(not(feature = "minimal-spec"))]
+    #[test]
+    #[ignore = "needs the consensus-specs PeerDAS vectors in kzg-bench/src/test_vectors"]
+    pub fn test_vectors_verify_cell_kzg_proof_batch_() {
+        test_vectors_verify_cell_kzg_proof_batch::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+        >(
+            &load_trusted_setup_filename_rust,
+            &bytes_to_cell,
+            &verify_cell_kzg_proof_batch_rust,
+        );
+    }
+
+    #[cfg(not(feature = "minimal-spec"))]
+    #[test]
+    #[ignore = "needs the consensus-specs PeerDAS vectors in kzg-bench/src/test_vectors"]
+    pub fn test_vectors_recover_cells_and_kzg_proofs_() {
+        test_vectors_recover_cells_and_kzg_proofs::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+        >(
+            &load_trusted_setup_filename_rust,
+            &bytes_to_cell,
+            &recover_cells_and_kzg_proofs_rust,
+        );
+    }
+
+    /// Checks the FK20 proofs against the definition of a cell proof: a commitment to the
+    /// quotient of the blob polynomial by the vanishing polynomial `X^n - h^n` of the cell's coset.
+    #[test]
+    pub fn cell_proofs_commit_to_quotients() {
+        let settings =
+            load_trusted_setup_filename_rust(&trusted_setup_path("trusted_setup.txt")).unwrap();
+        let field_elements_per_blob = settings.blob_preset.field_elements_per_blob;
+        let blob = (0..field_elements_per_blob)
+            .map(|_| FsFr::rand())
+            .collect::<Vec<_>>();
+        let (_, proofs) = compute_cells_and_kzg_proofs_rust(&blob, &settings).unwrap();
+
+        let mut evaluations = blob.clone();
+        reverse_bit_order(&mut evaluations);
+        let coeffs = settings.fs.fft_fr(&evaluations, true).unwrap();
+
+        let fs_ext =
+            FsFFTSettings::new((2 * field_elements_per_blob).trailing_zeros() as usize).unwrap();
+        let mut coset_shifts = fs_ext.expanded_roots_of_unity[..fs_ext.max_width].to_vec();
+        reverse_bit_order(&mut coset_shifts);
+
+        for index in [0, 1, 63, 64, proofs.len() - 1] {
+            let vanishing_constant =
+                coset_shifts[index * FIELD_ELEMENTS_PER_CELL].pow(FIELD_ELEMENTS_PER_CELL);
+
+            let mut remainder = coeffs.clone();
+            let mut quotient =
+                vec![FsFr::zero(); field_elements_per_blob - FIELD_ELEMENTS_PER_CELL];
+            for k in (FIELD_ELEMENTS_PER_CELL..field_elements_per_blob).rev() {
+                quotient[k - FIELD_ELEMENTS_PER_CELL] = remainder[k];
+                remainder[k - FIELD_ELEMENTS_PER_CELL] = remainder[k - FIELD_ELEMENTS_PER_CELL]
+                    .add(&remainder[k].mul(&vanishing_constant));
+            }
+
+            let expected =
+                FsG1::g1_lincomb(&settings.secret_g1_monomial, &quotient, quotient.len());
+            assert!(proofs[index].equals(&expected));
+        }
+    }
+
+    #[test]
+    pub fn cell_functions_reject_small_blobs() {
+        let settings =
+            load_trusted_setup_filename_rust(&trusted_setup_path("trusted_setup_4.txt")).unwrap();
+        let blob = vec![FsFr::zero(); settings.blob_preset.field_elements_per_blob];
+
+        assert!(compute_cells_and_kzg_proofs_rust(&blob, &settings).is_err());
+        assert!(recover_cells_and_kzg_proofs_rust(&[], &[], &settings).is_err());
+        assert!(verify_cell_kzg_proof_batch_rust(&[], &[], &[], &[], &settings).is_err());
+    }
+
+    #[cfg(not(feature = "minimal-spec"))]
+    #[test]
+    pub fn c_api_cells_round_trip() {
+        let contents = std::fs::read_to_string(trusted_setup_path("trusted_setup.txt")).unwrap();
+        let (g1_bytes, g2_bytes) = load_trusted_setup_string(&contents).unwrap();
+
+        let blob = Blob {
+            bytes: (0..4096u32)
+                .flat_map(|i| {
+                    let mut element = [0u8; 32];
+                    element[..4].copy_from_slice(&i.to_be_bytes());
+                    element
+                })
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap(),
+        };
+
+        unsafe {
+            let mut settings = core::mem::MaybeUninit::<CKZGSettings>::uninit();
+            assert_eq!(
+                load_trusted_setup(
+                    settings.as_mut_ptr(),
+                    g1_bytes.as_ptr(),
+                    g1_bytes.len() / BYTES_PER_G1,
+                    g2_bytes.as_ptr(),
+                    g2_bytes.len() / BYTES_PER_G2,
+                ),
+                C_KZG_RET_OK
+            );
+            let mut settings = settings.assume_init();
+
+            // The FK20 tables are computed by the first cell call, not while loading the setup
+            let backend_settings = &*(settings.backend_settings as *const FsKZGSettings);
+            assert!(backend_settings.cell_proof_tables.get().is_none());
+
+            let mut commitment = KZGCommitment { bytes: [0; 48] };
+            assert_eq!(
+                blob_to_kzg_commitment(&mut commitment, &blob, &settings),
+                C_KZG_RET_OK
+            );
+
+            let mut cells = (0..CELLS_PER_EXT_BLOB)
+                .map(|_| Cell { bytes: [0; 2048] })
+                .collect::<Vec<_>>();
+            let mut proofs = (0..CELLS_PER_EXT_BLOB)
+                .map(|_| KZGProof { bytes: [0; 48] })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                compute_cells_and_kzg_proofs(
+                    cells.as_mut_ptr(),
+                    proofs.as_mut_ptr(),
+                    &blob,
+                    &settings
+                ),
+                C_KZG_RET_OK
+            );
+            assert!(backend_settings.cell_proof_tables.get().is_some());
+
+            // Recover from the odd cells, then verify all recovered cells in a batch
+            let indices = (1..CELLS_PER_EXT_BLOB as u64)
+                .step_by(2)
+                .collect::<Vec<_>>();
+            let odd_cells = indices
+                .iter()
+                .map(|&i| Cell {
+                    bytes: cells[i as usize].bytes,
+                })
+                .collect::<Vec<_>>();
+            let mut recovered_cells = (0..CELLS_PER_EXT_BLOB)
+                .map(|_| Cell { bytes: [0; 2048] })
+                .collect::<Vec<_>>();
+            let mut recovered_proofs = (0..CELLS_PER_EXT_BLOB)
+                .map(|_| KZGProof { bytes: [0; 48] })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                recover_cells_and_kzg_proofs(
+                    recovered_cells.as_mut_ptr(),
+                    recovered_proofs.as_mut_ptr(),
+                    indices.as_ptr(),
+                    odd_cells.as_ptr(),
+                    indices.len() as u64,
+                    &settings,
+                ),
+                C_KZG_RET_OK
+            );
+            for (recovered, cell) in recovered_cells.iter().zip(&cells) {
+                assert_eq!(recovered.bytes, cell.bytes);
+            }
+            for (recovered, proof) in recovered_proofs.iter().zip(&proofs) {
+                assert_eq!(recovered.bytes, proof.bytes);
+            }
+
+            let commitments = (0..CELLS_PER_EXT_BLOB)
+                .map(|_| Bytes48 {
+                    bytes: commitment.bytes,
+                })
+                .collect::<Vec<_>>();
+            let proofs_bytes = recovered_proofs
+                .iter()
+                .map(|proof| Bytes48 { bytes: proof.bytes })
+                .collect::<Vec<_>>();
+            let all_indices = (0..CELLS_PER_EXT_BLOB as u64).collect::<Vec<_>>();
+            let mut ok = false;
+            assert_eq!(
+                verify_cell_kzg_proof_batch(
+                    &mut ok,
+                    commitments.as_ptr(),
+                    all_indices.as_ptr(),
+                    recovered_cells.as_ptr(),
+                    proofs_bytes.as_ptr(),
+                    CELLS_PER_EXT_BLOB as u64,
+                    &settings,
+                ),
+                C_KZG_RET_OK
+            );
+            assert!(ok);
+
+            // A commitment or proof outside the G1 subgroup is a bad argument
+            let mut bad_commitments = commitments
+                .iter()
+                .map(|commitment| Bytes48 {
+                    bytes: commitment.bytes,
+                })
+                .collect::<Vec<_>>();
+            bad_commitments[3].bytes = NOT_IN_G1;
+            assert_eq!(
+                verify_cell_kzg_proof_batch(
+                    &mut ok,
+                    bad_commitments.as_ptr(),
+                    all_indices.as_ptr(),
+                    recovered_cells.as_ptr(),
+                    proofs_bytes.as_ptr(),
+                    CELLS_PER_EXT_BLOB as u64,
+                    &settings,
+                ),
+                C_KZG_RET_BADARGS
+            );
+            assert!(!ok);
+
+            let mut bad_proofs = proofs_bytes
+                .iter()
+                .map(|proof| Bytes48 { bytes: proof.bytes })
+                .collect::<Vec<_>>();
+            bad_proofs[3].bytes = NOT_IN_G1;
+            assert_eq!(
+                verify_cell_kzg_proof_batch(
+                    &mut ok,
+                    commitments.as_ptr(),
+                    all_indices.as_ptr(),
+                    recovered_cells.as_ptr(),
+                    bad_proofs.as_ptr(),
+                    CELLS_PER_EXT_BLOB as u64,
+                    &settings,
+                ),
+                C_KZG_RET_BADARGS
+            );
+            assert!(!ok);
+
+            free_trusted_setup(&mut settings);
+        }
+    }
+}
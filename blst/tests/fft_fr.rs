@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod tests {
-    use kzg_bench::tests::fft_fr::{compare_sft_fft, inverse_fft, roundtrip_fft, stride_fft};
+    use kzg_bench::tests::fft_fr::{
+        compare_sft_fft, coset_fft, inverse_fft, roundtrip_fft, stride_fft,
+    };
     use rust_kzg_blst::fft_fr::{fft_fr_fast, fft_fr_slow};
     use rust_kzg_blst::types::fft_settings::FsFFTSettings;
     use rust_kzg_blst::types::fr::FsFr;
@@ -24,4 +26,9 @@ mod tests {
     fn stride_fft_() {
         stride_fft::<FsFr, FsFFTSettings>();
     }
+
+    #[test]
+    fn coset_fft_() {
+        coset_fft::<FsFr, FsFFTSettings>();
+    }
 }
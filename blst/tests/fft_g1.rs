@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
     use kzg::G1;
-    use kzg_bench::tests::fft_g1::{compare_ft_fft, roundtrip_fft, stride_fft};
+    use kzg_bench::tests::fft_g1::{compare_ft_fft, coset_fft, roundtrip_fft, stride_fft};
     use rust_kzg_blst::consts::G1_GENERATOR;
     use rust_kzg_blst::fft_g1::{fft_g1_fast, fft_g1_slow};
     use rust_kzg_blst::types::fft_settings::FsFFTSettings;
@@ -35,4 +35,9 @@ mod tests {
     fn compare_sft_fft_() {
         compare_ft_fft::<FsFr, FsG1, FsFFTSettings>(&fft_g1_slow, &fft_g1_fast, &make_data);
     }
+
+    #[test]
+    fn coset_fft_() {
+        coset_fft::<FsFr, FsG1, FsFFTSettings>();
+    }
 }
@@ -6,7 +6,8 @@ mod tests {
     };
     use kzg::G1;
     use kzg_bench::tests::kzg_proofs::{
-        commit_to_nil_poly, commit_to_too_long_poly_returns_err, proof_multi, proof_single,
+        commit_to_nil_poly, commit_to_too_long_poly_returns_err, proof_multi,
+        proof_multi_with_few_g2_points, proof_single, setup_basis_conversion,
     };
 
     use rust_kzg_blst::types::fft_settings::FsFFTSettings;
@@ -45,6 +46,20 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn test_proof_multi_with_few_g2_points() {
+        proof_multi_with_few_g2_points::<FsFr, FsG1, FsG2, FsPoly, FsFFTSettings, FsKZGSettings>(
+            &generate_trusted_setup,
+        );
+    }
+
+    #[test]
+    pub fn test_setup_basis_conversion() {
+        setup_basis_conversion::<FsFr, FsG1, FsG2, FsPoly, FsFFTSettings, FsKZGSettings>(
+            &generate_trusted_setup,
+        );
+    }
+
     // This aims at showing that the use of the blst::Pairing engine in pairings_verify
     // has the desired semantics.
     #[cfg(feature = "rand")]
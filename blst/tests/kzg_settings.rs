@@ -0,0 +1,214 @@
+#[cfg(test)]
+mod tests {
+    use kzg::eip_4844::hash;
+    use kzg::{Error, Fr, KZGSettings, Poly, G1, G2};
+    use rust_kzg_blst::eip_4844::{
+        blob_to_kzg_commitment_rust, compute_kzg_proof_rust, load_trusted_setup_filename_rust,
+    };
+    use rust_kzg_blst::types::fixed_base_msm::FsFixedBaseMsm;
+    use rust_kzg_blst::types::fr::FsFr;
+    use rust_kzg_blst::types::kzg_settings::FsKZGSettings;
+    use rust_kzg_blst::types::poly::FsPoly;
+
+    fn trusted_setup_path(name: &str) -> String {
+        format!(
+            "{}/../kzg-bench/src/trusted_setups/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            name
+        )
+    }
+
+    fn assert_settings_eq(a: &FsKZGSettings, b: &FsKZGSettings) {
+        assert_eq!(a.get_blob_preset(), b.get_blob_preset());
+        assert_eq!(a.basis, b.basis);
+        assert_eq!(a.fs.max_width, b.fs.max_width);
+        assert_eq!(a.fs.root_of_unity, b.fs.root_of_unity);
+        assert_eq!(a.fs.expanded_roots_of_unity, b.fs.expanded_roots_of_unity);
+        assert_eq!(a.fs.reverse_roots_of_unity, b.fs.reverse_roots_of_unity);
+        assert_eq!(a.fs.roots_of_unity, b.fs.roots_of_unity);
+        assert_eq!(a.secret_g1.len(), b.secret_g1.len());
+        assert!(a
+            .secret_g1
+            .iter()
+            .zip(&b.secret_g1)
+            .all(|(x, y)| x.equals(y)));
+        assert_eq!(a.secret_g2.len(), b.secret_g2.len());
+        assert!(a
+            .secret_g2
+            .iter()
+            .zip(&b.secret_g2)
+            .all(|(x, y)| x.equals(y)));
+        assert_eq!(a.secret_g1_monomial.len(), b.secret_g1_monomial.len());
+        assert!(a
+            .secret_g1_monomial
+            .iter()
+            .zip(&b.secret_g1_monomial)
+            .all(|(x, y)| x.equals(y)));
+    }
+
+    /// Replaces the checksum at the end of `bytes` so that only the tampered content is checked.
+    fn rehash(bytes: &mut [u8]) {
+        let len = bytes.len() - 32;
+        let checksum = hash(&bytes[..len]);
+        bytes[len..].copy_from_slice(&checksum);
+    }
+
+    #[test]
+    pub fn settings_bytes_round_trip() {
+        for name in ["trusted_setup_4.txt", "trusted_setup.txt"] {
+            let lagrange = load_trusted_setup_filename_rust(&trusted_setup_path(name)).unwrap();
+            let monomial = lagrange.to_monomial().unwrap();
+            for settings in [lagrange, monomial] {
+                let bytes = settings.to_bytes();
+
+                assert_settings_eq(&FsKZGSettings::from_bytes(&bytes).unwrap(), &settings);
+                assert_settings_eq(
+                    &FsKZGSettings::from_bytes_unchecked(&bytes).unwrap(),
+                    &settings,
+                );
+            }
+        }
+    }
+
+    #[test]
+    pub fn settings_bytes_rejects_corruption() {
+        let settings =
+            load_trusted_setup_filename_rust(&trusted_setup_path("trusted_setup_4.txt")).unwrap();
+        let bytes = settings.to_bytes();
+
+        let mut flipped = bytes.clone();
+        flipped[100] ^= 1;
+        assert_eq!(
+            FsKZGSettings::from_bytes_unchecked(&flipped).err(),
+            Some(Error::InvalidArgument("Settings checksum does not match"))
+        );
+
+        let mut truncated = bytes[..bytes.len() - 64].to_vec();
+        rehash(&mut truncated);
+        assert_eq!(
+            FsKZGSettings::from_bytes_unchecked(&truncated).err(),
+            Some(Error::InvalidArgument("Settings are truncated"))
+        );
+
+        let mut future_version = bytes.clone();
+        future_version[4] = 2;
+        rehash(&mut future_version);
+        assert_eq!(
+            FsKZGSettings::from_bytes_unchecked(&future_version).err(),
+            Some(Error::InvalidArgument("Unsupported KZG settings version"))
+        );
+    }
+
+    #[test]
+    pub fn settings_bytes_checks_header() {
+        let settings =
+            load_trusted_setup_filename_rust(&trusted_setup_path("trusted_setup_4.txt")).unwrap();
+        let bytes = settings.to_bytes();
+
+        // Claim a preset of 8 field elements per blob for a setup of 4 G1 points
+        let mut preset = bytes.clone();
+        preset[8..16].copy_from_slice(&8u64.to_le_bytes());
+        rehash(&mut preset);
+
+        // Claim an FFT width of 2 for a setup of 4 G1 points
+        let mut width = bytes.clone();
+        width[16..24].copy_from_slice(&2u64.to_le_bytes());
+        rehash(&mut width);
+
+        // Claim fewer monomial than Lagrange G1 points
+        let mut monomial = bytes;
+        monomial[40..48].copy_from_slice(&3u64.to_le_bytes());
+        rehash(&mut monomial);
+
+        for (bytes, expected, actual) in [(preset, 8, 4), (width, 4, 2), (monomial, 4, 3)] {
+            let err = Some(Error::InvalidLength { expected, actual });
+            assert_eq!(FsKZGSettings::from_bytes(&bytes).err(), err);
+            assert_eq!(FsKZGSettings::from_bytes_unchecked(&bytes).err(), err);
+        }
+    }
+
+    #[test]
+    pub fn settings_bytes_validation_checks_roots() {
+        let settings =
+            load_trusted_setup_filename_rust(&trusted_setup_path("trusted_setup_4.txt")).unwrap();
+        let mut bytes = settings.to_bytes();
+
+        // Swap the second and third roots of unity, which the trusted path does not recompute
+        let (second, third) = (56 + 32, 56 + 64);
+        let root = bytes[second..third].to_vec();
+        bytes.copy_within(third..third + 32, second);
+        bytes[third..third + 32].copy_from_slice(&root);
+        rehash(&mut bytes);
+
+        assert!(FsKZGSettings::from_bytes_unchecked(&bytes).is_ok());
+        assert_eq!(
+            FsKZGSettings::from_bytes(&bytes).err(),
+            Some(Error::InvalidArgument(
+                "Roots of unity do not match the max width"
+            ))
+        );
+    }
+
+    #[test]
+    pub fn precomputed_commitments_match() {
+        let mut settings =
+            load_trusted_setup_filename_rust(&trusted_setup_path("trusted_setup.txt")).unwrap();
+        let blob: Vec<FsFr> = (0..settings.get_blob_preset().field_elements_per_blob)
+            .map(|_| FsFr::rand())
+            .collect();
+        let z = FsFr::rand();
+
+        let commitment = blob_to_kzg_commitment_rust(&blob, &settings);
+        let (proof, y) = compute_kzg_proof_rust(&blob, &z, &settings);
+
+        for wbits in [1, 7, 8, 12, 15] {
+            settings.precompute(wbits).unwrap();
+            assert!(blob_to_kzg_commitment_rust(&blob, &settings).equals(&commitment));
+
+            let (precomputed_proof, precomputed_y) = compute_kzg_proof_rust(&blob, &z, &settings);
+            assert!(precomputed_proof.equals(&proof));
+            assert!(precomputed_y.equals(&y));
+        }
+    }
+
+    #[test]
+    pub fn precomputed_short_polynomials_match() {
+        let settings = load_trusted_setup_filename_rust(&trusted_setup_path("trusted_setup_4.txt"))
+            .unwrap()
+            .to_monomial()
+            .unwrap();
+        let mut precomputed = settings.clone();
+        precomputed.precompute(2).unwrap();
+
+        for len in 0..=settings.secret_g1.len() {
+            let mut poly = FsPoly::new(len).unwrap();
+            for i in 0..len {
+                poly.set_coeff_at(i, &FsFr::rand());
+            }
+            assert!(precomputed
+                .commit_to_poly(&poly)
+                .unwrap()
+                .equals(&settings.commit_to_poly(&poly).unwrap()));
+        }
+
+        let too_long = FsPoly::new(settings.secret_g1.len() + 1).unwrap();
+        assert_eq!(
+            precomputed.commit_to_poly(&too_long).err(),
+            Some(Error::SetupTooSmall)
+        );
+    }
+
+    #[test]
+    pub fn precompute_rejects_bad_window() {
+        let settings =
+            load_trusted_setup_filename_rust(&trusted_setup_path("trusted_setup_4.txt")).unwrap();
+        for wbits in [0, 16] {
+            assert_eq!(
+                FsFixedBaseMsm::new(&settings.secret_g1, wbits).err(),
+                Some(Error::InvalidArgument(
+                    "Window size must be between 1 and 15"
+                ))
+            );
+        }
+    }
+}
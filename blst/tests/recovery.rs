@@ -26,4 +26,14 @@ mod tests {
     fn more_than_half_missing_() {
         more_than_half_missing::<FsFr, FsFFTSettings, FsPoly, FsPoly>();
     }
+
+    #[test]
+    fn correct_errors_simple_() {
+        correct_errors_simple::<FsFr, FsFFTSettings, FsPoly, FsPoly>();
+    }
+
+    #[test]
+    fn correct_errors_too_many_() {
+        correct_errors_too_many::<FsFr, FsFFTSettings, FsPoly, FsPoly>();
+    }
 }
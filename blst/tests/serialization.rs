@@ -0,0 +1,52 @@
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use kzg_bench::tests::serialization::{
+        fr_serde_rejects_invalid_test, fr_serde_round_trip_test, g1_serde_rejects_invalid_test,
+        g1_serde_round_trip_test, g2_serde_round_trip_test,
+    };
+    use rust_kzg_blst::eip_4844::load_trusted_setup_filename_rust;
+    use rust_kzg_blst::types::{fr::FsFr, g1::FsG1, g2::FsG2, kzg_settings::FsKZGSettings};
+
+    #[test]
+    fn fr_serde_round_trip_test_() {
+        fr_serde_round_trip_test::<FsFr>();
+    }
+
+    #[test]
+    fn fr_serde_rejects_invalid_test_() {
+        fr_serde_rejects_invalid_test::<FsFr>();
+    }
+
+    #[test]
+    fn g1_serde_round_trip_test_() {
+        g1_serde_round_trip_test::<FsG1>();
+    }
+
+    #[test]
+    fn g1_serde_rejects_invalid_test_() {
+        g1_serde_rejects_invalid_test::<FsG1>();
+    }
+
+    #[test]
+    fn g2_serde_round_trip_test_() {
+        g2_serde_round_trip_test::<FsG2>();
+    }
+
+    #[test]
+    fn settings_serde_round_trip_test_() {
+        let settings = load_trusted_setup_filename_rust(&format!(
+            "{}/../kzg-bench/src/trusted_setups/trusted_setup_4.txt",
+            env!("CARGO_MANIFEST_DIR")
+        ))
+        .unwrap();
+
+        let json = serde_json::to_string(&settings).unwrap();
+        let decoded: FsKZGSettings = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.to_bytes(), settings.to_bytes());
+
+        let mut corrupted = json.into_bytes();
+        corrupted[100] = if corrupted[100] == b'0' { b'1' } else { b'0' };
+        let corrupted = String::from_utf8(corrupted).unwrap();
+        assert!(serde_json::from_str::<FsKZGSettings>(&corrupted).is_err());
+    }
+}
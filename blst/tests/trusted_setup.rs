@@ -0,0 +1,22 @@
+#[cfg(test)]
+mod tests {
+    use kzg_bench::tests::trusted_setup::{
+        powers_of_tau_ceremony_test, powers_of_tau_tampered_transcript_test,
+    };
+    use rust_kzg_blst::types::fft_settings::FsFFTSettings;
+    use rust_kzg_blst::types::fr::FsFr;
+    use rust_kzg_blst::types::g1::FsG1;
+    use rust_kzg_blst::types::g2::FsG2;
+    use rust_kzg_blst::types::kzg_settings::FsKZGSettings;
+    use rust_kzg_blst::types::poly::FsPoly;
+
+    #[test]
+    pub fn powers_of_tau_ceremony_test_() {
+        powers_of_tau_ceremony_test::<FsFr, FsG1, FsG2, FsPoly, FsFFTSettings, FsKZGSettings>();
+    }
+
+    #[test]
+    pub fn powers_of_tau_tampered_transcript_test_() {
+        powers_of_tau_tampered_transcript_test::<FsFr, FsG1, FsG2>();
+    }
+}
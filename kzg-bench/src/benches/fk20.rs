@@ -52,7 +52,7 @@ pub fn bench_fk_single_da<
     // Initialise the secrets and data structures
     let (s1, s2) = generate_trusted_setup(secrets_len, SECRET);
     let fs = TFFTSettings::new(BENCH_SCALE).unwrap();
-    let ks = TKZGSettings::new(&s1, &s2, secrets_len, &fs).unwrap();
+    let ks = TKZGSettings::new(&s1, &s2, secrets_len, secrets_len, &fs).unwrap();
     let fk = TFK20SingleSettings::new(&ks, 2 * poly_len).unwrap();
 
     // Commit to the polynomial
@@ -91,7 +91,7 @@ pub fn bench_fk_multi_da<
     // Initialise the secrets and data structures
     let (s1, s2) = generate_trusted_setup(secrets_len, SECRET);
     let fs = TFFTSettings::new(width).unwrap();
-    let ks = TKZGSettings::new(&s1, &s2, secrets_len, &fs).unwrap();
+    let ks = TKZGSettings::new(&s1, &s2, secrets_len, secrets_len, &fs).unwrap();
     let fk = TFK20MultiSettings::new(&ks, secrets_len, chunk_len).unwrap();
 
     // Create a test polynomial of size n that's independent of chunk_len
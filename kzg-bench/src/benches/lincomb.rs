@@ -1,21 +1,24 @@
 use criterion::Criterion;
-use kzg::{Fr, G1};
+use kzg::{Fr, G1LinComb, G2LinComb, G1, G2};
 
-#[allow(clippy::type_complexity)]
-pub fn bench_g1_lincomb<TFr: Fr + Copy, TG1: G1 + Copy>(
-    c: &mut Criterion,
-    g1_linear_combination: &dyn Fn(&mut TG1, &[TG1], &[TFr], usize),
-) {
-    const NUM_POINTS: usize = 4096;
+const NUM_POINTS: usize = 4096;
 
+pub fn bench_g1_lincomb<TFr: Fr + Copy, TG1: G1 + G1LinComb<TFr> + Copy>(c: &mut Criterion) {
     let points = [TG1::rand(); NUM_POINTS];
     let scalars = [TFr::rand(); NUM_POINTS];
 
     let id = format!("bench_g1_lincomb points: '{}'", NUM_POINTS);
     c.bench_function(&id, |b| {
-        b.iter(|| {
-            let mut out = TG1::default();
-            g1_linear_combination(&mut out, points.as_slice(), scalars.as_slice(), NUM_POINTS)
-        })
+        b.iter(|| TG1::g1_lincomb(points.as_slice(), scalars.as_slice(), NUM_POINTS))
+    });
+}
+
+pub fn bench_g2_lincomb<TFr: Fr + Copy, TG2: G2 + G2LinComb<TFr>>(c: &mut Criterion) {
+    let points = vec![TG2::generator().mul(&TFr::rand()); NUM_POINTS];
+    let scalars = [TFr::rand(); NUM_POINTS];
+
+    let id = format!("bench_g2_lincomb points: '{}'", NUM_POINTS);
+    c.bench_function(&id, |b| {
+        b.iter(|| TG2::g2_lincomb(points.as_slice(), scalars.as_slice(), NUM_POINTS))
     });
 }
@@ -0,0 +1,37 @@
+#![allow(dead_code)]
+
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct Input {
+    blob: String,
+}
+
+impl Input {
+    pub fn get_blob_bytes(&self) -> Vec<u8> {
+        hex::decode(&self.blob[2..]).unwrap()
+    }
+}
+
+#[derive(Deserialize)]
+pub struct Test {
+    pub input: Input,
+    output: Option<(Vec<String>, Vec<String>)>,
+}
+
+impl Test {
+    pub fn get_output(&self) -> Option<(Vec<Vec<u8>>, Vec<Vec<u8>>)> {
+        self.output.as_ref().map(|(cells, proofs)| {
+            (
+                cells
+                    .iter()
+                    .map(|cell| hex::decode(&cell[2..]).unwrap())
+                    .collect(),
+                proofs
+                    .iter()
+                    .map(|proof| hex::decode(&proof[2..]).unwrap())
+                    .collect(),
+            )
+        })
+    }
+}
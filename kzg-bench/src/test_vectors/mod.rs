@@ -1,6 +1,9 @@
 pub mod blob_to_kzg_commitment;
 pub mod compute_blob_kzg_proof;
+pub mod compute_cells_and_kzg_proofs;
 pub mod compute_kzg_proof;
+pub mod recover_cells_and_kzg_proofs;
 pub mod verify_blob_kzg_proof;
 pub mod verify_blob_kzg_proof_batch;
+pub mod verify_cell_kzg_proof_batch;
 pub mod verify_kzg_proof;
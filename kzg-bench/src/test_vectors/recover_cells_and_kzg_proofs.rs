@@ -0,0 +1,49 @@
+#![allow(dead_code)]
+
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct Input {
+    cell_indices: Vec<u64>,
+    cells: Vec<String>,
+}
+
+impl Input {
+    pub fn get_cell_indices(&self) -> Vec<usize> {
+        self.cell_indices
+            .iter()
+            .map(|&index| index as usize)
+            .collect()
+    }
+
+    pub fn get_cells_bytes(&self) -> Vec<Vec<u8>> {
+        let mut v = Vec::new();
+        for cell in &self.cells {
+            v.push(hex::decode(&cell[2..]).unwrap());
+        }
+        v
+    }
+}
+
+#[derive(Deserialize)]
+pub struct Test {
+    pub input: Input,
+    output: Option<(Vec<String>, Vec<String>)>,
+}
+
+impl Test {
+    pub fn get_output(&self) -> Option<(Vec<Vec<u8>>, Vec<Vec<u8>>)> {
+        self.output.as_ref().map(|(cells, proofs)| {
+            (
+                cells
+                    .iter()
+                    .map(|cell| hex::decode(&cell[2..]).unwrap())
+                    .collect(),
+                proofs
+                    .iter()
+                    .map(|proof| hex::decode(&proof[2..]).unwrap())
+                    .collect(),
+            )
+        })
+    }
+}
@@ -1,4 +1,4 @@
-use kzg::{Fr, G1Mul, G2Mul, G1, G2};
+use kzg::{Fr, G1LinComb, G1Mul, G2LinComb, G2Mul, PairingVerify, G1, G2};
 use std::convert::TryInto;
 
 pub fn log_2_byte_works(log_2_byte: &dyn Fn(u8) -> usize) {
@@ -111,6 +111,47 @@ pub fn fr_uint64s_roundtrip<TFr: Fr>() {
     assert_eq!(expected[3], actual[3]);
 }
 
+pub fn fr_batch_inverse_works<TFr: Fr>() {
+    // Long enough for backends that invert in parallel chunks
+    let mut values = (0..3000).map(|_| TFr::rand()).collect::<Vec<_>>();
+    values[0] = TFr::zero();
+    values[1234] = TFr::zero();
+    values[2999] = TFr::zero();
+
+    let mut inverses = values.clone();
+    TFr::batch_inverse(&mut inverses);
+    for (value, inverse) in values.iter().zip(&inverses) {
+        if value.is_zero() {
+            assert!(inverse.is_zero());
+        } else {
+            assert!(inverse.equals(&value.inverse()));
+        }
+    }
+
+    let mut empty: Vec<TFr> = Vec::new();
+    TFr::batch_inverse(&mut empty);
+    let mut zeros = vec![TFr::zero(); 3];
+    TFr::batch_inverse(&mut zeros);
+    assert!(zeros.iter().all(TFr::is_zero));
+}
+
+pub fn fr_batch_mul_and_inner_product_work<TFr: Fr>() {
+    let a = (1..=5).map(TFr::from_u64).collect::<Vec<_>>();
+    let b = (6..=10).map(TFr::from_u64).collect::<Vec<_>>();
+
+    // 1 * 6 + 2 * 7 + 3 * 8 + 4 * 9 + 5 * 10
+    let product = TFr::inner_product(&a, &b).unwrap();
+    assert!(product.equals(&TFr::from_u64(130)));
+    assert!(TFr::inner_product(&a, &b[1..]).is_err());
+    assert!(TFr::inner_product(&[], &[]).unwrap().is_zero());
+
+    let mut scaled = a.clone();
+    TFr::batch_mul_scalar(&mut scaled, &TFr::from_u64(3));
+    for (value, scaled) in a.iter().zip(&scaled) {
+        assert!(scaled.equals(&value.mul(&TFr::from_u64(3))));
+    }
+}
+
 pub fn p1_mul_works<TFr: Fr, TG1: G1 + G1Mul<TFr>>() {
     let m1: [u64; 4] = [
         0xffffffff00000000,
@@ -213,6 +254,47 @@ pub fn g1_random_linear_combination<TFr: Fr, TG1: G1 + G1Mul<TFr> + Copy>(
     assert!(exp.equals(&res));
 }
 
+pub fn g1_lincomb_matches_naive<TFr: Fr, TG1: G1 + G1LinComb<TFr>>() {
+    const MAX_LEN: usize = 100;
+
+    let scalars: Vec<TFr> = (0..MAX_LEN).map(|_| TFr::rand()).collect();
+    let mut points = vec![TG1::generator()];
+    for i in 1..MAX_LEN {
+        points.push(points[i - 1].dbl());
+    }
+
+    // Only the first `len` points and scalars should be used, on both sides of small-input cutoffs
+    for len in [0, 1, 7, 8, 33, MAX_LEN] {
+        let mut exp = TG1::identity();
+        for i in 0..len {
+            exp = exp.add_or_dbl(&points[i].mul(&scalars[i]));
+        }
+
+        let res = TG1::g1_lincomb(&points, &scalars, len);
+        assert!(exp.equals(&res));
+    }
+}
+
+pub fn g2_lincomb_matches_naive<TFr: Fr, TG2: G2 + G2LinComb<TFr>>() {
+    const MAX_LEN: usize = 100;
+
+    let scalars: Vec<TFr> = (0..MAX_LEN).map(|_| TFr::rand()).collect();
+    let mut points = vec![TG2::generator()];
+    for i in 1..MAX_LEN {
+        points.push(points[i - 1].dbl());
+    }
+
+    for len in [0, 1, 7, 8, 33, MAX_LEN] {
+        let mut exp = TG2::generator().sub(&TG2::generator());
+        for i in 0..len {
+            exp = exp.add_or_dbl(&points[i].mul(&scalars[i]));
+        }
+
+        let res = TG2::g2_lincomb(&points, &scalars, len);
+        assert!(exp.equals(&res));
+    }
+}
+
 pub fn pairings_work<TFr: Fr, TG1: G1 + G1Mul<TFr>, TG2: G2 + G2Mul<TFr>>(
     pairings_verify: &dyn Fn(&TG1, &TG2, &TG1, &TG2) -> bool,
 ) {
@@ -230,3 +312,35 @@ pub fn pairings_work<TFr: Fr, TG1: G1 + G1Mul<TFr>, TG2: G2 + G2Mul<TFr>>(
     assert!(pairings_verify(&g1_3, &g2_5, &g1_5, &g2_3));
     assert!(!pairings_verify(&g1_3, &g2_3, &g1_5, &g2_5));
 }
+
+pub fn pairings_verify_multi_works<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + PairingVerify<TG1, TG2>,
+    TG2: G2 + G2Mul<TFr>,
+>() {
+    // Verify that e([2]g1, [3]g2) * e([4]g1, [5]g2) * e(-[26]g1, g2) = 1
+    let g1_2 = TG1::generator().mul(&TFr::from_u64(2));
+    let g1_4 = TG1::generator().mul(&TFr::from_u64(4));
+    let g1_26_neg = TG1::identity().sub(&TG1::generator().mul(&TFr::from_u64(26)));
+    let g1_27_neg = TG1::identity().sub(&TG1::generator().mul(&TFr::from_u64(27)));
+
+    let g2_3 = TG2::generator().mul(&TFr::from_u64(3));
+    let g2_5 = TG2::generator().mul(&TFr::from_u64(5));
+
+    let mut pairs = vec![
+        (g1_2, g2_3.clone()),
+        (g1_4, g2_5),
+        (g1_26_neg, TG2::generator()),
+    ];
+    assert!(TG1::verify_multi(&pairs));
+
+    // Pairs containing the point at infinity do not change the product
+    pairs.push((TG1::identity(), g2_3));
+    assert!(TG1::verify_multi(&pairs));
+
+    pairs[2].0 = g1_27_neg;
+    assert!(!TG1::verify_multi(&pairs));
+
+    assert!(TG1::verify_multi(&[]));
+    assert!(!TG1::verify_multi(&[(TG1::generator(), TG2::generator())]));
+}
@@ -1,4 +1,4 @@
-use kzg::{FFTSettings, Fr};
+use kzg::{Error, FFTSettings, Fr};
 
 pub fn roots_of_unity_is_the_expected_size(roots: &[[u64; 4]; 32]) {
     assert_eq!(roots.len(), 32);
@@ -25,7 +25,7 @@ pub fn roots_of_unity_are_plausible<TFr: Fr>(roots: &[[u64; 4]; 32]) {
 #[allow(clippy::type_complexity)]
 pub fn expand_roots_is_plausible<TFr: Fr>(
     roots: &[[u64; 4]; 32],
-    expand_root_of_unity: &dyn Fn(&TFr, usize) -> Result<Vec<TFr>, String>,
+    expand_root_of_unity: &dyn Fn(&TFr, usize) -> Result<Vec<TFr>, Error>,
 ) {
     let scale = 15;
     let width: usize = 1 << scale;
@@ -0,0 +1,206 @@
+use crate::tests::fk20_proofs::{reverse_bit_order, SECRET};
+use kzg::danksharding::{
+    compute_sample_proofs, extend_commitments, extend_matrix, recover_matrix, verify_sample_proof,
+};
+use kzg::{
+    FFTFr, FFTSettings, FK20MultiSettings, Fr, KZGSettings, Poly, PolyRecover, DAS, FFTG1, G1, G2,
+};
+
+const BLOB_COUNT: usize = 4;
+const BLOB_LEN: usize = 32;
+const SAMPLE_LEN: usize = 8;
+
+fn test_blobs<TFr: Fr>() -> Vec<Vec<TFr>> {
+    (0..BLOB_COUNT)
+        .map(|row| {
+            (0..BLOB_LEN)
+                .map(|column| {
+                    let i = (row * BLOB_LEN + column) as u64;
+                    TFr::from_u64(i * i * 7 + i + 3)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn is_extended<TFr: Fr, TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>>(
+    line: &[TFr],
+    fs: &TFFTSettings,
+) -> bool {
+    let mut evaluations = line.to_vec();
+    reverse_bit_order(&mut evaluations);
+    let coeffs = fs.fft_fr(&evaluations, true).unwrap();
+    coeffs[line.len() / 2..].iter().all(TFr::is_zero)
+}
+
+fn column<TFr: Clone>(matrix: &[Vec<TFr>], index: usize) -> Vec<TFr> {
+    matrix.iter().map(|row| row[index].clone()).collect()
+}
+
+fn blob_to_poly<TFr: Fr, TPoly: Poly<TFr>, TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>>(
+    blob: &[TFr],
+    fs: &TFFTSettings,
+) -> TPoly {
+    let mut evaluations = blob.to_vec();
+    reverse_bit_order(&mut evaluations);
+    let coeffs = fs.fft_fr(&evaluations, true).unwrap();
+
+    let mut poly = TPoly::new(coeffs.len()).unwrap();
+    for (i, coeff) in coeffs.iter().enumerate() {
+        poly.set_coeff_at(i, coeff);
+    }
+    poly
+}
+
+pub fn extend_matrix_test<TFr: Fr, TFFTSettings: FFTSettings<TFr> + FFTFr<TFr> + DAS<TFr>>() {
+    let fs = TFFTSettings::new(6).unwrap();
+    let blobs = test_blobs::<TFr>();
+    let extended = extend_matrix(&blobs, &fs).unwrap();
+
+    assert_eq!(extended.len(), 2 * BLOB_COUNT);
+    for (row, blob) in extended.iter().zip(&blobs) {
+        assert_eq!(row.len(), 2 * BLOB_LEN);
+        assert!(row[..BLOB_LEN]
+            .iter()
+            .zip(blob)
+            .all(|(value, expected)| value.equals(expected)));
+    }
+
+    for row in &extended {
+        assert!(is_extended(row, &fs));
+    }
+    for i in 0..2 * BLOB_LEN {
+        assert!(is_extended(&column(&extended, i), &fs));
+    }
+
+    // Ragged and non-power-of-two inputs are rejected
+    let mut ragged = blobs.clone();
+    ragged[1].pop();
+    assert!(extend_matrix(&ragged, &fs).is_err());
+    assert!(extend_matrix(&blobs[..3], &fs).is_err());
+}
+
+pub fn sample_proofs_test<
+    TFr: Fr,
+    TG1: G1,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr> + FFTFr<TFr> + FFTG1<TG1> + DAS<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+    TFK20MultiSettings: FK20MultiSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings>,
+>(
+    generate_trusted_setup: &dyn Fn(usize, [u8; 32usize]) -> (Vec<TG1>, Vec<TG2>),
+) {
+    let (s1, s2) = generate_trusted_setup(2 * BLOB_LEN, SECRET);
+    let fs = TFFTSettings::new(6).unwrap();
+    let ks = TKZGSettings::new(&s1, &s2, 2 * BLOB_LEN, 2 * BLOB_LEN, &fs).unwrap();
+    let fk = TFK20MultiSettings::new(&ks, 2 * BLOB_LEN, SAMPLE_LEN).unwrap();
+
+    let blobs = test_blobs::<TFr>();
+    let extended = extend_matrix(&blobs, &fs).unwrap();
+
+    // Extending the commitments gives the commitments to the extended rows
+    let commitments = blobs
+        .iter()
+        .map(|blob| {
+            ks.commit_to_poly(&blob_to_poly::<TFr, TPoly, TFFTSettings>(blob, &fs))
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let extended_commitments = extend_commitments(&commitments, &fs).unwrap();
+    assert_eq!(extended_commitments.len(), 2 * BLOB_COUNT);
+    for (row, commitment) in extended.iter().zip(&extended_commitments) {
+        let expected = ks
+            .commit_to_poly(&blob_to_poly::<TFr, TPoly, TFFTSettings>(row, &fs))
+            .unwrap();
+        assert!(commitment.equals(&expected));
+    }
+
+    let proofs = compute_sample_proofs(&extended, &fk, &fs).unwrap();
+    assert_eq!(proofs.len(), 2 * BLOB_COUNT);
+    for ((row, row_proofs), commitment) in extended.iter().zip(&proofs).zip(&extended_commitments) {
+        assert_eq!(row_proofs.len(), 2 * BLOB_LEN / SAMPLE_LEN);
+        for (i, (sample, proof)) in row.chunks(SAMPLE_LEN).zip(row_proofs).enumerate() {
+            assert!(verify_sample_proof(commitment, proof, i, sample, &ks).unwrap());
+        }
+    }
+
+    // A changed sample, or a proof for another sample, does not verify
+    let mut sample = extended[1][SAMPLE_LEN..2 * SAMPLE_LEN].to_vec();
+    assert!(
+        !verify_sample_proof(&extended_commitments[1], &proofs[1][0], 1, &sample, &ks).unwrap()
+    );
+    sample[3] = sample[3].add(&TFr::one());
+    assert!(
+        !verify_sample_proof(&extended_commitments[1], &proofs[1][1], 1, &sample, &ks).unwrap()
+    );
+
+    // So does a sample out of range
+    assert!(verify_sample_proof(
+        &extended_commitments[1],
+        &proofs[1][0],
+        2 * BLOB_LEN / SAMPLE_LEN,
+        &sample,
+        &ks
+    )
+    .is_err());
+
+    // Rows that are not extensions are rejected
+    let mut invalid = extended.clone();
+    invalid[2][5] = invalid[2][5].add(&TFr::one());
+    assert!(compute_sample_proofs(&invalid, &fk, &fs).is_err());
+}
+
+pub fn recover_matrix_test<
+    TFr: Fr,
+    TPoly: Poly<TFr> + PolyRecover<TFr, TPoly, TFFTSettings>,
+    TFFTSettings: FFTSettings<TFr> + FFTFr<TFr> + DAS<TFr>,
+>() {
+    let fs = TFFTSettings::new(6).unwrap();
+    let extended = extend_matrix(&test_blobs::<TFr>(), &fs).unwrap();
+    let known = |keep: &dyn Fn(usize, usize) -> bool| {
+        extended
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                row.iter()
+                    .enumerate()
+                    .map(|(j, value)| keep(i, j).then(|| value.clone()))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>()
+    };
+    let matches = |recovered: &[Vec<TFr>]| {
+        recovered
+            .iter()
+            .flatten()
+            .zip(extended.iter().flatten())
+            .all(|(a, b)| a.equals(b))
+    };
+
+    // Half of every row
+    let samples = known(&|i, j| (i + j) % 2 == 0);
+    assert!(matches(
+        &recover_matrix::<TFr, TPoly, _>(&samples, &fs).unwrap()
+    ));
+
+    // The original quadrant without one element, and the first extended column. The first row and
+    // the extended rows only have half of their elements once the columns are recovered.
+    let samples = known(&|i, j| {
+        (i < BLOB_COUNT && j < BLOB_LEN && (i, j) != (0, 0)) || (i >= BLOB_COUNT && j == BLOB_LEN)
+    });
+    assert!(matches(
+        &recover_matrix::<TFr, TPoly, _>(&samples, &fs).unwrap()
+    ));
+
+    // The original quadrant without its last column, which leaves less than half of every row
+    let samples = known(&|i, j| i < BLOB_COUNT && j < BLOB_LEN - 1);
+    assert!(recover_matrix::<TFr, TPoly, _>(&samples, &fs).is_err());
+
+    // A corrupted element in a complete row, and one in a row that needs recovery
+    for (i, j) in [(0, 0), (5, 6)] {
+        let mut samples = known(&|row, column| row != 5 || column % 2 == 0);
+        samples[i][j] = samples[i][j].as_ref().map(|value| value.add(&TFr::one()));
+        assert!(recover_matrix::<TFr, TPoly, _>(&samples, &fs).is_err());
+    }
+}
@@ -4,16 +4,24 @@ use crate::test_vectors::{
     blob_to_kzg_commitment, compute_blob_kzg_proof, compute_kzg_proof, verify_blob_kzg_proof,
     verify_blob_kzg_proof_batch, verify_kzg_proof,
 };
+use kzg::eip_4844::encoding::{
+    blobs_needed, decode_payload_with_preset, encode_payload_with_preset, max_payload_size,
+};
 use kzg::eip_4844::{
-    BYTES_PER_BLOB, BYTES_PER_COMMITMENT, BYTES_PER_FIELD_ELEMENT, BYTES_PER_PROOF,
-    FIELD_ELEMENTS_PER_BLOB, TRUSTED_SETUP_PATH,
+    kzg_to_versioned_hash, load_trusted_setup_json, load_trusted_setup_string, BlobPreset,
+    PointEvaluationError, ProofUpdateTerms, BLS_MODULUS_BYTES_BE, BYTES_PER_BLOB,
+    BYTES_PER_COMMITMENT, BYTES_PER_FIELD_ELEMENT, BYTES_PER_G1, BYTES_PER_G2, BYTES_PER_PROOF,
+    FIELD_ELEMENTS_PER_BLOB, POINT_EVALUATION_INPUT_LENGTH, POINT_EVALUATION_OUTPUT_LENGTH,
+    TRUSTED_SETUP_PATH,
 };
-use kzg::{FFTSettings, Fr, KZGSettings, Poly, G1, G2};
+use kzg::trusted_setup::monomial_to_lagrange;
+use kzg::{Error, FFTSettings, Fr, KZGSettings, Poly, PolyEval, FFTG1, G1, G2};
 use rand::rngs::ThreadRng;
 use rand::Rng;
 use std::env::set_current_dir;
 use std::fs;
 use std::path::PathBuf;
+use std::time::Instant;
 
 fn u64_to_bytes(x: u64) -> [u8; 32] {
     let mut bytes = [0u8; 32];
@@ -64,6 +72,103 @@ pub fn bytes_to_bls_field_test<TFr: Fr>() {
     assert_eq!(x, x_fr.to_u64_arr()[0]);
 }
 
+#[allow(clippy::type_complexity)]
+pub fn bytes_to_blob_rejects_invalid_length_test<TFr: Fr>(
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
+) {
+    let bytes = [0u8; BYTES_PER_BLOB - 1];
+    assert_eq!(
+        bytes_to_blob(&bytes).err(),
+        Some(Error::InvalidLength {
+            expected: BYTES_PER_BLOB,
+            actual: BYTES_PER_BLOB - 1
+        })
+    );
+    assert_eq!(
+        TFr::from_bytes(&bytes[..BYTES_PER_FIELD_ELEMENT - 1]).err(),
+        Some(Error::InvalidLength {
+            expected: BYTES_PER_FIELD_ELEMENT,
+            actual: BYTES_PER_FIELD_ELEMENT - 1
+        })
+    );
+}
+
+pub fn encode_payload_round_trip_test<TFr: Fr>() {
+    let mut rng = rand::thread_rng();
+
+    for preset in [BlobPreset::MINIMAL, BlobPreset::MAINNET] {
+        let one_blob = max_payload_size(1, &preset);
+        let two_blobs = max_payload_size(2, &preset);
+        let lengths = [
+            0,
+            1,
+            30,
+            31,
+            32,
+            one_blob - 1,
+            one_blob,
+            one_blob + 1,
+            two_blobs,
+            two_blobs + 1,
+            rng.gen_range(0..4 * one_blob),
+        ];
+
+        for len in lengths {
+            let mut payload = vec![0u8; len];
+            rng.fill(payload.as_mut_slice());
+
+            let blobs = encode_payload_with_preset::<TFr>(&payload, &preset);
+            assert_eq!(blobs.len(), blobs_needed(len, &preset));
+            assert!(blobs
+                .iter()
+                .all(|blob| blob.len() == preset.field_elements_per_blob));
+            assert_eq!(
+                decode_payload_with_preset(&blobs, &preset).unwrap(),
+                payload
+            );
+        }
+    }
+}
+
+pub fn decode_payload_rejects_malformed_blobs_test<TFr: Fr>() {
+    let preset = BlobPreset::MINIMAL;
+    let payload = (0..100u8).collect::<Vec<_>>();
+    let blobs = encode_payload_with_preset::<TFr>(&payload, &preset);
+    assert_eq!(blobs.len(), 1);
+
+    // No blobs at all
+    assert!(decode_payload_with_preset::<TFr>(&[], &preset).is_err());
+
+    // Blob of the wrong size
+    let mut short_blob = blobs.clone();
+    short_blob[0].pop();
+    assert!(decode_payload_with_preset(&short_blob, &preset).is_err());
+
+    // Field element that uses its most significant byte
+    let mut wide_element = blobs.clone();
+    let mut bytes = [0u8; BYTES_PER_FIELD_ELEMENT];
+    bytes[BYTES_PER_FIELD_ELEMENT - 1] = 1;
+    wide_element[0][3] = TFr::from_bytes(&bytes).unwrap();
+    assert!(decode_payload_with_preset(&wide_element, &preset).is_err());
+
+    // Trailing blob that the length header does not account for
+    let mut extra_blob = blobs.clone();
+    extra_blob.push(vec![TFr::zero(); preset.field_elements_per_blob]);
+    assert!(decode_payload_with_preset(&extra_blob, &preset).is_err());
+
+    // Data after the payload, which ends 15 bytes into the last element
+    let mut dirty_padding = blobs.clone();
+    let mut bytes = dirty_padding[0][3].to_bytes();
+    bytes[20] = 1;
+    dirty_padding[0][3] = TFr::from_bytes(&bytes).unwrap();
+    assert!(decode_payload_with_preset(&dirty_padding, &preset).is_err());
+
+    // Length header larger than the blobs
+    let mut long_header = blobs;
+    long_header[0][0] = TFr::from_u64(max_payload_size(1, &preset) as u64 + 1);
+    assert!(decode_payload_with_preset(&long_header, &preset).is_err());
+}
+
 pub fn compute_powers_test<TFr: Fr>(compute_powers: &dyn Fn(&TFr, usize) -> Vec<TFr>) {
     let x: u64 = 32930439;
     let n = 11;
@@ -85,11 +190,11 @@ pub fn blob_to_kzg_commitment_test<
     TFFTSettings: FFTSettings<TFr>,
     TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
 >(
-    load_trusted_setup: &dyn Fn(&str) -> TKZGSettings,
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
     blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> TG1,
 ) {
     set_current_dir(env!("CARGO_MANIFEST_DIR")).unwrap();
-    let ts = load_trusted_setup(TRUSTED_SETUP_PATH);
+    let ts = load_trusted_setup(TRUSTED_SETUP_PATH).unwrap();
 
     let field_element =
         TFr::from_hex("0xad5570f5a3810b7af9d4b24bc1c2ea670245db2eaa49aae654b8f7393a9a6214")
@@ -130,13 +235,13 @@ pub fn compute_kzg_proof_test<
     TFFTSettings: FFTSettings<TFr>,
     TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
 >(
-    load_trusted_setup: &dyn Fn(&str) -> TKZGSettings,
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
     compute_kzg_proof: &dyn Fn(&[TFr], &TFr, &TKZGSettings) -> (TG1, TFr),
-    blob_to_polynomial: &dyn Fn(&[TFr]) -> TPoly,
-    evaluate_polynomial_in_evaluation_form: &dyn Fn(&TPoly, &TFr, &TKZGSettings) -> TFr,
+    blob_to_polynomial: &dyn Fn(&[TFr]) -> PolyEval<TFr>,
+    evaluate_polynomial_in_evaluation_form: &dyn Fn(&PolyEval<TFr>, &TFr, &TKZGSettings) -> TFr,
 ) {
     set_current_dir(env!("CARGO_MANIFEST_DIR")).unwrap();
-    let ts = load_trusted_setup(TRUSTED_SETUP_PATH);
+    let ts = load_trusted_setup(TRUSTED_SETUP_PATH).unwrap();
 
     let field_element =
         TFr::from_hex("0x138a16c66bdd9b0b17978ebd00bedf62307aa545d6b899b35703aedb696e3869")
@@ -185,16 +290,16 @@ pub fn compute_and_verify_kzg_proof_round_trip_test<
     TFFTSettings: FFTSettings<TFr>,
     TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
 >(
-    load_trusted_setup: &dyn Fn(&str) -> TKZGSettings,
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
     blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> TG1,
-    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, String>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
     compute_kzg_proof: &dyn Fn(&[TFr], &TFr, &TKZGSettings) -> (TG1, TFr),
-    blob_to_polynomial: &dyn Fn(&[TFr]) -> TPoly,
-    evaluate_polynomial_in_evaluation_form: &dyn Fn(&TPoly, &TFr, &TKZGSettings) -> TFr,
-    verify_kzg_proof: &dyn Fn(&TG1, &TFr, &TFr, &TG1, &TKZGSettings) -> Result<bool, String>,
+    blob_to_polynomial: &dyn Fn(&[TFr]) -> PolyEval<TFr>,
+    evaluate_polynomial_in_evaluation_form: &dyn Fn(&PolyEval<TFr>, &TFr, &TKZGSettings) -> TFr,
+    verify_kzg_proof: &dyn Fn(&TG1, &TFr, &TFr, &TG1, &TKZGSettings) -> Result<bool, Error>,
 ) {
     set_current_dir(env!("CARGO_MANIFEST_DIR")).unwrap();
-    let ts = load_trusted_setup(TRUSTED_SETUP_PATH);
+    let ts = load_trusted_setup(TRUSTED_SETUP_PATH).unwrap();
     let mut rng = rand::thread_rng();
 
     let z_fr = {
@@ -238,16 +343,16 @@ pub fn compute_and_verify_kzg_proof_within_domain_test<
     TFFTSettings: FFTSettings<TFr>,
     TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
 >(
-    load_trusted_setup: &dyn Fn(&str) -> TKZGSettings,
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
     blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> TG1,
-    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, String>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
     compute_kzg_proof: &dyn Fn(&[TFr], &TFr, &TKZGSettings) -> (TG1, TFr),
-    blob_to_polynomial: &dyn Fn(&[TFr]) -> TPoly,
-    evaluate_polynomial_in_evaluation_form: &dyn Fn(&TPoly, &TFr, &TKZGSettings) -> TFr,
-    verify_kzg_proof: &dyn Fn(&TG1, &TFr, &TFr, &TG1, &TKZGSettings) -> Result<bool, String>,
+    blob_to_polynomial: &dyn Fn(&[TFr]) -> PolyEval<TFr>,
+    evaluate_polynomial_in_evaluation_form: &dyn Fn(&PolyEval<TFr>, &TFr, &TKZGSettings) -> TFr,
+    verify_kzg_proof: &dyn Fn(&TG1, &TFr, &TFr, &TG1, &TKZGSettings) -> Result<bool, Error>,
 ) {
     set_current_dir(env!("CARGO_MANIFEST_DIR")).unwrap();
-    let ts = load_trusted_setup(TRUSTED_SETUP_PATH);
+    let ts = load_trusted_setup(TRUSTED_SETUP_PATH).unwrap();
     let mut rng = rand::thread_rng();
 
     for i in 0..25 {
@@ -287,16 +392,16 @@ pub fn compute_and_verify_kzg_proof_fails_with_incorrect_proof_test<
     TFFTSettings: FFTSettings<TFr>,
     TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
 >(
-    load_trusted_setup: &dyn Fn(&str) -> TKZGSettings,
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
     blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> TG1,
-    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, String>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
     compute_kzg_proof: &dyn Fn(&[TFr], &TFr, &TKZGSettings) -> (TG1, TFr),
-    blob_to_polynomial: &dyn Fn(&[TFr]) -> TPoly,
-    evaluate_polynomial_in_evaluation_form: &dyn Fn(&TPoly, &TFr, &TKZGSettings) -> TFr,
-    verify_kzg_proof: &dyn Fn(&TG1, &TFr, &TFr, &TG1, &TKZGSettings) -> Result<bool, String>,
+    blob_to_polynomial: &dyn Fn(&[TFr]) -> PolyEval<TFr>,
+    evaluate_polynomial_in_evaluation_form: &dyn Fn(&PolyEval<TFr>, &TFr, &TKZGSettings) -> TFr,
+    verify_kzg_proof: &dyn Fn(&TG1, &TFr, &TFr, &TG1, &TKZGSettings) -> Result<bool, Error>,
 ) {
     set_current_dir(env!("CARGO_MANIFEST_DIR")).unwrap();
-    let ts = load_trusted_setup(TRUSTED_SETUP_PATH);
+    let ts = load_trusted_setup(TRUSTED_SETUP_PATH).unwrap();
     let mut rng = rand::thread_rng();
 
     let z_fr = {
@@ -339,14 +444,14 @@ pub fn compute_and_verify_blob_kzg_proof_test<
     TFFTSettings: FFTSettings<TFr>,
     TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
 >(
-    load_trusted_setup: &dyn Fn(&str) -> TKZGSettings,
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
     blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> TG1,
-    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, String>,
-    compute_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TKZGSettings) -> Result<TG1, String>,
-    verify_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TG1, &TKZGSettings) -> Result<bool, String>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
+    compute_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TKZGSettings) -> Result<TG1, Error>,
+    verify_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TG1, &TKZGSettings) -> Result<bool, Error>,
 ) {
     set_current_dir(env!("CARGO_MANIFEST_DIR")).unwrap();
-    let ts = load_trusted_setup(TRUSTED_SETUP_PATH);
+    let ts = load_trusted_setup(TRUSTED_SETUP_PATH).unwrap();
     let mut rng = rand::thread_rng();
 
     // Some preparation
@@ -373,14 +478,14 @@ pub fn compute_and_verify_blob_kzg_proof_fails_with_incorrect_proof_test<
     TFFTSettings: FFTSettings<TFr>,
     TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
 >(
-    load_trusted_setup: &dyn Fn(&str) -> TKZGSettings,
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
     blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> TG1,
-    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, String>,
-    compute_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TKZGSettings) -> Result<TG1, String>,
-    verify_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TG1, &TKZGSettings) -> Result<bool, String>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
+    compute_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TKZGSettings) -> Result<TG1, Error>,
+    verify_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TG1, &TKZGSettings) -> Result<bool, Error>,
 ) {
     set_current_dir(env!("CARGO_MANIFEST_DIR")).unwrap();
-    let ts = load_trusted_setup(TRUSTED_SETUP_PATH);
+    let ts = load_trusted_setup(TRUSTED_SETUP_PATH).unwrap();
     let mut rng = rand::thread_rng();
 
     // Some preparation
@@ -401,6 +506,460 @@ pub fn compute_and_verify_blob_kzg_proof_fails_with_incorrect_proof_test<
     assert!(!result);
 }
 
+#[allow(clippy::type_complexity)]
+pub fn compute_and_verify_blob_kzg_proof_with_runtime_preset_test<
+    TFr: Fr,
+    TG1: G1,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> TG1,
+    bytes_to_blob_with_preset: &dyn Fn(&[u8], &BlobPreset) -> Result<Vec<TFr>, Error>,
+    compute_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TKZGSettings) -> Result<TG1, Error>,
+    verify_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TG1, &TKZGSettings) -> Result<bool, Error>,
+) {
+    set_current_dir(env!("CARGO_MANIFEST_DIR")).unwrap();
+    let mut rng = rand::thread_rng();
+
+    // Both presets must work in the same build, whichever one is the default
+    for preset in [BlobPreset::MINIMAL, BlobPreset::MAINNET] {
+        let ts = load_trusted_setup(preset.trusted_setup_path().unwrap()).unwrap();
+        assert_eq!(ts.get_blob_preset(), &preset);
+
+        let blob_bytes = (0..preset.field_elements_per_blob)
+            .flat_map(|_| generate_random_field_element_bytes(&mut rng))
+            .collect::<Vec<u8>>();
+        assert_eq!(
+            bytes_to_blob_with_preset(&blob_bytes[BYTES_PER_FIELD_ELEMENT..], &preset).err(),
+            Some(Error::InvalidLength {
+                expected: preset.bytes_per_blob(),
+                actual: preset.bytes_per_blob() - BYTES_PER_FIELD_ELEMENT
+            })
+        );
+        let blob = bytes_to_blob_with_preset(&blob_bytes, &preset).unwrap();
+
+        let commitment = blob_to_kzg_commitment(&blob, &ts);
+        let proof = compute_blob_kzg_proof(&blob, &commitment, &ts).unwrap();
+        let result = verify_blob_kzg_proof(&blob, &commitment, &proof, &ts).unwrap();
+        assert!(result);
+    }
+}
+
+pub fn load_trusted_setup_string_reports_line_test() {
+    set_current_dir(env!("CARGO_MANIFEST_DIR")).unwrap();
+    let contents = fs::read_to_string("src/trusted_setups/trusted_setup_4.txt").unwrap();
+    let lines = contents.lines().collect::<Vec<_>>();
+    let (g1_bytes, g2_bytes) = load_trusted_setup_string(&contents).unwrap();
+    assert_eq!(g1_bytes.len(), 4 * BYTES_PER_G1);
+    assert_eq!(g2_bytes.len(), 65 * BYTES_PER_G2);
+
+    // Truncated after the second G1 point
+    let truncated = lines[..4].join("\n");
+    assert_eq!(
+        load_trusted_setup_string(&truncated),
+        Err(Error::InvalidTrustedSetup {
+            line: 5,
+            reason: "missing G1 point"
+        })
+    );
+
+    // Non-hex character in the first G2 point
+    let mut corrupted = lines.clone();
+    let g2_line = corrupted[6].replacen(|c: char| c.is_ascii_hexdigit(), "z", 1);
+    corrupted[6] = &g2_line;
+    assert_eq!(
+        load_trusted_setup_string(&corrupted.join("\n")),
+        Err(Error::InvalidTrustedSetup {
+            line: 7,
+            reason: "point is not valid hex"
+        })
+    );
+
+    // G1 point missing its last byte
+    let mut short = lines.clone();
+    short[2] = &lines[2][..lines[2].len() - 2];
+    assert_eq!(
+        load_trusted_setup_string(&short.join("\n")),
+        Err(Error::InvalidTrustedSetup {
+            line: 3,
+            reason: "point has an unexpected length"
+        })
+    );
+
+    assert_eq!(
+        load_trusted_setup_string("four\n65\n"),
+        Err(Error::InvalidTrustedSetup {
+            line: 1,
+            reason: "point count is not a number"
+        })
+    );
+}
+
+pub fn load_trusted_setup_json_reports_line_test() {
+    set_current_dir(env!("CARGO_MANIFEST_DIR")).unwrap();
+    let txt = fs::read_to_string("src/trusted_setups/trusted_setup_4.txt").unwrap();
+    let json = fs::read_to_string("src/trusted_setups/trusted_setup_4.json").unwrap();
+    // The G2 points are monomial in both formats, while the JSON G1 points are in Lagrange form
+    let (json_g1, json_g2) = load_trusted_setup_json(&json).unwrap();
+    let (txt_g1, txt_g2) = load_trusted_setup_string(&txt).unwrap();
+    assert_eq!(json_g2, txt_g2);
+    assert_eq!(json_g1.len(), txt_g1.len());
+    assert_ne!(json_g1, txt_g1);
+
+    // Close `g1_lagrange` with the wrong bracket
+    let unterminated = json.replacen("],", "},", 1);
+    let line = json.lines().position(|l| l.trim() == "],").unwrap() + 1;
+    assert_eq!(
+        load_trusted_setup_json(&unterminated),
+        Err(Error::InvalidTrustedSetup {
+            line,
+            reason: "expected ',' or ']'"
+        })
+    );
+
+    assert_eq!(
+        load_trusted_setup_json(&json.replace("g2_monomial", "g2_lagrange")),
+        Err(Error::InvalidTrustedSetup {
+            line: json.lines().count() + 1,
+            reason: "missing point array"
+        })
+    );
+}
+
+#[allow(clippy::type_complexity)]
+pub fn load_trusted_setup_json_test<
+    TFr: Fr,
+    TG1: G1,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    load_trusted_setup_json: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+) {
+    set_current_dir(env!("CARGO_MANIFEST_DIR")).unwrap();
+    let txt = load_trusted_setup("src/trusted_setups/trusted_setup_4.txt").unwrap();
+    let json = load_trusted_setup_json("src/trusted_setups/trusted_setup_4.json").unwrap();
+
+    assert_eq!(json.get_blob_preset(), txt.get_blob_preset());
+    assert_eq!(json.get_g1_secret().len(), txt.get_g1_secret().len());
+    assert!(json
+        .get_g1_secret()
+        .iter()
+        .zip(txt.get_g1_secret())
+        .all(|(a, b)| a.equals(b)));
+    assert_eq!(json.get_g2_secret().len(), txt.get_g2_secret().len());
+    assert!(json
+        .get_g2_secret()
+        .iter()
+        .zip(txt.get_g2_secret())
+        .all(|(a, b)| a.equals(b)));
+}
+
+#[allow(clippy::type_complexity)]
+pub fn verify_trusted_setup_test<
+    TFr: Fr,
+    TG1: G1,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+>(
+    load_trusted_setup: &dyn Fn(&[u8], &[u8], bool) -> Result<TKZGSettings, Error>,
+    verify_trusted_setup: &dyn Fn(&TKZGSettings) -> Result<(), Error>,
+) {
+    set_current_dir(env!("CARGO_MANIFEST_DIR")).unwrap();
+    let contents = fs::read_to_string("src/trusted_setups/trusted_setup.txt").unwrap();
+    let (g1_bytes, g2_bytes) = load_trusted_setup_string(&contents).unwrap();
+
+    let ts = load_trusted_setup(&g1_bytes, &g2_bytes, true).unwrap();
+    assert_eq!(verify_trusted_setup(&ts), Ok(()));
+
+    // Two G1 points swapped
+    let mut swapped = g1_bytes.clone();
+    let (first, rest) = swapped.split_at_mut(2 * BYTES_PER_G1);
+    first[BYTES_PER_G1..].swap_with_slice(&mut rest[..BYTES_PER_G1]);
+    assert!(matches!(
+        load_trusted_setup(&swapped, &g2_bytes, true),
+        Err(Error::InconsistentTrustedSetup(_))
+    ));
+
+    // A G2 point replaced by a valid point of a different power
+    let mut replaced = g2_bytes.clone();
+    replaced.copy_within(3 * BYTES_PER_G2..4 * BYTES_PER_G2, 2 * BYTES_PER_G2);
+    assert!(matches!(
+        load_trusted_setup(&g1_bytes, &replaced, true),
+        Err(Error::InconsistentTrustedSetup(_))
+    ));
+
+    // Without verification, a corrupted setup still loads
+    assert!(load_trusted_setup(&swapped, &g2_bytes, false).is_ok());
+}
+
+#[allow(clippy::type_complexity)]
+pub fn load_trusted_setup_lagrange_test<
+    TFr: Fr,
+    TG1: G1,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr> + FFTG1<TG1>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+>(
+    load_trusted_setup: &dyn Fn(&[u8], &[u8], bool) -> Result<TKZGSettings, Error>,
+    load_trusted_setup_lagrange: &dyn Fn(&[u8], &[u8], bool) -> Result<TKZGSettings, Error>,
+) {
+    set_current_dir(env!("CARGO_MANIFEST_DIR")).unwrap();
+    let contents = fs::read_to_string("src/trusted_setups/trusted_setup.txt").unwrap();
+    let (g1_bytes, g2_bytes) = load_trusted_setup_string(&contents).unwrap();
+
+    let g1_monomial = g1_bytes
+        .chunks(BYTES_PER_G1)
+        .map(|chunk| TG1::from_bytes(chunk).unwrap())
+        .collect::<Vec<TG1>>();
+    let fs = TFFTSettings::new(12).unwrap();
+    let lagrange_bytes = monomial_to_lagrange(&g1_monomial, &fs)
+        .unwrap()
+        .iter()
+        .flat_map(|point| point.to_bytes())
+        .collect::<Vec<u8>>();
+
+    // Both forms of the same setup give the same settings
+    let ts = load_trusted_setup(&g1_bytes, &g2_bytes, false).unwrap();
+    let lagrange_ts = load_trusted_setup_lagrange(&lagrange_bytes, &g2_bytes, true).unwrap();
+    assert!(lagrange_ts
+        .get_g1_secret()
+        .iter()
+        .zip(ts.get_g1_secret())
+        .all(|(a, b)| a.equals(b)));
+
+    // Monomial points passed as Lagrange ones are not a consistent setup
+    assert!(matches!(
+        load_trusted_setup_lagrange(&g1_bytes, &g2_bytes, true),
+        Err(Error::InconsistentTrustedSetup(_))
+    ));
+}
+
+#[allow(clippy::type_complexity)]
+pub fn update_kzg_commitment_and_proof_test<
+    TFr: Fr,
+    TG1: G1,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> TG1,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
+    compute_kzg_proof: &dyn Fn(&[TFr], &TFr, &TKZGSettings) -> (TG1, TFr),
+    update_kzg_commitment: &dyn Fn(&TG1, &[(usize, TFr, TFr)], &TKZGSettings) -> Result<TG1, Error>,
+    precompute_proof_update_terms: &dyn Fn(
+        &TFr,
+        &TKZGSettings,
+    ) -> Result<ProofUpdateTerms<TFr, TG1>, Error>,
+    update_kzg_proof: &dyn Fn(
+        &TG1,
+        &TFr,
+        &[(usize, TFr, TFr)],
+        &ProofUpdateTerms<TFr, TG1>,
+        &TKZGSettings,
+    ) -> Result<(TG1, TFr), Error>,
+) {
+    set_current_dir(env!("CARGO_MANIFEST_DIR")).unwrap();
+    let ts = load_trusted_setup(TRUSTED_SETUP_PATH).unwrap();
+    let mut rng = rand::thread_rng();
+
+    let mut blob = bytes_to_blob(&generate_random_blob_bytes(&mut rng)).unwrap();
+    let commitment = blob_to_kzg_commitment(&blob, &ts);
+    let z_outside = TFr::from_bytes(&generate_random_field_element_bytes(&mut rng)).unwrap();
+    let z_inside = ts.get_roots_of_unity_at(1);
+    let (proof_outside, y_outside) = compute_kzg_proof(&blob, &z_outside, &ts);
+    let (proof_inside, y_inside) = compute_kzg_proof(&blob, &z_inside, &ts);
+
+    // Change a few elements, one of them twice
+    let last = blob.len() - 1;
+    let mut changes = Vec::new();
+    for index in [0, last, 1, 0] {
+        let new = TFr::from_bytes(&generate_random_field_element_bytes(&mut rng)).unwrap();
+        changes.push((index, blob[index].clone(), new.clone()));
+        blob[index] = new;
+    }
+
+    let updated_commitment = update_kzg_commitment(&commitment, &changes, &ts).unwrap();
+    assert!(updated_commitment.equals(&blob_to_kzg_commitment(&blob, &ts)));
+
+    for (z, proof, y) in [
+        (z_outside, proof_outside, y_outside),
+        (z_inside.clone(), proof_inside, y_inside),
+    ] {
+        let terms = precompute_proof_update_terms(&z, &ts).unwrap();
+        let (updated_proof, updated_y) =
+            update_kzg_proof(&proof, &y, &changes, &terms, &ts).unwrap();
+        let (expected_proof, expected_y) = compute_kzg_proof(&blob, &z, &ts);
+        assert!(updated_proof.equals(&expected_proof));
+        assert!(updated_y.equals(&expected_y));
+    }
+
+    // No changes leave everything as it was
+    let unchanged = update_kzg_commitment(&updated_commitment, &[], &ts).unwrap();
+    assert!(unchanged.equals(&updated_commitment));
+
+    // Indices past the end of the blob
+    let out_of_range = [(blob.len(), TFr::zero(), TFr::one())];
+    assert!(update_kzg_commitment(&commitment, &out_of_range, &ts).is_err());
+    let terms = precompute_proof_update_terms(&z_inside, &ts).unwrap();
+    assert!(update_kzg_proof(&commitment, &TFr::zero(), &out_of_range, &terms, &ts).is_err());
+}
+
+/// Updating a proof for a few changes must take a fraction of the time of recomputing it, which
+/// commits to the whole blob.
+#[allow(clippy::type_complexity)]
+pub fn update_kzg_proof_cost_test<
+    TFr: Fr,
+    TG1: G1,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
+    compute_kzg_proof: &dyn Fn(&[TFr], &TFr, &TKZGSettings) -> (TG1, TFr),
+    precompute_proof_update_terms: &dyn Fn(
+        &TFr,
+        &TKZGSettings,
+    ) -> Result<ProofUpdateTerms<TFr, TG1>, Error>,
+    update_kzg_proof: &dyn Fn(
+        &TG1,
+        &TFr,
+        &[(usize, TFr, TFr)],
+        &ProofUpdateTerms<TFr, TG1>,
+        &TKZGSettings,
+    ) -> Result<(TG1, TFr), Error>,
+) {
+    const RUNS: usize = 5;
+
+    set_current_dir(env!("CARGO_MANIFEST_DIR")).unwrap();
+    let ts = load_trusted_setup(TRUSTED_SETUP_PATH).unwrap();
+    let mut rng = rand::thread_rng();
+
+    let blob = bytes_to_blob(&generate_random_blob_bytes(&mut rng)).unwrap();
+    let z = TFr::from_bytes(&generate_random_field_element_bytes(&mut rng)).unwrap();
+    let (proof, y) = compute_kzg_proof(&blob, &z, &ts);
+    let terms = precompute_proof_update_terms(&z, &ts).unwrap();
+    let changes = (0..blob.len())
+        .step_by(blob.len() / 8)
+        .map(|index| {
+            let new = TFr::from_bytes(&generate_random_field_element_bytes(&mut rng)).unwrap();
+            (index, blob[index].clone(), new)
+        })
+        .collect::<Vec<_>>();
+
+    // The fastest of a few runs, to leave out scheduling noise
+    let fastest = |f: &dyn Fn()| {
+        (0..RUNS)
+            .map(|_| {
+                let start = Instant::now();
+                f();
+                start.elapsed()
+            })
+            .min()
+            .unwrap()
+    };
+    let recompute = fastest(&|| {
+        compute_kzg_proof(&blob, &z, &ts);
+    });
+    let update = fastest(&|| {
+        update_kzg_proof(&proof, &y, &changes, &terms, &ts).unwrap();
+    });
+
+    assert!(
+        update * 10 < recompute,
+        "updating {} elements took {:?}, recomputing the proof {:?}",
+        changes.len(),
+        update,
+        recompute
+    );
+}
+
+#[allow(clippy::type_complexity)]
+pub fn compute_all_kzg_proofs_test<
+    TFr: Fr,
+    TG1: G1,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> TG1,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
+    compute_kzg_proof: &dyn Fn(&[TFr], &TFr, &TKZGSettings) -> (TG1, TFr),
+    compute_all_kzg_proofs: &dyn Fn(&[TFr], &TKZGSettings) -> Result<Vec<TG1>, Error>,
+    verify_all_kzg_proofs_batch: &dyn Fn(
+        &TG1,
+        &[usize],
+        &[TFr],
+        &[TG1],
+        &TKZGSettings,
+    ) -> Result<bool, Error>,
+) {
+    set_current_dir(env!("CARGO_MANIFEST_DIR")).unwrap();
+    let ts = load_trusted_setup(TRUSTED_SETUP_PATH).unwrap();
+    let mut rng = rand::thread_rng();
+
+    let blob = bytes_to_blob(&generate_random_blob_bytes(&mut rng)).unwrap();
+    let commitment = blob_to_kzg_commitment(&blob, &ts);
+    let proofs = compute_all_kzg_proofs(&blob, &ts).unwrap();
+    assert_eq!(proofs.len(), blob.len());
+
+    // Every proof opens the blob at the domain point of its element
+    let last = blob.len() - 1;
+    for i in [0, 1, 2, last / 2, last] {
+        let (expected_proof, y) = compute_kzg_proof(&blob, &ts.get_roots_of_unity_at(i), &ts);
+        assert!(proofs[i].equals(&expected_proof));
+        assert!(y.equals(&blob[i]));
+    }
+
+    // All proofs at once, and a subset
+    let all_indices = (0..blob.len()).collect::<Vec<_>>();
+    assert!(verify_all_kzg_proofs_batch(&commitment, &all_indices, &blob, &proofs, &ts).unwrap());
+
+    let indices = [last, 0, 2];
+    let values = indices.map(|i| blob[i].clone());
+    let subset = indices.map(|i| proofs[i].clone());
+    assert!(verify_all_kzg_proofs_batch(&commitment, &indices, &values, &subset, &ts).unwrap());
+    assert!(verify_all_kzg_proofs_batch(&commitment, &[], &[], &[], &ts).unwrap());
+
+    // A wrong value or a proof for another element fails the batch
+    let mut wrong_values = values.clone();
+    wrong_values[1] = wrong_values[1].add(&TFr::one());
+    assert!(
+        !verify_all_kzg_proofs_batch(&commitment, &indices, &wrong_values, &subset, &ts).unwrap()
+    );
+    let mut swapped = subset.clone();
+    swapped.swap(0, 2);
+    assert!(!verify_all_kzg_proofs_batch(&commitment, &indices, &values, &swapped, &ts).unwrap());
+
+    // Malformed input
+    assert!(compute_all_kzg_proofs(&blob[1..], &ts).is_err());
+    assert!(verify_all_kzg_proofs_batch(
+        &commitment,
+        &[blob.len()],
+        &values[..1],
+        &subset[..1],
+        &ts
+    )
+    .is_err());
+    assert!(
+        verify_all_kzg_proofs_batch(&commitment, &indices, &values[..2], &subset, &ts).is_err()
+    );
+}
+
 #[allow(clippy::type_complexity)]
 pub fn verify_kzg_proof_batch_test<
     TFr: Fr,
@@ -410,19 +969,19 @@ pub fn verify_kzg_proof_batch_test<
     TFFTSettings: FFTSettings<TFr>,
     TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
 >(
-    load_trusted_setup: &dyn Fn(&str) -> TKZGSettings,
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
     blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> TG1,
-    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, String>,
-    compute_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TKZGSettings) -> Result<TG1, String>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
+    compute_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TKZGSettings) -> Result<TG1, Error>,
     verify_blob_kzg_proof_batch: &dyn Fn(
         &[Vec<TFr>],
         &[TG1],
         &[TG1],
         &TKZGSettings,
-    ) -> Result<bool, String>,
+    ) -> Result<bool, Error>,
 ) {
     set_current_dir(env!("CARGO_MANIFEST_DIR")).unwrap();
-    let ts = load_trusted_setup(TRUSTED_SETUP_PATH);
+    let ts = load_trusted_setup(TRUSTED_SETUP_PATH).unwrap();
     let mut rng = rand::thread_rng();
 
     const N_SAMPLES: usize = 16;
@@ -469,19 +1028,19 @@ pub fn verify_kzg_proof_batch_fails_with_incorrect_proof_test<
     TFFTSettings: FFTSettings<TFr>,
     TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
 >(
-    load_trusted_setup: &dyn Fn(&str) -> TKZGSettings,
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
     blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> TG1,
-    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, String>,
-    compute_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TKZGSettings) -> Result<TG1, String>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
+    compute_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TKZGSettings) -> Result<TG1, Error>,
     verify_blob_kzg_proof_batch: &dyn Fn(
         &[Vec<TFr>],
         &[TG1],
         &[TG1],
         &TKZGSettings,
-    ) -> Result<bool, String>,
+    ) -> Result<bool, Error>,
 ) {
     set_current_dir(env!("CARGO_MANIFEST_DIR")).unwrap();
-    let ts = load_trusted_setup(TRUSTED_SETUP_PATH);
+    let ts = load_trusted_setup(TRUSTED_SETUP_PATH).unwrap();
     let mut rng = rand::thread_rng();
 
     const N_SAMPLES: usize = 2;
@@ -512,6 +1071,213 @@ pub fn verify_kzg_proof_batch_fails_with_incorrect_proof_test<
     assert!(!result);
 }
 
+pub fn kzg_to_versioned_hash_test<TG1: G1>(kzg_to_versioned_hash: &dyn Fn(&TG1) -> [u8; 32]) {
+    // Versioned hash of the commitment to the zero blob
+    let expected =
+        hex::decode("010657f37554c781402a22917dee2f75def7ab966d7b770905398eba3c444014").unwrap();
+    assert_eq!(kzg_to_versioned_hash(&TG1::identity()).as_slice(), expected);
+}
+
+/// Precompile input for the given opening, with `z` and `y` encoded big-endian as in the EIP.
+fn point_evaluation_input<TFr: Fr, TG1: G1>(
+    commitment: &TG1,
+    z: &TFr,
+    y: &TFr,
+    proof: &TG1,
+) -> Vec<u8> {
+    let commitment_bytes = commitment.to_bytes();
+    let mut input = Vec::with_capacity(POINT_EVALUATION_INPUT_LENGTH);
+    input.extend_from_slice(&kzg_to_versioned_hash(&commitment_bytes));
+    input.extend(z.to_bytes().iter().rev());
+    input.extend(y.to_bytes().iter().rev());
+    input.extend_from_slice(&commitment_bytes);
+    input.extend_from_slice(&proof.to_bytes());
+    input
+}
+
+#[allow(clippy::type_complexity)]
+pub fn point_evaluation_precompile_test<
+    TFr: Fr,
+    TG1: G1,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> TG1,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
+    compute_kzg_proof: &dyn Fn(&[TFr], &TFr, &TKZGSettings) -> (TG1, TFr),
+    point_evaluation_precompile: &dyn Fn(
+        &[u8],
+        &TKZGSettings,
+    ) -> Result<
+        [u8; POINT_EVALUATION_OUTPUT_LENGTH],
+        PointEvaluationError,
+    >,
+) {
+    set_current_dir(env!("CARGO_MANIFEST_DIR")).unwrap();
+    let ts = load_trusted_setup(TRUSTED_SETUP_PATH).unwrap();
+    let mut rng = rand::thread_rng();
+
+    let blob = bytes_to_blob(&generate_random_blob_bytes(&mut rng)).unwrap();
+    let z = TFr::from_bytes(&generate_random_field_element_bytes(&mut rng)).unwrap();
+    let commitment = blob_to_kzg_commitment(&blob, &ts);
+    let (proof, y) = compute_kzg_proof(&blob, &z, &ts);
+    let input = point_evaluation_input(&commitment, &z, &y, &proof);
+
+    let output = point_evaluation_precompile(&input, &ts).unwrap();
+    assert_eq!(
+        output[..32],
+        u64_to_bytes(FIELD_ELEMENTS_PER_BLOB as u64)
+            .iter()
+            .rev()
+            .copied()
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(output[32..], BLS_MODULUS_BYTES_BE);
+
+    // Input of the wrong length
+    assert_eq!(
+        point_evaluation_precompile(&input[1..], &ts),
+        Err(PointEvaluationError::InvalidInputLength(
+            POINT_EVALUATION_INPUT_LENGTH - 1
+        ))
+    );
+
+    // Versioned hash of another commitment
+    let mut wrong_hash = input.clone();
+    wrong_hash[..32].copy_from_slice(&kzg_to_versioned_hash(&TG1::identity().to_bytes()));
+    assert_eq!(
+        point_evaluation_precompile(&wrong_hash, &ts),
+        Err(PointEvaluationError::VersionedHashMismatch)
+    );
+
+    // Versioned hash with a different version byte
+    let mut wrong_version = input.clone();
+    wrong_version[0] = 0x02;
+    assert_eq!(
+        point_evaluation_precompile(&wrong_version, &ts),
+        Err(PointEvaluationError::VersionedHashMismatch)
+    );
+
+    // Non-canonical z
+    let mut non_canonical_z = input.clone();
+    non_canonical_z[32..64].copy_from_slice(&[0xff; 32]);
+    assert!(matches!(
+        point_evaluation_precompile(&non_canonical_z, &ts),
+        Err(PointEvaluationError::InvalidZ(_))
+    ));
+
+    // Proof that is not a point
+    let mut invalid_proof = input.clone();
+    invalid_proof[144..].copy_from_slice(&[0xff; 48]);
+    assert!(matches!(
+        point_evaluation_precompile(&invalid_proof, &ts),
+        Err(PointEvaluationError::InvalidProof(_))
+    ));
+
+    // Well-formed input claiming the wrong evaluation
+    let wrong_y = point_evaluation_input(&commitment, &z, &y.add(&TFr::one()), &proof);
+    assert_eq!(
+        point_evaluation_precompile(&wrong_y, &ts),
+        Err(PointEvaluationError::VerificationFailed)
+    );
+}
+
+/// The setup-independent precompile cases of the execution-spec-tests reference suite for
+/// EIP-4844: the commitment and proof are the point at infinity, which opens to zero everywhere.
+#[allow(clippy::type_complexity)]
+pub fn point_evaluation_precompile_reference_test<
+    TFr: Fr,
+    TG1: G1,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    point_evaluation_precompile: &dyn Fn(
+        &[u8],
+        &TKZGSettings,
+    ) -> Result<
+        [u8; POINT_EVALUATION_OUTPUT_LENGTH],
+        PointEvaluationError,
+    >,
+) {
+    const INF_POINT: &str = "c00000000000000000000000000000000000000000000000\
+                             000000000000000000000000000000000000000000000000";
+    const INF_POINT_VERSIONED_HASH: &str =
+        "010657f37554c781402a22917dee2f75def7ab966d7b770905398eba3c444014";
+    const ZERO: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+    const ONE: &str = "0000000000000000000000000000000000000000000000000000000000000001";
+    const BLS_MODULUS: &str = "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001";
+    const BLS_MODULUS_MINUS_ONE: &str =
+        "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000000";
+
+    set_current_dir(env!("CARGO_MANIFEST_DIR")).unwrap();
+    let ts = load_trusted_setup(TRUSTED_SETUP_PATH).unwrap();
+    let input = |versioned_hash: &str, z: &str, y: &str| {
+        hex::decode([versioned_hash, z, y, INF_POINT, INF_POINT].concat()).unwrap()
+    };
+    let mut expected_output = u64_to_bytes(FIELD_ELEMENTS_PER_BLOB as u64);
+    expected_output.reverse();
+    let expected_output = [expected_output, BLS_MODULUS_BYTES_BE].concat();
+
+    // in_bounds_z
+    let output = point_evaluation_precompile(
+        &input(INF_POINT_VERSIONED_HASH, BLS_MODULUS_MINUS_ONE, ZERO),
+        &ts,
+    )
+    .unwrap();
+    assert_eq!(output.as_slice(), expected_output);
+
+    // zero_z
+    let output =
+        point_evaluation_precompile(&input(INF_POINT_VERSIONED_HASH, ZERO, ZERO), &ts).unwrap();
+    assert_eq!(output.as_slice(), expected_output);
+
+    // out_of_bounds_z
+    assert!(matches!(
+        point_evaluation_precompile(&input(INF_POINT_VERSIONED_HASH, BLS_MODULUS, ZERO), &ts),
+        Err(PointEvaluationError::InvalidZ(_))
+    ));
+
+    // out_of_bounds_y
+    assert!(matches!(
+        point_evaluation_precompile(&input(INF_POINT_VERSIONED_HASH, ZERO, BLS_MODULUS), &ts),
+        Err(PointEvaluationError::InvalidY(_))
+    ));
+
+    // correct_proof_incorrect_y
+    assert_eq!(
+        point_evaluation_precompile(&input(INF_POINT_VERSIONED_HASH, ZERO, ONE), &ts),
+        Err(PointEvaluationError::VerificationFailed)
+    );
+
+    // incorrect_versioned_hash_version
+    let versioned_hash = ["00", &INF_POINT_VERSIONED_HASH[2..]].concat();
+    assert_eq!(
+        point_evaluation_precompile(&input(&versioned_hash, ZERO, ZERO), &ts),
+        Err(PointEvaluationError::VersionedHashMismatch)
+    );
+
+    // invalid_input_length
+    let valid = input(INF_POINT_VERSIONED_HASH, ZERO, ZERO);
+    assert_eq!(
+        point_evaluation_precompile(&valid[1..], &ts),
+        Err(PointEvaluationError::InvalidInputLength(
+            POINT_EVALUATION_INPUT_LENGTH - 1
+        ))
+    );
+    assert_eq!(
+        point_evaluation_precompile(&[valid.as_slice(), &[0]].concat(), &ts),
+        Err(PointEvaluationError::InvalidInputLength(
+            POINT_EVALUATION_INPUT_LENGTH + 1
+        ))
+    );
+}
+
 const BLOB_TO_KZG_COMMITMENT_TESTS: &str = "src/test_vectors/blob_to_kzg_commitment/*/*/*";
 const COMPUTE_KZG_PROOF_TESTS: &str = "src/test_vectors/compute_kzg_proof/*/*/*";
 const COMPUTE_BLOB_KZG_PROOF_TESTS: &str = "src/test_vectors/compute_blob_kzg_proof/*/*/*";
@@ -529,12 +1295,12 @@ pub fn test_vectors_blob_to_kzg_commitment<
     TFFTSettings: FFTSettings<TFr>,
     TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
 >(
-    load_trusted_setup: &dyn Fn(&str) -> TKZGSettings,
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
     blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> TG1,
-    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, String>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
 ) {
     set_current_dir(env!("CARGO_MANIFEST_DIR")).unwrap();
-    let ts = load_trusted_setup(TRUSTED_SETUP_PATH);
+    let ts = load_trusted_setup(TRUSTED_SETUP_PATH).unwrap();
     let test_files: Vec<PathBuf> = glob::glob(BLOB_TO_KZG_COMMITMENT_TESTS)
         .unwrap()
         .map(Result::unwrap)
@@ -572,12 +1338,12 @@ pub fn test_vectors_compute_kzg_proof<
     TFFTSettings: FFTSettings<TFr>,
     TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
 >(
-    load_trusted_setup: &dyn Fn(&str) -> TKZGSettings,
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
     compute_kzg_proof: &dyn Fn(&[TFr], &TFr, &TKZGSettings) -> (TG1, TFr),
-    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, String>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
 ) {
     set_current_dir(env!("CARGO_MANIFEST_DIR")).unwrap();
-    let ts = load_trusted_setup(TRUSTED_SETUP_PATH);
+    let ts = load_trusted_setup(TRUSTED_SETUP_PATH).unwrap();
     let test_files: Vec<PathBuf> = glob::glob(COMPUTE_KZG_PROOF_TESTS)
         .unwrap()
         .map(Result::unwrap)
@@ -632,12 +1398,12 @@ pub fn test_vectors_compute_blob_kzg_proof<
     TFFTSettings: FFTSettings<TFr>,
     TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
 >(
-    load_trusted_setup: &dyn Fn(&str) -> TKZGSettings,
-    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, String>,
-    compute_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TKZGSettings) -> Result<TG1, String>,
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
+    compute_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TKZGSettings) -> Result<TG1, Error>,
 ) {
     set_current_dir(env!("CARGO_MANIFEST_DIR")).unwrap();
-    let ts = load_trusted_setup(TRUSTED_SETUP_PATH);
+    let ts = load_trusted_setup(TRUSTED_SETUP_PATH).unwrap();
     let test_files: Vec<PathBuf> = glob::glob(COMPUTE_BLOB_KZG_PROOF_TESTS)
         .unwrap()
         .map(Result::unwrap)
@@ -690,11 +1456,11 @@ pub fn test_vectors_verify_kzg_proof<
     TFFTSettings: FFTSettings<TFr>,
     TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
 >(
-    load_trusted_setup: &dyn Fn(&str) -> TKZGSettings,
-    verify_kzg_proof: &dyn Fn(&TG1, &TFr, &TFr, &TG1, &TKZGSettings) -> Result<bool, String>,
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    verify_kzg_proof: &dyn Fn(&TG1, &TFr, &TFr, &TG1, &TKZGSettings) -> Result<bool, Error>,
 ) {
     set_current_dir(env!("CARGO_MANIFEST_DIR")).unwrap();
-    let ts = load_trusted_setup(TRUSTED_SETUP_PATH);
+    let ts = load_trusted_setup(TRUSTED_SETUP_PATH).unwrap();
     let test_files: Vec<PathBuf> = glob::glob(VERIFY_KZG_PROOF_TESTS)
         .unwrap()
         .map(Result::unwrap)
@@ -746,6 +1512,59 @@ pub fn test_vectors_verify_kzg_proof<
     }
 }
 
+/// Runs the point evaluation precompile on the `verify_kzg_proof` vectors, with the versioned
+/// hash derived from each commitment.
+#[cfg(not(feature = "minimal-spec"))]
+#[allow(clippy::type_complexity)]
+pub fn test_vectors_point_evaluation_precompile<
+    TFr: Fr,
+    TG1: G1,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    point_evaluation_precompile: &dyn Fn(
+        &[u8],
+        &TKZGSettings,
+    ) -> Result<
+        [u8; POINT_EVALUATION_OUTPUT_LENGTH],
+        PointEvaluationError,
+    >,
+) {
+    set_current_dir(env!("CARGO_MANIFEST_DIR")).unwrap();
+    let ts = load_trusted_setup(TRUSTED_SETUP_PATH).unwrap();
+    let test_files: Vec<PathBuf> = glob::glob(VERIFY_KZG_PROOF_TESTS)
+        .unwrap()
+        .map(Result::unwrap)
+        .collect();
+    assert!(!test_files.is_empty());
+
+    for test_file in test_files {
+        let yaml_data = fs::read_to_string(test_file).unwrap();
+        let test: verify_kzg_proof::Test = serde_yaml::from_str(&yaml_data).unwrap();
+
+        let commitment_bytes = test.input.get_commitment_bytes();
+        let mut input = match commitment_bytes.as_slice().try_into() {
+            Ok(commitment) => kzg_to_versioned_hash(commitment).to_vec(),
+            Err(_) => vec![0; 32],
+        };
+        // The vectors encode field elements little-endian, the precompile big-endian
+        input.extend(test.input.get_z_bytes().iter().rev());
+        input.extend(test.input.get_y_bytes().iter().rev());
+        input.extend(commitment_bytes);
+        input.extend(test.input.get_proof_bytes());
+
+        match (point_evaluation_precompile(&input, &ts), test.get_output()) {
+            (Ok(output), Some(true)) => assert_eq!(output[32..], BLS_MODULUS_BYTES_BE),
+            (Err(PointEvaluationError::VerificationFailed), Some(false)) => {}
+            (Err(err), None) => assert_ne!(err, PointEvaluationError::VerificationFailed),
+            (result, expected) => panic!("Unexpected result {:?}, expected {:?}", result, expected),
+        }
+    }
+}
+
 #[cfg(not(feature = "minimal-spec"))]
 pub fn test_vectors_verify_blob_kzg_proof<
     TFr: Fr,
@@ -755,12 +1574,12 @@ pub fn test_vectors_verify_blob_kzg_proof<
     TFFTSettings: FFTSettings<TFr>,
     TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
 >(
-    load_trusted_setup: &dyn Fn(&str) -> TKZGSettings,
-    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, String>,
-    verify_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TG1, &TKZGSettings) -> Result<bool, String>,
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
+    verify_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TG1, &TKZGSettings) -> Result<bool, Error>,
 ) {
     set_current_dir(env!("CARGO_MANIFEST_DIR")).unwrap();
-    let ts = load_trusted_setup(TRUSTED_SETUP_PATH);
+    let ts = load_trusted_setup(TRUSTED_SETUP_PATH).unwrap();
     let test_files: Vec<PathBuf> = glob::glob(VERIFY_BLOB_KZG_PROOF_TESTS)
         .unwrap()
         .map(Result::unwrap)
@@ -814,17 +1633,17 @@ pub fn test_vectors_verify_blob_kzg_proof_batch<
     TFFTSettings: FFTSettings<TFr>,
     TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
 >(
-    load_trusted_setup: &dyn Fn(&str) -> TKZGSettings,
-    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, String>,
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
     verify_blob_kzg_proof_batch: &dyn Fn(
         &[Vec<TFr>],
         &[TG1],
         &[TG1],
         &TKZGSettings,
-    ) -> Result<bool, String>,
+    ) -> Result<bool, Error>,
 ) {
     set_current_dir(env!("CARGO_MANIFEST_DIR")).unwrap();
-    let ts = load_trusted_setup(TRUSTED_SETUP_PATH);
+    let ts = load_trusted_setup(TRUSTED_SETUP_PATH).unwrap();
     let test_files: Vec<PathBuf> = glob::glob(VERIFY_BLOB_KZG_PROOF_BATCH_TESTS)
         .unwrap()
         .map(Result::unwrap)
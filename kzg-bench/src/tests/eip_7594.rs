@@ -0,0 +1,611 @@
+#![allow(unused)]
+
+use crate::test_vectors::{
+    compute_cells_and_kzg_proofs, recover_cells_and_kzg_proofs, verify_cell_kzg_proof_batch,
+};
+use crate::tests::eip_4844::generate_random_blob_bytes;
+use kzg::eip_4844::{
+    hash, hash_to_bls_field, load_trusted_setup_string, BYTES_PER_G1, TRUSTED_SETUP_PATH,
+};
+use kzg::eip_7594::{
+    compute_verify_cell_kzg_proof_batch_challenge, CELLS_PER_EXT_BLOB, FIELD_ELEMENTS_PER_CELL,
+};
+use kzg::{Error, FFTFr, FFTSettings, Fr, G1LinComb, KZGSettings, Poly, G1, G2};
+use rand::seq::SliceRandom;
+use rand::thread_rng;
+use std::env::set_current_dir;
+use std::fs;
+use std::path::PathBuf;
+
+/// A compressed point that is on the curve but not in the G1 subgroup.
+pub const NOT_IN_G1: [u8; BYTES_PER_G1] = [
+    0x81, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef,
+    0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef,
+    0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef,
+];
+
+#[allow(clippy::type_complexity)]
+pub fn compute_cells_and_kzg_proofs_test<
+    TFr: Fr,
+    TG1: G1,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
+    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> TG1,
+    compute_cells_and_kzg_proofs: &dyn Fn(
+        &[TFr],
+        &TKZGSettings,
+    ) -> Result<
+        (Vec<[TFr; FIELD_ELEMENTS_PER_CELL]>, Vec<TG1>),
+        Error,
+    >,
+    verify_cell_kzg_proof_batch: &dyn Fn(
+        &[TG1],
+        &[usize],
+        &[[TFr; FIELD_ELEMENTS_PER_CELL]],
+        &[TG1],
+        &TKZGSettings,
+    ) -> Result<bool, Error>,
+) {
+    set_current_dir(env!("CARGO_MANIFEST_DIR")).unwrap();
+    let ts = load_trusted_setup(TRUSTED_SETUP_PATH).unwrap();
+    let mut rng = thread_rng();
+
+    let blob = bytes_to_blob(&generate_random_blob_bytes(&mut rng)).unwrap();
+    let commitment = blob_to_kzg_commitment(&blob, &ts);
+    let (cells, proofs) = compute_cells_and_kzg_proofs(&blob, &ts).unwrap();
+    assert_eq!(cells.len(), CELLS_PER_EXT_BLOB);
+    assert_eq!(proofs.len(), CELLS_PER_EXT_BLOB);
+
+    // In bit-reversed order, the first half of the extended blob is the blob itself
+    for (cell_element, blob_element) in cells.iter().flatten().zip(&blob) {
+        assert!(cell_element.equals(blob_element));
+    }
+
+    // Every cell verifies on its own
+    for (i, (cell, proof)) in cells.iter().zip(&proofs).enumerate() {
+        assert!(verify_cell_kzg_proof_batch(
+            core::slice::from_ref(&commitment),
+            &[i],
+            core::slice::from_ref(cell),
+            core::slice::from_ref(proof),
+            &ts
+        )
+        .unwrap());
+    }
+
+    // And all of them in one batch
+    let commitments = vec![commitment; CELLS_PER_EXT_BLOB];
+    let indices = (0..CELLS_PER_EXT_BLOB).collect::<Vec<_>>();
+    assert!(verify_cell_kzg_proof_batch(&commitments, &indices, &cells, &proofs, &ts).unwrap());
+}
+
+#[allow(clippy::type_complexity)]
+pub fn verify_cell_kzg_proof_batch_test<
+    TFr: Fr,
+    TG1: G1,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
+    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> TG1,
+    compute_cells_and_kzg_proofs: &dyn Fn(
+        &[TFr],
+        &TKZGSettings,
+    ) -> Result<
+        (Vec<[TFr; FIELD_ELEMENTS_PER_CELL]>, Vec<TG1>),
+        Error,
+    >,
+    verify_cell_kzg_proof_batch: &dyn Fn(
+        &[TG1],
+        &[usize],
+        &[[TFr; FIELD_ELEMENTS_PER_CELL]],
+        &[TG1],
+        &TKZGSettings,
+    ) -> Result<bool, Error>,
+) {
+    set_current_dir(env!("CARGO_MANIFEST_DIR")).unwrap();
+    let ts = load_trusted_setup(TRUSTED_SETUP_PATH).unwrap();
+    let mut rng = thread_rng();
+
+    // Pick a few cells of each of three blobs, some of them more than once
+    let mut commitments = Vec::new();
+    let mut indices = Vec::new();
+    let mut cells = Vec::new();
+    let mut proofs = Vec::new();
+    for _ in 0..3 {
+        let blob = bytes_to_blob(&generate_random_blob_bytes(&mut rng)).unwrap();
+        let commitment = blob_to_kzg_commitment(&blob, &ts);
+        let (blob_cells, blob_proofs) = compute_cells_and_kzg_proofs(&blob, &ts).unwrap();
+        for index in [0, 5, 5, CELLS_PER_EXT_BLOB - 1] {
+            commitments.push(commitment.clone());
+            indices.push(index);
+            cells.push(blob_cells[index].clone());
+            proofs.push(blob_proofs[index].clone());
+        }
+    }
+    assert!(verify_cell_kzg_proof_batch(&commitments, &indices, &cells, &proofs, &ts).unwrap());
+
+    // An empty batch is trivially valid
+    assert!(verify_cell_kzg_proof_batch(&[], &[], &[], &[], &ts).unwrap());
+
+    // Changing any single input makes the batch fail
+    let mut wrong_cells = cells.clone();
+    wrong_cells[3][7] = wrong_cells[3][7].add(&TFr::one());
+    assert!(
+        !verify_cell_kzg_proof_batch(&commitments, &indices, &wrong_cells, &proofs, &ts).unwrap()
+    );
+
+    let mut wrong_proofs = proofs.clone();
+    wrong_proofs[4] = wrong_proofs[4].add_or_dbl(&TG1::generator());
+    assert!(
+        !verify_cell_kzg_proof_batch(&commitments, &indices, &cells, &wrong_proofs, &ts).unwrap()
+    );
+
+    let mut wrong_indices = indices.clone();
+    wrong_indices[0] = 1;
+    assert!(
+        !verify_cell_kzg_proof_batch(&commitments, &wrong_indices, &cells, &proofs, &ts).unwrap()
+    );
+
+    let mut wrong_commitments = commitments.clone();
+    wrong_commitments.swap(0, 4);
+    assert!(
+        !verify_cell_kzg_proof_batch(&wrong_commitments, &indices, &cells, &proofs, &ts).unwrap()
+    );
+
+    // Malformed batches are errors rather than invalid proofs
+    let mut out_of_range = indices.clone();
+    out_of_range[0] = CELLS_PER_EXT_BLOB;
+    assert!(
+        verify_cell_kzg_proof_batch(&commitments, &out_of_range, &cells, &proofs, &ts).is_err()
+    );
+    assert!(
+        verify_cell_kzg_proof_batch(&commitments[1..], &indices, &cells, &proofs, &ts).is_err()
+    );
+
+    // Points on the curve but outside the G1 subgroup are rejected before the pairing check
+    let outside_subgroup = TG1::from_bytes(&NOT_IN_G1).unwrap();
+    assert!(!outside_subgroup.is_valid());
+    let mut bad_commitments = commitments.clone();
+    bad_commitments[2] = outside_subgroup.clone();
+    assert!(verify_cell_kzg_proof_batch(&bad_commitments, &indices, &cells, &proofs, &ts).is_err());
+    let mut bad_proofs = proofs.clone();
+    bad_proofs[2] = outside_subgroup;
+    assert!(verify_cell_kzg_proof_batch(&commitments, &indices, &cells, &bad_proofs, &ts).is_err());
+}
+
+pub fn verify_cell_kzg_proof_batch_challenge_test<TFr: Fr, TG1: G1>() {
+    let commitment = TG1::generator();
+    let proof = TG1::generator().add_or_dbl(&TG1::generator());
+    let cell: [TFr; FIELD_ELEMENTS_PER_CELL] =
+        core::array::from_fn(|i| TFr::from_u64(i as u64 + 1));
+
+    // The hash input of the specification, with its integers as 8-byte big-endian values
+    let mut expected_input = b"RCKZGCBATCH__V1_".to_vec();
+    expected_input.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0x10, 0]); // FIELD_ELEMENTS_PER_BLOB
+    expected_input.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 64]); // FIELD_ELEMENTS_PER_CELL
+    expected_input.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 1]); // number of commitments
+    expected_input.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 2]); // number of cells
+    expected_input.extend_from_slice(&commitment.to_bytes());
+    for cell_index in [3, 0x0102] {
+        expected_input.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 0]); // commitment index
+        expected_input.extend_from_slice(&u64::to_be_bytes(cell_index));
+        for element in &cell {
+            expected_input.extend_from_slice(&element.to_bytes());
+        }
+        expected_input.extend_from_slice(&proof.to_bytes());
+    }
+    let expected: TFr = hash_to_bls_field(&hash(&expected_input));
+
+    let challenge = compute_verify_cell_kzg_proof_batch_challenge(
+        4096,
+        &[commitment],
+        &[0, 0],
+        &[3, 0x0102],
+        &[cell.clone(), cell],
+        &[proof.clone(), proof],
+    );
+    assert!(challenge.equals(&expected));
+}
+
+#[allow(clippy::type_complexity)]
+pub fn recover_cells_and_kzg_proofs_test<
+    TFr: Fr,
+    TG1: G1,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
+    compute_cells_and_kzg_proofs: &dyn Fn(
+        &[TFr],
+        &TKZGSettings,
+    ) -> Result<
+        (Vec<[TFr; FIELD_ELEMENTS_PER_CELL]>, Vec<TG1>),
+        Error,
+    >,
+    recover_cells_and_kzg_proofs: &dyn Fn(
+        &[usize],
+        &[[TFr; FIELD_ELEMENTS_PER_CELL]],
+        &TKZGSettings,
+    ) -> Result<
+        (Vec<[TFr; FIELD_ELEMENTS_PER_CELL]>, Vec<TG1>),
+        Error,
+    >,
+) {
+    set_current_dir(env!("CARGO_MANIFEST_DIR")).unwrap();
+    let ts = load_trusted_setup(TRUSTED_SETUP_PATH).unwrap();
+    let mut rng = thread_rng();
+
+    let blob = bytes_to_blob(&generate_random_blob_bytes(&mut rng)).unwrap();
+    let (cells, proofs) = compute_cells_and_kzg_proofs(&blob, &ts).unwrap();
+
+    let assert_recovers = |indices: &[usize]| {
+        let partial_cells = indices
+            .iter()
+            .map(|&i| cells[i].clone())
+            .collect::<Vec<_>>();
+        let (recovered_cells, recovered_proofs) =
+            recover_cells_and_kzg_proofs(indices, &partial_cells, &ts).unwrap();
+        assert_eq!(recovered_cells.len(), CELLS_PER_EXT_BLOB);
+        for (recovered, cell) in recovered_cells.iter().zip(&cells) {
+            assert!(recovered.iter().zip(cell).all(|(a, b)| a.equals(b)));
+        }
+        for (recovered, proof) in recovered_proofs.iter().zip(&proofs) {
+            assert!(recovered.equals(proof));
+        }
+    };
+
+    // From a random half of the cells, in random order
+    let mut indices = (0..CELLS_PER_EXT_BLOB).collect::<Vec<_>>();
+    indices.shuffle(&mut rng);
+    assert_recovers(&indices[..CELLS_PER_EXT_BLOB / 2]);
+
+    // From every cell, and from the second half only, which holds none of the original blob
+    assert_recovers(&indices);
+    assert_recovers(&(CELLS_PER_EXT_BLOB / 2..CELLS_PER_EXT_BLOB).collect::<Vec<_>>());
+
+    // Too few cells, a repeated cell or an index out of range cannot be recovered from
+    let too_few = &indices[..CELLS_PER_EXT_BLOB / 2 - 1];
+    let too_few_cells = too_few
+        .iter()
+        .map(|&i| cells[i].clone())
+        .collect::<Vec<_>>();
+    assert!(recover_cells_and_kzg_proofs(too_few, &too_few_cells, &ts).is_err());
+
+    let mut repeated = indices[..CELLS_PER_EXT_BLOB / 2].to_vec();
+    repeated[1] = repeated[0];
+    let repeated_cells = repeated
+        .iter()
+        .map(|&i| cells[i].clone())
+        .collect::<Vec<_>>();
+    assert!(recover_cells_and_kzg_proofs(&repeated, &repeated_cells, &ts).is_err());
+
+    let mut out_of_range = indices[..CELLS_PER_EXT_BLOB / 2].to_vec();
+    let out_of_range_cells = out_of_range
+        .iter()
+        .map(|&i| cells[i].clone())
+        .collect::<Vec<_>>();
+    out_of_range[0] = CELLS_PER_EXT_BLOB;
+    assert!(recover_cells_and_kzg_proofs(&out_of_range, &out_of_range_cells, &ts).is_err());
+}
+
+/// Reorders `values`, whose length is a power of two, by bit-reversed index as in the consensus
+/// specs.
+fn bit_reversal_permutation<T: Clone>(values: &[T]) -> Vec<T> {
+    let bits = values.len().trailing_zeros();
+    (0..values.len())
+        .map(|i| values[i.reverse_bits() >> (usize::BITS - bits)].clone())
+        .collect()
+}
+
+/// Computes the cells and proofs of a blob the way the consensus specs define them, without FK20
+/// or an extension FFT. Every cell evaluates the blob polynomial on its coset point by point, and
+/// every proof commits to the quotient of the polynomial by the vanishing polynomial of the coset.
+/// It is slow, but shares none of the optimised code paths it is used to check.
+pub fn spec_compute_cells_and_kzg_proofs<
+    TFr: Fr,
+    TG1: G1 + G1LinComb<TFr>,
+    TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>,
+>(
+    blob: &[TFr],
+    g1_monomial: &[TG1],
+) -> (Vec<[TFr; FIELD_ELEMENTS_PER_CELL]>, Vec<TG1>) {
+    let n = blob.len();
+    let fs = TFFTSettings::new(n.trailing_zeros() as usize).unwrap();
+    let fs_ext = TFFTSettings::new((2 * n).trailing_zeros() as usize).unwrap();
+
+    // polynomial_eval_to_coeff
+    let coeffs = fs.fft_fr(&bit_reversal_permutation(blob), true).unwrap();
+    // coset_for_cell takes consecutive chunks of the bit-reversed extended domain
+    let domain = bit_reversal_permutation(&fs_ext.get_expanded_roots_of_unity()[..2 * n]);
+
+    let evaluate = |z: &TFr| {
+        coeffs
+            .iter()
+            .rev()
+            .fold(TFr::zero(), |acc, coeff| acc.mul(z).add(coeff))
+    };
+
+    let mut cells = Vec::with_capacity(CELLS_PER_EXT_BLOB);
+    let mut proofs = Vec::with_capacity(CELLS_PER_EXT_BLOB);
+    for coset in domain.chunks(FIELD_ELEMENTS_PER_CELL) {
+        cells.push(core::array::from_fn(|i| evaluate(&coset[i])));
+
+        // The product of (X - z) over the coset
+        let mut vanishing = vec![TFr::one()];
+        for z in coset {
+            let mut next = vec![TFr::zero(); vanishing.len() + 1];
+            for (i, coeff) in vanishing.iter().enumerate() {
+                next[i + 1] = next[i + 1].add(coeff);
+                next[i] = next[i].sub(&coeff.mul(z));
+            }
+            vanishing = next;
+        }
+
+        // The remainder of the long division is the interpolation polynomial of the cell, so
+        // dividing the polynomial itself gives the quotient the specs commit to
+        let degree = vanishing.len() - 1;
+        let mut remainder = coeffs.clone();
+        let mut quotient = vec![TFr::zero(); n - degree];
+        for k in (0..n - degree).rev() {
+            let factor = remainder[k + degree].clone();
+            for (j, coeff) in vanishing.iter().enumerate() {
+                remainder[k + j] = remainder[k + j].sub(&factor.mul(coeff));
+            }
+            quotient[k] = factor;
+        }
+        proofs.push(TG1::g1_lincomb(g1_monomial, &quotient, quotient.len()));
+    }
+
+    (cells, proofs)
+}
+
+/// Compares the cells and proofs of a random blob with [`spec_compute_cells_and_kzg_proofs`].
+#[allow(clippy::type_complexity)]
+pub fn compute_cells_and_kzg_proofs_matches_spec_test<
+    TFr: Fr,
+    TG1: G1 + G1LinComb<TFr>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
+    compute_cells_and_kzg_proofs: &dyn Fn(
+        &[TFr],
+        &TKZGSettings,
+    ) -> Result<
+        (Vec<[TFr; FIELD_ELEMENTS_PER_CELL]>, Vec<TG1>),
+        Error,
+    >,
+) {
+    set_current_dir(env!("CARGO_MANIFEST_DIR")).unwrap();
+    let ts = load_trusted_setup(TRUSTED_SETUP_PATH).unwrap();
+    let mut rng = thread_rng();
+
+    // The setup file holds the monomial points, so the reference does not depend on the settings
+    let contents = fs::read_to_string(TRUSTED_SETUP_PATH).unwrap();
+    let (g1_bytes, _) = load_trusted_setup_string(&contents).unwrap();
+    let g1_monomial = g1_bytes
+        .chunks(BYTES_PER_G1)
+        .map(|chunk| TG1::from_bytes(chunk).unwrap())
+        .collect::<Vec<TG1>>();
+
+    let blob = bytes_to_blob(&generate_random_blob_bytes(&mut rng)).unwrap();
+    let (cells, proofs) = compute_cells_and_kzg_proofs(&blob, &ts).unwrap();
+    let (expected_cells, expected_proofs) =
+        spec_compute_cells_and_kzg_proofs::<TFr, TG1, TFFTSettings>(&blob, &g1_monomial);
+
+    assert_eq!(cells.len(), expected_cells.len());
+    for (cell, expected) in cells.iter().zip(&expected_cells) {
+        assert!(cell.iter().zip(expected).all(|(a, b)| a.equals(b)));
+    }
+    assert_eq!(proofs.len(), expected_proofs.len());
+    for (proof, expected) in proofs.iter().zip(&expected_proofs) {
+        assert!(proof.equals(expected));
+    }
+}
+
+// The consensus-specs vectors of each function go in its directory, one case per directory,
+// e.g. `compute_cells_and_kzg_proofs/kzg-mainnet/<case>/data.yaml`.
+const COMPUTE_CELLS_AND_KZG_PROOFS_TESTS: &str =
+    "src/test_vectors/compute_cells_and_kzg_proofs/*/*/*";
+const VERIFY_CELL_KZG_PROOF_BATCH_TESTS: &str =
+    "src/test_vectors/verify_cell_kzg_proof_batch/*/*/*";
+const RECOVER_CELLS_AND_KZG_PROOFS_TESTS: &str =
+    "src/test_vectors/recover_cells_and_kzg_proofs/*/*/*";
+
+#[allow(clippy::type_complexity)]
+fn cells_from_bytes<TFr: Fr>(
+    cells_bytes: &[Vec<u8>],
+    bytes_to_cell: &dyn Fn(&[u8]) -> Result<[TFr; FIELD_ELEMENTS_PER_CELL], Error>,
+) -> Option<Vec<[TFr; FIELD_ELEMENTS_PER_CELL]>> {
+    cells_bytes
+        .iter()
+        .map(|bytes| bytes_to_cell(bytes).ok())
+        .collect()
+}
+
+fn points_from_bytes<TG1: G1>(points_bytes: &[Vec<u8>]) -> Option<Vec<TG1>> {
+    points_bytes
+        .iter()
+        .map(|bytes| TG1::from_bytes(bytes).ok())
+        .collect()
+}
+
+fn assert_cells_and_proofs_eq<TFr: Fr, TG1: G1>(
+    (cells, proofs): (Vec<[TFr; FIELD_ELEMENTS_PER_CELL]>, Vec<TG1>),
+    (expected_cells, expected_proofs): (Vec<Vec<u8>>, Vec<Vec<u8>>),
+) {
+    let cells_bytes = cells
+        .iter()
+        .map(|cell| cell.iter().flat_map(|element| element.to_bytes()).collect())
+        .collect::<Vec<Vec<u8>>>();
+    let proofs_bytes = proofs
+        .iter()
+        .map(|proof| proof.to_bytes().to_vec())
+        .collect::<Vec<Vec<u8>>>();
+    assert_eq!(cells_bytes, expected_cells);
+    assert_eq!(proofs_bytes, expected_proofs);
+}
+
+#[allow(clippy::type_complexity)]
+pub fn test_vectors_compute_cells_and_kzg_proofs<
+    TFr: Fr,
+    TG1: G1,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
+    compute_cells_and_kzg_proofs: &dyn Fn(
+        &[TFr],
+        &TKZGSettings,
+    ) -> Result<
+        (Vec<[TFr; FIELD_ELEMENTS_PER_CELL]>, Vec<TG1>),
+        Error,
+    >,
+) {
+    set_current_dir(env!("CARGO_MANIFEST_DIR")).unwrap();
+    let ts = load_trusted_setup(TRUSTED_SETUP_PATH).unwrap();
+    let test_files: Vec<PathBuf> = glob::glob(COMPUTE_CELLS_AND_KZG_PROOFS_TESTS)
+        .unwrap()
+        .map(Result::unwrap)
+        .collect();
+    assert!(!test_files.is_empty());
+
+    for test_file in test_files {
+        let yaml_data = fs::read_to_string(test_file).unwrap();
+        let test: compute_cells_and_kzg_proofs::Test = serde_yaml::from_str(&yaml_data).unwrap();
+
+        let blob = match bytes_to_blob(&test.input.get_blob_bytes()) {
+            Ok(blob) => blob,
+            Err(_) => {
+                assert!(test.get_output().is_none());
+                continue;
+            }
+        };
+
+        match compute_cells_and_kzg_proofs(&blob, &ts) {
+            Ok(result) => assert_cells_and_proofs_eq(result, test.get_output().unwrap()),
+            Err(_) => assert!(test.get_output().is_none()),
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn test_vectors_verify_cell_kzg_proof_batch<
+    TFr: Fr,
+    TG1: G1,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    bytes_to_cell: &dyn Fn(&[u8]) -> Result<[TFr; FIELD_ELEMENTS_PER_CELL], Error>,
+    verify_cell_kzg_proof_batch: &dyn Fn(
+        &[TG1],
+        &[usize],
+        &[[TFr; FIELD_ELEMENTS_PER_CELL]],
+        &[TG1],
+        &TKZGSettings,
+    ) -> Result<bool, Error>,
+) {
+    set_current_dir(env!("CARGO_MANIFEST_DIR")).unwrap();
+    let ts = load_trusted_setup(TRUSTED_SETUP_PATH).unwrap();
+    let test_files: Vec<PathBuf> = glob::glob(VERIFY_CELL_KZG_PROOF_BATCH_TESTS)
+        .unwrap()
+        .map(Result::unwrap)
+        .collect();
+    assert!(!test_files.is_empty());
+
+    for test_file in test_files {
+        let yaml_data = fs::read_to_string(test_file).unwrap();
+        let test: verify_cell_kzg_proof_batch::Test = serde_yaml::from_str(&yaml_data).unwrap();
+
+        let (commitments, cells, proofs) = match (
+            points_from_bytes::<TG1>(&test.input.get_commitments_bytes()),
+            cells_from_bytes(&test.input.get_cells_bytes(), bytes_to_cell),
+            points_from_bytes::<TG1>(&test.input.get_proofs_bytes()),
+        ) {
+            (Some(commitments), Some(cells), Some(proofs)) => (commitments, cells, proofs),
+            _ => {
+                assert!(test.get_output().is_none());
+                continue;
+            }
+        };
+
+        match verify_cell_kzg_proof_batch(
+            &commitments,
+            &test.input.get_cell_indices(),
+            &cells,
+            &proofs,
+            &ts,
+        ) {
+            Ok(result) => assert_eq!(result, test.get_output().unwrap()),
+            Err(_) => assert!(test.get_output().is_none()),
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn test_vectors_recover_cells_and_kzg_proofs<
+    TFr: Fr,
+    TG1: G1,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    bytes_to_cell: &dyn Fn(&[u8]) -> Result<[TFr; FIELD_ELEMENTS_PER_CELL], Error>,
+    recover_cells_and_kzg_proofs: &dyn Fn(
+        &[usize],
+        &[[TFr; FIELD_ELEMENTS_PER_CELL]],
+        &TKZGSettings,
+    ) -> Result<
+        (Vec<[TFr; FIELD_ELEMENTS_PER_CELL]>, Vec<TG1>),
+        Error,
+    >,
+) {
+    set_current_dir(env!("CARGO_MANIFEST_DIR")).unwrap();
+    let ts = load_trusted_setup(TRUSTED_SETUP_PATH).unwrap();
+    let test_files: Vec<PathBuf> = glob::glob(RECOVER_CELLS_AND_KZG_PROOFS_TESTS)
+        .unwrap()
+        .map(Result::unwrap)
+        .collect();
+    assert!(!test_files.is_empty());
+
+    for test_file in test_files {
+        let yaml_data = fs::read_to_string(test_file).unwrap();
+        let test: recover_cells_and_kzg_proofs::Test = serde_yaml::from_str(&yaml_data).unwrap();
+
+        let cells = match cells_from_bytes(&test.input.get_cells_bytes(), bytes_to_cell) {
+            Some(cells) => cells,
+            None => {
+                assert!(test.get_output().is_none());
+                continue;
+            }
+        };
+
+        match recover_cells_and_kzg_proofs(&test.input.get_cell_indices(), &cells, &ts) {
+            Ok(result) => assert_cells_and_proofs_eq(result, test.get_output().unwrap()),
+            Err(_) => assert!(test.get_output().is_none()),
+        }
+    }
+}
@@ -116,3 +116,36 @@ pub fn stride_fft<TFr: Fr, TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>>() {
         assert!(r1.equals(&result2[i]));
     }
 }
+
+/// Check the coset FFT against direct evaluation over the coset, and its inverse
+pub fn coset_fft<TFr: Fr, TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>>() {
+    let fft_settings = TFFTSettings::new(6).unwrap();
+    let width: usize = 16;
+    let stride = fft_settings.get_max_width() / width;
+    let shift = TFr::from_u64(7);
+
+    let coeffs = (0..width)
+        .map(|i| TFr::from_u64((i * i + 3) as u64))
+        .collect::<Vec<_>>();
+    let evaluations = fft_settings.coset_fft_fr(&coeffs, &shift).unwrap();
+    assert_eq!(evaluations.len(), width);
+
+    for (i, evaluation) in evaluations.iter().enumerate() {
+        let x = shift.mul(&fft_settings.get_expanded_roots_of_unity_at(i * stride));
+        let expected = coeffs
+            .iter()
+            .rev()
+            .fold(TFr::zero(), |acc, coeff| acc.mul(&x).add(coeff));
+        assert!(evaluation.equals(&expected));
+    }
+
+    let inverse_result = fft_settings.coset_ifft_fr(&evaluations, &shift).unwrap();
+    for (coeff, result) in coeffs.iter().zip(&inverse_result) {
+        assert!(coeff.equals(result));
+    }
+
+    assert!(fft_settings.coset_fft_fr(&coeffs, &TFr::zero()).is_err());
+    assert!(fft_settings
+        .coset_ifft_fr(&evaluations, &TFr::zero())
+        .is_err());
+}
@@ -1,4 +1,4 @@
-use kzg::{FFTFr, FFTSettings, Fr, FFTG1, G1};
+use kzg::{FFTFr, FFTSettings, Fr, G1Mul, FFTG1, G1};
 
 #[allow(clippy::type_complexity)]
 pub fn compare_ft_fft<TFr: Fr, TG1: G1, TFFTSettings: FFTSettings<TFr> + FFTG1<TG1>>(
@@ -120,3 +120,37 @@ pub fn compare_sft_fft<TFr: Fr, TG1: G1, TFFTSettings: FFTSettings<TFr> + FFTFr<
         assert!(slow[i].equals(&fast[i]));
     }
 }
+
+/// Check that the coset FFT of multiples of the generator matches the coset FFT of the scalars
+pub fn coset_fft<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr>,
+    TFFTSettings: FFTSettings<TFr> + FFTFr<TFr> + FFTG1<TG1>,
+>() {
+    let fs = TFFTSettings::new(5).unwrap();
+    let width: usize = 16;
+    let shift = TFr::from_u64(7);
+
+    let scalars = (0..width)
+        .map(|i| TFr::from_u64((i * i + 3) as u64))
+        .collect::<Vec<_>>();
+    let data = scalars
+        .iter()
+        .map(|scalar| TG1::generator().mul(scalar))
+        .collect::<Vec<_>>();
+
+    let expected = fs.coset_fft_fr(&scalars, &shift).unwrap();
+    let result = fs.coset_fft_g1(&data, &shift).unwrap();
+    assert_eq!(result.len(), width);
+    for (point, scalar) in result.iter().zip(&expected) {
+        assert!(point.equals(&TG1::generator().mul(scalar)));
+    }
+
+    let inverse_result = fs.coset_ifft_g1(&result, &shift).unwrap();
+    for (point, expected) in inverse_result.iter().zip(&data) {
+        assert!(point.equals(expected));
+    }
+
+    assert!(fs.coset_fft_g1(&data, &TFr::zero()).is_err());
+    assert!(fs.coset_ifft_g1(&result, &TFr::zero()).is_err());
+}
@@ -67,7 +67,7 @@ pub fn fk_single<
     // Initialise the secrets and data structures
     let (s1, s2) = generate_trusted_setup(secrets_len, SECRET);
     let fs = TFFTSettings::new(n).unwrap();
-    let ks = TKZGSettings::new(&s1, &s2, secrets_len, &fs).unwrap();
+    let ks = TKZGSettings::new(&s1, &s2, secrets_len, secrets_len, &fs).unwrap();
     let fk = TFK20SingleSettings::new(&ks, 2 * poly_len).unwrap();
 
     // Commit to the polynomial
@@ -126,7 +126,7 @@ pub fn fk_single_strided<
     // Initialise the secrets and data structures
     let (s1, s2) = generate_trusted_setup(secrets_len, SECRET);
     let fs = TFFTSettings::new(n).unwrap();
-    let ks = TKZGSettings::new(&s1, &s2, secrets_len, &fs).unwrap();
+    let ks = TKZGSettings::new(&s1, &s2, secrets_len, secrets_len, &fs).unwrap();
     let fk = TFK20SingleSettings::new(&ks, 2 * poly_len).unwrap();
 
     // Commit to the polynomial
@@ -161,7 +161,7 @@ pub fn fk_multi_settings<
     // Initialise the secrets and data structures
     let (s1, s2) = generate_trusted_setup(secrets_len, SECRET);
     let fs = TFFTSettings::new(n).unwrap();
-    let ks = TKZGSettings::new(&s1, &s2, secrets_len, &fs).unwrap();
+    let ks = TKZGSettings::new(&s1, &s2, secrets_len, secrets_len, &fs).unwrap();
     let _fk = TFK20MultiSettings::new(&ks, 32, 4).unwrap();
 }
 
@@ -192,7 +192,7 @@ fn fk_multi_case<
     // Initialise the secrets and data structures
     let (s1, s2) = generate_trusted_setup(secrets_len, SECRET);
     let fs = TFFTSettings::new(width).unwrap();
-    let ks = TKZGSettings::new(&s1, &s2, secrets_len, &fs).unwrap();
+    let ks = TKZGSettings::new(&s1, &s2, secrets_len, secrets_len, &fs).unwrap();
     let fk = TFK20MultiSettings::new(&ks, n * 2, chunk_len).unwrap();
 
     // Create a test polynomial of size n that's independent of chunk_len
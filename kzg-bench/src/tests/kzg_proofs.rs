@@ -1,4 +1,5 @@
-use kzg::{FFTSettings, Fr, KZGSettings, Poly, G1, G2};
+use kzg::trusted_setup::SetupBasis;
+use kzg::{Error, FFTFr, FFTSettings, Fr, KZGSettings, Poly, PolyEval, G1, G2};
 
 pub const SECRET: [u8; 32usize] = [
     0xa4, 0x73, 0x31, 0x95, 0x28, 0xc8, 0xb6, 0xea, 0x4d, 0x08, 0xcc, 0x53, 0x18, 0x00, 0x00, 0x00,
@@ -30,7 +31,7 @@ pub fn proof_single<
     // Initialise the secrets and data structures
     let (s1, s2) = generate_trusted_setup(secrets_len, SECRET);
     let fs = TFFTSettings::new(4).unwrap();
-    let ks = TKZGSettings::new(&s1, &s2, secrets_len, &fs).unwrap();
+    let ks = TKZGSettings::new(&s1, &s2, secrets_len, secrets_len, &fs).unwrap();
 
     // Compute the proof for x = 25
     let x = TFr::from_u64(25);
@@ -66,7 +67,7 @@ pub fn commit_to_nil_poly<
         // Initialise the (arbitrary) secrets and data structures
         let (s1, s2) = generate_trusted_setup(secrets_len, SECRET);
         let fs = TFFTSettings::new(4).unwrap();
-        let ks = TKZGSettings::new(&s1, &s2, secrets_len, &fs).unwrap();
+        let ks = TKZGSettings::new(&s1, &s2, secrets_len, secrets_len, &fs).unwrap();
 
         let a = TPoly::new(0).unwrap();
         let result = ks.commit_to_poly(&a).unwrap();
@@ -93,7 +94,7 @@ pub fn commit_to_too_long_poly<
         // Initialise the (arbitrary) secrets and data structures
         let (s1, s2) = generate_trusted_setup(secrets_len, SECRET);
         let fs = TFFTSettings::new(4).unwrap();
-        let ks = TKZGSettings::new(&s1, &s2, secrets_len, &fs).unwrap();
+        let ks = TKZGSettings::new(&s1, &s2, secrets_len, secrets_len, &fs).unwrap();
 
         let a = TPoly::new(poly_len).unwrap();
         let _result = ks.commit_to_poly(&a);
@@ -117,7 +118,7 @@ pub fn commit_to_too_long_poly_returns_err<
     // Initialise the (arbitrary) secrets and data structures
     let (s1, s2) = generate_trusted_setup(secrets_len, SECRET);
     let fs = TFFTSettings::new(4).unwrap();
-    let ks = TKZGSettings::new(&s1, &s2, secrets_len, &fs).unwrap();
+    let ks = TKZGSettings::new(&s1, &s2, secrets_len, secrets_len, &fs).unwrap();
 
     let a = TPoly::new(poly_len).unwrap();
     let _result = ks.commit_to_poly(&a);
@@ -159,13 +160,13 @@ pub fn proof_multi<
     // Initialise the secrets and data structures
     let (s1, s2) = generate_trusted_setup(secrets_len, SECRET);
     let fs1 = TFFTSettings::new(4).unwrap();
-    let ks1 = TKZGSettings::new(&s1, &s2, secrets_len, &fs1).unwrap();
+    let ks1 = TKZGSettings::new(&s1, &s2, secrets_len, secrets_len, &fs1).unwrap();
 
     // Commit to the polynomial
     let commitment = ks1.commit_to_poly(&p).unwrap();
 
     let fs2 = TFFTSettings::new(coset_scale).unwrap();
-    let ks2 = TKZGSettings::new(&s1, &s2, secrets_len, &fs2).unwrap();
+    let ks2 = TKZGSettings::new(&s1, &s2, secrets_len, secrets_len, &fs2).unwrap();
 
     // Compute proof at the points [x * root_i] 0 <= i < coset_len
     let x = TFr::from_u64(5431);
@@ -191,3 +192,132 @@ pub fn proof_multi<
         .unwrap();
     assert!(!result);
 }
+
+/// Ceremonies publish far fewer G2 powers than G1 powers. Multi proofs work up to the G2 length.
+pub fn proof_multi_with_few_g2_points<
+    TFr: Fr,
+    TG1: G1,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+>(
+    generate_trusted_setup: &dyn Fn(usize, [u8; 32usize]) -> (Vec<TG1>, Vec<TG2>),
+) {
+    let coeffs = vec![1, 2, 3, 4, 7, 7, 7, 7, 13, 13, 13, 13, 13, 13, 13, 13];
+    let poly_len = coeffs.len();
+    let secrets_len = poly_len + 1;
+    let secrets_len_g2 = 9;
+
+    let mut p = TPoly::new(poly_len).unwrap();
+    for (x, &coeff) in coeffs.iter().enumerate() {
+        p.set_coeff_at(x, &TFr::from_u64(coeff));
+    }
+
+    let (s1, s2) = generate_trusted_setup(secrets_len, SECRET);
+    let x = TFr::from_u64(5431);
+
+    // 8 points need the G2 power s^8, which is the last one available
+    let fs = TFFTSettings::new(3).unwrap();
+    let ks = TKZGSettings::new(&s1, &s2, secrets_len, secrets_len_g2, &fs).unwrap();
+    let commitment = ks.commit_to_poly(&p).unwrap();
+    let proof = ks.compute_proof_multi(&p, &x, 8).unwrap();
+    let y = (0..8)
+        .map(|i| p.eval(&x.mul(&ks.get_expanded_roots_of_unity_at(i))))
+        .collect::<Vec<_>>();
+    assert!(ks
+        .check_proof_multi(&commitment, &proof, &x, &y, 8)
+        .unwrap());
+
+    // 16 points need s^16
+    let fs = TFFTSettings::new(4).unwrap();
+    let ks = TKZGSettings::new(&s1, &s2, secrets_len, secrets_len_g2, &fs).unwrap();
+    let proof = ks.compute_proof_multi(&p, &x, 16).unwrap();
+    let y = (0..16)
+        .map(|i| p.eval(&x.mul(&ks.get_expanded_roots_of_unity_at(i))))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        ks.check_proof_multi(&commitment, &proof, &x, &y, 16),
+        Err(Error::SetupTooSmall)
+    );
+
+    // More G2 points are requested than given
+    let s2 = &s2[..secrets_len_g2];
+    assert!(TKZGSettings::new(&s1, s2, secrets_len, secrets_len, &fs).is_err());
+}
+
+/// Check that settings convert between bases and reject polynomials in the other form
+pub fn setup_basis_conversion<
+    TFr: Fr,
+    TG1: G1,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+>(
+    generate_trusted_setup: &dyn Fn(usize, [u8; 32usize]) -> (Vec<TG1>, Vec<TG2>),
+) {
+    let poly_len = 16;
+    let secrets_len = poly_len + 1;
+
+    let mut p = TPoly::new(poly_len).unwrap();
+    for i in 0..poly_len {
+        p.set_coeff_at(i, &TFr::from_u64((3 * i + 1) as u64));
+    }
+
+    let (s1, s2) = generate_trusted_setup(secrets_len, SECRET);
+    let fs = TFFTSettings::new(4).unwrap();
+    let monomial = TKZGSettings::new(&s1, &s2, secrets_len, secrets_len, &fs).unwrap();
+    assert_eq!(monomial.get_basis(), SetupBasis::Monomial);
+    let commitment = monomial.commit_to_poly(&p).unwrap();
+
+    // The evaluations committed with the Lagrange setup give the same commitment
+    let evals = PolyEval::from_coeffs(&p, poly_len, &fs).unwrap();
+    let lagrange = monomial.to_lagrange().unwrap();
+    assert_eq!(lagrange.get_basis(), SetupBasis::Lagrange);
+    assert!(lagrange
+        .commit_to_poly_eval(&evals)
+        .unwrap()
+        .equals(&commitment));
+
+    let monomial_error = Error::SetupBasisMismatch {
+        expected: SetupBasis::Monomial,
+        actual: SetupBasis::Lagrange,
+    };
+    assert_eq!(
+        lagrange.commit_to_poly(&p).err(),
+        Some(monomial_error.clone())
+    );
+    assert_eq!(
+        lagrange.compute_proof_single(&p, &TFr::from_u64(25)).err(),
+        Some(monomial_error)
+    );
+    assert_eq!(
+        monomial.commit_to_poly_eval(&evals).err(),
+        Some(Error::SetupBasisMismatch {
+            expected: SetupBasis::Lagrange,
+            actual: SetupBasis::Monomial,
+        })
+    );
+
+    // Converting back recovers the monomial points covered by the FFT settings
+    let round_trip = lagrange.to_monomial().unwrap();
+    assert_eq!(round_trip.get_basis(), SetupBasis::Monomial);
+    assert_eq!(round_trip.get_g1_secret().len(), poly_len);
+    assert!(round_trip
+        .get_g1_secret()
+        .iter()
+        .zip(monomial.get_g1_secret())
+        .all(|(a, b)| a.equals(b)));
+    assert!(round_trip.commit_to_poly(&p).unwrap().equals(&commitment));
+
+    // Converting to the basis the settings are already in changes nothing
+    assert_eq!(
+        monomial.to_monomial().unwrap().get_basis(),
+        SetupBasis::Monomial
+    );
+    assert_eq!(
+        lagrange.to_lagrange().unwrap().get_basis(),
+        SetupBasis::Lagrange
+    );
+}
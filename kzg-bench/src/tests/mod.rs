@@ -1,7 +1,9 @@
 pub mod bls12_381;
 pub mod consts;
+pub mod danksharding;
 pub mod das;
 pub mod eip_4844;
+pub mod eip_7594;
 pub mod fft_fr;
 pub mod fft_g1;
 pub mod finite;
@@ -9,4 +11,6 @@ pub mod fk20_proofs;
 pub mod kzg_proofs;
 pub mod poly;
 pub mod recover;
+pub mod serialization;
+pub mod trusted_setup;
 pub mod zero_poly;
@@ -1,4 +1,4 @@
-use kzg::{FFTSettings, FFTSettingsPoly, Fr, Poly};
+use kzg::{FFTFr, FFTSettings, FFTSettingsPoly, Fr, Poly, PolyEval};
 use rand::rngs::StdRng;
 use rand::{RngCore, SeedableRng};
 
@@ -372,3 +372,199 @@ pub fn poly_div_fast_test<TFr: Fr, TPoly: Poly<TFr>>() {
         }
     }
 }
+
+fn poly_from_u64s<TFr: Fr, TPoly: Poly<TFr>>(coeffs: &[u64]) -> TPoly {
+    let mut poly = TPoly::new(coeffs.len()).unwrap();
+    for (i, coeff) in coeffs.iter().enumerate() {
+        poly.set_coeff_at(i, &TFr::from_u64(*coeff));
+    }
+    poly
+}
+
+fn random_poly<TFr: Fr, TPoly: Poly<TFr>>(len: usize) -> TPoly {
+    let mut poly = TPoly::new(len).unwrap();
+    for i in 0..len {
+        poly.set_coeff_at(i, &TFr::rand());
+    }
+    poly
+}
+
+fn assert_coeffs<TFr: Fr, TPoly: Poly<TFr>>(poly: &TPoly, expected: &[TFr]) {
+    assert_eq!(poly.len(), expected.len());
+    for (i, expected) in expected.iter().enumerate() {
+        assert!(poly.get_coeff_at(i).equals(expected));
+    }
+}
+
+pub fn poly_add_sub_test<TFr: Fr, TPoly: Poly<TFr>>() {
+    let a = poly_from_u64s::<TFr, TPoly>(&[1, 2, 3, 4, 5]);
+    let b = poly_from_u64s::<TFr, TPoly>(&[10, 20, 30]);
+
+    let sum = a.add(&b).unwrap();
+    assert_coeffs(&sum, &[11, 22, 33, 4, 5].map(TFr::from_u64));
+    assert_coeffs(&b.add(&a).unwrap(), sum.get_coeffs());
+
+    let difference = b.sub(&a).unwrap();
+    let expected = [9, 18, 27, 0, 0]
+        .map(TFr::from_u64)
+        .iter()
+        .zip([0, 0, 0, 4, 5].map(TFr::from_u64))
+        .map(|(x, y)| x.sub(&y))
+        .collect::<Vec<_>>();
+    assert_coeffs(&difference, &expected);
+    assert!(a.sub(&a).unwrap().get_coeffs().iter().all(Fr::is_zero));
+
+    let x = TFr::rand();
+    let c = TFr::from_u64(7);
+    let scaled = a.mul_scalar(&c).unwrap();
+    assert!(scaled.eval(&x).equals(&a.eval(&x).mul(&c)));
+}
+
+pub fn poly_derivative_test<TFr: Fr, TPoly: Poly<TFr>>() {
+    let poly = poly_from_u64s::<TFr, TPoly>(&[3, 2, 5, 7]);
+    assert_coeffs(&poly.derivative().unwrap(), &[2, 10, 21].map(TFr::from_u64));
+
+    let constant = poly_from_u64s::<TFr, TPoly>(&[3]);
+    assert!(constant.derivative().unwrap().is_empty());
+}
+
+pub fn poly_compose_x_pow_test<TFr: Fr, TPoly: Poly<TFr>>() {
+    let poly = random_poly::<TFr, TPoly>(6);
+    let x = TFr::rand();
+
+    let composed = poly.compose_x_pow(3).unwrap();
+    assert_eq!(composed.len(), 16);
+    assert!(composed.eval(&x).equals(&poly.eval(&x.pow(3))));
+
+    assert_coeffs(&poly.compose_x_pow(1).unwrap(), poly.get_coeffs());
+    assert_coeffs(&poly.compose_x_pow(0).unwrap(), &[poly.eval(&TFr::one())]);
+}
+
+pub fn poly_div_rem_test<TFr: Fr, TPoly: Poly<TFr>>() {
+    let dividend = random_poly::<TFr, TPoly>(20);
+    let mut divisor = random_poly::<TFr, TPoly>(7);
+    divisor.set_coeff_at(6, &TFr::from_u64(3));
+
+    let (quotient, rem) = dividend.div_rem(&divisor).unwrap();
+    assert!(rem.len() < divisor.len());
+    let x = TFr::rand();
+    let expected = quotient.eval(&x).mul(&divisor.eval(&x)).add(&rem.eval(&x));
+    assert!(dividend.eval(&x).equals(&expected));
+
+    // A shorter dividend is all remainder
+    let (quotient, rem) = divisor.div_rem(&dividend).unwrap();
+    assert!(quotient.get_coeffs().iter().all(Fr::is_zero));
+    assert!(rem.eval(&x).equals(&divisor.eval(&x)));
+
+    assert!(dividend.div_rem(&TPoly::new(3).unwrap()).is_err());
+}
+
+pub fn poly_eval_multi_test<TFr: Fr, TPoly: Poly<TFr>>() {
+    let poly = random_poly::<TFr, TPoly>(30);
+    let xs = (0..13).map(|_| TFr::rand()).collect::<Vec<_>>();
+
+    let values = poly.eval_multi(&xs).unwrap();
+    assert_eq!(values.len(), xs.len());
+    for (x, value) in xs.iter().zip(&values) {
+        assert!(poly.eval(x).equals(value));
+    }
+
+    assert!(poly.eval_multi(&[]).unwrap().is_empty());
+}
+
+pub fn poly_interpolate_test<TFr: Fr, TPoly: Poly<TFr>>() {
+    let xs = (0..17).map(|_| TFr::rand()).collect::<Vec<_>>();
+    let ys = (0..17).map(|_| TFr::rand()).collect::<Vec<_>>();
+
+    let poly = TPoly::interpolate(&xs, &ys).unwrap();
+    assert_eq!(poly.len(), xs.len());
+    for (x, y) in xs.iter().zip(&ys) {
+        assert!(poly.eval(x).equals(y));
+    }
+
+    // Interpolating the evaluations of a polynomial gives it back
+    let original = random_poly::<TFr, TPoly>(17);
+    let values = original.eval_multi(&xs).unwrap();
+    assert_coeffs(
+        &TPoly::interpolate(&xs, &values).unwrap(),
+        original.get_coeffs(),
+    );
+
+    let mut duplicate = xs.clone();
+    duplicate[5] = duplicate[11].clone();
+    assert!(TPoly::interpolate(&duplicate, &ys).is_err());
+    assert!(TPoly::interpolate(&xs, &ys[1..]).is_err());
+}
+
+pub fn poly_eval_form_test<
+    TFr: Fr,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>,
+>() {
+    let fs = TFFTSettings::new(5).unwrap();
+    let a = random_poly::<TFr, TPoly>(10);
+    let b = random_poly::<TFr, TPoly>(6);
+
+    // The evaluations are at the bit-reversed roots of unity
+    let a_eval = PolyEval::from_coeffs(&a, 16, &fs).unwrap();
+    assert_eq!(a_eval.evals().len(), 16);
+    for (i, value) in a_eval.evals().iter().enumerate() {
+        assert!(value.equals(&a.eval(&fs.get_roots_of_unity_at(i))));
+    }
+
+    let mut expected = a.get_coeffs().to_vec();
+    expected.resize(16, TFr::zero());
+    assert_coeffs(&a_eval.to_coeffs::<TPoly, _>(&fs).unwrap(), &expected);
+
+    let x = TFr::rand();
+    assert!(a_eval.evaluate(&x, &fs).unwrap().equals(&a.eval(&x)));
+    let root = fs.get_roots_of_unity_at(3);
+    assert!(a_eval
+        .evaluate(&root, &fs)
+        .unwrap()
+        .equals(&a_eval.evals()[3]));
+
+    // The product has degree 14, so it fits into 16 evaluations
+    let b_eval = PolyEval::from_coeffs(&b, 16, &fs).unwrap();
+    let sum = a_eval.add(&b_eval).unwrap();
+    assert!(sum
+        .evaluate(&x, &fs)
+        .unwrap()
+        .equals(&a.eval(&x).add(&b.eval(&x))));
+    let difference = a_eval.sub(&b_eval).unwrap();
+    assert!(difference
+        .evaluate(&x, &fs)
+        .unwrap()
+        .equals(&a.eval(&x).sub(&b.eval(&x))));
+    let product = a_eval.mul(&b_eval).unwrap();
+    assert!(product
+        .evaluate(&x, &fs)
+        .unwrap()
+        .equals(&a.eval(&x).mul(&b.eval(&x))));
+
+    assert!(PolyEval::from_coeffs(&a, 8, &fs).is_err());
+    assert!(PolyEval::from_coeffs(&a, 64, &fs).is_err());
+    assert!(PolyEval::new(vec![TFr::one(); 3]).is_err());
+    let short = PolyEval::from_coeffs(&b, 8, &fs).unwrap();
+    assert!(a_eval.add(&short).is_err());
+}
+
+pub fn poly_eval_form_div_test<
+    TFr: Fr,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>,
+>() {
+    let fs = TFFTSettings::new(4).unwrap();
+    let poly = random_poly::<TFr, TPoly>(16);
+    let poly_eval = PolyEval::from_coeffs(&poly, 16, &fs).unwrap();
+    let x = TFr::rand();
+
+    // Outside of the domain and on one of its points
+    for z in [TFr::rand(), fs.get_roots_of_unity_at(5)] {
+        let (quotient, y) = poly_eval.div_by_linear(&z, &fs).unwrap();
+        assert!(y.equals(&poly.eval(&z)));
+
+        let expected = quotient.evaluate(&x, &fs).unwrap().mul(&x.sub(&z)).add(&y);
+        assert!(poly.eval(&x).equals(&expected));
+    }
+}
@@ -1,145 +1,227 @@
-use kzg::FFTFr;
-use kzg::FFTSettings;
-use kzg::Fr;
-use kzg::Poly;
-use kzg::PolyRecover;
-use rand::seq::SliceRandom;
-use rand::thread_rng;
-use std::convert::TryInto;
-
-pub fn recover_simple<
-    TFr: Fr,
-    TFTTSettings: FFTSettings<TFr> + FFTFr<TFr>,
-    TPoly: Poly<TFr>,
-    TPolyRecover: PolyRecover<TFr, TPoly, TFTTSettings>,
->() {
-    let fs = TFTTSettings::new(2).unwrap();
-    let max_width: usize = fs.get_max_width();
-
-    let mut poly = vec![TFr::zero(); max_width];
-
-    for (i, p) in poly.iter_mut().enumerate().take(max_width / 2) {
-        *p = TFr::from_u64(i.try_into().unwrap());
-    }
-
-    //I think it is not needed, since vec! is set as Fr::zero(), but leaving just in case
-    // for i in (max_width / 2)..max_width {
-    //     poly[i] = TFr::zero();
-    // }
-
-    let data = fs.fft_fr(&poly, false).unwrap();
-    let samples: [Option<TFr>; 4] = [Some(data[0].clone()), None, None, Some(data[3].clone())];
-
-    let recovered = TPolyRecover::recover_poly_from_samples(&samples, &fs).unwrap();
-
-    //Check recovered data
-    assert_eq!(data.len(), max_width);
-    for (i, d) in data.iter().enumerate() {
-        assert!(d.equals(&recovered.get_coeff_at(i)));
-    }
-
-    let mut recovered_vec: Vec<TFr> = vec![];
-
-    for i in 0..max_width {
-        recovered_vec.push(recovered.get_coeff_at(i));
-    }
-
-    //Also check against original coefficients
-    let back = fs.fft_fr(&recovered_vec, true).unwrap();
-    for (i, p) in poly.iter().enumerate().take(max_width / 2) {
-        assert!(p.equals(&back[i]));
-    }
-
-    for p in poly.iter().take(max_width).skip(max_width / 2) {
-        assert!(p.is_zero());
-    }
-}
-
-pub fn recover_random<
-    TFr: Fr,
-    TFTTSettings: FFTSettings<TFr> + FFTFr<TFr>,
-    TPoly: Poly<TFr>,
-    TPolyRecover: PolyRecover<TFr, TPoly, TFTTSettings>,
->() {
-    let fs = TFTTSettings::new(12).unwrap();
-    let max_width: usize = fs.get_max_width();
-
-    let mut poly = vec![TFr::zero(); max_width];
-
-    for (i, p) in poly.iter_mut().enumerate().take(max_width / 2) {
-        *p = TFr::from_u64(i.try_into().unwrap());
-    }
-
-    let data = fs.fft_fr(&poly, false).unwrap();
-
-    //Having half of the data is the minimum
-    let mut known_ratio: f64 = 0.5;
-    while known_ratio < 1.0 {
-        let known: u64 = (max_width as f64 * known_ratio) as u64;
-        for _ in 0..4 {
-            let samples = random_missing(data.clone(), max_width, known);
-
-            let recovered = TPolyRecover::recover_poly_from_samples(&samples, &fs).unwrap();
-            //Assert
-            assert_eq!(data.len(), max_width);
-            for (i, d) in data.iter().enumerate() {
-                assert!(d.equals(&recovered.get_coeff_at(i)));
-            }
-
-            let recovered_vec = (0..max_width)
-                .map(|i| recovered.get_coeff_at(i))
-                .collect::<Vec<_>>();
-
-            //Also check against original coefficients
-            let back = fs.fft_fr(&recovered_vec, true).unwrap();
-            for i in 0..(max_width / 2) {
-                assert!(poly[i].equals(&back[i]));
-            }
-
-            for p in poly.iter().take(max_width).skip(max_width / 2) {
-                assert!(p.is_zero());
-            }
-        }
-
-        //loop increment
-        known_ratio += 0.05;
-    }
-}
-
-pub fn more_than_half_missing<
-    TFr: Fr,
-    TFTTSettings: FFTSettings<TFr> + FFTFr<TFr>,
-    TPoly: Poly<TFr>,
-    TPolyRecover: PolyRecover<TFr, TPoly, TFTTSettings>,
->() {
-    let fs = TFTTSettings::new(2).unwrap();
-    let max_width: usize = fs.get_max_width();
-
-    let mut poly = vec![TFr::zero(); max_width];
-
-    for (i, p) in poly.iter_mut().enumerate().take(max_width / 2) {
-        *p = TFr::from_u64(i.try_into().unwrap());
-    }
-
-    let data = fs.fft_fr(&poly, false).unwrap();
-    let samples: [Option<TFr>; 4] = [Some(data[0].clone()), None, None, None];
-
-    assert!(TPolyRecover::recover_poly_from_samples(&samples, &fs).is_err());
-    assert!(TPolyRecover::recover_poly_from_samples(&[None], &fs).is_err());
-}
-
-fn random_missing<TFr: Fr>(data: Vec<TFr>, len_data: usize, known: u64) -> Vec<Option<TFr>> {
-    let mut missing_idx: Vec<usize> = vec![];
-    let mut with_missing = data.into_iter().map(Some).collect::<Vec<_>>();
-
-    for i in 0..len_data {
-        missing_idx.push(i);
-    }
-
-    missing_idx.shuffle(&mut thread_rng());
-
-    for missing_idx in missing_idx.into_iter().take(len_data - (known as usize)) {
-        with_missing[missing_idx] = None;
-    }
-    with_missing
-}
+use kzg::FFTFr;
+use kzg::FFTSettings;
+use kzg::Fr;
+use kzg::Poly;
+use kzg::PolyErrorCorrect;
+use kzg::PolyRecover;
+use rand::seq::SliceRandom;
+use rand::thread_rng;
+use std::convert::TryInto;
+
+pub fn recover_simple<
+    TFr: Fr,
+    TFTTSettings: FFTSettings<TFr> + FFTFr<TFr>,
+    TPoly: Poly<TFr>,
+    TPolyRecover: PolyRecover<TFr, TPoly, TFTTSettings>,
+>() {
+    let fs = TFTTSettings::new(2).unwrap();
+    let max_width: usize = fs.get_max_width();
+
+    let mut poly = vec![TFr::zero(); max_width];
+
+    for (i, p) in poly.iter_mut().enumerate().take(max_width / 2) {
+        *p = TFr::from_u64(i.try_into().unwrap());
+    }
+
+    //I think it is not needed, since vec! is set as Fr::zero(), but leaving just in case
+    // for i in (max_width / 2)..max_width {
+    //     poly[i] = TFr::zero();
+    // }
+
+    let data = fs.fft_fr(&poly, false).unwrap();
+    let samples: [Option<TFr>; 4] = [Some(data[0].clone()), None, None, Some(data[3].clone())];
+
+    let recovered = TPolyRecover::recover_poly_from_samples(&samples, &fs).unwrap();
+
+    //Check recovered data
+    assert_eq!(data.len(), max_width);
+    for (i, d) in data.iter().enumerate() {
+        assert!(d.equals(&recovered.get_coeff_at(i)));
+    }
+
+    let mut recovered_vec: Vec<TFr> = vec![];
+
+    for i in 0..max_width {
+        recovered_vec.push(recovered.get_coeff_at(i));
+    }
+
+    //Also check against original coefficients
+    let back = fs.fft_fr(&recovered_vec, true).unwrap();
+    for (i, p) in poly.iter().enumerate().take(max_width / 2) {
+        assert!(p.equals(&back[i]));
+    }
+
+    for p in poly.iter().take(max_width).skip(max_width / 2) {
+        assert!(p.is_zero());
+    }
+}
+
+pub fn recover_random<
+    TFr: Fr,
+    TFTTSettings: FFTSettings<TFr> + FFTFr<TFr>,
+    TPoly: Poly<TFr>,
+    TPolyRecover: PolyRecover<TFr, TPoly, TFTTSettings>,
+>() {
+    let fs = TFTTSettings::new(12).unwrap();
+    let max_width: usize = fs.get_max_width();
+
+    let mut poly = vec![TFr::zero(); max_width];
+
+    for (i, p) in poly.iter_mut().enumerate().take(max_width / 2) {
+        *p = TFr::from_u64(i.try_into().unwrap());
+    }
+
+    let data = fs.fft_fr(&poly, false).unwrap();
+
+    //Having half of the data is the minimum
+    let mut known_ratio: f64 = 0.5;
+    while known_ratio < 1.0 {
+        let known: u64 = (max_width as f64 * known_ratio) as u64;
+        for _ in 0..4 {
+            let samples = random_missing(data.clone(), max_width, known);
+
+            let recovered = TPolyRecover::recover_poly_from_samples(&samples, &fs).unwrap();
+            //Assert
+            assert_eq!(data.len(), max_width);
+            for (i, d) in data.iter().enumerate() {
+                assert!(d.equals(&recovered.get_coeff_at(i)));
+            }
+
+            let recovered_vec = (0..max_width)
+                .map(|i| recovered.get_coeff_at(i))
+                .collect::<Vec<_>>();
+
+            //Also check against original coefficients
+            let back = fs.fft_fr(&recovered_vec, true).unwrap();
+            for i in 0..(max_width / 2) {
+                assert!(poly[i].equals(&back[i]));
+            }
+
+            for p in poly.iter().take(max_width).skip(max_width / 2) {
+                assert!(p.is_zero());
+            }
+        }
+
+        //loop increment
+        known_ratio += 0.05;
+    }
+}
+
+pub fn more_than_half_missing<
+    TFr: Fr,
+    TFTTSettings: FFTSettings<TFr> + FFTFr<TFr>,
+    TPoly: Poly<TFr>,
+    TPolyRecover: PolyRecover<TFr, TPoly, TFTTSettings>,
+>() {
+    let fs = TFTTSettings::new(2).unwrap();
+    let max_width: usize = fs.get_max_width();
+
+    let mut poly = vec![TFr::zero(); max_width];
+
+    for (i, p) in poly.iter_mut().enumerate().take(max_width / 2) {
+        *p = TFr::from_u64(i.try_into().unwrap());
+    }
+
+    let data = fs.fft_fr(&poly, false).unwrap();
+    let samples: [Option<TFr>; 4] = [Some(data[0].clone()), None, None, None];
+
+    assert!(TPolyRecover::recover_poly_from_samples(&samples, &fs).is_err());
+    assert!(TPolyRecover::recover_poly_from_samples(&[None], &fs).is_err());
+}
+
+fn error_correction_data<TFr: Fr, TFTTSettings: FFTSettings<TFr> + FFTFr<TFr>>(
+    fs: &TFTTSettings,
+) -> Vec<TFr> {
+    let max_width: usize = fs.get_max_width();
+    let mut poly = vec![TFr::zero(); max_width];
+    for (i, p) in poly.iter_mut().enumerate().take(max_width / 2) {
+        *p = TFr::from_u64((i * i + 1).try_into().unwrap());
+    }
+
+    fs.fft_fr(&poly, false).unwrap()
+}
+
+fn with_errors<TFr: Fr>(data: &[TFr], missing: &[usize], wrong: &[usize]) -> Vec<Option<TFr>> {
+    data.iter()
+        .enumerate()
+        .map(|(i, d)| {
+            if missing.contains(&i) {
+                None
+            } else if wrong.contains(&i) {
+                Some(d.add(&TFr::from_u64(i as u64 + 1)))
+            } else {
+                Some(d.clone())
+            }
+        })
+        .collect()
+}
+
+pub fn correct_errors_simple<
+    TFr: Fr,
+    TFTTSettings: FFTSettings<TFr> + FFTFr<TFr>,
+    TPoly: Poly<TFr>,
+    TPolyErrorCorrect: PolyErrorCorrect<TFr, TPoly, TFTTSettings>,
+>() {
+    let fs = TFTTSettings::new(5).unwrap();
+    let max_width: usize = fs.get_max_width();
+    let data = error_correction_data(&fs);
+
+    // Half of the samples are redundant, which corrects up to a quarter of them, or fewer
+    // together with missing ones
+    let cases: [(&[usize], &[usize]); 4] = [
+        (&[], &[]),
+        (&[1, 8, 13, 30], &[]),
+        (&[1, 8, 13, 30], &[3, 17, 22, 25, 31, 0]),
+        (&[], &[2, 5, 9, 11, 18, 20, 27, 29]),
+    ];
+    for (missing, wrong) in cases {
+        let samples = with_errors(&data, missing, wrong);
+        let (recovered, corrected) =
+            TPolyErrorCorrect::correct_poly_from_samples(&samples, max_width / 2, &fs).unwrap();
+
+        let mut expected_corrected = wrong.to_vec();
+        expected_corrected.sort();
+        assert_eq!(corrected, expected_corrected);
+        for (i, d) in data.iter().enumerate() {
+            assert!(d.equals(&recovered.get_coeff_at(i)));
+        }
+    }
+}
+
+pub fn correct_errors_too_many<
+    TFr: Fr,
+    TFTTSettings: FFTSettings<TFr> + FFTFr<TFr>,
+    TPoly: Poly<TFr>,
+    TPolyErrorCorrect: PolyErrorCorrect<TFr, TPoly, TFTTSettings>,
+>() {
+    let fs = TFTTSettings::new(5).unwrap();
+    let max_width: usize = fs.get_max_width();
+    let data = error_correction_data(&fs);
+
+    // One wrong sample more than can be corrected
+    let samples = with_errors(&data, &[], &[0, 2, 5, 9, 11, 18, 20, 27, 29]);
+    assert!(TPolyErrorCorrect::correct_poly_from_samples(&samples, max_width / 2, &fs).is_err());
+    let samples = with_errors(&data, &[1, 8], &[3, 17, 22, 25, 31, 0, 6, 10]);
+    assert!(TPolyErrorCorrect::correct_poly_from_samples(&samples, max_width / 2, &fs).is_err());
+
+    // Fewer known samples than coefficients
+    let missing = (0..max_width / 2 + 1).collect::<Vec<_>>();
+    let samples = with_errors(&data, &missing, &[]);
+    assert!(TPolyErrorCorrect::correct_poly_from_samples(&samples, max_width / 2, &fs).is_err());
+}
+
+fn random_missing<TFr: Fr>(data: Vec<TFr>, len_data: usize, known: u64) -> Vec<Option<TFr>> {
+    let mut missing_idx: Vec<usize> = vec![];
+    let mut with_missing = data.into_iter().map(Some).collect::<Vec<_>>();
+
+    for i in 0..len_data {
+        missing_idx.push(i);
+    }
+
+    missing_idx.shuffle(&mut thread_rng());
+
+    for missing_idx in missing_idx.into_iter().take(len_data - (known as usize)) {
+        with_missing[missing_idx] = None;
+    }
+    with_missing
+}
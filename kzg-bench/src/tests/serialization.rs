@@ -0,0 +1,77 @@
+use kzg::{Fr, G1, G2};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+const G1_GENERATOR_HEX: &str = "0x97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb";
+const G2_GENERATOR_HEX: &str = "0x93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8";
+
+fn json_string(hex: &str) -> String {
+    format!("\"{}\"", hex)
+}
+
+pub fn fr_serde_round_trip_test<TFr: Fr + Serialize + DeserializeOwned>() {
+    let fr = TFr::from_u64(0x1234_5678);
+
+    let json = serde_json::to_string(&fr).unwrap();
+    assert_eq!(
+        json,
+        json_string(&format!("0x{}", hex::encode(fr.to_bytes())))
+    );
+    assert!(serde_json::from_str::<TFr>(&json).unwrap().equals(&fr));
+
+    let binary = bincode::serialize(&fr).unwrap();
+    assert!(bincode::deserialize::<TFr>(&binary).unwrap().equals(&fr));
+}
+
+pub fn fr_serde_rejects_invalid_test<TFr: Fr + Serialize + DeserializeOwned>() {
+    let fr_hex = format!("0x{}", hex::encode(TFr::one().to_bytes()));
+
+    // Missing prefix, bad digit, wrong length and a value above the modulus
+    assert!(serde_json::from_str::<TFr>(&json_string(&fr_hex[2..])).is_err());
+    assert!(serde_json::from_str::<TFr>(&json_string(&fr_hex.replacen('0', "g", 2))).is_err());
+    assert!(serde_json::from_str::<TFr>(&json_string(&fr_hex[..fr_hex.len() - 2])).is_err());
+    assert!(serde_json::from_str::<TFr>(&json_string(&format!("0x{}", "ff".repeat(32)))).is_err());
+
+    let binary = bincode::serialize(&TFr::one()).unwrap();
+    assert!(bincode::deserialize::<TFr>(&binary[..binary.len() - 1]).is_err());
+    assert!(bincode::deserialize::<TFr>(&bincode::serialize(&[0xffu8; 32][..]).unwrap()).is_err());
+}
+
+pub fn g1_serde_round_trip_test<TG1: G1 + Serialize + DeserializeOwned>() {
+    let json = serde_json::to_string(&TG1::generator()).unwrap();
+    assert_eq!(json, json_string(G1_GENERATOR_HEX));
+
+    let point = TG1::rand();
+    let json = serde_json::to_string(&point).unwrap();
+    assert!(serde_json::from_str::<TG1>(&json).unwrap().equals(&point));
+
+    let binary = bincode::serialize(&point).unwrap();
+    assert!(bincode::deserialize::<TG1>(&binary).unwrap().equals(&point));
+}
+
+pub fn g1_serde_rejects_invalid_test<TG1: G1 + Serialize + DeserializeOwned>() {
+    // Wrong length
+    assert!(serde_json::from_str::<TG1>(&json_string(&G1_GENERATOR_HEX[..96])).is_err());
+    // Compressed flag set, but the x coordinate is not a field element
+    let not_a_point = format!("0x{}", "ff".repeat(48));
+    assert!(serde_json::from_str::<TG1>(&json_string(&not_a_point)).is_err());
+
+    let binary = bincode::serialize(&TG1::generator()).unwrap();
+    assert!(bincode::deserialize::<TG1>(&binary[..binary.len() - 1]).is_err());
+}
+
+pub fn g2_serde_round_trip_test<TG2: G2 + Serialize + DeserializeOwned>() {
+    let json = serde_json::to_string(&TG2::generator()).unwrap();
+    assert_eq!(json, json_string(G2_GENERATOR_HEX));
+    assert!(serde_json::from_str::<TG2>(&json)
+        .unwrap()
+        .equals(&TG2::generator()));
+
+    let binary = bincode::serialize(&TG2::negative_generator()).unwrap();
+    assert!(bincode::deserialize::<TG2>(&binary)
+        .unwrap()
+        .equals(&TG2::negative_generator()));
+
+    let not_a_point = format!("0x{}", "ff".repeat(96));
+    assert!(serde_json::from_str::<TG2>(&json_string(&not_a_point)).is_err());
+}
@@ -0,0 +1,115 @@
+use kzg::eip_4844::{load_trusted_setup_string, trusted_setup_to_string};
+use kzg::trusted_setup::{contribute, lagrange_to_monomial, Transcript};
+use kzg::{
+    Error, FFTSettings, Fr, G1LinComb, G2Mul, KZGSettings, PairingVerify, Poly, FFTG1, G1, G2,
+};
+
+/// Run a small ceremony and check that its output is a usable setup
+pub fn powers_of_tau_ceremony_test<
+    TFr: Fr,
+    TG1: G1 + G1LinComb<TFr> + PairingVerify<TG1, TG2>,
+    TG2: G2 + G2Mul<TFr>,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr> + FFTG1<TG1>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+>() {
+    let secrets_len = 16;
+
+    let mut transcript = Transcript::<TFr, TG1, TG2>::new(secrets_len, secrets_len).unwrap();
+    for _ in 0..3 {
+        transcript.contribute(&TFr::rand()).unwrap();
+    }
+    assert_eq!(transcript.proofs.len(), 3);
+    assert_eq!(transcript.running_products.len(), 4);
+    assert!(transcript.verify().is_ok());
+
+    // Commit to a polynomial and prove an evaluation with the resulting setup
+    let fs = TFFTSettings::new(4).unwrap();
+    let ks = TKZGSettings::new(
+        &transcript.g1_powers,
+        &transcript.g2_powers,
+        secrets_len,
+        secrets_len,
+        &fs,
+    )
+    .unwrap();
+
+    let mut p = TPoly::new(secrets_len).unwrap();
+    for i in 0..secrets_len {
+        p.set_coeff_at(i, &TFr::from_u64((i * i + 1) as u64));
+    }
+    let x = TFr::from_u64(25);
+    let commitment = ks.commit_to_poly(&p).unwrap();
+    let proof = ks.compute_proof_single(&p, &x).unwrap();
+    assert!(ks
+        .check_proof_single(&commitment, &proof, &x, &p.eval(&x))
+        .unwrap());
+
+    // The Lagrange form converts back to the same powers
+    let lagrange = transcript.g1_lagrange(&fs).unwrap();
+    let monomial = lagrange_to_monomial(&lagrange, &fs).unwrap();
+    for (a, b) in monomial.iter().zip(&transcript.g1_powers) {
+        assert!(a.equals(b));
+    }
+
+    // The trusted_setup.txt output parses back to the same points
+    let contents = trusted_setup_to_string(&transcript.g1_powers, &transcript.g2_powers);
+    let (g1_bytes, g2_bytes) = load_trusted_setup_string(&contents).unwrap();
+    let expected_g1 = transcript
+        .g1_powers
+        .iter()
+        .flat_map(|point| point.to_bytes())
+        .collect::<Vec<u8>>();
+    let expected_g2 = transcript
+        .g2_powers
+        .iter()
+        .flat_map(|point| point.to_bytes())
+        .collect::<Vec<u8>>();
+    assert_eq!(g1_bytes, expected_g1);
+    assert_eq!(g2_bytes, expected_g2);
+}
+
+/// Check that a transcript with altered or missing contributions does not verify
+pub fn powers_of_tau_tampered_transcript_test<
+    TFr: Fr,
+    TG1: G1 + G1LinComb<TFr> + PairingVerify<TG1, TG2>,
+    TG2: G2 + G2Mul<TFr>,
+>() {
+    let mut transcript = Transcript::<TFr, TG1, TG2>::new(8, 8).unwrap();
+    transcript.contribute(&TFr::rand()).unwrap();
+    transcript.contribute(&TFr::rand()).unwrap();
+    assert!(transcript.verify().is_ok());
+
+    // A zero secret would erase the setup
+    assert!(matches!(
+        contribute(&transcript.g1_powers, &transcript.g2_powers, &TFr::zero()),
+        Err(Error::InvalidArgument(_))
+    ));
+
+    // Proofs in the wrong order
+    let mut swapped = transcript.clone();
+    swapped.proofs.swap(0, 1);
+    assert!(matches!(
+        swapped.verify(),
+        Err(Error::InconsistentTrustedSetup(_))
+    ));
+
+    // Proof of knowledge with a wrong response
+    let mut forged = transcript.clone();
+    forged.proofs[1].response = forged.proofs[1].response.add(&TFr::one());
+    assert!(matches!(
+        forged.verify(),
+        Err(Error::InconsistentTrustedSetup(_))
+    ));
+
+    // Powers updated by a contribution that is not recorded
+    let mut unrecorded = transcript.clone();
+    let (g1_powers, g2_powers, _) =
+        contribute(&unrecorded.g1_powers, &unrecorded.g2_powers, &TFr::rand()).unwrap();
+    unrecorded.g1_powers = g1_powers;
+    unrecorded.g2_powers = g2_powers;
+    assert!(matches!(
+        unrecorded.verify(),
+        Err(Error::InconsistentTrustedSetup(_))
+    ));
+}
@@ -0,0 +1,299 @@
+//! Two-dimensional erasure coding of a matrix of blobs, as in full Danksharding.
+//!
+//! A block of `K` blobs of `N` field elements each is extended to a `2K x 2N` matrix: first every
+//! row over a domain of twice its size, then every column of the extended rows. Rows and columns
+//! stay in bit-reversed order like blobs, so the original blobs are the top-left `K x N` quadrant.
+//! Every extended row is still a polynomial of degree below `N`, and its commitment is the same
+//! extension of the original row commitments.
+//!
+//! Samples are chunks of `chunk_len` consecutive elements of an extended row, each with an FK20
+//! multiproof against its row commitment.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::poly::reverse_bit_order;
+use crate::{
+    Error, FFTFr, FFTSettings, FK20MultiSettings, Fr, KZGSettings, Poly, PolyRecover, DAS, FFTG1,
+    G1, G2,
+};
+
+/// Returns the number of rows and the row length of a matrix, which must both be powers of two.
+fn matrix_dimensions<T>(rows: &[Vec<T>]) -> Result<(usize, usize), Error> {
+    if !rows.len().is_power_of_two() {
+        return Err(Error::NotPowerOfTwo("number of rows"));
+    }
+    let row_len = rows[0].len();
+    if !row_len.is_power_of_two() {
+        return Err(Error::NotPowerOfTwo("row length"));
+    }
+    if let Some(row) = rows.iter().find(|row| row.len() != row_len) {
+        return Err(Error::InvalidLength {
+            expected: row_len,
+            actual: row.len(),
+        });
+    }
+
+    Ok((rows.len(), row_len))
+}
+
+/// Extends evaluations in bit-reversed order to twice as many, keeping the originals in front.
+fn extend_bit_reversed<TFr: Fr, TFFTSettings: FFTSettings<TFr> + DAS<TFr>>(
+    values: &[TFr],
+    fs: &TFFTSettings,
+) -> Result<Vec<TFr>, Error> {
+    let mut evens = values.to_vec();
+    reverse_bit_order(&mut evens);
+    let odds = fs.das_fft_extension(&evens)?;
+
+    let mut extended = evens
+        .into_iter()
+        .zip(odds)
+        .flat_map(|(even, odd)| [even, odd])
+        .collect::<Vec<_>>();
+    reverse_bit_order(&mut extended);
+    Ok(extended)
+}
+
+/// Extends `K` blobs of `N` elements each to the `2K x 2N` matrix.
+///
+/// `fs` must be wide enough for both `2K` and `2N` elements.
+pub fn extend_matrix<TFr: Fr, TFFTSettings: FFTSettings<TFr> + DAS<TFr>>(
+    blobs: &[Vec<TFr>],
+    fs: &TFFTSettings,
+) -> Result<Vec<Vec<TFr>>, Error> {
+    let (num_rows, row_len) = matrix_dimensions(blobs)?;
+
+    let rows = blobs
+        .iter()
+        .map(|blob| extend_bit_reversed(blob, fs))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut extended = vec![Vec::with_capacity(2 * row_len); 2 * num_rows];
+    for column_index in 0..2 * row_len {
+        let column = rows
+            .iter()
+            .map(|row| row[column_index].clone())
+            .collect::<Vec<_>>();
+        for (row, value) in extended.iter_mut().zip(extend_bit_reversed(&column, fs)?) {
+            row.push(value);
+        }
+    }
+
+    Ok(extended)
+}
+
+/// Extends the commitments to `K` blobs to the commitments to the `2K` rows of the extended
+/// matrix.
+pub fn extend_commitments<TFr: Fr, TG1: G1, TFFTSettings: FFTSettings<TFr> + FFTG1<TG1>>(
+    commitments: &[TG1],
+    fs: &TFFTSettings,
+) -> Result<Vec<TG1>, Error> {
+    if !commitments.len().is_power_of_two() {
+        return Err(Error::NotPowerOfTwo("number of commitments"));
+    }
+
+    let mut evaluations = commitments.to_vec();
+    reverse_bit_order(&mut evaluations);
+    let mut coeffs = fs.fft_g1(&evaluations, true)?;
+    coeffs.resize(2 * commitments.len(), TG1::identity());
+
+    let mut extended = fs.fft_g1(&coeffs, false)?;
+    reverse_bit_order(&mut extended);
+    Ok(extended)
+}
+
+/// Computes the proofs of all samples of every row of an extended matrix.
+///
+/// `fk` must be built over `2N` elements from settings with the setup in monomial form. Returns
+/// the proofs per row, where proof `j` opens elements `j * chunk_len..(j + 1) * chunk_len`.
+pub fn compute_sample_proofs<
+    TFr: Fr,
+    TG1: G1,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+    TFK20MultiSettings: FK20MultiSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings>,
+>(
+    extended: &[Vec<TFr>],
+    fk: &TFK20MultiSettings,
+    fs: &TFFTSettings,
+) -> Result<Vec<Vec<TG1>>, Error> {
+    let (_, extended_row_len) = matrix_dimensions(extended)?;
+    let row_len = extended_row_len / 2;
+
+    extended
+        .iter()
+        .map(|row| {
+            let mut evaluations = row.clone();
+            reverse_bit_order(&mut evaluations);
+            let coeffs = fs.fft_fr(&evaluations, true)?;
+            if !coeffs[row_len..].iter().all(TFr::is_zero) {
+                return Err(Error::InvalidArgument("Row is not an extended blob"));
+            }
+
+            let mut poly = TPoly::new(row_len)?;
+            for (i, coeff) in coeffs[..row_len].iter().enumerate() {
+                poly.set_coeff_at(i, coeff);
+            }
+            fk.data_availability(&poly)
+        })
+        .collect()
+}
+
+/// Verifies the proof of sample `sample_index` of an extended row against the row commitment.
+///
+/// `ks` must be the settings `fk` was built from in [`compute_sample_proofs`], so that their FFT
+/// settings cover exactly the `2N` elements of an extended row.
+pub fn verify_sample_proof<
+    TFr: Fr,
+    TG1: G1,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+>(
+    commitment: &TG1,
+    proof: &TG1,
+    sample_index: usize,
+    sample: &[TFr],
+    ks: &TKZGSettings,
+) -> Result<bool, Error> {
+    let chunk_len = sample.len();
+    let extended_row_len = ks.get_fft_settings().get_max_width();
+    if !chunk_len.is_power_of_two() {
+        return Err(Error::NotPowerOfTwo("sample length"));
+    }
+    if (sample_index + 1) * chunk_len > extended_row_len {
+        return Err(Error::InvalidArgument("Sample index out of range"));
+    }
+
+    // The sample is the coset of `x` in bit-reversed order
+    let unused_bit_len = extended_row_len.leading_zeros() + 1;
+    let x = ks.get_fft_settings().get_expanded_roots_of_unity_at(
+        (sample_index * chunk_len).reverse_bits() >> unused_bit_len,
+    );
+    let mut ys = sample.to_vec();
+    reverse_bit_order(&mut ys);
+
+    ks.check_proof_multi(commitment, proof, &x, &ys, chunk_len)
+}
+
+/// Checks that a row or column is the extension of one of half its length.
+fn is_extended_line<TFr: Fr, TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>>(
+    line: &[TFr],
+    fs: &TFFTSettings,
+) -> Result<bool, Error> {
+    let mut evaluations = line.to_vec();
+    reverse_bit_order(&mut evaluations);
+    let coeffs = fs.fft_fr(&evaluations, true)?;
+    Ok(coeffs[line.len() / 2..].iter().all(TFr::is_zero))
+}
+
+/// Recovers a row or column with at least half of its elements, or returns `None` if it is
+/// complete or cannot be recovered yet.
+fn recover_line<
+    TFr: Fr,
+    TPoly: Poly<TFr> + PolyRecover<TFr, TPoly, TFFTSettings>,
+    TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>,
+>(
+    line: &[Option<TFr>],
+    fs: &TFFTSettings,
+) -> Result<Option<Vec<TFr>>, Error> {
+    let known = line.iter().filter(|value| value.is_some()).count();
+    if known == line.len() || 2 * known < line.len() {
+        return Ok(None);
+    }
+
+    let mut samples = line.to_vec();
+    reverse_bit_order(&mut samples);
+    let poly = TPoly::recover_poly_coeffs_from_samples(&samples, fs)?;
+    let coeffs = poly.get_coeffs();
+    if !coeffs[line.len() / 2..].iter().all(TFr::is_zero) {
+        return Err(Error::InvalidArgument(
+            "Samples are not consistent with an extended matrix",
+        ));
+    }
+
+    let mut recovered = fs.fft_fr(coeffs, false)?;
+    reverse_bit_order(&mut recovered);
+    Ok(Some(recovered))
+}
+
+/// Reconstructs the extended matrix from the known elements, where missing ones are `None`.
+///
+/// Alternately recovers every row and every column with at least half of its elements known,
+/// until the matrix is complete or no row or column can be recovered anymore. The result is
+/// rejected unless all of its rows and columns are extensions, which also catches corrupted
+/// samples in rows and columns that were complete from the start.
+pub fn recover_matrix<
+    TFr: Fr,
+    TPoly: Poly<TFr> + PolyRecover<TFr, TPoly, TFFTSettings>,
+    TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>,
+>(
+    samples: &[Vec<Option<TFr>>],
+    fs: &TFFTSettings,
+) -> Result<Vec<Vec<TFr>>, Error> {
+    let (_, row_len) = matrix_dimensions(samples)?;
+    let mut matrix = samples.to_vec();
+
+    loop {
+        let mut progress = false;
+
+        for row in matrix.iter_mut() {
+            if let Some(recovered) = recover_line::<TFr, TPoly, TFFTSettings>(row, fs)? {
+                *row = recovered.into_iter().map(Some).collect();
+                progress = true;
+            }
+        }
+
+        for column_index in 0..row_len {
+            let column = matrix
+                .iter()
+                .map(|row| row[column_index].clone())
+                .collect::<Vec<_>>();
+            if let Some(recovered) = recover_line::<TFr, TPoly, TFFTSettings>(&column, fs)? {
+                for (row, value) in matrix.iter_mut().zip(recovered) {
+                    row[column_index] = Some(value);
+                }
+                progress = true;
+            }
+        }
+
+        if matrix.iter().flatten().all(Option::is_some) {
+            break;
+        }
+        if !progress {
+            return Err(Error::InvalidArgument(
+                "Not enough samples to reconstruct the matrix",
+            ));
+        }
+    }
+
+    let matrix = matrix
+        .into_iter()
+        .map(|row| row.into_iter().map(Option::unwrap).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    for row in &matrix {
+        if !is_extended_line(row, fs)? {
+            return Err(Error::InvalidArgument(
+                "Samples are not consistent with an extended matrix",
+            ));
+        }
+    }
+    for column_index in 0..row_len {
+        let column = matrix
+            .iter()
+            .map(|row| row[column_index].clone())
+            .collect::<Vec<_>>();
+        if !is_extended_line(&column, fs)? {
+            return Err(Error::InvalidArgument(
+                "Samples are not consistent with an extended matrix",
+            ));
+        }
+    }
+
+    Ok(matrix)
+}
@@ -1,9 +1,21 @@
 #![allow(non_camel_case_types)]
-use crate::Vec;
+use crate::trusted_setup::SetupBasis;
+use crate::{
+    Error, FFTSettings, Fr, G1LinComb, KZGSettings, PairingVerify, Poly, PolyEval, Vec, G1, G2,
+};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
 pub use blst::{blst_fr, blst_p1, blst_p2};
-use core::ffi::c_uint;
+use core::ffi::{c_uint, c_void};
+use core::fmt;
 use sha2::{Digest, Sha256};
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+pub mod encoding;
+
 ////////////////////////////// Constant values for EIP-4844 //////////////////////////////
 
 pub const FIELD_ELEMENTS_PER_BLOB: usize = if cfg!(feature = "minimal-spec") {
@@ -25,10 +37,6 @@ pub const TRUSTED_SETUP_PATH: &str = if cfg!(feature = "minimal-spec") {
     "src/trusted_setups/trusted_setup.txt"
 };
 
-// Currently, we only support fixed amount of G1 and G2 points contained in trusted setups.
-// Issue arises when a binding using the C API loads different G1 point quantities each time.
-pub static mut TRUSTED_SETUP_NUM_G1_POINTS: usize = 0;
-
 pub const TRUSTED_SETUP_NUM_G2_POINTS: usize = 65;
 
 pub const CHALLENGE_INPUT_SIZE: usize =
@@ -42,6 +50,74 @@ pub const RANDOM_CHALLENGE_KZG_BATCH_DOMAIN: [u8; 16] = [
     82, 67, 75, 90, 71, 66, 65, 84, 67, 72, 95, 95, 95, 86, 49, 95,
 ]; // "RCKZGBATCH___V1_"
 
+pub const VERSIONED_HASH_VERSION_KZG: u8 = 0x01;
+
+pub const POINT_EVALUATION_INPUT_LENGTH: usize =
+    32 + 2 * BYTES_PER_FIELD_ELEMENT + BYTES_PER_COMMITMENT + BYTES_PER_PROOF;
+pub const POINT_EVALUATION_OUTPUT_LENGTH: usize = 64;
+
+/// `BLS_MODULUS` as a big-endian 256-bit integer, as returned by the point evaluation precompile.
+pub const BLS_MODULUS_BYTES_BE: [u8; 32] = [
+    0x73, 0xed, 0xa7, 0x53, 0x29, 0x9d, 0x7d, 0x48, 0x33, 0x39, 0xd8, 0x08, 0x09, 0xa1, 0xd8, 0x05,
+    0x53, 0xbd, 0xa4, 0x02, 0xff, 0xfe, 0x5b, 0xfe, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x01,
+];
+
+////////////////////////////// Runtime presets for EIP-4844 //////////////////////////////
+
+/// Blob parameters that differ between the mainnet and minimal presets.
+///
+/// Every EIP-4844 function takes the preset from the KZG settings it is given, so a single build
+/// can serve both presets. The compile-time constants above describe [`BlobPreset::default`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlobPreset {
+    pub field_elements_per_blob: usize,
+}
+
+impl BlobPreset {
+    pub const MAINNET: Self = Self {
+        field_elements_per_blob: 4096,
+    };
+
+    pub const MINIMAL: Self = Self {
+        field_elements_per_blob: 4,
+    };
+
+    pub fn new(field_elements_per_blob: usize) -> Result<Self, Error> {
+        if !field_elements_per_blob.is_power_of_two() {
+            return Err(Error::NotPowerOfTwo("field elements per blob"));
+        }
+
+        Ok(Self {
+            field_elements_per_blob,
+        })
+    }
+
+    pub const fn bytes_per_blob(&self) -> usize {
+        BYTES_PER_FIELD_ELEMENT * self.field_elements_per_blob
+    }
+
+    pub const fn challenge_input_size(&self) -> usize {
+        FIAT_SHAMIR_PROTOCOL_DOMAIN.len() + 16 + self.bytes_per_blob() + BYTES_PER_COMMITMENT
+    }
+
+    /// Path of the trusted setup shipped for this preset, relative to the `kzg-bench` crate.
+    pub fn trusted_setup_path(&self) -> Option<&'static str> {
+        match self.field_elements_per_blob {
+            4096 => Some("src/trusted_setups/trusted_setup.txt"),
+            4 => Some("src/trusted_setups/trusted_setup_4.txt"),
+            _ => None,
+        }
+    }
+}
+
+impl Default for BlobPreset {
+    fn default() -> Self {
+        Self {
+            field_elements_per_blob: FIELD_ELEMENTS_PER_BLOB,
+        }
+    }
+}
+
 ////////////////////////////// C API for EIP-4844 //////////////////////////////
 
 pub type C_KZG_RET = c_uint;
@@ -51,6 +127,16 @@ pub const C_KZG_RET_BADARGS: C_KZG_RET = 1;
 pub const C_KZG_RET_ERROR: C_KZG_RET = 2;
 pub const C_KZG_RET_MALLOC: C_KZG_RET = 3;
 
+impl From<Error> for C_KZG_RET {
+    fn from(err: Error) -> Self {
+        if err.is_bad_args() {
+            C_KZG_RET_BADARGS
+        } else {
+            C_KZG_RET_ERROR
+        }
+    }
+}
+
 #[repr(C)]
 pub struct Bytes32 {
     pub bytes: [u8; 32],
@@ -89,43 +175,196 @@ pub struct CFFTSettings {
     pub roots_of_unity: *mut blst_fr,
 }
 
+/// Version of the [`CKZGSettings`] layout. Version 1 holds only `fs`, `g1_values` and
+/// `g2_values`, like c-kzg; version 2 appends `num_g1_points` and `backend_settings`. The C APIs
+/// return it from `ckzg_settings_version`, so that callers can check it against their header.
+pub const CKZG_SETTINGS_VERSION: u32 = 2;
+
 #[repr(C)]
 pub struct CKZGSettings {
     pub fs: *const CFFTSettings,
     pub g1_values: *mut blst_p1,
     pub g2_values: *mut blst_p2,
+    /// Number of points in `g1_values`.
+    pub num_g1_points: usize,
+    /// Settings owned by the backend that loaded them, which keep the monomial points and the
+    /// EIP-7594 tables computed on first use. Null for settings assembled by the caller.
+    pub backend_settings: *const c_void,
 }
 
 ////////////////////////////// Utility functions for EIP-4844 //////////////////////////////
 
-pub fn load_trusted_setup_string(contents: &str) -> (Vec<u8>, Vec<u8>) {
-    let mut lines = contents.lines();
-    let length = lines.next().unwrap().parse::<usize>().unwrap();
-    let n2 = lines.next().unwrap().parse::<usize>().unwrap();
-
-    let g1_bytes = (0..length)
-        .flat_map(|_| {
-            let line = lines.next().unwrap();
-            assert_eq!(line.len(), 96);
-            (0..line.len())
-                .step_by(2)
-                .map(|i| u8::from_str_radix(&line[i..i + 2], 16).unwrap())
-                .collect::<Vec<u8>>()
+fn encode_hex_point(bytes: &[u8], out: &mut String) {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    for byte in bytes {
+        out.push(DIGITS[(byte >> 4) as usize] as char);
+        out.push(DIGITS[(byte & 0xf) as usize] as char);
+    }
+    out.push('\n');
+}
+
+fn decode_hex_point(hex: &str, expected_len: usize, line: usize) -> Result<Vec<u8>, Error> {
+    let hex = hex.as_bytes();
+    if hex.len() != expected_len * 2 {
+        return Err(Error::InvalidTrustedSetup {
+            line,
+            reason: "point has an unexpected length",
+        });
+    }
+
+    fn nibble(c: u8) -> Option<u8> {
+        match c {
+            b'0'..=b'9' => Some(c - b'0'),
+            b'a'..=b'f' => Some(c - b'a' + 10),
+            b'A'..=b'F' => Some(c - b'A' + 10),
+            _ => None,
+        }
+    }
+
+    hex.chunks(2)
+        .map(|pair| match (nibble(pair[0]), nibble(pair[1])) {
+            (Some(hi), Some(lo)) => Ok((hi << 4) | lo),
+            _ => Err(Error::InvalidTrustedSetup {
+                line,
+                reason: "point is not valid hex",
+            }),
         })
-        .collect::<Vec<u8>>();
-
-    let g2_bytes = (0..n2)
-        .flat_map(|_| {
-            let line = lines.next().unwrap();
-            assert_eq!(line.len(), 192);
-            (0..line.len())
-                .step_by(2)
-                .map(|i| u8::from_str_radix(&line[i..i + 2], 16).unwrap())
-                .collect::<Vec<u8>>()
+        .collect()
+}
+
+/// Parses a trusted setup in the `trusted_setup.txt` format: the number of G1 points, the number
+/// of G2 points, then one hex-encoded compressed point per line.
+///
+/// Returns the concatenated G1 and G2 point bytes, or [`Error::InvalidTrustedSetup`] pointing at
+/// the first line that could not be parsed.
+pub fn load_trusted_setup_string(contents: &str) -> Result<(Vec<u8>, Vec<u8>), Error> {
+    let mut lines = contents.lines().enumerate().map(|(i, line)| (i + 1, line));
+    let mut next_line = |reason| {
+        lines.next().ok_or_else(|| Error::InvalidTrustedSetup {
+            line: contents.lines().count() + 1,
+            reason,
         })
-        .collect::<Vec<u8>>();
+    };
+
+    let mut parse_count = || {
+        let (line, count) = next_line("missing point count")?;
+        count
+            .trim()
+            .parse::<usize>()
+            .map_err(|_| Error::InvalidTrustedSetup {
+                line,
+                reason: "point count is not a number",
+            })
+    };
+    let n1 = parse_count()?;
+    let n2 = parse_count()?;
 
-    (g1_bytes, g2_bytes)
+    let mut g1_bytes = Vec::with_capacity(n1 * BYTES_PER_G1);
+    for _ in 0..n1 {
+        let (line, hex) = next_line("missing G1 point")?;
+        g1_bytes.extend(decode_hex_point(hex.trim(), BYTES_PER_G1, line)?);
+    }
+
+    let mut g2_bytes = Vec::with_capacity(n2 * BYTES_PER_G2);
+    for _ in 0..n2 {
+        let (line, hex) = next_line("missing G2 point")?;
+        g2_bytes.extend(decode_hex_point(hex.trim(), BYTES_PER_G2, line)?);
+    }
+
+    Ok((g1_bytes, g2_bytes))
+}
+
+/// Writes a trusted setup in the `trusted_setup.txt` format read by [`load_trusted_setup_string`].
+/// The G1 points are expected in monomial form, which the loaders turn into Lagrange form.
+pub fn trusted_setup_to_string<TG1: G1, TG2: G2>(g1: &[TG1], g2: &[TG2]) -> String {
+    let mut out = String::with_capacity(
+        16 + g1.len() * (BYTES_PER_G1 * 2 + 1) + g2.len() * (BYTES_PER_G2 * 2 + 1),
+    );
+    out.push_str(&format!("{}\n{}\n", g1.len(), g2.len()));
+    for point in g1 {
+        encode_hex_point(&point.to_bytes(), &mut out);
+    }
+    for point in g2 {
+        encode_hex_point(&point.to_bytes(), &mut out);
+    }
+    out
+}
+
+/// Parses a trusted setup in the `trusted_setup.json` format published by the KZG ceremony,
+/// reading the `g1_lagrange` and `g2_monomial` arrays of `0x`-prefixed hex strings. Other
+/// fields, such as `g1_monomial`, are ignored.
+///
+/// Returns the concatenated G1 and G2 point bytes in the same layout as
+/// [`load_trusted_setup_string`]. Unlike there, the G1 points are in bit-reversed Lagrange form,
+/// so they must be loaded with a backend's Lagrange loader rather than the monomial one.
+pub fn load_trusted_setup_json(contents: &str) -> Result<(Vec<u8>, Vec<u8>), Error> {
+    let g1_bytes = parse_json_point_array(contents, "g1_lagrange", BYTES_PER_G1)?;
+    let g2_bytes = parse_json_point_array(contents, "g2_monomial", BYTES_PER_G2)?;
+    Ok((g1_bytes, g2_bytes))
+}
+
+/// Decodes the array of hex strings stored under `key`. Only the subset of JSON used by the
+/// ceremony output is understood, which is enough to locate the array without a full parser.
+fn parse_json_point_array(
+    contents: &str,
+    key: &'static str,
+    point_len: usize,
+) -> Result<Vec<u8>, Error> {
+    let bytes = contents.as_bytes();
+    let line_at = |pos: usize| bytes[..pos].iter().filter(|&&c| c == b'\n').count() + 1;
+    let error_at = |pos: usize, reason| Error::InvalidTrustedSetup {
+        line: line_at(pos.min(bytes.len())),
+        reason,
+    };
+    let skip_whitespace = |mut pos: usize| {
+        while pos < bytes.len() && bytes[pos].is_ascii_whitespace() {
+            pos += 1;
+        }
+        pos
+    };
+
+    let mut quoted_key = String::with_capacity(key.len() + 2);
+    quoted_key.push('"');
+    quoted_key.push_str(key);
+    quoted_key.push('"');
+    let key_pos = contents
+        .find(&quoted_key)
+        .ok_or_else(|| error_at(bytes.len(), "missing point array"))?;
+
+    let mut pos = skip_whitespace(key_pos + quoted_key.len());
+    if bytes.get(pos) != Some(&b':') {
+        return Err(error_at(pos, "expected ':'"));
+    }
+    pos = skip_whitespace(pos + 1);
+    if bytes.get(pos) != Some(&b'[') {
+        return Err(error_at(pos, "expected '['"));
+    }
+    pos = skip_whitespace(pos + 1);
+
+    let mut out = Vec::new();
+    if bytes.get(pos) == Some(&b']') {
+        return Ok(out);
+    }
+    loop {
+        if bytes.get(pos) != Some(&b'"') {
+            return Err(error_at(pos, "expected a hex string"));
+        }
+        let start = pos + 1;
+        let len = bytes[start..]
+            .iter()
+            .position(|&c| c == b'"')
+            .ok_or_else(|| error_at(bytes.len(), "unterminated string"))?;
+        let value = &contents[start..start + len];
+        let hex = value.strip_prefix("0x").unwrap_or(value);
+        out.extend(decode_hex_point(hex, point_len, line_at(start))?);
+
+        pos = skip_whitespace(start + len + 1);
+        match bytes.get(pos) {
+            Some(b',') => pos = skip_whitespace(pos + 1),
+            Some(b']') => return Ok(out),
+            _ => return Err(error_at(pos, "expected ',' or ']'")),
+        }
+    }
 }
 
 pub fn bytes_of_uint64(out: &mut [u8], mut n: u64) {
@@ -151,3 +390,777 @@ macro_rules! cfg_into_iter {
         result
     }};
 }
+
+////////////////////////////// Trait based implementations of functions for EIP-4844 //////////////////////////////
+
+pub fn hash_to_bls_field<TFr: Fr>(x: &[u8; BYTES_PER_FIELD_ELEMENT]) -> TFr {
+    TFr::from_bytes_unchecked(x).unwrap()
+}
+
+pub fn bytes_to_blob<TFr: Fr>(bytes: &[u8]) -> Result<Vec<TFr>, Error> {
+    bytes_to_blob_with_preset(bytes, &BlobPreset::default())
+}
+
+pub fn bytes_to_blob_with_preset<TFr: Fr>(
+    bytes: &[u8],
+    preset: &BlobPreset,
+) -> Result<Vec<TFr>, Error> {
+    if bytes.len() != preset.bytes_per_blob() {
+        return Err(Error::InvalidLength {
+            expected: preset.bytes_per_blob(),
+            actual: bytes.len(),
+        });
+    }
+
+    bytes
+        .chunks(BYTES_PER_FIELD_ELEMENT)
+        .map(TFr::from_bytes)
+        .collect()
+}
+
+pub fn compute_powers<TFr: Fr>(base: &TFr, num_powers: usize) -> Vec<TFr> {
+    let mut powers: Vec<TFr> = vec![TFr::default(); num_powers];
+    if num_powers == 0 {
+        return powers;
+    }
+    powers[0] = TFr::one();
+    for i in 1..num_powers {
+        powers[i] = powers[i - 1].mul(base);
+    }
+
+    powers
+}
+
+/// Returns the blob as the polynomial in evaluation form it encodes.
+pub fn blob_to_polynomial_rust<TFr: Fr>(blob: &[TFr]) -> PolyEval<TFr> {
+    PolyEval::new(blob.to_vec()).unwrap()
+}
+
+pub fn evaluate_polynomial_in_evaluation_form_rust<
+    TFr: Fr,
+    TG1: G1,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+>(
+    p: &PolyEval<TFr>,
+    x: &TFr,
+    s: &TKZGSettings,
+) -> TFr {
+    assert_eq!(p.evals().len(), s.get_blob_preset().field_elements_per_blob);
+    p.evaluate(x, s.get_fft_settings()).unwrap()
+}
+
+fn compute_challenge<TFr: Fr, TG1: G1>(blob: &[TFr], commitment: &TG1, preset: &BlobPreset) -> TFr {
+    let mut bytes: Vec<u8> = vec![0; preset.challenge_input_size()];
+
+    // Copy domain separator
+    bytes[..16].copy_from_slice(&FIAT_SHAMIR_PROTOCOL_DOMAIN);
+    bytes_of_uint64(&mut bytes[16..24], preset.field_elements_per_blob as u64);
+    // Set all other bytes of this 16-byte (little-endian) field to zero
+    bytes_of_uint64(&mut bytes[24..32], 0);
+
+    // Copy blob
+    for i in 0..blob.len() {
+        let v = blob[i].to_bytes();
+        bytes[(32 + i * BYTES_PER_FIELD_ELEMENT)..(32 + (i + 1) * BYTES_PER_FIELD_ELEMENT)]
+            .copy_from_slice(&v);
+    }
+
+    // Copy commitment
+    let v = commitment.to_bytes();
+    for i in 0..v.len() {
+        bytes[32 + preset.bytes_per_blob() + i] = v[i];
+    }
+
+    // Now let's create the challenge!
+    let eval_challenge = hash(&bytes);
+    hash_to_bls_field(&eval_challenge)
+}
+
+fn compute_r_powers<TFr: Fr, TG1: G1>(
+    commitments_g1: &[TG1],
+    zs_fr: &[TFr],
+    ys_fr: &[TFr],
+    proofs_g1: &[TG1],
+    preset: &BlobPreset,
+) -> Vec<TFr> {
+    let n = commitments_g1.len();
+    let input_size =
+        32 + n * (BYTES_PER_COMMITMENT + 2 * BYTES_PER_FIELD_ELEMENT + BYTES_PER_PROOF);
+
+    #[allow(unused_assignments)]
+    let mut offset = 0;
+    let mut bytes: Vec<u8> = vec![0; input_size];
+
+    // Copy domain separator
+    bytes[..16].copy_from_slice(&RANDOM_CHALLENGE_KZG_BATCH_DOMAIN);
+    bytes_of_uint64(&mut bytes[16..24], preset.field_elements_per_blob as u64);
+    bytes_of_uint64(&mut bytes[24..32], n as u64);
+    offset = 32;
+
+    for i in 0..n {
+        // Copy commitment
+        let v = commitments_g1[i].to_bytes();
+        bytes[offset..(v.len() + offset)].copy_from_slice(&v[..]);
+        offset += BYTES_PER_COMMITMENT;
+
+        // Copy evaluation challenge
+        let v = zs_fr[i].to_bytes();
+        bytes[offset..(v.len() + offset)].copy_from_slice(&v[..]);
+        offset += BYTES_PER_FIELD_ELEMENT;
+
+        // Copy polynomial's evaluation value
+        let v = ys_fr[i].to_bytes();
+        bytes[offset..(v.len() + offset)].copy_from_slice(&v[..]);
+        offset += BYTES_PER_FIELD_ELEMENT;
+
+        // Copy proof
+        let v = proofs_g1[i].to_bytes();
+        bytes[offset..(v.len() + offset)].copy_from_slice(&v[..]);
+        offset += BYTES_PER_PROOF;
+    }
+
+    // Make sure we wrote the entire buffer
+    assert_eq!(offset, input_size);
+
+    // Now let's create the challenge!
+    let eval_challenge = hash(&bytes);
+    let r = hash_to_bls_field(&eval_challenge);
+    compute_powers(&r, n)
+}
+
+pub fn blob_to_kzg_commitment_rust<
+    TFr: Fr,
+    TG1: G1 + G1LinComb<TFr>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+>(
+    blob: &[TFr],
+    s: &TKZGSettings,
+) -> TG1 {
+    let field_elements_per_blob = s.get_blob_preset().field_elements_per_blob;
+    assert_eq!(blob.len(), field_elements_per_blob);
+    // Goes through the settings so that backends can use precomputed tables for the setup
+    s.commit_to_poly_eval(&blob_to_polynomial_rust(blob))
+        .unwrap()
+}
+
+pub fn compute_kzg_proof_rust<
+    TFr: Fr,
+    TG1: G1 + G1LinComb<TFr>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+>(
+    blob: &[TFr],
+    z: &TFr,
+    s: &TKZGSettings,
+) -> (TG1, TFr) {
+    let field_elements_per_blob = s.get_blob_preset().field_elements_per_blob;
+    assert_eq!(blob.len(), field_elements_per_blob);
+
+    let (quotient, y) = blob_to_polynomial_rust(blob)
+        .div_by_linear(z, s.get_fft_settings())
+        .unwrap();
+    let proof = s.commit_to_poly_eval(&quotient).unwrap();
+    (proof, y)
+}
+
+/// Splits blob changes `(index, old, new)` into the Lagrange points and the differences
+/// `new - old` they are multiplied with.
+fn blob_changes_to_deltas<
+    TFr: Fr,
+    TG1: G1,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+>(
+    changes: &[(usize, TFr, TFr)],
+    s: &TKZGSettings,
+) -> Result<(Vec<TG1>, Vec<TFr>), Error> {
+    if s.get_basis() != SetupBasis::Lagrange {
+        return Err(Error::SetupBasisMismatch {
+            expected: SetupBasis::Lagrange,
+            actual: s.get_basis(),
+        });
+    }
+
+    let field_elements_per_blob = s.get_blob_preset().field_elements_per_blob;
+    let secret_g1 = s.get_g1_secret();
+
+    changes
+        .iter()
+        .map(|(index, old, new)| {
+            if *index >= field_elements_per_blob {
+                return Err(Error::InvalidArgument("Blob element index out of range"));
+            }
+            Ok((secret_g1[*index].clone(), new.sub(old)))
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(|pairs| pairs.into_iter().unzip())
+}
+
+/// Updates the commitment to a blob after its elements changed, without the blob itself.
+///
+/// Every change `(index, old, new)` adds `(new - old)` times the Lagrange point of `index`, so
+/// the cost only depends on the number of changes. Changes to the same index accumulate.
+pub fn update_kzg_commitment_rust<
+    TFr: Fr,
+    TG1: G1 + G1LinComb<TFr>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+>(
+    commitment: &TG1,
+    changes: &[(usize, TFr, TFr)],
+    s: &TKZGSettings,
+) -> Result<TG1, Error> {
+    if !commitment.is_valid() {
+        return Err(Error::InvalidArgument("Invalid commitment"));
+    }
+
+    let (points, deltas) = blob_changes_to_deltas(changes, s)?;
+    Ok(commitment.add(&TG1::g1_lincomb(&points, &deltas, points.len())))
+}
+
+/// Terms of the opening proofs of the Lagrange polynomials at a fixed `z`, from which
+/// [`update_kzg_proof_rust`] updates a proof at `z`.
+///
+/// With `Z = X^n - 1` and `L_i = w_i / n * Z / (X - w_i)`, the proof of `L_i` commits to
+/// `(L_i - L_i(z)) / (X - z) = (w_i / n * H - L_i) / (z - w_i)`, where `H = (Z - Z(z)) / (X - z)`.
+/// Only the commitment to `H` is precomputed, plus the proof of `L_m` if `z = w_m` is in the
+/// domain, where the formula does not apply.
+#[derive(Debug, Clone)]
+pub struct ProofUpdateTerms<TFr, TG1> {
+    z: TFr,
+    /// `Z(z)`, which is zero inside the domain
+    vanishing_at_z: TFr,
+    /// Commitment to `H`
+    h: TG1,
+    /// `m` and the proof of `L_m` if `z = w_m`
+    in_domain: Option<(usize, TG1)>,
+}
+
+impl<TFr, TG1> ProofUpdateTerms<TFr, TG1> {
+    pub fn z(&self) -> &TFr {
+        &self.z
+    }
+}
+
+/// Precomputes the terms to update proofs at `z`, which takes a multi-scalar multiplication over
+/// the whole setup once.
+pub fn precompute_proof_update_terms_rust<
+    TFr: Fr,
+    TG1: G1 + G1LinComb<TFr>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+>(
+    z: &TFr,
+    s: &TKZGSettings,
+) -> Result<ProofUpdateTerms<TFr, TG1>, Error> {
+    if s.get_basis() != SetupBasis::Lagrange {
+        return Err(Error::SetupBasisMismatch {
+            expected: SetupBasis::Lagrange,
+            actual: s.get_basis(),
+        });
+    }
+
+    let field_elements_per_blob = s.get_blob_preset().field_elements_per_blob;
+    let roots = &s.get_fft_settings().get_roots_of_unity()[..field_elements_per_blob];
+    let secret_g1 = &s.get_g1_secret()[..field_elements_per_blob];
+    let vanishing_at_z = z.pow(field_elements_per_blob).sub(&TFr::one());
+
+    match roots.iter().position(|root| root.equals(z)) {
+        // H = Z / (X - w_m) = n / w_m * L_m
+        Some(m) => {
+            let h =
+                secret_g1[m].mul(&TFr::from_u64(field_elements_per_blob as u64).div(&roots[m])?);
+            let mut lagrange = vec![TFr::zero(); field_elements_per_blob];
+            lagrange[m] = TFr::one();
+            let (quotient, _) =
+                blob_to_polynomial_rust(&lagrange).div_by_linear(z, s.get_fft_settings())?;
+            Ok(ProofUpdateTerms {
+                z: z.clone(),
+                vanishing_at_z,
+                h,
+                in_domain: Some((m, s.commit_to_poly_eval(&quotient)?)),
+            })
+        }
+        // H(w_i) = Z(z) / (z - w_i)
+        None => {
+            let mut evals = roots.iter().map(|root| z.sub(root)).collect::<Vec<_>>();
+            TFr::batch_inverse(&mut evals);
+            for eval in evals.iter_mut() {
+                *eval = eval.mul(&vanishing_at_z);
+            }
+            Ok(ProofUpdateTerms {
+                z: z.clone(),
+                vanishing_at_z,
+                h: TG1::g1_lincomb(secret_g1, &evals, field_elements_per_blob),
+                in_domain: None,
+            })
+        }
+    }
+}
+
+/// Updates an opening proof at the point of `terms` and its evaluation `y` for the same changes
+/// as [`update_kzg_commitment_rust`].
+///
+/// Proofs and evaluations are linear in the blob, so every change `(index, old, new)` adds
+/// `(new - old)` times the proof and evaluation of the Lagrange polynomial of `index`. These
+/// follow from `terms`, so like the commitment update the cost only depends on the number of
+/// changes.
+pub fn update_kzg_proof_rust<
+    TFr: Fr,
+    TG1: G1 + G1LinComb<TFr>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+>(
+    proof: &TG1,
+    y: &TFr,
+    changes: &[(usize, TFr, TFr)],
+    terms: &ProofUpdateTerms<TFr, TG1>,
+    s: &TKZGSettings,
+) -> Result<(TG1, TFr), Error> {
+    if !proof.is_valid() {
+        return Err(Error::InvalidArgument("Invalid proof"));
+    }
+
+    let (lagrange_points, deltas) = blob_changes_to_deltas(changes, s)?;
+    let n_inverse = TFr::from_u64(s.get_blob_preset().field_elements_per_blob as u64).inverse();
+    let in_domain_index = terms.in_domain.as_ref().map(|(m, _)| *m);
+
+    // 1 / (z - w_i), which batch inversion leaves as zero for i = m
+    let mut inverses = changes
+        .iter()
+        .map(|(index, _, _)| terms.z.sub(&s.get_roots_of_unity_at(*index)))
+        .collect::<Vec<_>>();
+    TFr::batch_inverse(&mut inverses);
+
+    let mut points = Vec::with_capacity(changes.len() + 2);
+    let mut scalars = Vec::with_capacity(changes.len() + 2);
+    let mut h_scalar = TFr::zero();
+    let mut in_domain_delta = TFr::zero();
+    for (((index, _, _), point), (delta, inverse)) in changes
+        .iter()
+        .zip(lagrange_points)
+        .zip(deltas.iter().zip(&inverses))
+    {
+        if Some(*index) == in_domain_index {
+            in_domain_delta = in_domain_delta.add(delta);
+            continue;
+        }
+
+        let scaled = delta.mul(inverse);
+        h_scalar = h_scalar.add(&scaled.mul(&s.get_roots_of_unity_at(*index)).mul(&n_inverse));
+        points.push(point);
+        scalars.push(scaled.negate());
+    }
+
+    // L_i(z) = w_i / n * Z(z) / (z - w_i), and L_m(w_m) = 1
+    let delta_y = h_scalar.mul(&terms.vanishing_at_z).add(&in_domain_delta);
+    points.push(terms.h.clone());
+    scalars.push(h_scalar);
+    if let Some((_, lagrange_proof)) = &terms.in_domain {
+        points.push(lagrange_proof.clone());
+        scalars.push(in_domain_delta);
+    }
+
+    let delta_proof = TG1::g1_lincomb(&points, &scalars, points.len());
+    Ok((proof.add(&delta_proof), y.add(&delta_y)))
+}
+
+pub fn compute_blob_kzg_proof_rust<
+    TFr: Fr,
+    TG1: G1 + G1LinComb<TFr>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+>(
+    blob: &[TFr],
+    commitment: &TG1,
+    ts: &TKZGSettings,
+) -> Result<TG1, Error> {
+    if !commitment.is_valid() {
+        return Err(Error::InvalidArgument("Invalid commitment"));
+    }
+
+    let evaluation_challenge_fr = compute_challenge(blob, commitment, ts.get_blob_preset());
+    let (proof, _) = compute_kzg_proof_rust(blob, &evaluation_challenge_fr, ts);
+    Ok(proof)
+}
+
+pub fn verify_kzg_proof_rust<
+    TFr: Fr,
+    TG1: G1,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+>(
+    commitment: &TG1,
+    z: &TFr,
+    y: &TFr,
+    proof: &TG1,
+    s: &TKZGSettings,
+) -> Result<bool, Error> {
+    if !commitment.is_valid() {
+        return Err(Error::InvalidArgument("Invalid commitment"));
+    }
+    if !proof.is_valid() {
+        return Err(Error::InvalidArgument("Invalid proof"));
+    }
+
+    Ok(s.check_proof_single(commitment, proof, z, y)
+        .unwrap_or(false))
+}
+
+pub fn verify_blob_kzg_proof_rust<
+    TFr: Fr,
+    TG1: G1,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+>(
+    blob: &[TFr],
+    commitment_g1: &TG1,
+    proof_g1: &TG1,
+    ts: &TKZGSettings,
+) -> Result<bool, Error> {
+    if !commitment_g1.is_valid() {
+        return Err(Error::InvalidArgument("Invalid commitment"));
+    }
+    if !proof_g1.is_valid() {
+        return Err(Error::InvalidArgument("Invalid proof"));
+    }
+
+    let polynomial = blob_to_polynomial_rust(blob);
+    let evaluation_challenge_fr = compute_challenge(blob, commitment_g1, ts.get_blob_preset());
+    let y_fr =
+        evaluate_polynomial_in_evaluation_form_rust(&polynomial, &evaluation_challenge_fr, ts);
+    verify_kzg_proof_rust(commitment_g1, &evaluation_challenge_fr, &y_fr, proof_g1, ts)
+}
+
+pub fn verify_kzg_proof_batch<
+    TFr: Fr,
+    TG1: G1 + G1LinComb<TFr> + PairingVerify<TG1, TG2>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+>(
+    commitments_g1: &[TG1],
+    zs_fr: &[TFr],
+    ys_fr: &[TFr],
+    proofs_g1: &[TG1],
+    ts: &TKZGSettings,
+) -> bool {
+    let n = commitments_g1.len();
+    let mut c_minus_y: Vec<TG1> = Vec::with_capacity(n);
+    let mut r_times_z: Vec<TFr> = Vec::with_capacity(n);
+
+    // Compute the random lincomb challenges
+    let r_powers = compute_r_powers(
+        commitments_g1,
+        zs_fr,
+        ys_fr,
+        proofs_g1,
+        ts.get_blob_preset(),
+    );
+
+    // Compute \sum r^i * Proof_i
+    let proof_lincomb = TG1::g1_lincomb(proofs_g1, &r_powers, n);
+
+    for i in 0..n {
+        // Get [y_i]
+        let ys_encrypted = TG1::generator().mul(&ys_fr[i]);
+        // Get C_i - [y_i]
+        c_minus_y.push(commitments_g1[i].sub(&ys_encrypted));
+        // Get r^i * z_i
+        r_times_z.push(r_powers[i].mul(&zs_fr[i]));
+    }
+
+    // Get \sum r^i z_i Proof_i
+    let proof_z_lincomb = TG1::g1_lincomb(proofs_g1, &r_times_z, n);
+    // Get \sum r^i (C_i - [y_i])
+    let mut c_minus_y_lincomb = TG1::g1_lincomb(&c_minus_y, &r_powers, n);
+
+    // Get C_minus_y_lincomb + proof_z_lincomb
+    let rhs_g1 = c_minus_y_lincomb.add_or_dbl(&proof_z_lincomb);
+
+    // Do the pairing check!
+    TG1::verify(
+        &proof_lincomb,
+        &ts.get_g2_secret()[1],
+        &rhs_g1,
+        &TG2::generator(),
+    )
+}
+
+fn compute_challenges_and_evaluate_polynomial<
+    TFr: Fr,
+    TG1: G1,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+>(
+    blobs: &[Vec<TFr>],
+    commitments_g1: &[TG1],
+    ts: &TKZGSettings,
+) -> (Vec<TFr>, Vec<TFr>) {
+    let mut evaluation_challenges_fr = Vec::with_capacity(blobs.len());
+    let mut ys_fr = Vec::with_capacity(blobs.len());
+
+    for i in 0..blobs.len() {
+        let polynomial = blob_to_polynomial_rust(&blobs[i]);
+        let evaluation_challenge_fr =
+            compute_challenge(&blobs[i], &commitments_g1[i], ts.get_blob_preset());
+        let y_fr =
+            evaluate_polynomial_in_evaluation_form_rust(&polynomial, &evaluation_challenge_fr, ts);
+
+        evaluation_challenges_fr.push(evaluation_challenge_fr);
+        ys_fr.push(y_fr);
+    }
+
+    (evaluation_challenges_fr, ys_fr)
+}
+
+fn validate_batched_input<TG1: G1 + Sync>(
+    commitments: &[TG1],
+    proofs: &[TG1],
+) -> Result<(), Error> {
+    let invalid_commitment = cfg_into_iter!(commitments).any(|commitment| !commitment.is_valid());
+    let invalid_proof = cfg_into_iter!(proofs).any(|proof| !proof.is_valid());
+
+    if invalid_commitment {
+        return Err(Error::InvalidArgument("Invalid commitment"));
+    }
+    if invalid_proof {
+        return Err(Error::InvalidArgument("Invalid proof"));
+    }
+
+    Ok(())
+}
+
+pub fn verify_blob_kzg_proof_batch_rust<
+    TFr: Fr + Sync,
+    TG1: G1 + G1LinComb<TFr> + PairingVerify<TG1, TG2> + Sync,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly> + Sync,
+>(
+    blobs: &[Vec<TFr>],
+    commitments_g1: &[TG1],
+    proofs_g1: &[TG1],
+    ts: &TKZGSettings,
+) -> Result<bool, Error> {
+    // Exit early if we are given zero blobs
+    if blobs.is_empty() {
+        return Ok(true);
+    }
+
+    if blobs.len() != commitments_g1.len() || blobs.len() != proofs_g1.len() {
+        return Err(Error::InvalidArgument("Invalid amount of arguments"));
+    }
+
+    // For a single blob, just do a regular single verification
+    if blobs.len() == 1 {
+        return verify_blob_kzg_proof_rust(&blobs[0], &commitments_g1[0], &proofs_g1[0], ts);
+    }
+
+    #[cfg(feature = "parallel")]
+    {
+        let num_blobs = blobs.len();
+        let num_cores = num_cpus::get_physical();
+
+        return if num_blobs > num_cores {
+            validate_batched_input(commitments_g1, proofs_g1)?;
+
+            // Process blobs in parallel subgroups
+            let blobs_per_group = num_blobs / num_cores;
+
+            Ok(blobs
+                .par_chunks(blobs_per_group)
+                .enumerate()
+                .all(|(i, blob_group)| {
+                    let num_blobs_in_group = blob_group.len();
+                    let commitment_group = &commitments_g1
+                        [blobs_per_group * i..blobs_per_group * i + num_blobs_in_group];
+                    let proof_group =
+                        &proofs_g1[blobs_per_group * i..blobs_per_group * i + num_blobs_in_group];
+                    let (evaluation_challenges_fr, ys_fr) =
+                        compute_challenges_and_evaluate_polynomial::<TFr, TG1, TG2, TPoly, _, _>(
+                            blob_group,
+                            commitment_group,
+                            ts,
+                        );
+
+                    verify_kzg_proof_batch(
+                        commitment_group,
+                        &evaluation_challenges_fr,
+                        &ys_fr,
+                        proof_group,
+                        ts,
+                    )
+                }))
+        } else {
+            // Each group contains either one or zero blobs, so iterate
+            // over the single blob verification function in parallel
+            let results = (blobs, commitments_g1, proofs_g1)
+                .into_par_iter()
+                .map(|(blob, commitment, proof)| {
+                    verify_blob_kzg_proof_rust(blob, commitment, proof, ts)
+                })
+                .collect::<Result<Vec<bool>, Error>>()?;
+
+            Ok(results.into_iter().all(|result| result))
+        };
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    {
+        validate_batched_input(commitments_g1, proofs_g1)?;
+        let (evaluation_challenges_fr, ys_fr) =
+            compute_challenges_and_evaluate_polynomial(blobs, commitments_g1, ts);
+
+        Ok(verify_kzg_proof_batch(
+            commitments_g1,
+            &evaluation_challenges_fr,
+            &ys_fr,
+            proofs_g1,
+            ts,
+        ))
+    }
+}
+
+////////////////////////////// Point evaluation precompile for EIP-4844 //////////////////////////////
+
+/// Reasons for the point evaluation precompile to reject its input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PointEvaluationError {
+    /// The input was not [`POINT_EVALUATION_INPUT_LENGTH`] bytes long.
+    InvalidInputLength(usize),
+    /// The versioned hash is not `kzg_to_versioned_hash(commitment)`.
+    VersionedHashMismatch,
+    /// `z` could not be decoded into a field element.
+    InvalidZ(Error),
+    /// `y` could not be decoded into a field element.
+    InvalidY(Error),
+    /// The commitment could not be decoded into a G1 point of the subgroup.
+    InvalidCommitment(Error),
+    /// The proof could not be decoded into a G1 point of the subgroup.
+    InvalidProof(Error),
+    /// The input is well formed, but the proof does not open the commitment to `y` at `z`.
+    VerificationFailed,
+}
+
+impl fmt::Display for PointEvaluationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PointEvaluationError::InvalidInputLength(actual) => write!(
+                f,
+                "Invalid input length. Expected {}, got {}",
+                POINT_EVALUATION_INPUT_LENGTH, actual
+            ),
+            PointEvaluationError::VersionedHashMismatch => {
+                write!(f, "Versioned hash does not match the commitment")
+            }
+            PointEvaluationError::InvalidZ(err) => write!(f, "Invalid z: {}", err),
+            PointEvaluationError::InvalidY(err) => write!(f, "Invalid y: {}", err),
+            PointEvaluationError::InvalidCommitment(err) => {
+                write!(f, "Invalid commitment: {}", err)
+            }
+            PointEvaluationError::InvalidProof(err) => write!(f, "Invalid proof: {}", err),
+            PointEvaluationError::VerificationFailed => write!(f, "Proof verification failed"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PointEvaluationError {}
+
+/// Computes the versioned hash of a commitment, as stored in blob transactions: the SHA-256 hash
+/// of the commitment with its first byte replaced by [`VERSIONED_HASH_VERSION_KZG`].
+pub fn kzg_to_versioned_hash(commitment: &[u8; BYTES_PER_COMMITMENT]) -> [u8; 32] {
+    let mut versioned_hash = hash(commitment);
+    versioned_hash[0] = VERSIONED_HASH_VERSION_KZG;
+    versioned_hash
+}
+
+/// Runs the `0x0A` point evaluation precompile on `versioned_hash || z || y || commitment || proof`.
+/// As in the EIP, `z` and `y` are big-endian, unlike the little-endian field elements of blobs.
+///
+/// On success returns `FIELD_ELEMENTS_PER_BLOB || BLS_MODULUS`, both as big-endian 256-bit
+/// integers. The number of field elements is taken from the blob preset of `s`.
+pub fn point_evaluation_precompile_rust<
+    TFr: Fr,
+    TG1: G1,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly>,
+>(
+    input: &[u8],
+    s: &TKZGSettings,
+) -> Result<[u8; POINT_EVALUATION_OUTPUT_LENGTH], PointEvaluationError> {
+    if input.len() != POINT_EVALUATION_INPUT_LENGTH {
+        return Err(PointEvaluationError::InvalidInputLength(input.len()));
+    }
+
+    let (versioned_hash, input) = input.split_at(32);
+    let (z_bytes, input) = input.split_at(BYTES_PER_FIELD_ELEMENT);
+    let (y_bytes, input) = input.split_at(BYTES_PER_FIELD_ELEMENT);
+    let (commitment_bytes, proof_bytes) = input.split_at(BYTES_PER_COMMITMENT);
+
+    if kzg_to_versioned_hash(commitment_bytes.try_into().unwrap()) != versioned_hash {
+        return Err(PointEvaluationError::VersionedHashMismatch);
+    }
+
+    let z = fr_from_be_bytes(z_bytes).map_err(PointEvaluationError::InvalidZ)?;
+    let y = fr_from_be_bytes(y_bytes).map_err(PointEvaluationError::InvalidY)?;
+    let commitment = TG1::from_bytes(commitment_bytes)
+        .and_then(validate_subgroup)
+        .map_err(PointEvaluationError::InvalidCommitment)?;
+    let proof = TG1::from_bytes(proof_bytes)
+        .and_then(validate_subgroup)
+        .map_err(PointEvaluationError::InvalidProof)?;
+
+    if verify_kzg_proof_rust(&commitment, &z, &y, &proof, s) != Ok(true) {
+        return Err(PointEvaluationError::VerificationFailed);
+    }
+
+    let mut output = [0u8; POINT_EVALUATION_OUTPUT_LENGTH];
+    output[24..32]
+        .copy_from_slice(&(s.get_blob_preset().field_elements_per_blob as u64).to_be_bytes());
+    output[32..].copy_from_slice(&BLS_MODULUS_BYTES_BE);
+    Ok(output)
+}
+
+fn fr_from_be_bytes<TFr: Fr>(bytes: &[u8]) -> Result<TFr, Error> {
+    let mut bytes = bytes.to_vec();
+    bytes.reverse();
+    TFr::from_bytes(&bytes)
+}
+
+fn validate_subgroup<TG1: G1>(point: TG1) -> Result<TG1, Error> {
+    if point.is_valid() {
+        Ok(point)
+    } else {
+        Err(Error::PointNotInSubgroup)
+    }
+}
@@ -0,0 +1,126 @@
+//! Packing of arbitrary byte payloads into blobs.
+//!
+//! `bytes_to_blob` only accepts canonical field elements, so raw data cannot be used as a blob
+//! directly. Here every field element carries 31 bytes of data in its low bytes and a zero most
+//! significant byte, which keeps it below the modulus. The data stream starts with the payload
+//! length as a little-endian `u64`, is followed by the payload, and is zero-padded to fill a
+//! whole number of blobs.
+
+use alloc::vec::Vec;
+
+use super::{BlobPreset, BYTES_PER_FIELD_ELEMENT};
+use crate::{Error, Fr};
+
+/// Number of payload bytes carried by every field element.
+pub const BYTES_PER_ENCODED_FIELD_ELEMENT: usize = BYTES_PER_FIELD_ELEMENT - 1;
+
+/// Size of the payload length prefix at the start of the first blob.
+pub const ENCODED_LENGTH_HEADER_SIZE: usize = 8;
+
+/// Returns the largest payload that fits into `num_blobs` blobs.
+pub fn max_payload_size(num_blobs: usize, preset: &BlobPreset) -> usize {
+    (num_blobs * preset.field_elements_per_blob * BYTES_PER_ENCODED_FIELD_ELEMENT)
+        .saturating_sub(ENCODED_LENGTH_HEADER_SIZE)
+}
+
+/// Returns the number of blobs needed to encode a payload of `payload_len` bytes. Even an empty
+/// payload needs one blob for its length header.
+pub fn blobs_needed(payload_len: usize, preset: &BlobPreset) -> usize {
+    let bytes_per_blob = preset.field_elements_per_blob * BYTES_PER_ENCODED_FIELD_ELEMENT;
+    (ENCODED_LENGTH_HEADER_SIZE + payload_len + bytes_per_blob - 1) / bytes_per_blob
+}
+
+pub fn encode_payload<TFr: Fr>(payload: &[u8]) -> Vec<Vec<TFr>> {
+    encode_payload_with_preset(payload, &BlobPreset::default())
+}
+
+/// Splits `payload` over as many blobs as [`blobs_needed`] returns.
+pub fn encode_payload_with_preset<TFr: Fr>(payload: &[u8], preset: &BlobPreset) -> Vec<Vec<TFr>> {
+    let bytes_per_blob = preset.field_elements_per_blob * BYTES_PER_ENCODED_FIELD_ELEMENT;
+    let stream_len = blobs_needed(payload.len(), preset) * bytes_per_blob;
+
+    let mut stream = Vec::with_capacity(stream_len);
+    stream.extend_from_slice(&(payload.len() as u64).to_le_bytes());
+    stream.extend_from_slice(payload);
+    stream.resize(stream_len, 0);
+
+    stream
+        .chunks(bytes_per_blob)
+        .map(|blob_bytes| {
+            blob_bytes
+                .chunks(BYTES_PER_ENCODED_FIELD_ELEMENT)
+                .map(|chunk| {
+                    let mut bytes = [0u8; BYTES_PER_FIELD_ELEMENT];
+                    bytes[..BYTES_PER_ENCODED_FIELD_ELEMENT].copy_from_slice(chunk);
+                    // Below 2^248, so always canonical
+                    TFr::from_bytes(&bytes).unwrap()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+pub fn decode_payload<TFr: Fr>(blobs: &[Vec<TFr>]) -> Result<Vec<u8>, Error> {
+    decode_payload_with_preset(blobs, &BlobPreset::default())
+}
+
+/// Rebuilds the payload from blobs produced by [`encode_payload_with_preset`].
+///
+/// Only the exact output of the encoder is accepted: the number of blobs must match the encoded
+/// length, and the padding after the payload must be zero.
+pub fn decode_payload_with_preset<TFr: Fr>(
+    blobs: &[Vec<TFr>],
+    preset: &BlobPreset,
+) -> Result<Vec<u8>, Error> {
+    if blobs.is_empty() {
+        return Err(Error::InvalidArgument("At least one blob is required"));
+    }
+
+    let mut stream = Vec::with_capacity(
+        blobs.len() * preset.field_elements_per_blob * BYTES_PER_ENCODED_FIELD_ELEMENT,
+    );
+    for blob in blobs {
+        if blob.len() != preset.field_elements_per_blob {
+            return Err(Error::InvalidLength {
+                expected: preset.field_elements_per_blob,
+                actual: blob.len(),
+            });
+        }
+
+        for element in blob {
+            let bytes = element.to_bytes();
+            if bytes[BYTES_PER_ENCODED_FIELD_ELEMENT] != 0 {
+                return Err(Error::InvalidArgument(
+                    "Field element has a non-zero most significant byte",
+                ));
+            }
+            stream.extend_from_slice(&bytes[..BYTES_PER_ENCODED_FIELD_ELEMENT]);
+        }
+    }
+
+    if stream.len() < ENCODED_LENGTH_HEADER_SIZE {
+        return Err(Error::InvalidArgument(
+            "Blobs are too small for the length header",
+        ));
+    }
+    let mut header = [0u8; ENCODED_LENGTH_HEADER_SIZE];
+    header.copy_from_slice(&stream[..ENCODED_LENGTH_HEADER_SIZE]);
+    let payload_len = usize::try_from(u64::from_le_bytes(header))
+        .ok()
+        .filter(|&len| len <= stream.len() - ENCODED_LENGTH_HEADER_SIZE)
+        .ok_or(Error::InvalidArgument("Encoded length exceeds the blobs"))?;
+
+    if blobs.len() != blobs_needed(payload_len, preset) {
+        return Err(Error::InvalidArgument(
+            "Number of blobs does not match the encoded length",
+        ));
+    }
+    let payload_end = ENCODED_LENGTH_HEADER_SIZE + payload_len;
+    if stream[payload_end..].iter().any(|&byte| byte != 0) {
+        return Err(Error::InvalidArgument("Non-zero padding after the payload"));
+    }
+
+    stream.truncate(payload_end);
+    stream.drain(..ENCODED_LENGTH_HEADER_SIZE);
+    Ok(stream)
+}
@@ -0,0 +1,64 @@
+use crate::eip_4844::{hash, hash_to_bls_field, BYTES_PER_FIELD_ELEMENT, FIELD_ELEMENTS_PER_BLOB};
+use crate::{Fr, Vec, G1};
+
+////////////////////////////// Constant values for EIP-7594 //////////////////////////////
+
+pub const FIELD_ELEMENTS_PER_EXT_BLOB: usize = 2 * FIELD_ELEMENTS_PER_BLOB;
+pub const FIELD_ELEMENTS_PER_CELL: usize = 64;
+pub const BYTES_PER_CELL: usize = FIELD_ELEMENTS_PER_CELL * BYTES_PER_FIELD_ELEMENT;
+pub const CELLS_PER_EXT_BLOB: usize = FIELD_ELEMENTS_PER_EXT_BLOB / FIELD_ELEMENTS_PER_CELL;
+
+pub const RANDOM_CHALLENGE_KZG_CELL_BATCH_DOMAIN: [u8; 16] = [
+    82, 67, 75, 90, 71, 67, 66, 65, 84, 67, 72, 95, 95, 86, 49, 95,
+]; // "RCKZGCBATCH__V1_"
+
+////////////////////////////// C API for EIP-7594 //////////////////////////////
+
+#[repr(C)]
+pub struct Cell {
+    pub bytes: [u8; BYTES_PER_CELL],
+}
+
+////////////////////////////// Trait based implementations of functions for EIP-7594 //////////////////////////////
+
+/// Derives the Fiat-Shamir challenge for `verify_cell_kzg_proof_batch` from every input of the
+/// batch, as `compute_verify_cell_kzg_proof_batch_challenge` does in the specification.
+///
+/// `commitments` must already be deduplicated, with `commitment_indices[k]` pointing at the
+/// commitment of the `k`-th cell.
+pub fn compute_verify_cell_kzg_proof_batch_challenge<TFr: Fr, TG1: G1>(
+    field_elements_per_blob: usize,
+    commitments: &[TG1],
+    commitment_indices: &[usize],
+    cell_indices: &[usize],
+    cells: &[[TFr; FIELD_ELEMENTS_PER_CELL]],
+    proofs: &[TG1],
+) -> TFr {
+    let mut bytes = Vec::with_capacity(
+        RANDOM_CHALLENGE_KZG_CELL_BATCH_DOMAIN.len()
+            + 32
+            + commitments.len() * 48
+            + cells.len() * (16 + BYTES_PER_CELL + 48),
+    );
+
+    bytes.extend_from_slice(&RANDOM_CHALLENGE_KZG_CELL_BATCH_DOMAIN);
+    bytes.extend_from_slice(&(field_elements_per_blob as u64).to_be_bytes());
+    bytes.extend_from_slice(&(FIELD_ELEMENTS_PER_CELL as u64).to_be_bytes());
+    bytes.extend_from_slice(&(commitments.len() as u64).to_be_bytes());
+    bytes.extend_from_slice(&(cell_indices.len() as u64).to_be_bytes());
+
+    for commitment in commitments {
+        bytes.extend_from_slice(&commitment.to_bytes());
+    }
+
+    for (k, cell) in cells.iter().enumerate() {
+        bytes.extend_from_slice(&(commitment_indices[k] as u64).to_be_bytes());
+        bytes.extend_from_slice(&(cell_indices[k] as u64).to_be_bytes());
+        for element in cell {
+            bytes.extend_from_slice(&element.to_bytes());
+        }
+        bytes.extend_from_slice(&proofs[k].to_bytes());
+    }
+
+    hash_to_bls_field(&hash(&bytes))
+}
@@ -0,0 +1,89 @@
+use core::fmt;
+
+use crate::trusted_setup::SetupBasis;
+
+/// Errors returned by the traits in this crate and by every backend implementing them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// An input slice or byte string did not have the expected length.
+    InvalidLength { expected: usize, actual: usize },
+    /// A scalar was not smaller than the field modulus.
+    NonCanonicalScalar,
+    /// Bytes could not be decoded into a point on the curve.
+    InvalidPoint,
+    /// A point is on the curve, but not in the prime-order subgroup.
+    PointNotInSubgroup,
+    /// A hex string could not be decoded.
+    InvalidHex,
+    /// The named value was required to be a power of two.
+    NotPowerOfTwo(&'static str),
+    /// The trusted setup does not contain enough points for the operation.
+    SetupTooSmall,
+    /// A trusted setup file could not be parsed; `line` is 1-based.
+    InvalidTrustedSetup { line: usize, reason: &'static str },
+    /// The points of a trusted setup are not powers of one secret.
+    InconsistentTrustedSetup(&'static str),
+    /// The operation needs the setup points in another basis, e.g. a polynomial in coefficient
+    /// form was committed with a Lagrange setup.
+    SetupBasisMismatch {
+        expected: SetupBasis,
+        actual: SetupBasis,
+    },
+    /// A division by a zero field element or by the zero polynomial.
+    DivisionByZero,
+    /// Any other invalid input, described by the message.
+    InvalidArgument(&'static str),
+    /// A file, such as a trusted setup, could not be read.
+    #[cfg(feature = "std")]
+    Io(std::io::ErrorKind),
+    /// A failure that was not caused by the caller's input.
+    Internal(&'static str),
+}
+
+impl Error {
+    /// Returns `true` if the error was caused by invalid input rather than an internal failure.
+    pub fn is_bad_args(&self) -> bool {
+        !matches!(self, Error::Internal(_))
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidLength { expected, actual } => {
+                write!(f, "Invalid length. Expected {}, got {}", expected, actual)
+            }
+            Error::NonCanonicalScalar => write!(f, "Invalid scalar"),
+            Error::InvalidPoint => write!(f, "Failed to uncompress"),
+            Error::PointNotInSubgroup => write!(f, "Point is not in the subgroup"),
+            Error::InvalidHex => write!(f, "Invalid hex string"),
+            Error::NotPowerOfTwo(name) => write!(f, "{} must be a power of two", name),
+            Error::SetupTooSmall => write!(f, "Trusted setup is too small"),
+            Error::InvalidTrustedSetup { line, reason } => {
+                write!(f, "Invalid trusted setup at line {}: {}", line, reason)
+            }
+            Error::InconsistentTrustedSetup(reason) => {
+                write!(f, "Inconsistent trusted setup: {}", reason)
+            }
+            Error::SetupBasisMismatch { expected, actual } => write!(
+                f,
+                "Trusted setup is in {} basis, expected {} basis",
+                actual, expected
+            ),
+            Error::DivisionByZero => write!(f, "Division by zero"),
+            #[cfg(feature = "std")]
+            Error::Io(kind) => write!(f, "I/O error: {}", kind),
+            Error::InvalidArgument(msg) | Error::Internal(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::Io(err.kind())
+    }
+}
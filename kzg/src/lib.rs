@@ -2,10 +2,21 @@
 
 extern crate alloc;
 
-use alloc::string::String;
 use alloc::vec::Vec;
 
+pub mod danksharding;
 pub mod eip_4844;
+pub mod eip_7594;
+mod error;
+mod poly;
+mod poly_eval;
+pub mod reed_solomon;
+#[cfg(feature = "serde")]
+pub mod serde_utils;
+pub mod trusted_setup;
+
+pub use error::Error;
+pub use poly_eval::PolyEval;
 
 pub trait Fr: Default + Clone {
     fn null() -> Self;
@@ -17,9 +28,13 @@ pub trait Fr: Default + Clone {
     #[cfg(feature = "rand")]
     fn rand() -> Self;
 
-    fn from_bytes(bytes: &[u8]) -> Result<Self, String>;
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error>;
 
-    fn from_hex(hex: &str) -> Result<Self, String>;
+    /// Same as [`Fr::from_bytes`], but reduces the value modulo the field order instead of
+    /// rejecting non-canonical scalars.
+    fn from_bytes_unchecked(bytes: &[u8]) -> Result<Self, Error>;
+
+    fn from_hex(hex: &str) -> Result<Self, Error>;
 
     fn from_u64_arr(u: &[u64; 4]) -> Self;
 
@@ -51,9 +66,54 @@ pub trait Fr: Default + Clone {
 
     fn pow(&self, n: usize) -> Self;
 
-    fn div(&self, b: &Self) -> Result<Self, String>;
+    fn div(&self, b: &Self) -> Result<Self, Error>;
 
     fn equals(&self, b: &Self) -> bool;
+
+    /// Inverts all `values` in place with a single field inversion (Montgomery's trick). Zeros
+    /// have no inverse and are left as zero.
+    fn batch_inverse(values: &mut [Self]) {
+        // prefix[i] is the product of the nonzero values before i
+        let mut prefix = Vec::with_capacity(values.len());
+        let mut product = Self::one();
+        for value in values.iter() {
+            prefix.push(product.clone());
+            if !value.is_zero() {
+                product = product.mul(value);
+            }
+        }
+
+        let mut inverse = product.inverse();
+        for (value, prefix) in values.iter_mut().zip(prefix).rev() {
+            if value.is_zero() {
+                continue;
+            }
+            let next = inverse.mul(value);
+            *value = inverse.mul(&prefix);
+            inverse = next;
+        }
+    }
+
+    /// Multiplies all `values` in place by `scalar`.
+    fn batch_mul_scalar(values: &mut [Self], scalar: &Self) {
+        for value in values {
+            *value = value.mul(scalar);
+        }
+    }
+
+    /// Returns `a[0] * b[0] + ... + a[n - 1] * b[n - 1]`.
+    fn inner_product(a: &[Self], b: &[Self]) -> Result<Self, Error> {
+        if a.len() != b.len() {
+            return Err(Error::InvalidLength {
+                expected: a.len(),
+                actual: b.len(),
+            });
+        }
+
+        Ok(a.iter()
+            .zip(b)
+            .fold(Self::zero(), |sum, (a, b)| sum.add(&a.mul(b))))
+    }
 }
 
 pub trait G1: Clone + Default {
@@ -66,9 +126,9 @@ pub trait G1: Clone + Default {
     #[cfg(feature = "rand")]
     fn rand() -> Self;
 
-    fn from_bytes(bytes: &[u8]) -> Result<Self, String>;
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error>;
 
-    fn from_hex(hex: &str) -> Result<Self, String>;
+    fn from_hex(hex: &str) -> Result<Self, Error>;
 
     fn to_bytes(&self) -> [u8; 48];
 
@@ -91,12 +151,18 @@ pub trait G1Mul<Fr>: Clone {
     fn mul(&self, b: &Fr) -> Self;
 }
 
+pub trait G1LinComb<Fr>: G1Mul<Fr> {
+    /// Calculates the linear combination `sum(points[i] * scalars[i])` of the first `len` points,
+    /// using the fastest multi-scalar multiplication available in the backend.
+    fn g1_lincomb(points: &[Self], scalars: &[Fr], len: usize) -> Self;
+}
+
 pub trait G2: Clone + Default {
     fn generator() -> Self;
 
     fn negative_generator() -> Self;
 
-    fn from_bytes(bytes: &[u8]) -> Result<Self, String>;
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error>;
 
     fn to_bytes(&self) -> [u8; 96];
 
@@ -113,16 +179,94 @@ pub trait G2Mul<Fr>: Clone {
     fn mul(&self, b: &Fr) -> Self;
 }
 
+pub trait G2LinComb<Fr>: G2Mul<Fr> {
+    /// Calculates the linear combination `sum(points[i] * scalars[i])` of the first `len` points,
+    /// using the fastest multi-scalar multiplication available in the backend.
+    fn g2_lincomb(points: &[Self], scalars: &[Fr], len: usize) -> Self;
+}
+
+pub trait PairingVerify<TG1: G1, TG2: G2> {
+    /// Checks that `e(a1, a2) == e(b1, b2)`.
+    fn verify(a1: &TG1, a2: &TG2, b1: &TG1, b2: &TG2) -> bool;
+
+    /// Checks that the product of `e(p, q)` over all `(p, q)` in `pairs` is the identity, using a
+    /// single multi-Miller loop and final exponentiation. An empty product is the identity.
+    fn verify_multi(pairs: &[(TG1, TG2)]) -> bool;
+}
+
 pub trait FFTFr<Coeff: Fr> {
-    fn fft_fr(&self, data: &[Coeff], inverse: bool) -> Result<Vec<Coeff>, String>;
+    fn fft_fr(&self, data: &[Coeff], inverse: bool) -> Result<Vec<Coeff>, Error>;
+
+    /// Evaluates the polynomial with coefficients `data` over the coset `shift * w^i`, where `w` is
+    /// the root of unity of order `data.len()`. The shift must not be zero.
+    fn coset_fft_fr(&self, data: &[Coeff], shift: &Coeff) -> Result<Vec<Coeff>, Error> {
+        if shift.is_zero() {
+            return Err(Error::InvalidArgument("Coset shift must not be zero"));
+        }
+
+        self.fft_fr(&mul_by_powers(data, shift, Coeff::mul), false)
+    }
+
+    /// Inverse of [`Self::coset_fft_fr`] with the same `shift`.
+    fn coset_ifft_fr(&self, data: &[Coeff], shift: &Coeff) -> Result<Vec<Coeff>, Error> {
+        if shift.is_zero() {
+            return Err(Error::InvalidArgument("Coset shift must not be zero"));
+        }
+
+        Ok(mul_by_powers(
+            &self.fft_fr(data, true)?,
+            &shift.inverse(),
+            Coeff::mul,
+        ))
+    }
 }
 
 pub trait FFTG1<Coeff: G1> {
-    fn fft_g1(&self, data: &[Coeff], inverse: bool) -> Result<Vec<Coeff>, String>;
+    fn fft_g1(&self, data: &[Coeff], inverse: bool) -> Result<Vec<Coeff>, Error>;
+
+    /// Same as [`FFTFr::coset_fft_fr`], but with group elements as coefficients.
+    fn coset_fft_g1<TFr: Fr>(&self, data: &[Coeff], shift: &TFr) -> Result<Vec<Coeff>, Error>
+    where
+        Coeff: G1Mul<TFr>,
+    {
+        if shift.is_zero() {
+            return Err(Error::InvalidArgument("Coset shift must not be zero"));
+        }
+
+        self.fft_g1(&mul_by_powers(data, shift, G1Mul::mul), false)
+    }
+
+    /// Inverse of [`Self::coset_fft_g1`] with the same `shift`.
+    fn coset_ifft_g1<TFr: Fr>(&self, data: &[Coeff], shift: &TFr) -> Result<Vec<Coeff>, Error>
+    where
+        Coeff: G1Mul<TFr>,
+    {
+        if shift.is_zero() {
+            return Err(Error::InvalidArgument("Coset shift must not be zero"));
+        }
+
+        Ok(mul_by_powers(
+            &self.fft_g1(data, true)?,
+            &shift.inverse(),
+            G1Mul::mul,
+        ))
+    }
+}
+
+/// Multiplies `data[i]` by `factor^i`
+fn mul_by_powers<T, TFr: Fr>(data: &[T], factor: &TFr, mul: impl Fn(&T, &TFr) -> T) -> Vec<T> {
+    let mut power = TFr::one();
+    data.iter()
+        .map(|x| {
+            let ret = mul(x, &power);
+            power = power.mul(factor);
+            ret
+        })
+        .collect()
 }
 
 pub trait DAS<Coeff: Fr> {
-    fn das_fft_extension(&self, evens: &[Coeff]) -> Result<Vec<Coeff>, String>;
+    fn das_fft_extension(&self, evens: &[Coeff]) -> Result<Vec<Coeff>, Error>;
 }
 
 pub trait ZeroPoly<Coeff: Fr, Polynomial: Poly<Coeff>> {
@@ -132,8 +276,7 @@ pub trait ZeroPoly<Coeff: Fr, Polynomial: Poly<Coeff>> {
     ///
     /// Uses straightforward long multiplication to calculate the product of `(x - r^i)` where `r`
     /// is a root of unity and the `i`s are the indices at which it must evaluate to zero.
-    fn do_zero_poly_mul_partial(&self, idxs: &[usize], stride: usize)
-        -> Result<Polynomial, String>;
+    fn do_zero_poly_mul_partial(&self, idxs: &[usize], stride: usize) -> Result<Polynomial, Error>;
 
     /// Reduce partials using a specified domain size.
     /// Calculates the product of all polynomials via FFT and then applies an inverse FFT to produce
@@ -142,7 +285,7 @@ pub trait ZeroPoly<Coeff: Fr, Polynomial: Poly<Coeff>> {
         &self,
         domain_size: usize,
         partials: &[Polynomial],
-    ) -> Result<Polynomial, String>;
+    ) -> Result<Polynomial, Error>;
 
     /// Calculate the minimal polynomial that evaluates to zero for powers of roots of unity that
     /// correspond to missing indices.
@@ -154,11 +297,11 @@ pub trait ZeroPoly<Coeff: Fr, Polynomial: Poly<Coeff>> {
         &self,
         domain_size: usize,
         idxs: &[usize],
-    ) -> Result<(Vec<Coeff>, Polynomial), String>;
+    ) -> Result<(Vec<Coeff>, Polynomial), Error>;
 }
 
 pub trait FFTSettings<Coeff: Fr>: Default + Clone {
-    fn new(scale: usize) -> Result<Self, String>;
+    fn new(scale: usize) -> Result<Self, Error>;
 
     fn get_max_width(&self) -> usize;
 
@@ -181,11 +324,12 @@ pub trait FFTSettingsPoly<Coeff: Fr, Polynomial: Poly<Coeff>, FSettings: FFTSett
         b: &Polynomial,
         len: usize,
         fs: Option<&FSettings>,
-    ) -> Result<Polynomial, String>;
+    ) -> Result<Polynomial, Error>;
 }
 
 pub trait Poly<Coeff: Fr>: Default + Clone {
-    fn new(size: usize) -> Result<Self, String>;
+    /// Returns a polynomial of `size` zero coefficients.
+    fn new(size: usize) -> Result<Self, Error>;
 
     fn get_coeff_at(&self, i: usize) -> Coeff;
 
@@ -205,27 +349,192 @@ pub trait Poly<Coeff: Fr>: Default + Clone {
 
     fn unscale(&mut self);
 
-    fn inverse(&mut self, new_len: usize) -> Result<Self, String>;
+    fn inverse(&mut self, new_len: usize) -> Result<Self, Error>;
+
+    fn div(&mut self, x: &Self) -> Result<Self, Error>;
+
+    fn long_div(&mut self, x: &Self) -> Result<Self, Error>;
 
-    fn div(&mut self, x: &Self) -> Result<Self, String>;
+    fn fast_div(&mut self, x: &Self) -> Result<Self, Error>;
 
-    fn long_div(&mut self, x: &Self) -> Result<Self, String>;
+    fn mul_direct(&mut self, x: &Self, len: usize) -> Result<Self, Error>;
 
-    fn fast_div(&mut self, x: &Self) -> Result<Self, String>;
+    /// Returns `self + b`, as long as the longer of the two.
+    fn add(&self, b: &Self) -> Result<Self, Error> {
+        let (a, b) = (self.get_coeffs(), b.get_coeffs());
+        let coeffs = (0..a.len().max(b.len()))
+            .map(|i| match (a.get(i), b.get(i)) {
+                (Some(a), Some(b)) => a.add(b),
+                (Some(x), None) | (None, Some(x)) => x.clone(),
+                (None, None) => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        poly::poly_from_coeffs(&coeffs)
+    }
+
+    /// Returns `self - b`, as long as the longer of the two.
+    fn sub(&self, b: &Self) -> Result<Self, Error> {
+        self.add(&b.mul_scalar(&Coeff::one().negate())?)
+    }
+
+    /// Returns `self * x` for a field element `x`.
+    fn mul_scalar(&self, x: &Coeff) -> Result<Self, Error> {
+        let coeffs = self
+            .get_coeffs()
+            .iter()
+            .map(|coeff| coeff.mul(x))
+            .collect::<Vec<_>>();
+        poly::poly_from_coeffs(&coeffs)
+    }
+
+    /// Returns the formal derivative, one coefficient shorter.
+    fn derivative(&self) -> Result<Self, Error> {
+        let coeffs = self
+            .get_coeffs()
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, coeff)| coeff.mul(&Coeff::from_u64(i as u64)))
+            .collect::<Vec<_>>();
+        poly::poly_from_coeffs(&coeffs)
+    }
+
+    /// Returns `self(x^k)`. For `k == 0`, this is the constant `self(1)`.
+    fn compose_x_pow(&self, k: usize) -> Result<Self, Error> {
+        if self.is_empty() {
+            return Self::new(0);
+        } else if k == 0 {
+            return poly::poly_from_coeffs(&[self.eval(&Coeff::one())]);
+        }
+
+        let mut ret = Self::new((self.len() - 1) * k + 1)?;
+        for (i, coeff) in self.get_coeffs().iter().enumerate() {
+            ret.set_coeff_at(i * k, coeff);
+        }
+        Ok(ret)
+    }
+
+    /// Returns the quotient and the remainder of the division by `divisor`. The remainder is
+    /// shorter than `divisor` without its trailing zero coefficients.
+    fn div_rem(&self, divisor: &Self) -> Result<(Self, Self), Error> {
+        let divisor = poly::trimmed(divisor.get_coeffs());
+        if divisor.is_empty() {
+            return Err(Error::DivisionByZero);
+        }
+        let dividend = poly::trimmed(self.get_coeffs());
+        if dividend.len() < divisor.len() {
+            return Ok((Self::new(0)?, poly::poly_from_coeffs(dividend)?));
+        }
+
+        let divisor: Self = poly::poly_from_coeffs(divisor)?;
+        let quotient = poly::poly_from_coeffs::<Coeff, Self>(dividend)?.div(&divisor)?;
+        let product = quotient.clone().mul_direct(&divisor, divisor.len() - 1)?;
+        let rem = dividend[..divisor.len() - 1]
+            .iter()
+            .enumerate()
+            .map(|(i, a)| match product.get_coeffs().get(i) {
+                Some(b) => a.sub(b),
+                None => a.clone(),
+            })
+            .collect::<Vec<_>>();
+        Ok((quotient, poly::poly_from_coeffs(&rem)?))
+    }
+
+    /// Evaluates at all of `xs` by reducing modulo the product tree of the points.
+    fn eval_multi(&self, xs: &[Coeff]) -> Result<Vec<Coeff>, Error> {
+        if xs.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let tree = poly::product_tree::<Coeff, Self>(xs)?;
+        let mut rems = Vec::from([self.div_rem(&tree[tree.len() - 1][0])?.1]);
+        for level in tree.iter().rev().skip(1) {
+            rems = level
+                .iter()
+                .enumerate()
+                .map(|(j, node)| rems[j / 2].div_rem(node).map(|(_, rem)| rem))
+                .collect::<Result<Vec<_>, _>>()?;
+        }
+
+        Ok(rems
+            .iter()
+            .map(|rem| {
+                rem.get_coeffs()
+                    .first()
+                    .cloned()
+                    .unwrap_or_else(Coeff::zero)
+            })
+            .collect())
+    }
 
-    fn mul_direct(&mut self, x: &Self, len: usize) -> Result<Self, String>;
+    /// Returns the polynomial with `xs.len()` coefficients that takes the values `ys` at the
+    /// distinct points `xs`, combining Lagrange terms up the product tree of the points.
+    fn interpolate(xs: &[Coeff], ys: &[Coeff]) -> Result<Self, Error> {
+        if xs.len() != ys.len() {
+            return Err(Error::InvalidLength {
+                expected: xs.len(),
+                actual: ys.len(),
+            });
+        } else if xs.is_empty() {
+            return Self::new(0);
+        }
+
+        // The weight of every point is its value over the derivative of the root at the point
+        let tree = poly::product_tree::<Coeff, Self>(xs)?;
+        let mut derivatives = tree[tree.len() - 1][0].derivative()?.eval_multi(xs)?;
+        if derivatives.iter().any(Coeff::is_zero) {
+            return Err(Error::InvalidArgument(
+                "Interpolation points must be distinct",
+            ));
+        }
+        Coeff::batch_inverse(&mut derivatives);
+        let mut nodes = ys
+            .iter()
+            .zip(&derivatives)
+            .map(|(y, inverse)| poly::poly_from_coeffs(&[y.mul(inverse)]))
+            .collect::<Result<Vec<Self>, _>>()?;
+
+        for level in &tree[..tree.len() - 1] {
+            nodes = nodes
+                .chunks(2)
+                .zip(level.chunks(2))
+                .map(|(pair, products)| match (pair, products) {
+                    ([a, b], [a_products, b_products]) => {
+                        poly::poly_mul(a, b_products)?.add(&poly::poly_mul(b, a_products)?)
+                    }
+                    _ => Ok(pair[0].clone()),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+        }
+
+        let mut coeffs = nodes[0].get_coeffs().to_vec();
+        coeffs.resize(xs.len(), Coeff::zero());
+        poly::poly_from_coeffs(&coeffs)
+    }
 }
 
 pub trait PolyRecover<Coeff: Fr, Polynomial: Poly<Coeff>, FSettings: FFTSettings<Coeff>> {
     fn recover_poly_coeffs_from_samples(
         samples: &[Option<Coeff>],
         fs: &FSettings,
-    ) -> Result<Polynomial, String>;
+    ) -> Result<Polynomial, Error>;
 
     fn recover_poly_from_samples(
         samples: &[Option<Coeff>],
         fs: &FSettings,
-    ) -> Result<Polynomial, String>;
+    ) -> Result<Polynomial, Error>;
+}
+
+pub trait PolyErrorCorrect<Coeff: Fr, Polynomial: Poly<Coeff>, FSettings: FFTSettings<Coeff>> {
+    /// Same as [`PolyRecover::recover_poly_from_samples`], but also corrects up to
+    /// `(known - data_len) / 2` samples with wrong values instead of trusting every known one,
+    /// where the samples are evaluations of a polynomial with `data_len` coefficients. Returns the
+    /// corrected evaluations and the indices of the samples that were wrong.
+    fn correct_poly_from_samples(
+        samples: &[Option<Coeff>],
+        data_len: usize,
+        fs: &FSettings,
+    ) -> Result<(Polynomial, Vec<usize>), Error>;
 }
 
 pub trait KZGSettings<
@@ -236,16 +545,26 @@ pub trait KZGSettings<
     Polynomial: Poly<Coeff1>,
 >: Default + Clone
 {
+    /// Takes the first `length_g1` and `length_g2` powers of the secret. Ceremonies publish far
+    /// fewer G2 than G1 powers, which only limits [`Self::check_proof_multi`] to `n` below
+    /// `length_g2`.
     fn new(
         secret_g1: &[Coeff2],
         secret_g2: &[Coeff3],
-        length: usize,
+        length_g1: usize,
+        length_g2: usize,
         fs: &Fs,
-    ) -> Result<Self, String>;
+    ) -> Result<Self, Error>;
+
+    /// Commits to `p` by its coefficients. Fails with [`Error::SetupBasisMismatch`] if the
+    /// setup is in Lagrange basis.
+    fn commit_to_poly(&self, p: &Polynomial) -> Result<Coeff2, Error>;
 
-    fn commit_to_poly(&self, p: &Polynomial) -> Result<Coeff2, String>;
+    /// Commits to `p` by its evaluations, which are the coefficients in the Lagrange basis. Fails
+    /// with [`Error::SetupBasisMismatch`] if the setup is in monomial basis.
+    fn commit_to_poly_eval(&self, p: &PolyEval<Coeff1>) -> Result<Coeff2, Error>;
 
-    fn compute_proof_single(&self, p: &Polynomial, x: &Coeff1) -> Result<Coeff2, String>;
+    fn compute_proof_single(&self, p: &Polynomial, x: &Coeff1) -> Result<Coeff2, Error>;
 
     fn check_proof_single(
         &self,
@@ -253,10 +572,11 @@ pub trait KZGSettings<
         proof: &Coeff2,
         x: &Coeff1,
         value: &Coeff1,
-    ) -> Result<bool, String>;
+    ) -> Result<bool, Error>;
 
-    fn compute_proof_multi(&self, p: &Polynomial, x: &Coeff1, n: usize) -> Result<Coeff2, String>;
+    fn compute_proof_multi(&self, p: &Polynomial, x: &Coeff1, n: usize) -> Result<Coeff2, Error>;
 
+    /// Fails with [`Error::SetupTooSmall`] if there is no G2 power of the secret for `n`.
     fn check_proof_multi(
         &self,
         com: &Coeff2,
@@ -264,11 +584,33 @@ pub trait KZGSettings<
         x: &Coeff1,
         values: &[Coeff1],
         n: usize,
-    ) -> Result<bool, String>;
+    ) -> Result<bool, Error>;
 
     fn get_expanded_roots_of_unity_at(&self, i: usize) -> Coeff1;
 
     fn get_roots_of_unity_at(&self, i: usize) -> Coeff1;
+
+    fn get_fft_settings(&self) -> &Fs;
+
+    fn get_g1_secret(&self) -> &[Coeff2];
+
+    fn get_g2_secret(&self) -> &[Coeff3];
+
+    /// Blob parameters used by the EIP-4844 functions when given these settings.
+    fn get_blob_preset(&self) -> &eip_4844::BlobPreset;
+
+    /// Basis of the points returned by [`Self::get_g1_secret`]. Settings created with
+    /// [`Self::new`] are in monomial basis, while the EIP-4844 trusted setup loaders produce
+    /// Lagrange ones.
+    fn get_basis(&self) -> trusted_setup::SetupBasis;
+
+    /// Returns the settings with the G1 points in bit-reversed Lagrange basis over the roots of
+    /// unity of the FFT settings, see [`trusted_setup::monomial_to_lagrange`].
+    fn to_lagrange(&self) -> Result<Self, Error>;
+
+    /// Returns the settings with the G1 points in monomial basis, see
+    /// [`trusted_setup::lagrange_to_monomial`].
+    fn to_monomial(&self) -> Result<Self, Error>;
 }
 
 pub trait FK20SingleSettings<
@@ -280,11 +622,11 @@ pub trait FK20SingleSettings<
     Ks: KZGSettings<Coeff1, Coeff2, Coeff3, Fs, Polynomial>,
 >: Default + Clone
 {
-    fn new(ks: &Ks, n2: usize) -> Result<Self, String>;
+    fn new(ks: &Ks, n2: usize) -> Result<Self, Error>;
 
-    fn data_availability(&self, p: &Polynomial) -> Result<Vec<Coeff2>, String>;
+    fn data_availability(&self, p: &Polynomial) -> Result<Vec<Coeff2>, Error>;
 
-    fn data_availability_optimized(&self, p: &Polynomial) -> Result<Vec<Coeff2>, String>;
+    fn data_availability_optimized(&self, p: &Polynomial) -> Result<Vec<Coeff2>, Error>;
 }
 
 pub trait FK20MultiSettings<
@@ -296,9 +638,9 @@ pub trait FK20MultiSettings<
     Ks: KZGSettings<Coeff1, Coeff2, Coeff3, Fs, Polynomial>,
 >: Default + Clone
 {
-    fn new(ks: &Ks, n2: usize, chunk_len: usize) -> Result<Self, String>;
+    fn new(ks: &Ks, n2: usize, chunk_len: usize) -> Result<Self, Error>;
 
-    fn data_availability(&self, p: &Polynomial) -> Result<Vec<Coeff2>, String>;
+    fn data_availability(&self, p: &Polynomial) -> Result<Vec<Coeff2>, Error>;
 
-    fn data_availability_optimized(&self, p: &Polynomial) -> Result<Vec<Coeff2>, String>;
+    fn data_availability_optimized(&self, p: &Polynomial) -> Result<Vec<Coeff2>, Error>;
 }
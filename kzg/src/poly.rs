@@ -0,0 +1,70 @@
+//! Helpers for the default methods of [`Poly`] and for [`crate::PolyEval`].
+
+use alloc::vec::Vec;
+
+use crate::{Error, Fr, Poly};
+
+pub(crate) fn poly_from_coeffs<TFr: Fr, TPoly: Poly<TFr>>(coeffs: &[TFr]) -> Result<TPoly, Error> {
+    let mut poly = TPoly::new(coeffs.len())?;
+    for (i, coeff) in coeffs.iter().enumerate() {
+        poly.set_coeff_at(i, coeff);
+    }
+    Ok(poly)
+}
+
+/// Returns the coefficients without the trailing zeros.
+pub(crate) fn trimmed<TFr: Fr>(coeffs: &[TFr]) -> &[TFr] {
+    let len = coeffs
+        .iter()
+        .rposition(|coeff| !coeff.is_zero())
+        .map_or(0, |i| i + 1);
+    &coeffs[..len]
+}
+
+pub(crate) fn poly_mul<TFr: Fr, TPoly: Poly<TFr>>(a: &TPoly, b: &TPoly) -> Result<TPoly, Error> {
+    if a.is_empty() || b.is_empty() {
+        return TPoly::new(0);
+    }
+
+    a.clone().mul_direct(b, a.len() + b.len() - 1)
+}
+
+/// Builds the product tree of `(x - x_i)` over all points, from the leaves up to the root. Every
+/// level holds the products of pairs of the level below; an odd node out moves up unchanged.
+pub(crate) fn product_tree<TFr: Fr, TPoly: Poly<TFr>>(
+    xs: &[TFr],
+) -> Result<Vec<Vec<TPoly>>, Error> {
+    let leaves = xs
+        .iter()
+        .map(|x| poly_from_coeffs(&[x.negate(), TFr::one()]))
+        .collect::<Result<Vec<TPoly>, _>>()?;
+
+    let mut tree = Vec::from([leaves]);
+    while tree[tree.len() - 1].len() > 1 {
+        let level = tree[tree.len() - 1]
+            .chunks(2)
+            .map(|pair| match pair {
+                [a, b] => poly_mul(a, b),
+                _ => Ok(pair[0].clone()),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        tree.push(level);
+    }
+
+    Ok(tree)
+}
+
+/// Permutes `values`, whose length is a power of two, into bit-reversed order.
+pub(crate) fn reverse_bit_order<T: Clone>(values: &mut [T]) {
+    if values.len() < 2 {
+        return;
+    }
+
+    let unused_bit_len = values.len().leading_zeros() + 1;
+    for i in 0..values.len() - 1 {
+        let r = i.reverse_bits() >> unused_bit_len;
+        if r > i {
+            values.swap(r, i);
+        }
+    }
+}
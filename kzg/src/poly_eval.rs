@@ -0,0 +1,181 @@
+//! Polynomials in evaluation form, the representation of blobs.
+//!
+//! A polynomial of degree below `n` is given by its values at the `n`-th roots of unity, in
+//! bit-reversed order, so `evals[i]` is the value at `fs.get_roots_of_unity_at(i)`. The first `n`
+//! entries of the bit-reversed roots of any larger domain are the bit-reversed `n`-th roots, so
+//! any FFT settings at least `n` wide describe the same points.
+
+use alloc::vec::Vec;
+
+use crate::poly::{poly_from_coeffs, reverse_bit_order};
+use crate::{Error, FFTFr, FFTSettings, Fr, Poly};
+
+#[derive(Debug, Clone)]
+pub struct PolyEval<TFr> {
+    evals: Vec<TFr>,
+}
+
+impl<TFr: Fr> PolyEval<TFr> {
+    /// Takes the values at the roots of unity in bit-reversed order. Their number must be a power
+    /// of two.
+    pub fn new(evals: Vec<TFr>) -> Result<Self, Error> {
+        if !evals.len().is_power_of_two() {
+            return Err(Error::NotPowerOfTwo("number of evaluations"));
+        }
+
+        Ok(Self { evals })
+    }
+
+    /// Evaluates the polynomial with the coefficients of `poly` at the `n`-th roots of unity.
+    pub fn from_coeffs<TPoly: Poly<TFr>, TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>>(
+        poly: &TPoly,
+        n: usize,
+        fs: &TFFTSettings,
+    ) -> Result<Self, Error> {
+        if !n.is_power_of_two() {
+            return Err(Error::NotPowerOfTwo("number of evaluations"));
+        }
+        if poly.len() > n {
+            return Err(Error::InvalidArgument(
+                "Polynomial has more coefficients than evaluations",
+            ));
+        }
+
+        let mut coeffs = poly.get_coeffs().to_vec();
+        coeffs.resize(n, TFr::zero());
+        let mut evals = fs.fft_fr(&coeffs, false)?;
+        reverse_bit_order(&mut evals);
+
+        Ok(Self { evals })
+    }
+
+    /// Interpolates the coefficients, of which there are as many as evaluations.
+    pub fn to_coeffs<TPoly: Poly<TFr>, TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>>(
+        &self,
+        fs: &TFFTSettings,
+    ) -> Result<TPoly, Error> {
+        let mut evals = self.evals.clone();
+        reverse_bit_order(&mut evals);
+        poly_from_coeffs(&fs.fft_fr(&evals, true)?)
+    }
+
+    pub fn evals(&self) -> &[TFr] {
+        &self.evals
+    }
+
+    pub fn into_evals(self) -> Vec<TFr> {
+        self.evals
+    }
+
+    /// Evaluates at any point with the barycentric formula
+    /// `p(x) = (x^n - 1) / n * sum(p_i * w_i / (x - w_i))`.
+    pub fn evaluate<TFFTSettings: FFTSettings<TFr>>(
+        &self,
+        x: &TFr,
+        fs: &TFFTSettings,
+    ) -> Result<TFr, Error> {
+        let n = self.evals.len();
+        let roots = self.domain(fs)?;
+
+        let mut weights = Vec::with_capacity(n);
+        for (eval, root) in self.evals.iter().zip(roots) {
+            if x.equals(root) {
+                return Ok(eval.clone());
+            }
+            weights.push(x.sub(root));
+        }
+        TFr::batch_inverse(&mut weights);
+        for (weight, root) in weights.iter_mut().zip(roots) {
+            *weight = weight.mul(root);
+        }
+
+        TFr::inner_product(&self.evals, &weights)?
+            .mul(&x.pow(n).sub(&TFr::one()))
+            .div(&TFr::from_u64(n as u64))
+    }
+
+    pub fn add(&self, other: &Self) -> Result<Self, Error> {
+        self.pointwise(other, TFr::add)
+    }
+
+    pub fn sub(&self, other: &Self) -> Result<Self, Error> {
+        self.pointwise(other, TFr::sub)
+    }
+
+    /// Multiplies pointwise, which gives the product modulo `X^n - 1`. The product itself is only
+    /// represented if the degrees add up to less than `n`.
+    pub fn mul(&self, other: &Self) -> Result<Self, Error> {
+        self.pointwise(other, TFr::mul)
+    }
+
+    /// Returns `q = (p - p(z)) / (X - z)` and `p(z)`.
+    ///
+    /// `q_i = (p_i - p(z)) / (w_i - z)`, except where `z = w_m` is in the domain. There `q_m` is
+    /// `p'(w_m) = sum(i != m, (p_i - p(z)) * w_i / (z * (z - w_i)))`.
+    pub fn div_by_linear<TFFTSettings: FFTSettings<TFr>>(
+        &self,
+        z: &TFr,
+        fs: &TFFTSettings,
+    ) -> Result<(Self, TFr), Error> {
+        let roots = self.domain(fs)?;
+        let y = self.evaluate(z, fs)?;
+
+        // At z = w_m the denominator is zero, which batch inversion leaves as zero
+        let mut inverses = roots.iter().map(|root| root.sub(z)).collect::<Vec<_>>();
+        TFr::batch_inverse(&mut inverses);
+        let mut quotient = self
+            .evals
+            .iter()
+            .zip(&inverses)
+            .map(|(eval, inverse)| eval.sub(&y).mul(inverse))
+            .collect::<Vec<_>>();
+
+        if let Some(m) = roots.iter().position(|root| root.equals(z)) {
+            let mut inverses = roots
+                .iter()
+                .map(|root| z.sub(root).mul(z))
+                .collect::<Vec<_>>();
+            TFr::batch_inverse(&mut inverses);
+            let terms = self
+                .evals
+                .iter()
+                .zip(roots)
+                .map(|(eval, root)| eval.sub(&y).mul(root))
+                .collect::<Vec<_>>();
+            quotient[m] = TFr::inner_product(&terms, &inverses)?;
+        }
+
+        Ok((Self { evals: quotient }, y))
+    }
+
+    fn domain<'a, TFFTSettings: FFTSettings<TFr>>(
+        &self,
+        fs: &'a TFFTSettings,
+    ) -> Result<&'a [TFr], Error> {
+        if self.evals.len() > fs.get_max_width() {
+            return Err(Error::InvalidArgument(
+                "Number of evaluations exceeds the FFT settings width",
+            ));
+        }
+
+        Ok(&fs.get_roots_of_unity()[..self.evals.len()])
+    }
+
+    fn pointwise(&self, other: &Self, op: impl Fn(&TFr, &TFr) -> TFr) -> Result<Self, Error> {
+        if other.evals.len() != self.evals.len() {
+            return Err(Error::InvalidLength {
+                expected: self.evals.len(),
+                actual: other.evals.len(),
+            });
+        }
+
+        Ok(Self {
+            evals: self
+                .evals
+                .iter()
+                .zip(&other.evals)
+                .map(|(a, b)| op(a, b))
+                .collect(),
+        })
+    }
+}
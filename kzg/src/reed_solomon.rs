@@ -0,0 +1,160 @@
+//! Reed-Solomon decoding that corrects wrong samples, not only missing ones.
+//!
+//! Samples are evaluations of a polynomial of degree below `data_len` at the powers of a root of
+//! unity, in natural order, like in [`crate::PolyRecover`]. With `known` samples present, up to
+//! `(known - data_len) / 2` of them may be wrong. Decoding follows Gao: the partial extended
+//! Euclidean algorithm on the vanishing polynomial of the known points and the interpolation of
+//! their values gives the error locator and the data polynomial times it in one go.
+//!
+//! Polynomials here are coefficient vectors without trailing zeros, so the zero polynomial is
+//! empty.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{Error, FFTFr, FFTSettings, Fr};
+
+fn trim<TFr: Fr>(mut p: Vec<TFr>) -> Vec<TFr> {
+    while matches!(p.last(), Some(x) if x.is_zero()) {
+        p.pop();
+    }
+    p
+}
+
+fn poly_sub<TFr: Fr>(a: &[TFr], b: &[TFr]) -> Vec<TFr> {
+    let mut out = vec![TFr::zero(); a.len().max(b.len())];
+    for (i, out) in out.iter_mut().enumerate() {
+        *out = match (a.get(i), b.get(i)) {
+            (Some(a), Some(b)) => a.sub(b),
+            (Some(a), None) => a.clone(),
+            (None, Some(b)) => b.negate(),
+            (None, None) => unreachable!(),
+        };
+    }
+    trim(out)
+}
+
+fn poly_mul<TFr: Fr>(a: &[TFr], b: &[TFr]) -> Vec<TFr> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+
+    let mut out = vec![TFr::zero(); a.len() + b.len() - 1];
+    for (i, a) in a.iter().enumerate() {
+        for (j, b) in b.iter().enumerate() {
+            out[i + j] = out[i + j].add(&a.mul(b));
+        }
+    }
+    trim(out)
+}
+
+/// Returns the quotient and remainder of `a / b`, where `b` is not zero.
+fn poly_div_rem<TFr: Fr>(a: &[TFr], b: &[TFr]) -> (Vec<TFr>, Vec<TFr>) {
+    if a.len() < b.len() {
+        return (Vec::new(), a.to_vec());
+    }
+
+    let lead_inverse = b[b.len() - 1].inverse();
+    let mut rem = a.to_vec();
+    let mut quotient = vec![TFr::zero(); a.len() - b.len() + 1];
+    for i in (0..quotient.len()).rev() {
+        let factor = rem[i + b.len() - 1].mul(&lead_inverse);
+        for (j, b) in b.iter().enumerate() {
+            rem[i + j] = rem[i + j].sub(&factor.mul(b));
+        }
+        quotient[i] = factor;
+    }
+    rem.truncate(b.len() - 1);
+    (trim(quotient), trim(rem))
+}
+
+/// Decodes samples with missing and wrong values.
+///
+/// Returns the corrected evaluations at all `samples.len()` points, in the same order as the
+/// samples, and the indices of the samples that were wrong in ascending order. Fails if fewer than
+/// `data_len` samples are known, or if the samples are too far from any polynomial of degree below
+/// `data_len` to be corrected.
+pub fn correct_errors<TFr: Fr, TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>>(
+    samples: &[Option<TFr>],
+    data_len: usize,
+    fs: &TFFTSettings,
+) -> Result<(Vec<TFr>, Vec<usize>), Error> {
+    let n = samples.len();
+    if !n.is_power_of_two() {
+        return Err(Error::NotPowerOfTwo("samples length"));
+    }
+    if n > fs.get_max_width() {
+        return Err(Error::InvalidArgument(
+            "Samples length exceeds the FFT settings width",
+        ));
+    }
+    if data_len == 0 || data_len > n {
+        return Err(Error::InvalidArgument("Invalid data length"));
+    }
+
+    let known = samples.iter().filter(|sample| sample.is_some()).count();
+    if known < data_len {
+        return Err(Error::InvalidArgument(
+            "Impossible to recover, too many shards are missing",
+        ));
+    }
+
+    // g0 is the vanishing polynomial of the known points: x^n - 1 without the missing ones
+    let stride = fs.get_max_width() / n;
+    let mut g0 = vec![TFr::zero(); n + 1];
+    g0[0] = TFr::one().negate();
+    g0[n] = TFr::one();
+    let missing = samples
+        .iter()
+        .enumerate()
+        .filter(|(_, sample)| sample.is_none())
+        .fold(vec![TFr::one()], |zero_poly, (i, _)| {
+            let root = fs.get_expanded_roots_of_unity_at(i * stride);
+            poly_mul(&zero_poly, &[root.negate(), TFr::one()])
+        });
+    let g0 = poly_div_rem(&g0, &missing).0;
+
+    // g1 interpolates the known values; setting the missing ones to zero and reducing modulo g0
+    // gives the interpolation on the known points only
+    let values = samples
+        .iter()
+        .map(|sample| sample.clone().unwrap_or_else(TFr::zero))
+        .collect::<Vec<_>>();
+    let g1 = poly_div_rem(&trim(fs.fft_fr(&values, true)?), &g0).1;
+
+    // Stop as soon as the remainder has degree below (known + data_len) / 2, keeping track of the
+    // Bezout coefficient of g1, which is the error locator
+    let (mut r_prev, mut r) = (g0, g1);
+    let (mut v_prev, mut v) = (Vec::new(), vec![TFr::one()]);
+    while 2 * r.len() >= known + data_len + 2 {
+        let (quotient, rem) = poly_div_rem(&r_prev, &r);
+        let v_next = poly_sub(&v_prev, &poly_mul(&quotient, &v));
+        r_prev = core::mem::replace(&mut r, rem);
+        v_prev = core::mem::replace(&mut v, v_next);
+    }
+
+    let (data, rem) = poly_div_rem(&r, &v);
+    if !rem.is_empty() || data.len() > data_len {
+        return Err(Error::InvalidArgument("Too many errors to correct"));
+    }
+
+    let mut coeffs = data;
+    coeffs.resize(n, TFr::zero());
+    let evaluations = fs.fft_fr(&coeffs, false)?;
+    let corrected = samples
+        .iter()
+        .zip(&evaluations)
+        .enumerate()
+        .filter(|(_, (sample, evaluation))| {
+            sample
+                .as_ref()
+                .map_or(false, |sample| !sample.equals(evaluation))
+        })
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+    if 2 * corrected.len() > known - data_len {
+        return Err(Error::InvalidArgument("Too many errors to correct"));
+    }
+
+    Ok((evaluations, corrected))
+}
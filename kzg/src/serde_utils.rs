@@ -0,0 +1,141 @@
+//! Helpers for implementing `serde` traits on backend scalars and points.
+//!
+//! Values are written as `0x`-prefixed hex strings in human-readable formats, and as raw bytes
+//! otherwise. Use [`impl_serde_bytes`](crate::impl_serde_bytes) to implement both traits for a
+//! type in terms of its byte conversions.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+pub use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Error, Fr, G1};
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+pub fn serialize_bytes<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        let mut hex = String::with_capacity(2 + bytes.len() * 2);
+        hex.push_str("0x");
+        for byte in bytes {
+            hex.push(HEX_DIGITS[(byte >> 4) as usize] as char);
+            hex.push(HEX_DIGITS[(byte & 0xf) as usize] as char);
+        }
+        serializer.serialize_str(&hex)
+    } else {
+        serializer.serialize_bytes(bytes)
+    }
+}
+
+/// Reads bytes written by [`serialize_bytes`]. The length and content are left for the caller's
+/// `from_bytes` to validate.
+pub fn deserialize_bytes<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(HexVisitor)
+    } else {
+        deserializer.deserialize_bytes(BytesVisitor)
+    }
+}
+
+/// Parses a scalar, rejecting any encoding that [`Fr::to_bytes`] would not produce.
+pub fn fr_from_canonical_bytes<TFr: Fr>(bytes: &[u8]) -> Result<TFr, Error> {
+    let fr = TFr::from_bytes(bytes)?;
+    if fr.to_bytes()[..] != *bytes {
+        return Err(Error::NonCanonicalScalar);
+    }
+    Ok(fr)
+}
+
+/// Parses a point, rejecting non-canonical encodings and points outside the subgroup.
+pub fn g1_from_canonical_bytes<TG1: G1>(bytes: &[u8]) -> Result<TG1, Error> {
+    let g1 = TG1::from_bytes(bytes)?;
+    if g1.to_bytes()[..] != *bytes {
+        return Err(Error::InvalidPoint);
+    }
+    if !g1.is_valid() {
+        return Err(Error::PointNotInSubgroup);
+    }
+    Ok(g1)
+}
+
+struct HexVisitor;
+
+impl<'de> de::Visitor<'de> for HexVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a 0x-prefixed hex string")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        let hex = v
+            .strip_prefix("0x")
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))?;
+        if hex.len() % 2 != 0 {
+            return Err(E::invalid_value(de::Unexpected::Str(v), &self));
+        }
+
+        hex.as_bytes()
+            .chunks(2)
+            .map(|pair| {
+                core::str::from_utf8(pair)
+                    .ok()
+                    .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))
+            })
+            .collect()
+    }
+}
+
+struct BytesVisitor;
+
+impl<'de> de::Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a byte array")
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(v.to_vec())
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(v)
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Ok(bytes)
+    }
+}
+
+/// Implements `Serialize` and `Deserialize` for `$ty` through [`serialize_bytes`] and
+/// [`deserialize_bytes`]. `$to_bytes` and `$from_bytes` convert between `&$ty` and bytes, the
+/// latter returning a [`crate::Error`] when the bytes are not a valid value.
+#[macro_export]
+macro_rules! impl_serde_bytes {
+    ($ty: ty, $to_bytes: expr, $from_bytes: expr) => {
+        impl $crate::serde_utils::Serialize for $ty {
+            fn serialize<S: $crate::serde_utils::Serializer>(
+                &self,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                $crate::serde_utils::serialize_bytes(&$to_bytes(self), serializer)
+            }
+        }
+
+        impl<'de> $crate::serde_utils::Deserialize<'de> for $ty {
+            fn deserialize<D: $crate::serde_utils::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, D::Error> {
+                let bytes = $crate::serde_utils::deserialize_bytes(deserializer)?;
+                $from_bytes(&bytes).map_err(<D::Error as $crate::serde_utils::de::Error>::custom)
+            }
+        }
+    };
+}
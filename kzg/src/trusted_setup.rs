@@ -0,0 +1,363 @@
+//! Consistency checks for trusted setups.
+//!
+//! A setup is consistent if its G1 points are `[s^i]_1` and its G2 points are `[s^i]_2` for one
+//! secret `s`. Rather than pairing every neighbouring pair of points, both sequences are checked
+//! with random linear combinations: for challenges `r^i`,
+//! `e(sum(r^i * [s^(i+1)]_1), [1]_2) == e(sum(r^i * [s^i]_1), [s]_2)`, and the same for G2 with
+//! the roles of the groups swapped. The challenge is derived from a hash of all points, so the
+//! check is deterministic and needs four pairings whatever the size of the setup.
+//!
+//! [`Transcript`] runs a powers-of-tau ceremony on top of such a setup. Each contributor
+//! multiplies the powers by powers of their own secret `x` with [`contribute`], publishing
+//! `[x]_2` and a Schnorr proof that they know `x`. Anyone can then check with
+//! [`Transcript::verify`] that every contribution was built on the previous one, and the final
+//! setup stays safe as long as one contributor discarded their secret.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::eip_4844::{compute_powers, hash, hash_to_bls_field};
+use crate::poly::reverse_bit_order;
+use crate::{Error, FFTSettings, Fr, G1LinComb, G1Mul, G2Mul, PairingVerify, FFTG1, G1, G2};
+
+/// Basis of the G1 points of a setup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SetupBasis {
+    /// The powers `[s^i]_1`, against which polynomials are committed by their coefficients.
+    #[default]
+    Monomial,
+    /// The points `[L_i(s)]_1` of the Lagrange polynomials over the roots of unity, in
+    /// bit-reversed order, against which polynomials are committed by their evaluations.
+    Lagrange,
+}
+
+impl fmt::Display for SetupBasis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SetupBasis::Monomial => write!(f, "monomial"),
+            SetupBasis::Lagrange => write!(f, "Lagrange"),
+        }
+    }
+}
+
+/// Domain separator of the challenge for [`verify_monomial_setup`].
+pub const TRUSTED_SETUP_CHECK_DOMAIN: [u8; 16] = *b"RCKZGSETUP___V1_";
+
+/// Domain separator of the challenge of a [`ContributionProof`].
+pub const CONTRIBUTION_PROOF_DOMAIN: [u8; 16] = *b"RCKZGPOTPOK__V1_";
+
+/// Checks that `g1` and `g2` are the powers `[s^i]` of one secret `s`, starting with the
+/// generators.
+pub fn verify_monomial_setup<
+    TFr: Fr,
+    TG1: G1 + G1LinComb<TFr> + PairingVerify<TG1, TG2>,
+    TG2: G2 + G2Mul<TFr>,
+>(
+    g1: &[TG1],
+    g2: &[TG2],
+) -> Result<(), Error> {
+    if g1.len() < 2 || g2.len() < 2 {
+        return Err(Error::SetupTooSmall);
+    }
+    if !g1[0].equals(&TG1::generator()) || !g2[0].equals(&TG2::generator()) {
+        return Err(Error::InconsistentTrustedSetup(
+            "first points are not the generators",
+        ));
+    }
+    // With s = 0 every check below holds trivially
+    if g1[1].is_inf() {
+        return Err(Error::InconsistentTrustedSetup("secret is zero"));
+    }
+
+    let r = compute_challenge(g1, g2);
+    let n = g1.len() - 1;
+    let m = g2.len() - 1;
+    let r_powers = compute_powers(&r, n.max(m));
+
+    let g1_sum = TG1::g1_lincomb(&g1[..n], &r_powers, n);
+    let g1_shifted_sum = TG1::g1_lincomb(&g1[1..], &r_powers, n);
+    if !TG1::verify(&g1_shifted_sum, &g2[0], &g1_sum, &g2[1]) {
+        return Err(Error::InconsistentTrustedSetup(
+            "G1 points are not powers of the secret",
+        ));
+    }
+
+    let g2_sum = g2_lincomb(&g2[..m], &r_powers);
+    let g2_shifted_sum = g2_lincomb(&g2[1..], &r_powers);
+    if !TG1::verify(&g1[0], &g2_shifted_sum, &g1[1], &g2_sum) {
+        return Err(Error::InconsistentTrustedSetup(
+            "G2 points are not powers of the secret",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks G1 points in bit-reversed Lagrange form, as the EIP-4844 settings hold them, by
+/// recovering their monomial form with [`lagrange_to_monomial`].
+pub fn verify_lagrange_setup<
+    TFr: Fr,
+    TG1: G1 + G1LinComb<TFr> + PairingVerify<TG1, TG2>,
+    TG2: G2 + G2Mul<TFr>,
+    TFFTSettings: FFTSettings<TFr> + FFTG1<TG1>,
+>(
+    g1_lagrange: &[TG1],
+    g2: &[TG2],
+    fs: &TFFTSettings,
+) -> Result<(), Error> {
+    verify_monomial_setup(&lagrange_to_monomial(g1_lagrange, fs)?, g2)
+}
+
+/// Turns the bit-reversed Lagrange points `[L_i(s)]_1` over the `n`-th roots of unity into the
+/// powers `[s^i]_1`, undoing the inverse FFT the setup loaders apply.
+pub fn lagrange_to_monomial<TFr: Fr, TG1: G1, TFFTSettings: FFTSettings<TFr> + FFTG1<TG1>>(
+    g1_lagrange: &[TG1],
+    fs: &TFFTSettings,
+) -> Result<Vec<TG1>, Error> {
+    if !g1_lagrange.len().is_power_of_two() {
+        return Err(Error::NotPowerOfTwo("number of G1 points"));
+    }
+    if g1_lagrange.len() > fs.get_max_width() {
+        return Err(Error::InvalidArgument(
+            "Number of G1 points exceeds the FFT settings width",
+        ));
+    }
+
+    let mut points = g1_lagrange.to_vec();
+    reverse_bit_order(&mut points);
+    fs.fft_g1(&points, false)
+}
+
+/// Turns the powers `[s^i]_1` into bit-reversed Lagrange points, as the EIP-4844 settings hold
+/// them. Inverse of [`lagrange_to_monomial`].
+pub fn monomial_to_lagrange<TFr: Fr, TG1: G1, TFFTSettings: FFTSettings<TFr> + FFTG1<TG1>>(
+    g1_monomial: &[TG1],
+    fs: &TFFTSettings,
+) -> Result<Vec<TG1>, Error> {
+    if !g1_monomial.len().is_power_of_two() {
+        return Err(Error::NotPowerOfTwo("number of G1 points"));
+    }
+    if g1_monomial.len() > fs.get_max_width() {
+        return Err(Error::InvalidArgument(
+            "Number of G1 points exceeds the FFT settings width",
+        ));
+    }
+
+    let mut points = fs.fft_g1(g1_monomial, true)?;
+    reverse_bit_order(&mut points);
+    Ok(points)
+}
+
+/// Proof that a contributor to a ceremony knows the secret `x` they applied.
+#[derive(Debug, Clone)]
+pub struct ContributionProof<TFr, TG2> {
+    /// `[x]_2`, which ties the new `[s]_1` to the previous one.
+    pub pubkey: TG2,
+    /// Schnorr commitment `[k]_2` to a nonce `k`.
+    pub nonce_commitment: TG2,
+    /// Schnorr response `k + c * x`.
+    pub response: TFr,
+}
+
+/// Applies a contribution with secret `x` to the monomial setup `g1`, `g2`, multiplying the
+/// `i`-th point of each by `x^i`.
+///
+/// Returns the updated points and a proof of knowledge of `x`, bound to the previous `[s]_1`
+/// so that it cannot be replayed on another setup. The secret must not be zero and should be
+/// thrown away afterwards.
+#[allow(clippy::type_complexity)]
+pub fn contribute<TFr: Fr, TG1: G1 + G1Mul<TFr>, TG2: G2 + G2Mul<TFr>>(
+    g1: &[TG1],
+    g2: &[TG2],
+    secret: &TFr,
+) -> Result<(Vec<TG1>, Vec<TG2>, ContributionProof<TFr, TG2>), Error> {
+    if g1.len() < 2 || g2.len() < 2 {
+        return Err(Error::SetupTooSmall);
+    }
+    if secret.is_zero() {
+        return Err(Error::InvalidArgument("secret must not be zero"));
+    }
+
+    let powers = compute_powers(secret, g1.len().max(g2.len()));
+    let new_g1 = g1
+        .iter()
+        .zip(&powers)
+        .map(|(point, power)| point.mul(power))
+        .collect();
+    let new_g2 = g2
+        .iter()
+        .zip(&powers)
+        .map(|(point, power)| point.mul(power))
+        .collect();
+
+    // Deterministic nonce, so that contributing needs no randomness besides the secret
+    let mut nonce_input = Vec::with_capacity(16 + 32 + 48);
+    nonce_input.extend_from_slice(&CONTRIBUTION_PROOF_DOMAIN);
+    nonce_input.extend_from_slice(&secret.to_bytes());
+    nonce_input.extend_from_slice(&g1[1].to_bytes());
+    let nonce: TFr = hash_to_bls_field(&hash(&nonce_input));
+
+    let pubkey = TG2::generator().mul(secret);
+    let nonce_commitment = TG2::generator().mul(&nonce);
+    let c: TFr = compute_proof_challenge(&g1[1], &pubkey, &nonce_commitment);
+
+    Ok((
+        new_g1,
+        new_g2,
+        ContributionProof {
+            pubkey,
+            nonce_commitment,
+            response: nonce.add(&c.mul(secret)),
+        },
+    ))
+}
+
+/// Checks that the contribution with `proof` turned `[s]_1` into `[s * x]_1`, where `x` is
+/// the secret the contributor proved knowledge of.
+pub fn verify_contribution<TFr: Fr, TG1: G1 + PairingVerify<TG1, TG2>, TG2: G2 + G2Mul<TFr>>(
+    previous: &TG1,
+    next: &TG1,
+    proof: &ContributionProof<TFr, TG2>,
+) -> Result<(), Error> {
+    // A zero secret wipes out the setup and would pass the pairing check
+    if next.is_inf() {
+        return Err(Error::InconsistentTrustedSetup(
+            "contribution secret is zero",
+        ));
+    }
+
+    let c: TFr = compute_proof_challenge(previous, &proof.pubkey, &proof.nonce_commitment);
+    let mut expected = proof.nonce_commitment.clone();
+    let expected = expected.add_or_dbl(&proof.pubkey.mul(&c));
+    if !TG2::generator().mul(&proof.response).equals(&expected) {
+        return Err(Error::InconsistentTrustedSetup(
+            "invalid proof of knowledge of the contribution secret",
+        ));
+    }
+
+    if !TG1::verify(next, &TG2::generator(), previous, &proof.pubkey) {
+        return Err(Error::InconsistentTrustedSetup(
+            "contribution is not built on the previous setup",
+        ));
+    }
+
+    Ok(())
+}
+
+/// State of a powers-of-tau ceremony.
+#[derive(Debug, Clone)]
+pub struct Transcript<TFr, TG1, TG2> {
+    /// Current powers `[s^i]_1`, in monomial form.
+    pub g1_powers: Vec<TG1>,
+    /// Current powers `[s^i]_2`.
+    pub g2_powers: Vec<TG2>,
+    /// `[s]_1` before the first contribution and after each one.
+    pub running_products: Vec<TG1>,
+    /// Proofs of the contributions, in order.
+    pub proofs: Vec<ContributionProof<TFr, TG2>>,
+}
+
+impl<TFr, TG1, TG2> Transcript<TFr, TG1, TG2>
+where
+    TFr: Fr,
+    TG1: G1 + G1LinComb<TFr> + PairingVerify<TG1, TG2>,
+    TG2: G2 + G2Mul<TFr>,
+{
+    /// Starts a ceremony from the secret `s = 1`, where every point is the generator.
+    pub fn new(num_g1_points: usize, num_g2_points: usize) -> Result<Self, Error> {
+        Self::from_setup(
+            vec![TG1::generator(); num_g1_points],
+            vec![TG2::generator(); num_g2_points],
+        )
+    }
+
+    /// Starts a ceremony from an existing monomial setup, which is checked with
+    /// [`verify_monomial_setup`].
+    pub fn from_setup(g1_powers: Vec<TG1>, g2_powers: Vec<TG2>) -> Result<Self, Error> {
+        verify_monomial_setup(&g1_powers, &g2_powers)?;
+
+        Ok(Self {
+            running_products: vec![g1_powers[1].clone()],
+            g1_powers,
+            g2_powers,
+            proofs: Vec::new(),
+        })
+    }
+
+    /// Applies a contribution with `secret` and records its proof.
+    pub fn contribute(&mut self, secret: &TFr) -> Result<(), Error> {
+        let (g1_powers, g2_powers, proof) = contribute(&self.g1_powers, &self.g2_powers, secret)?;
+        self.running_products.push(g1_powers[1].clone());
+        self.proofs.push(proof);
+        self.g1_powers = g1_powers;
+        self.g2_powers = g2_powers;
+        Ok(())
+    }
+
+    /// Checks every contribution against the previous `[s]_1`, and that the current powers are
+    /// consistent and include the last contribution.
+    pub fn verify(&self) -> Result<(), Error> {
+        if self.running_products.len() != self.proofs.len() + 1 {
+            return Err(Error::InconsistentTrustedSetup(
+                "number of running products does not match the contributions",
+            ));
+        }
+
+        for (products, proof) in self.running_products.windows(2).zip(&self.proofs) {
+            verify_contribution(&products[0], &products[1], proof)?;
+        }
+
+        verify_monomial_setup(&self.g1_powers, &self.g2_powers)?;
+        if !self.g1_powers[1].equals(&self.running_products[self.proofs.len()]) {
+            return Err(Error::InconsistentTrustedSetup(
+                "powers do not match the last contribution",
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the G1 powers as bit-reversed Lagrange points, see [`monomial_to_lagrange`].
+    pub fn g1_lagrange<TFFTSettings: FFTSettings<TFr> + FFTG1<TG1>>(
+        &self,
+        fs: &TFFTSettings,
+    ) -> Result<Vec<TG1>, Error> {
+        monomial_to_lagrange(&self.g1_powers, fs)
+    }
+}
+
+fn compute_proof_challenge<TFr: Fr, TG1: G1, TG2: G2>(
+    previous: &TG1,
+    pubkey: &TG2,
+    nonce_commitment: &TG2,
+) -> TFr {
+    let mut bytes = Vec::with_capacity(16 + 48 + 2 * 96);
+    bytes.extend_from_slice(&CONTRIBUTION_PROOF_DOMAIN);
+    bytes.extend_from_slice(&previous.to_bytes());
+    bytes.extend_from_slice(&pubkey.to_bytes());
+    bytes.extend_from_slice(&nonce_commitment.to_bytes());
+
+    hash_to_bls_field(&hash(&bytes))
+}
+
+fn compute_challenge<TFr: Fr, TG1: G1, TG2: G2>(g1: &[TG1], g2: &[TG2]) -> TFr {
+    let mut bytes = Vec::with_capacity(16 + g1.len() * 48 + g2.len() * 96);
+    bytes.extend_from_slice(&TRUSTED_SETUP_CHECK_DOMAIN);
+    for point in g1 {
+        bytes.extend_from_slice(&point.to_bytes());
+    }
+    for point in g2 {
+        bytes.extend_from_slice(&point.to_bytes());
+    }
+
+    hash_to_bls_field(&hash(&bytes))
+}
+
+fn g2_lincomb<TFr: Fr, TG2: G2 + G2Mul<TFr>>(points: &[TG2], scalars: &[TFr]) -> TG2 {
+    let mut out = points[0].mul(&scalars[0]);
+    for (point, scalar) in points.iter().zip(scalars).skip(1) {
+        out = out.add_or_dbl(&point.mul(scalar));
+    }
+    out
+}
@@ -20,5 +20,7 @@ mod shared_tests {
     mod kzg_proofs;
     mod poly;
     mod recover;
+    mod serialization;
+    mod trusted_setup;
     mod zero_poly;
 }
@@ -76,6 +76,18 @@ mod tests {
         fr_uint64s_roundtrip::<Fr>();
     }
 
+    #[test]
+    pub fn fr_batch_inverse_works_() {
+        assert!(init(CurveType::BLS12_381));
+        fr_batch_inverse_works::<Fr>();
+    }
+
+    #[test]
+    pub fn fr_batch_mul_and_inner_product_work_() {
+        assert!(init(CurveType::BLS12_381));
+        fr_batch_mul_and_inner_product_work::<Fr>();
+    }
+
     #[test]
     pub fn p1_mul_works_() {
         assert!(init(CurveType::BLS12_381));
@@ -130,9 +142,27 @@ mod tests {
         g1_random_linear_combination::<Fr, G1>(&g1_linear_combination);
     }
 
+    #[test]
+    pub fn g1_lincomb_matches_naive_() {
+        assert!(init(CurveType::BLS12_381));
+        g1_lincomb_matches_naive::<Fr, G1>();
+    }
+
+    #[test]
+    pub fn g2_lincomb_matches_naive_() {
+        assert!(init(CurveType::BLS12_381));
+        g2_lincomb_matches_naive::<Fr, G2>();
+    }
+
     #[test]
     pub fn pairings_work_() {
         assert!(init(CurveType::BLS12_381));
         pairings_work::<Fr, G1, G2>(&Curve::verify_pairing);
     }
+
+    #[test]
+    pub fn pairings_verify_multi_works_() {
+        assert!(init(CurveType::BLS12_381));
+        pairings_verify_multi_works::<Fr, G1, G2>();
+    }
 }
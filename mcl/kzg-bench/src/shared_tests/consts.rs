@@ -1,5 +1,6 @@
 #[cfg(test)]
 mod consts_tests {
+    use kzg::Error;
     use kzg_bench::tests::consts::*;
     use rust_kzg_mcl::data_types::fr::Fr;
     use rust_kzg_mcl::fk20_fft::{
@@ -8,7 +9,7 @@ mod consts_tests {
     use rust_kzg_mcl::mcl_methods::init;
     use rust_kzg_mcl::CurveType;
 
-    pub fn expand_root_of_unityarr(root: &Fr, _width: usize) -> Result<Vec<Fr>, String> {
+    pub fn expand_root_of_unityarr(root: &Fr, _width: usize) -> Result<Vec<Fr>, Error> {
         Ok(expand_root_of_unity(root))
     }
 
@@ -15,6 +15,18 @@ mod tests {
         bytes_to_bls_field_test::<Fr>();
     }
 
+    #[test]
+    pub fn encode_payload_round_trip_test_() {
+        assert!(init(CurveType::BLS12_381));
+        encode_payload_round_trip_test::<Fr>();
+    }
+
+    #[test]
+    pub fn decode_payload_rejects_malformed_blobs_test_() {
+        assert!(init(CurveType::BLS12_381));
+        decode_payload_rejects_malformed_blobs_test::<Fr>();
+    }
+
     #[test]
     pub fn compute_powers_test_() {
         assert!(init(CurveType::BLS12_381));
@@ -132,6 +144,62 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn compute_and_verify_blob_kzg_proof_with_runtime_preset_test_() {
+        assert!(init(CurveType::BLS12_381));
+        compute_and_verify_blob_kzg_proof_with_runtime_preset_test::<
+            Fr,
+            G1,
+            G2,
+            Polynomial,
+            FFTSettings,
+            KZGSettings,
+        >(
+            &load_trusted_setup,
+            &blob_to_kzg_commitment,
+            &bytes_to_blob_with_preset,
+            &compute_blob_kzg_proof,
+            &verify_blob_kzg_proof,
+        );
+    }
+
+    #[test]
+    pub fn load_trusted_setup_string_reports_line_test_() {
+        load_trusted_setup_string_reports_line_test();
+    }
+
+    #[test]
+    pub fn load_trusted_setup_json_reports_line_test_() {
+        load_trusted_setup_json_reports_line_test();
+    }
+
+    #[test]
+    pub fn load_trusted_setup_json_test_() {
+        assert!(init(CurveType::BLS12_381));
+        load_trusted_setup_json_test::<Fr, G1, G2, Polynomial, FFTSettings, KZGSettings>(
+            &load_trusted_setup,
+            &load_trusted_setup_json,
+        );
+    }
+
+    #[test]
+    pub fn verify_trusted_setup_test_() {
+        assert!(init(CurveType::BLS12_381));
+        verify_trusted_setup_test::<Fr, G1, G2, Polynomial, FFTSettings, KZGSettings>(
+            &load_trusted_setup_from_bytes,
+            &KZGSettings::verify_trusted_setup,
+        );
+    }
+
+    #[test]
+    pub fn load_trusted_setup_lagrange_test_() {
+        assert!(init(CurveType::BLS12_381));
+        load_trusted_setup_lagrange_test::<Fr, G1, G2, Polynomial, FFTSettings, KZGSettings>(
+            &load_trusted_setup_from_bytes,
+            &load_trusted_setup_lagrange_from_bytes,
+        );
+    }
+
     #[test]
     pub fn verify_kzg_proof_batch_test_() {
         assert!(init(CurveType::BLS12_381));
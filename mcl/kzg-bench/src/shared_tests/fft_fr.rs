@@ -29,4 +29,10 @@ mod fft_fr_tests {
         assert!(init(CurveType::BLS12_381));
         stride_fft::<Fr, FFTSettings>();
     }
+
+    #[test]
+    fn coset_fft_() {
+        assert!(init(CurveType::BLS12_381));
+        coset_fft::<Fr, FFTSettings>();
+    }
 }
@@ -28,4 +28,10 @@ mod fft_g1_tests {
             &make_data,
         );
     }
+
+    #[test]
+    fn coset_fft_() {
+        assert!(init(CurveType::BLS12_381));
+        coset_fft::<Fr, G1, FFTSettings>();
+    }
 }
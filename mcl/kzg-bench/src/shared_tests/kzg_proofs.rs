@@ -39,4 +39,20 @@ mod kzg_proofs_tests {
             &KZGSettings::generate_trusted_setup,
         );
     }
+
+    #[test]
+    fn proof_multi_with_few_g2_points_() {
+        assert!(init(CurveType::BLS12_381));
+        proof_multi_with_few_g2_points::<Fr, G1, G2, Polynomial, FFTSettings, KZGSettings>(
+            &KZGSettings::generate_trusted_setup,
+        );
+    }
+
+    #[test]
+    fn setup_basis_conversion_() {
+        assert!(init(CurveType::BLS12_381));
+        setup_basis_conversion::<Fr, G1, G2, Polynomial, FFTSettings, KZGSettings>(
+            &KZGSettings::generate_trusted_setup,
+        );
+    }
 }
@@ -90,4 +90,52 @@ mod poly_tests {
         assert!(init(CurveType::BLS12_381));
         poly_div_fast_test::<Fr, Polynomial>();
     }
+
+    #[test]
+    fn poly_add_sub_test_() {
+        assert!(init(CurveType::BLS12_381));
+        poly_add_sub_test::<Fr, Polynomial>();
+    }
+
+    #[test]
+    fn poly_derivative_test_() {
+        assert!(init(CurveType::BLS12_381));
+        poly_derivative_test::<Fr, Polynomial>();
+    }
+
+    #[test]
+    fn poly_compose_x_pow_test_() {
+        assert!(init(CurveType::BLS12_381));
+        poly_compose_x_pow_test::<Fr, Polynomial>();
+    }
+
+    #[test]
+    fn poly_div_rem_test_() {
+        assert!(init(CurveType::BLS12_381));
+        poly_div_rem_test::<Fr, Polynomial>();
+    }
+
+    #[test]
+    fn poly_eval_multi_test_() {
+        assert!(init(CurveType::BLS12_381));
+        poly_eval_multi_test::<Fr, Polynomial>();
+    }
+
+    #[test]
+    fn poly_interpolate_test_() {
+        assert!(init(CurveType::BLS12_381));
+        poly_interpolate_test::<Fr, Polynomial>();
+    }
+
+    #[test]
+    fn poly_eval_form_test_() {
+        assert!(init(CurveType::BLS12_381));
+        poly_eval_form_test::<Fr, Polynomial, FFTSettings>();
+    }
+
+    #[test]
+    fn poly_eval_form_div_test_() {
+        assert!(init(CurveType::BLS12_381));
+        poly_eval_form_div_test::<Fr, Polynomial, FFTSettings>();
+    }
 }
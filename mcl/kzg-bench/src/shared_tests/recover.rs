@@ -24,4 +24,16 @@ mod recover_tests {
         assert!(init(CurveType::BLS12_381));
         more_than_half_missing::<Fr, FFTSettings, Polynomial, Polynomial>();
     }
+
+    #[test]
+    fn correct_errors_simple_() {
+        assert!(init(CurveType::BLS12_381));
+        correct_errors_simple::<Fr, FFTSettings, Polynomial, Polynomial>();
+    }
+
+    #[test]
+    fn correct_errors_too_many_() {
+        assert!(init(CurveType::BLS12_381));
+        correct_errors_too_many::<Fr, FFTSettings, Polynomial, Polynomial>();
+    }
 }
@@ -0,0 +1,40 @@
+#[cfg(feature = "serde")]
+mod tests {
+    use kzg_bench::tests::serialization::{
+        fr_serde_rejects_invalid_test, fr_serde_round_trip_test, g1_serde_rejects_invalid_test,
+        g1_serde_round_trip_test, g2_serde_round_trip_test,
+    };
+    use rust_kzg_mcl::data_types::{fr::Fr, g1::G1, g2::G2};
+    use rust_kzg_mcl::mcl_methods::init;
+    use rust_kzg_mcl::CurveType;
+
+    #[test]
+    fn fr_serde_round_trip_test_() {
+        assert!(init(CurveType::BLS12_381));
+        fr_serde_round_trip_test::<Fr>();
+    }
+
+    #[test]
+    fn fr_serde_rejects_invalid_test_() {
+        assert!(init(CurveType::BLS12_381));
+        fr_serde_rejects_invalid_test::<Fr>();
+    }
+
+    #[test]
+    fn g1_serde_round_trip_test_() {
+        assert!(init(CurveType::BLS12_381));
+        g1_serde_round_trip_test::<G1>();
+    }
+
+    #[test]
+    fn g1_serde_rejects_invalid_test_() {
+        assert!(init(CurveType::BLS12_381));
+        g1_serde_rejects_invalid_test::<G1>();
+    }
+
+    #[test]
+    fn g2_serde_round_trip_test_() {
+        assert!(init(CurveType::BLS12_381));
+        g2_serde_round_trip_test::<G2>();
+    }
+}
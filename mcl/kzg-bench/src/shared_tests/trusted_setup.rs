@@ -0,0 +1,22 @@
+#[cfg(test)]
+mod trusted_setup_tests {
+    use kzg_bench::tests::trusted_setup::*;
+    use rust_kzg_mcl::data_types::{fr::Fr, g1::G1, g2::G2};
+    use rust_kzg_mcl::fk20_fft::FFTSettings;
+    use rust_kzg_mcl::kzg10::Polynomial;
+    use rust_kzg_mcl::kzg_settings::KZGSettings;
+    use rust_kzg_mcl::mcl_methods::init;
+    use rust_kzg_mcl::CurveType;
+
+    #[test]
+    fn powers_of_tau_ceremony_test_() {
+        assert!(init(CurveType::BLS12_381));
+        powers_of_tau_ceremony_test::<Fr, G1, G2, Polynomial, FFTSettings, KZGSettings>();
+    }
+
+    #[test]
+    fn powers_of_tau_tampered_transcript_test_() {
+        assert!(init(CurveType::BLS12_381));
+        powers_of_tau_tampered_transcript_test::<Fr, G1, G2>();
+    }
+}
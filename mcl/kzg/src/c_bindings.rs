@@ -3,14 +3,15 @@ use crate::fk20_fft::FFTSettings as mFFTSettings;
 use crate::kzg_settings::KZGSettings as mKZGSettings;
 use kzg::cfg_into_iter;
 use kzg::eip_4844::{
-    blst_p1, load_trusted_setup_string, Blob, Bytes32, Bytes48, CFFTSettings, CKZGSettings,
-    KZGCommitment, KZGProof, BYTES_PER_FIELD_ELEMENT, BYTES_PER_G1, BYTES_PER_G2, C_KZG_RET,
-    C_KZG_RET_BADARGS, C_KZG_RET_OK,
+    blst_p1, load_trusted_setup_string, Blob, BlobPreset, Bytes32, Bytes48, CFFTSettings,
+    CKZGSettings, KZGCommitment, KZGProof, BYTES_PER_FIELD_ELEMENT, BYTES_PER_G1, BYTES_PER_G2,
+    CKZG_SETTINGS_VERSION, C_KZG_RET, C_KZG_RET_BADARGS, C_KZG_RET_OK,
 };
 use std::boxed::Box;
 use std::slice::{from_raw_parts, from_raw_parts_mut};
 
 use crate::data_types::g1::G1;
+use kzg::trusted_setup::SetupBasis;
 use kzg::G1 as CommonG1;
 
 #[cfg(feature = "parallel")]
@@ -28,6 +29,8 @@ unsafe fn ks_to_cks(t: &mut mKZGSettings, out: *mut CKZGSettings) {
     assert_eq!(t.curve.g1_points.len(), t.fft_settings.max_width);
     (*out).g1_values = t.curve.g1_points.as_mut_ptr() as _;
     (*out).g2_values = t.curve.g2_points.as_mut_ptr() as _;
+    (*out).num_g1_points = t.curve.g1_points.len();
+    (*out).backend_settings = core::ptr::null();
     let fs = CFFTSettings {
         max_width: t.fft_settings.max_width as _,
         roots_of_unity: t.fft_settings.expanded_roots_of_unity.as_mut_ptr() as _,
@@ -46,7 +49,11 @@ unsafe fn cks_to_ks(t: *const CKZGSettings) -> mKZGSettings {
         curve: crate::kzg10::Curve {
             g1_gen: G1::gen(),
             g2_gen: G2::gen(),
-            g1_points: Vec::from_raw_parts((*t).g1_values as _, mw, mw),
+            g1_points: Vec::from_raw_parts(
+                (*t).g1_values as _,
+                (*t).num_g1_points,
+                (*t).num_g1_points,
+            ),
             g2_points: Vec::from_raw_parts((*t).g2_values as _, 65, 65),
         },
         fft_settings: mFFTSettings {
@@ -64,14 +71,27 @@ unsafe fn cks_to_ks(t: *const CKZGSettings) -> mKZGSettings {
             ),
             roots_of_unity: Vec::from_raw_parts((*fs).roots_of_unity as _, mw + 1, mw + 1),
         },
+        blob_preset: cks_blob_preset(t),
+        basis: SetupBasis::Lagrange,
     };
     ks.fft_settings.root_of_unity = ks.fft_settings.expanded_roots_of_unity[1];
     ks
 }
 
-unsafe fn deserialize_blob(blob: *const Blob) -> Result<Vec<Fr>, C_KZG_RET> {
-    (*blob)
-        .bytes
+unsafe fn cks_blob_preset(t: *const CKZGSettings) -> BlobPreset {
+    // Settings created by `load_trusted_setup` have exactly one root of unity per blob element
+    BlobPreset {
+        field_elements_per_blob: (*(*t).fs).max_width as usize,
+    }
+}
+
+/// Returns the bytes of `n` consecutive blobs, sized according to the preset of the settings.
+unsafe fn blob_bytes<'a>(blobs: *const Blob, n: usize, preset: &BlobPreset) -> &'a [u8] {
+    from_raw_parts(blobs as *const u8, n * preset.bytes_per_blob())
+}
+
+fn deserialize_blob(bytes: &[u8]) -> Result<Vec<Fr>, C_KZG_RET> {
+    bytes
         .chunks(BYTES_PER_FIELD_ELEMENT)
         .map(|chunk| {
             let mut bytes = [0u8; BYTES_PER_FIELD_ELEMENT];
@@ -112,10 +132,17 @@ pub unsafe extern "C" fn load_trusted_setup(
     n2: usize,
 ) -> C_KZG_RET {
     assert!(crate::mcl_methods::init(crate::CurveType::BLS12_381));
-    let mut mks = crate::eip_4844::load_trusted_setup_from_bytes(
+    if BlobPreset::new(n1).is_err() {
+        return C_KZG_RET_BADARGS;
+    }
+    let mut mks = match crate::eip_4844::load_trusted_setup_from_bytes(
         from_raw_parts(g1_bytes, n1 * BYTES_PER_G1),
         from_raw_parts(g2_bytes, n2 * BYTES_PER_G2),
-    );
+        false,
+    ) {
+        Ok(mks) => mks,
+        Err(_) => return C_KZG_RET_BADARGS,
+    };
     ks_to_cks(&mut mks, out);
     std::mem::forget(mks);
     C_KZG_RET_OK
@@ -131,17 +158,36 @@ pub unsafe extern "C" fn load_trusted_setup_file(
 
     let mut buf = vec![0u8; 1024 * 1024];
     let len: usize = libc::fread(buf.as_mut_ptr() as *mut libc::c_void, 1, buf.len(), in_);
-    let s = String::from_utf8(buf[..len].to_vec()).unwrap();
-
-    let (g1_bytes, g2_bytes) = load_trusted_setup_string(&s);
-    let mut mks =
-        crate::eip_4844::load_trusted_setup_from_bytes(g1_bytes.as_slice(), g2_bytes.as_slice());
+    let (g1_bytes, g2_bytes) = match String::from_utf8(buf[..len].to_vec()) {
+        Ok(s) => match load_trusted_setup_string(&s) {
+            Ok(bytes) => bytes,
+            Err(_) => return C_KZG_RET_BADARGS,
+        },
+        Err(_) => return C_KZG_RET_BADARGS,
+    };
+    if BlobPreset::new(g1_bytes.len() / BYTES_PER_G1).is_err() {
+        return C_KZG_RET_BADARGS;
+    }
+    let mut mks = match crate::eip_4844::load_trusted_setup_from_bytes(
+        g1_bytes.as_slice(),
+        g2_bytes.as_slice(),
+        false,
+    ) {
+        Ok(mks) => mks,
+        Err(_) => return C_KZG_RET_BADARGS,
+    };
     ks_to_cks(&mut mks, out);
     std::mem::forget(mks);
 
     C_KZG_RET_OK
 }
 
+/// Returns the [`CKZG_SETTINGS_VERSION`] of the `CKZGSettings` this library reads and writes.
+#[no_mangle]
+pub extern "C" fn ckzg_settings_version() -> u32 {
+    CKZG_SETTINGS_VERSION
+}
+
 /// # Safety
 #[no_mangle]
 pub unsafe extern "C" fn free_trusted_setup(s: *mut CKZGSettings) {
@@ -161,7 +207,7 @@ pub unsafe extern "C" fn compute_blob_kzg_proof(
 ) -> C_KZG_RET {
     assert!(crate::mcl_methods::init(crate::CurveType::BLS12_381));
 
-    let deserialized_blob = deserialize_blob(blob);
+    let deserialized_blob = deserialize_blob(blob_bytes(blob, 1, &cks_blob_preset(s)));
     if deserialized_blob.is_err() {
         return deserialized_blob.err().unwrap();
     }
@@ -178,11 +224,12 @@ pub unsafe extern "C" fn compute_blob_kzg_proof(
     );
     std::mem::forget(ms);
 
-    if let Ok(proof) = proof {
-        (*out).bytes = proof.to_bytes();
-        C_KZG_RET_OK
-    } else {
-        C_KZG_RET_BADARGS
+    match proof {
+        Ok(proof) => {
+            (*out).bytes = proof.to_bytes();
+            C_KZG_RET_OK
+        }
+        Err(err) => err.into(),
     }
 }
 
@@ -197,7 +244,7 @@ pub unsafe extern "C" fn verify_blob_kzg_proof(
 ) -> C_KZG_RET {
     assert!(crate::mcl_methods::init(crate::CurveType::BLS12_381));
 
-    let deserialized_blob = deserialize_blob(blob);
+    let deserialized_blob = deserialize_blob(blob_bytes(blob, 1, &cks_blob_preset(s)));
     if deserialized_blob.is_err() {
         return deserialized_blob.err().unwrap();
     }
@@ -216,11 +263,12 @@ pub unsafe extern "C" fn verify_blob_kzg_proof(
     );
     std::mem::forget(ms);
 
-    if let Ok(result) = result {
-        *ok = result;
-        C_KZG_RET_OK
-    } else {
-        C_KZG_RET_BADARGS
+    match result {
+        Ok(result) => {
+            *ok = result;
+            C_KZG_RET_OK
+        }
+        Err(err) => err.into(),
     }
 }
 
@@ -236,20 +284,22 @@ pub unsafe extern "C" fn verify_blob_kzg_proof_batch(
 ) -> C_KZG_RET {
     assert!(crate::mcl_methods::init(crate::CurveType::BLS12_381));
 
-    let raw_blobs = from_raw_parts(blobs, n);
+    let preset = cks_blob_preset(s);
+    let raw_blobs = blob_bytes(blobs, n, &preset)
+        .chunks(preset.bytes_per_blob())
+        .collect::<Vec<_>>();
     let raw_commitments = from_raw_parts(commitments_bytes, n);
     let raw_proofs = from_raw_parts(proofs_bytes, n);
 
-    let deserialized_blobs: Result<Vec<Vec<Fr>>, C_KZG_RET> = cfg_into_iter!(raw_blobs)
-        .map(|raw_blob| deserialize_blob(raw_blob).map_err(|_| C_KZG_RET_BADARGS))
-        .collect();
+    let deserialized_blobs: Result<Vec<Vec<Fr>>, C_KZG_RET> =
+        cfg_into_iter!(raw_blobs).map(deserialize_blob).collect();
 
     let commitments_g1: Result<Vec<G1>, C_KZG_RET> = cfg_into_iter!(raw_commitments)
-        .map(|raw_commitment| G1::from_bytes(&raw_commitment.bytes).map_err(|_| C_KZG_RET_BADARGS))
+        .map(|raw_commitment| G1::from_bytes(&raw_commitment.bytes).map_err(C_KZG_RET::from))
         .collect();
 
     let proofs_g1: Result<Vec<G1>, C_KZG_RET> = cfg_into_iter!(raw_proofs)
-        .map(|raw_proof| G1::from_bytes(&raw_proof.bytes).map_err(|_| C_KZG_RET_BADARGS))
+        .map(|raw_proof| G1::from_bytes(&raw_proof.bytes).map_err(C_KZG_RET::from))
         .collect();
 
     if let (Ok(blobs), Ok(commitments), Ok(proofs)) =
@@ -264,11 +314,12 @@ pub unsafe extern "C" fn verify_blob_kzg_proof_batch(
         );
         std::mem::forget(ms);
 
-        if let Ok(result) = result {
-            *ok = result;
-            C_KZG_RET_OK
-        } else {
-            C_KZG_RET_BADARGS
+        match result {
+            Ok(result) => {
+                *ok = result;
+                C_KZG_RET_OK
+            }
+            Err(err) => err.into(),
         }
     } else {
         *ok = false;
@@ -285,7 +336,7 @@ pub unsafe extern "C" fn blob_to_kzg_commitment(
 ) -> C_KZG_RET {
     assert!(crate::mcl_methods::init(crate::CurveType::BLS12_381));
 
-    let deserialized_blob = deserialize_blob(blob);
+    let deserialized_blob = deserialize_blob(blob_bytes(blob, 1, &cks_blob_preset(s)));
     if deserialized_blob.is_err() {
         return deserialized_blob.err().unwrap();
     }
@@ -328,11 +379,12 @@ pub unsafe extern "C" fn verify_kzg_proof(
     );
     std::mem::forget(ms);
 
-    if let Ok(result) = result {
-        *ok = result;
-        C_KZG_RET_OK
-    } else {
-        C_KZG_RET_BADARGS
+    match result {
+        Ok(result) => {
+            *ok = result;
+            C_KZG_RET_OK
+        }
+        Err(err) => err.into(),
     }
 }
 
@@ -347,7 +399,7 @@ pub unsafe extern "C" fn compute_kzg_proof(
 ) -> C_KZG_RET {
     assert!(crate::mcl_methods::init(crate::CurveType::BLS12_381));
 
-    let deserialized_blob = deserialize_blob(blob);
+    let deserialized_blob = deserialize_blob(blob_bytes(blob, 1, &cks_blob_preset(s)));
     if deserialized_blob.is_err() {
         return deserialized_blob.err().unwrap();
     }
@@ -1,17 +1,18 @@
 use crate::data_types::fr::Fr;
 use crate::fk20_fft::*;
 use crate::utilities::is_power_of_2;
+use kzg::Error;
 
 impl FFTSettings {
-    pub fn das_fft_extension(&self, values: &mut [Fr]) -> Result<(), String> {
+    pub fn das_fft_extension(&self, values: &mut [Fr]) -> Result<(), Error> {
         if values.is_empty() {
-            return Err(String::from("Values cannot be empty"));
+            return Err(Error::InvalidArgument("Values cannot be empty"));
         }
         if !is_power_of_2(values.len()) {
-            return Err(String::from("Value count must be a number of two"));
+            return Err(Error::NotPowerOfTwo("values length"));
         }
         if values.len() << 1 > self.max_width {
-            return Err(String::from("ftt settings max width too small!"));
+            return Err(Error::InvalidArgument("ftt settings max width too small!"));
         }
 
         //larger stride if more roots fttsettings
@@ -4,6 +4,7 @@ use crate::kzg10::*;
 use crate::utilities::is_power_of_2;
 #[cfg(feature = "parallel")]
 use crate::utilities::next_pow_of_2;
+use kzg::Error;
 #[cfg(feature = "parallel")]
 use once_cell::sync::OnceCell;
 
@@ -79,9 +80,9 @@ impl Polynomial {
     pub fn recover_coeffs_from_samples(
         fft_settings: &FFTSettings,
         samples: &[Option<Fr>],
-    ) -> Result<Self, String> {
+    ) -> Result<Self, Error> {
         if !is_power_of_2(samples.len()) {
-            return Err(String::from("length of samples must be a power of two"));
+            return Err(Error::NotPowerOfTwo("samples length"));
         }
 
         let missing_data_indices: Vec<usize> = samples
@@ -92,7 +93,7 @@ impl Polynomial {
             .collect();
 
         if missing_data_indices.len() > samples.len() / 2 {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "Impossible to recover, too many shards are missing",
             ));
         }
@@ -191,7 +192,7 @@ impl Polynomial {
     pub fn recover_from_samples(
         fft_settings: &FFTSettings,
         samples: &[Option<Fr>],
-    ) -> Result<Self, String> {
+    ) -> Result<Self, Error> {
         let reconstructed_data = fft_settings
             .fft(
                 &Self::recover_coeffs_from_samples(fft_settings, samples)?.coeffs,
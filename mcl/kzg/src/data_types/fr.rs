@@ -1,4 +1,5 @@
 use crate::mcl_methods;
+use kzg::Error;
 use std::ops::{Add, AddAssign};
 use std::ops::{Div, DivAssign};
 use std::ops::{Mul, MulAssign};
@@ -78,10 +79,10 @@ impl Fr {
         Fr::from_bytes(&arr).unwrap()
     }
 
-    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         let mut t = Fr::default();
         if !t.set_little_endian_mod(bytes) {
-            return Err("Invalid scalar".to_string());
+            return Err(Error::NonCanonicalScalar);
         }
         Ok(t)
     }
@@ -2,10 +2,15 @@ use crate::data_types::fp::Fp;
 use crate::data_types::fp2::Fp2;
 use crate::data_types::fr::Fr;
 use crate::mcl_methods;
+#[cfg(feature = "parallel")]
+use kzg::G2 as _;
 use std::ops::{Add, AddAssign};
 use std::ops::{Sub, SubAssign};
 use std::os::raw::c_int;
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 #[link(name = "mcl", kind = "static")]
 #[link(name = "mclbn384_256", kind = "static")]
 #[link(name = "stdc++")]
@@ -14,11 +19,13 @@ extern "C" {
     fn mclBnG2_isEqual(x: *const G2, y: *const G2) -> i32;
     fn mclBnG2_isValid(x: *const G2) -> i32;
     fn mclBnG2_isZero(x: *const G2) -> i32;
+    fn mclBnG2_isValidOrder(x: *const G2) -> i32;
 
     fn mclBnG2_setStr(x: *mut G2, buf: *const u8, bufSize: usize, ioMode: i32) -> c_int;
     fn mclBnG2_getStr(buf: *mut u8, maxBufSize: usize, x: *const G2, ioMode: i32) -> usize;
     fn mclBnG2_serialize(buf: *mut u8, maxBufSize: usize, x: *const G2) -> usize;
     fn mclBnG2_deserialize(x: *mut G2, buf: *const u8, bufSize: usize) -> usize;
+    fn mclBnG2_mulVec(x: *mut G2, vec1: *const G2, vec2: *const Fr, bufSize: usize);
 
     fn mclBnG2_add(z: *mut G2, x: *const G2, y: *const G2);
     fn mclBnG2_sub(z: *mut G2, x: *const G2, y: *const G2);
@@ -30,6 +37,35 @@ extern "C" {
     fn mclBnG2_hashAndMapTo(x: *mut G2, buf: *const u8, bufSize: usize) -> c_int;
 }
 
+pub fn g2_linear_combination(out: &mut G2, points: &[G2], scalars: &[Fr], len: usize) {
+    // Split the points between the threads and run mcl's multi-scalar multiplication on each part
+    #[cfg(feature = "parallel")]
+    {
+        let num_threads = rayon::current_num_threads();
+        let chunk_size = ((len + num_threads - 1) / num_threads).max(1);
+        *out = points[..len]
+            .par_chunks(chunk_size)
+            .zip(scalars[..len].par_chunks(chunk_size))
+            .map(|(points, scalars)| {
+                let mut part = G2::default();
+                unsafe {
+                    mclBnG2_mulVec(&mut part, points.as_ptr(), scalars.as_ptr(), points.len())
+                }
+                part
+            })
+            .reduce(G2::default, |mut acc, tmp| acc.add_or_dbl(&tmp));
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    {
+        unsafe { mclBnG2_mulVec(out, points.as_ptr(), scalars.as_ptr(), len) }
+    }
+}
+
+pub fn is_valid_order(g2: &G2) -> bool {
+    unsafe { mclBnG2_isValidOrder(g2) == 1 }
+}
+
 #[derive(Default, Debug, Clone, Copy)]
 #[repr(C)]
 pub struct G2 {
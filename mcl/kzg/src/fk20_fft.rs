@@ -1,5 +1,6 @@
 use crate::data_types::{fp::*, fr::*, g1::*};
 use crate::utilities::{is_power_of_2, next_pow_of_2, reverse_bit_order};
+use kzg::Error;
 use std::iter;
 
 // MODULUS = 52435875175126190479447740508185965837690552500527637822603658699938581184513
@@ -280,12 +281,12 @@ impl FFTSettings {
     pub fn new_custom_primitive_roots(
         max_scale: u8,
         root_strings: [&str; 32],
-    ) -> Result<FFTSettings, String> {
+    ) -> Result<FFTSettings, Error> {
         let root_of_unity: Fr;
         unsafe {
             init_globals_custom(root_strings);
             if max_scale as usize >= SCALE_2_ROOT_OF_UNITY.len() {
-                return Err(String::from(
+                return Err(Error::InvalidArgument(
                     "Scale is expected to be within root of unity matrix row size",
                 ));
             }
@@ -465,9 +466,9 @@ impl FFTSettings {
         }
     }
 
-    pub fn fft(&self, values: &[Fr], inv: bool) -> Result<Vec<Fr>, String> {
+    pub fn fft(&self, values: &[Fr], inv: bool) -> Result<Vec<Fr>, Error> {
         if values.len() > self.max_width {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "Supplied values is longer than the available max width",
             ));
         }
@@ -514,14 +515,14 @@ impl FFTSettings {
         );
     }
 
-    pub fn fft_g1(&self, values: &[G1]) -> Result<Vec<G1>, String> {
+    pub fn fft_g1(&self, values: &[G1]) -> Result<Vec<G1>, Error> {
         if values.len() > self.max_width {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "length of values is longer than the available max width",
             ));
         }
         if !is_power_of_2(values.len()) {
-            return Err(String::from("length of values must be a power of two"));
+            return Err(Error::NotPowerOfTwo("values length"));
         }
         // TODO: check if copy can be removed, opt?
         // let vals_copy = values.clone();
@@ -542,14 +543,14 @@ impl FFTSettings {
     }
 
     //just copied of for fk20_matrix
-    pub fn fft_g1_inv(&self, values: &[G1]) -> Result<Vec<G1>, String> {
+    pub fn fft_g1_inv(&self, values: &[G1]) -> Result<Vec<G1>, Error> {
         if values.len() > self.max_width {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "length of values is longer than the available max width",
             ));
         }
         if !is_power_of_2(values.len()) {
-            return Err(String::from("length of values must be a power of two"));
+            return Err(Error::NotPowerOfTwo("values length"));
         }
         // TODO: check if copy can be removed, opt?
         // let vals_copy = values.clone();
@@ -6,11 +6,13 @@ use crate::kzg_settings::KZGSettings;
 use crate::utilities::*;
 use std::iter;
 
+use kzg::trusted_setup::SetupBasis;
+use kzg::Error;
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
 impl FFTSettings {
-    pub fn toeplitz_part_1(&self, x: &[G1]) -> Result<Vec<G1>, String> {
+    pub fn toeplitz_part_1(&self, x: &[G1]) -> Result<Vec<G1>, Error> {
         let n = x.len();
 
         // extend x with zeroes
@@ -20,7 +22,7 @@ impl FFTSettings {
         self.fft_g1(&x_ext)
     }
 
-    pub fn toeplitz_part_2(&self, coeffs: &[Fr], x_ext_fft: &[G1]) -> Result<Vec<G1>, String> {
+    pub fn toeplitz_part_2(&self, coeffs: &[Fr], x_ext_fft: &[G1]) -> Result<Vec<G1>, Error> {
         let toeplitz_coeffs_fft = self.fft(coeffs, false).unwrap();
 
         #[cfg(feature = "parallel")]
@@ -42,7 +44,7 @@ impl FFTSettings {
         }
     }
 
-    pub fn toeplitz_part_3(&self, h_ext_fft: &[G1]) -> Result<Vec<G1>, String> {
+    pub fn toeplitz_part_3(&self, h_ext_fft: &[G1]) -> Result<Vec<G1>, Error> {
         let n2 = h_ext_fft.len();
         let n = n2 / 2;
 
@@ -63,20 +65,23 @@ pub struct FK20SingleMatrix {
 }
 
 impl FK20SingleMatrix {
-    pub fn new(kzg_settings: &KZGSettings, n2: usize) -> Result<Self, String> {
+    pub fn new(kzg_settings: &KZGSettings, n2: usize) -> Result<Self, Error> {
         let n = n2 >> 1; // div by 2
 
         if !is_power_of_2(n2) {
-            return Err(String::from("n2 must be a power of two"));
+            return Err(Error::NotPowerOfTwo("n2"));
         }
         if n2 < 2 {
-            return Err(String::from("n2 must be greater than or equal to 2"));
+            return Err(Error::InvalidArgument(
+                "n2 must be greater than or equal to 2",
+            ));
         }
         if n2 > kzg_settings.fft_settings.max_width {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "n2 must be less than or equal to fft settings max width",
             ));
         }
+        kzg_settings.check_basis(SetupBasis::Monomial)?;
 
         let mut x = Vec::new();
         for i in 0..n - 1 {
@@ -93,15 +98,15 @@ impl FK20SingleMatrix {
         })
     }
 
-    pub fn dau_using_fk20_single(&self, polynomial: &Polynomial) -> Result<Vec<G1>, String> {
+    pub fn dau_using_fk20_single(&self, polynomial: &Polynomial) -> Result<Vec<G1>, Error> {
         let n = polynomial.order();
         let n2 = n << 1;
 
         if !is_power_of_2(n2) {
-            return Err(String::from("n2 must be a power of two"));
+            return Err(Error::NotPowerOfTwo("n2"));
         }
         if n2 > self.kzg_settings.fft_settings.max_width {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "n2 must be less than or equal to fft settings max width",
             ));
         }
@@ -112,15 +117,15 @@ impl FK20SingleMatrix {
         Ok(proofs)
     }
 
-    pub fn fk20_single_dao_optimized(&self, polynomial: &Polynomial) -> Result<Vec<G1>, String> {
+    pub fn fk20_single_dao_optimized(&self, polynomial: &Polynomial) -> Result<Vec<G1>, Error> {
         let n = polynomial.order();
         let n2 = n * 2;
 
         if !is_power_of_2(n2) {
-            return Err(String::from("n2 must be a power of two"));
+            return Err(Error::NotPowerOfTwo("n2"));
         }
         if n2 > self.kzg_settings.fft_settings.max_width {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "n2 must be less than or equal to fft settings max width",
             ));
         }
@@ -159,31 +164,36 @@ impl Default for FK20Matrix {
 }
 
 impl FK20Matrix {
-    pub fn new(kzg_settings: &KZGSettings, n2: usize, chunk_len: usize) -> Result<Self, String> {
+    pub fn new(kzg_settings: &KZGSettings, n2: usize, chunk_len: usize) -> Result<Self, Error> {
         let n = n2 >> 1; // div by 2
         let k = n / chunk_len;
 
         if !is_power_of_2(n2) {
-            return Err(String::from("n2 must be a power of two"));
+            return Err(Error::NotPowerOfTwo("n2"));
         }
         if !is_power_of_2(chunk_len) {
-            return Err(String::from("chunk_len must be a power of two"));
+            return Err(Error::NotPowerOfTwo("chunk_len"));
         }
         if n2 < 2 {
-            return Err(String::from("n2 must be greater than or equal to 2"));
+            return Err(Error::InvalidArgument(
+                "n2 must be greater than or equal to 2",
+            ));
         }
         if n2 > kzg_settings.fft_settings.max_width {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "n2 must be less than or equal to kzg settings max width",
             ));
         }
         if n2 > kzg_settings.fft_settings.max_width {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "n2 must be less than or equal to fft settings max width",
             ));
         }
+        kzg_settings.check_basis(SetupBasis::Monomial)?;
         if chunk_len > n2 / 2 {
-            return Err(String::from("chunk_len must be greater or equal to n2 / 2"));
+            return Err(Error::InvalidArgument(
+                "chunk_len must be greater or equal to n2 / 2",
+            ));
         }
 
         let mut x_ext_fft_files: Vec<Vec<G1>> = vec![vec![]; chunk_len];
@@ -214,7 +224,7 @@ impl FK20Matrix {
         k: usize,
         chunk_len: usize,
         offset: usize,
-    ) -> Result<Vec<G1>, String> {
+    ) -> Result<Vec<G1>, Error> {
         let mut x: Vec<G1> = vec![G1::default(); k];
 
         let mut start = 0;
@@ -240,15 +250,15 @@ impl FK20Matrix {
         fft_settings.toeplitz_part_1(&x)
     }
 
-    pub fn dau_using_fk20_multi(&self, polynomial: &Polynomial) -> Result<Vec<G1>, String> {
+    pub fn dau_using_fk20_multi(&self, polynomial: &Polynomial) -> Result<Vec<G1>, Error> {
         let n = polynomial.order();
         let n2 = n << 1;
 
         if !is_power_of_2(n2) {
-            return Err(String::from("n2 must be a power of two"));
+            return Err(Error::NotPowerOfTwo("n2"));
         }
         if n2 > self.kzg_settings.fft_settings.max_width {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "n2 must be less than or equal to fft settings max width",
             ));
         }
@@ -260,17 +270,17 @@ impl FK20Matrix {
         Ok(proofs)
     }
 
-    pub fn fk20_multi_dao_optimized(&self, polynomial: &Polynomial) -> Result<Vec<G1>, String> {
+    pub fn fk20_multi_dao_optimized(&self, polynomial: &Polynomial) -> Result<Vec<G1>, Error> {
         let n = polynomial.order() >> 1;
         let k = n / self.chunk_len;
         let k2 = k << 1;
 
         let n2 = n << 1;
         if !is_power_of_2(n2) {
-            return Err(String::from("n2 must be a power of two"));
+            return Err(Error::NotPowerOfTwo("n2"));
         }
         if n2 > self.kzg_settings.fft_settings.max_width {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "n2 must be less than or equal to fft settings max width",
             ));
         }
@@ -1,9 +1,10 @@
 use crate::data_types::{fr::*, g1::*, g2::*, gt::*};
 use crate::fk20_fft::{FFTSettings, G1_GENERATOR};
-use crate::mcl_methods::{final_exp, mclBn_FrEvaluatePolynomial, pairing};
+use crate::mcl_methods::{final_exp, mclBn_FrEvaluatePolynomial, miller_loop_vec, pairing};
 use crate::utilities::{log_2, next_pow_of_2};
 use std::{cmp::min, iter, ops};
 
+use kzg::{Error, G1LinComb};
 #[cfg(feature = "parallel")]
 use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
 
@@ -240,7 +241,7 @@ impl Polynomial {
         result
     }
 
-    pub fn gen_proof_at(&self, g1_points: &[G1], point: &Fr) -> Result<G1, String> {
+    pub fn gen_proof_at(&self, g1_points: &[G1], point: &Fr) -> Result<G1, Error> {
         let divisor = vec![point.get_neg(), Fr::one()];
         let quotient_poly = self.long_division(&divisor).unwrap();
 
@@ -263,12 +264,12 @@ impl Polynomial {
         }
     }
 
-    pub fn long_division(&self, divisor: &[Fr]) -> Result<Polynomial, String> {
+    pub fn long_division(&self, divisor: &[Fr]) -> Result<Polynomial, Error> {
         if divisor.is_empty() {
-            return Err(String::from("Dividing by zero is undefined"));
+            return Err(Error::DivisionByZero);
         }
         if divisor.last().unwrap().is_zero() {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "The divisor's highest coefficient must be non-zero",
             ));
         }
@@ -300,12 +301,12 @@ impl Polynomial {
         Ok(Polynomial::from_fr(out_coeffs))
     }
 
-    pub fn fast_div(&self, divisor: &[Fr]) -> Result<Polynomial, String> {
+    pub fn fast_div(&self, divisor: &[Fr]) -> Result<Polynomial, Error> {
         if divisor.is_empty() {
-            return Err(String::from("Dividing by zero is undefined"));
+            return Err(Error::DivisionByZero);
         }
         if divisor.last().unwrap().is_zero() {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "The divisor's highest coefficient must be non-zero",
             ));
         }
@@ -356,7 +357,7 @@ impl Polynomial {
         Polynomial::from_fr(Polynomial::flip_coeffs(&self.coeffs))
     }
 
-    pub fn div(&self, _divisor: &[Fr]) -> Result<Polynomial, String> {
+    pub fn div(&self, _divisor: &[Fr]) -> Result<Polynomial, Error> {
         let dividend = self.normalise();
         let divisor = Polynomial::normalise_coeffs(_divisor);
 
@@ -368,19 +369,16 @@ impl Polynomial {
         }
     }
 
-    pub fn commit(&self, g1_points: &[G1]) -> Result<G1, String> {
+    pub fn commit(&self, g1_points: &[G1]) -> Result<G1, Error> {
         if self.order() > g1_points.len() {
-            return Err(String::from("Provided polynomial is longer than G1!"));
+            return Err(Error::SetupTooSmall);
         }
 
-        let mut result = G1::default();
-        g1_linear_combination(
-            &mut result,
+        Ok(G1::g1_lincomb(
             g1_points,
             self.coeffs.as_slice(),
             min(g1_points.len(), self.order()),
-        );
-        Ok(result)
+        ))
     }
 
     pub fn random(order: usize) -> Polynomial {
@@ -394,7 +392,7 @@ impl Polynomial {
         b: &Self,
         ft: Option<&FFTSettings>,
         len: usize,
-    ) -> Result<Polynomial, String> {
+    ) -> Result<Polynomial, Error> {
         if self.order() < 64 || b.order() < 64 || len < 128 {
             // Tunable parameter
             Polynomial::mul_direct(self, b, len)
@@ -403,7 +401,7 @@ impl Polynomial {
         }
     }
 
-    pub fn mul(&self, b: &Self, len: usize) -> Result<Polynomial, String> {
+    pub fn mul(&self, b: &Self, len: usize) -> Result<Polynomial, Error> {
         Polynomial::mul_(self, b, None, len)
     }
 
@@ -441,7 +439,7 @@ impl Polynomial {
         b: &Self,
         ft: Option<&FFTSettings>,
         len: usize,
-    ) -> Result<Polynomial, String> {
+    ) -> Result<Polynomial, Error> {
         // Truncate a and b so as not to do excess work for the number of coefficients required.
         let a_len = min(self.order(), len);
         let b_len = min(b.order(), len);
@@ -455,7 +453,9 @@ impl Polynomial {
         };
         let ft = fft_settings;
         if length > ft.max_width {
-            return Err(String::from("Mul fft only good up to length < 32 bits"));
+            return Err(Error::InvalidArgument(
+                "Mul fft only good up to length < 32 bits",
+            ));
         }
 
         let a_pad = self.pad(a_len, length);
@@ -508,7 +508,7 @@ impl Polynomial {
         Ok(Polynomial::from_fr(ret_coeffs))
     }
 
-    pub fn mul_direct(&self, b: &Self, len: usize) -> Result<Polynomial, String> {
+    pub fn mul_direct(&self, b: &Self, len: usize) -> Result<Polynomial, Error> {
         let mut coeffs: Vec<Fr> = vec![];
         for _ in 0..len {
             coeffs.push(Fr::zero());
@@ -525,13 +525,15 @@ impl Polynomial {
         Ok(Polynomial::from_fr(coeffs))
     }
 
-    pub fn inverse(&self, new_length: usize) -> Result<Polynomial, String> {
+    pub fn inverse(&self, new_length: usize) -> Result<Polynomial, Error> {
         let self_length = self.order();
         if self_length == 0 || new_length == 0 {
             return Ok(Polynomial::default());
         }
         if self.coeffs[0].is_zero() {
-            return Err(String::from("The constant term of self must be nonzero."));
+            return Err(Error::InvalidArgument(
+                "The constant term of self must be nonzero.",
+            ));
         }
 
         // If the input polynomial is constant, the remainder of the series is zero
@@ -581,7 +583,7 @@ impl Polynomial {
         }
 
         if d + 1 != new_length {
-            return Err(String::from("d + 1 != new_length"));
+            return Err(Error::Internal("d + 1 != new_length"));
         }
 
         Ok(out)
@@ -681,4 +683,15 @@ impl Curve {
 
         result.is_one()
     }
+
+    pub fn verify_pairings(pairs: &[(G1, G2)]) -> bool {
+        if pairs.is_empty() {
+            return true;
+        }
+
+        let (g1s, g2s): (Vec<G1>, Vec<G2>) = pairs.iter().cloned().unzip();
+        let mut gt = GT::default();
+        miller_loop_vec(&mut gt, &g1s, &g2s);
+        gt.get_final_exp().is_one()
+    }
 }
@@ -3,11 +3,20 @@ use crate::fk20_fft::FFTSettings;
 use crate::kzg10::Curve;
 use crate::kzg10::Polynomial;
 use crate::utilities::is_power_of_2;
+use kzg::eip_4844::BlobPreset;
+use kzg::trusted_setup::{
+    lagrange_to_monomial, monomial_to_lagrange, verify_lagrange_setup, verify_monomial_setup,
+    SetupBasis,
+};
+use kzg::{Error, PolyEval};
 
 #[derive(Debug, Clone, Default)]
 pub struct KZGSettings {
     pub fft_settings: FFTSettings,
     pub curve: Curve,
+    pub blob_preset: BlobPreset,
+    /// Basis of `curve.g1_points`.
+    pub basis: SetupBasis,
 }
 
 impl KZGSettings {
@@ -15,42 +24,105 @@ impl KZGSettings {
         KZGSettings {
             fft_settings: fft_settings.clone(),
             curve: curve.clone(),
+            blob_preset: BlobPreset::default(),
+            basis: SetupBasis::Monomial,
         }
     }
 
     pub fn new(
         secret_g1: &[G1],
         secret_g2: &[G2],
-        length: usize,
+        length_g1: usize,
+        length_g2: usize,
         fft_settings: &FFTSettings,
-    ) -> Result<Self, String> {
-        if length < fft_settings.max_width {
-            return Err(String::from(
-                "length must be equal to or greater than fft settings max width",
-            ));
+    ) -> Result<Self, Error> {
+        if secret_g1.len() < length_g1 || secret_g2.len() < length_g2 {
+            return Err(Error::SetupTooSmall);
         }
-        if secret_g1.len() < fft_settings.max_width {
-            return Err(String::from(
-                "secret g1 must have a length equal to or greater than fft settings max width",
+        if length_g1 < fft_settings.max_width {
+            return Err(Error::InvalidArgument(
+                "length_g1 must be equal to or greater than fft settings max width",
             ));
         }
-        if secret_g2.len() < fft_settings.max_width {
-            return Err(String::from(
-                "secret g2 must have a length equal to or greater than fft settings max width",
-            ));
+        if length_g2 < 2 {
+            return Err(Error::InvalidArgument("length_g2 must be at least 2"));
         }
 
-        let mut secret1: Vec<G1> = vec![];
-        let mut secret2: Vec<G2> = vec![];
-        for i in 0..length {
-            secret1.push(secret_g1[i]);
-            secret2.push(secret_g2[i]);
-        }
-        let curve = Curve::new2(&secret1, &secret2, length);
+        let curve = Curve {
+            g1_gen: G1::gen(),
+            g2_gen: G2::gen(),
+            g1_points: secret_g1[..length_g1].to_vec(),
+            g2_points: secret_g2[..length_g2].to_vec(),
+        };
 
         Ok(KZGSettings {
             fft_settings: fft_settings.clone(),
             curve,
+            blob_preset: BlobPreset::default(),
+            basis: SetupBasis::Monomial,
+        })
+    }
+
+    /// Checks that the points are powers of one secret, as described in [`kzg::trusted_setup`].
+    pub fn verify_trusted_setup(&self) -> Result<(), Error> {
+        match self.basis {
+            SetupBasis::Monomial => {
+                verify_monomial_setup(&self.curve.g1_points, &self.curve.g2_points)
+            }
+            SetupBasis::Lagrange => verify_lagrange_setup(
+                &self.curve.g1_points,
+                &self.curve.g2_points,
+                &self.fft_settings,
+            ),
+        }
+    }
+
+    pub(crate) fn check_basis(&self, expected: SetupBasis) -> Result<(), Error> {
+        if self.basis != expected {
+            return Err(Error::SetupBasisMismatch {
+                expected,
+                actual: self.basis,
+            });
+        }
+        Ok(())
+    }
+
+    pub fn commit_to_poly_eval(&self, p: &PolyEval<Fr>) -> Result<G1, Error> {
+        self.check_basis(SetupBasis::Lagrange)?;
+        Polynomial::from_fr(p.evals().to_vec()).commit(&self.curve.g1_points)
+    }
+
+    pub fn to_lagrange(&self) -> Result<Self, Error> {
+        if self.basis == SetupBasis::Lagrange {
+            return Ok(self.clone());
+        }
+        let width = self.fft_settings.max_width;
+        if self.curve.g1_points.len() < width {
+            return Err(Error::SetupTooSmall);
+        }
+
+        let mut curve = self.curve.clone();
+        curve.g1_points = monomial_to_lagrange(&self.curve.g1_points[..width], &self.fft_settings)?;
+        Ok(KZGSettings {
+            fft_settings: self.fft_settings.clone(),
+            curve,
+            blob_preset: self.blob_preset,
+            basis: SetupBasis::Lagrange,
+        })
+    }
+
+    pub fn to_monomial(&self) -> Result<Self, Error> {
+        if self.basis == SetupBasis::Monomial {
+            return Ok(self.clone());
+        }
+
+        let mut curve = self.curve.clone();
+        curve.g1_points = lagrange_to_monomial(&self.curve.g1_points, &self.fft_settings)?;
+        Ok(KZGSettings {
+            fft_settings: self.fft_settings.clone(),
+            curve,
+            blob_preset: self.blob_preset,
+            basis: SetupBasis::Monomial,
         })
     }
 
@@ -58,10 +130,11 @@ impl KZGSettings {
         self.curve.is_proof_valid(commitment, proof, x, y)
     }
 
-    pub fn compute_proof_multi(&self, p: &Polynomial, x0: &Fr, n: usize) -> Result<G1, String> {
+    pub fn compute_proof_multi(&self, p: &Polynomial, x0: &Fr, n: usize) -> Result<G1, Error> {
         if !is_power_of_2(n) {
-            return Err(String::from("n must be a power of 2"));
+            return Err(Error::NotPowerOfTwo("n"));
         }
+        self.check_basis(SetupBasis::Monomial)?;
 
         let mut divisor = Polynomial::from_fr(vec![]);
         let x_pow_n = x0.pow(n);
@@ -74,7 +147,7 @@ impl KZGSettings {
         divisor.coeffs.push(Fr::one());
         let temp_poly = p.clone();
         let q = temp_poly.div(&divisor.coeffs).unwrap();
-        Ok(q.commit(&self.curve.g1_points).unwrap())
+        q.commit(&self.curve.g1_points)
     }
 
     pub fn check_proof_multi(
@@ -84,10 +157,14 @@ impl KZGSettings {
         x: &Fr,
         ys: &[Fr],
         n: usize,
-    ) -> Result<bool, String> {
+    ) -> Result<bool, Error> {
         if !is_power_of_2(n) {
-            return Err(String::from("n must be a power of 2"));
+            return Err(Error::NotPowerOfTwo("n"));
+        }
+        if n >= self.curve.g2_points.len() {
+            return Err(Error::SetupTooSmall);
         }
+        self.check_basis(SetupBasis::Monomial)?;
 
         let mut interpolation_poly = Polynomial::new(n);
         interpolation_poly.coeffs = self.fft_settings.fft(ys, true).unwrap();
@@ -19,6 +19,7 @@ extern "C" {
     pub fn mclBn_getFieldOrder(buf: *mut u8, maxBufSize: usize) -> usize;
     pub fn mclBn_pairing(z: *mut GT, x: *const G1, y: *const G2);
     pub fn mclBn_millerLoop(z: *mut GT, x: *const G1, y: *const G2);
+    pub fn mclBn_millerLoopVec(z: *mut GT, x: *const G1, y: *const G2, n: usize);
     pub fn mclBn_finalExp(y: *mut GT, x: *const GT);
     pub fn mclBn_FrEvaluatePolynomial(
         result: *mut Fr,
@@ -81,6 +82,12 @@ pub fn miller_loop(z: &mut GT, x: &G1, y: &G2) {
     }
 }
 
+pub fn miller_loop_vec(z: &mut GT, x: &[G1], y: &[G2]) {
+    unsafe {
+        mclBn_millerLoopVec(z, x.as_ptr(), y.as_ptr(), x.len().min(y.len()));
+    }
+}
+
 pub fn final_exp(y: &mut GT, x: &GT) {
     unsafe {
         mclBn_finalExp(y, x);
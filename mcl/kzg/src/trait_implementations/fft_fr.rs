@@ -1,9 +1,10 @@
 use crate::data_types::fr::Fr;
 use crate::fk20_fft::FFTSettings;
+use kzg::Error;
 use kzg::FFTFr;
 
 impl FFTFr<Fr> for FFTSettings {
-    fn fft_fr(&self, data: &[Fr], inverse: bool) -> Result<Vec<Fr>, String> {
+    fn fft_fr(&self, data: &[Fr], inverse: bool) -> Result<Vec<Fr>, Error> {
         FFTSettings::fft(self, data, inverse)
     }
 }
@@ -1,9 +1,10 @@
 use crate::data_types::g1::G1;
 use crate::fk20_fft::FFTSettings;
+use kzg::Error;
 use kzg::FFTG1 as FftG1;
 
 impl FftG1<G1> for FFTSettings {
-    fn fft_g1(&self, data: &[G1], inverse: bool) -> Result<Vec<G1>, String> {
+    fn fft_g1(&self, data: &[G1], inverse: bool) -> Result<Vec<G1>, Error> {
         if inverse {
             FFTSettings::fft_g1_inv(self, data)
         } else {
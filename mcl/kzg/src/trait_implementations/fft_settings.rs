@@ -1,9 +1,10 @@
 use crate::data_types::fr::Fr;
 use crate::fk20_fft::{FFTSettings, SCALE_2_ROOT_OF_UNITY_PR7_STRINGS};
+use kzg::Error;
 use kzg::FFTSettings as CommonFFTSettings;
 
 impl CommonFFTSettings<Fr> for FFTSettings {
-    fn new(scale: usize) -> Result<FFTSettings, String> {
+    fn new(scale: usize) -> Result<FFTSettings, Error> {
         //currently alawys use PR 7 for shared tests
         FFTSettings::new_custom_primitive_roots(scale as u8, SCALE_2_ROOT_OF_UNITY_PR7_STRINGS)
     }
@@ -1,7 +1,15 @@
 use crate::data_types::fr::Fr;
 use kzg::eip_4844::BYTES_PER_FIELD_ELEMENT;
+use kzg::Error;
 use kzg::Fr as CommonFr;
 
+#[cfg(feature = "serde")]
+kzg::impl_serde_bytes!(
+    Fr,
+    CommonFr::to_bytes,
+    kzg::serde_utils::fr_from_canonical_bytes::<Fr>
+);
+
 impl CommonFr for Fr {
     fn null() -> Self {
         Fr::from_u64_arr(&[u64::MAX, u64::MAX, u64::MAX, u64::MAX / 3])
@@ -19,20 +27,27 @@ impl CommonFr for Fr {
         Fr::random()
     }
 
-    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         bytes
             .try_into()
-            .map_err(|_| {
-                format!(
-                    "Invalid byte length. Expected {}, got {}",
-                    BYTES_PER_FIELD_ELEMENT,
-                    bytes.len()
-                )
+            .map_err(|_| Error::InvalidLength {
+                expected: BYTES_PER_FIELD_ELEMENT,
+                actual: bytes.len(),
             })
             .and_then(|bytes: &[u8; BYTES_PER_FIELD_ELEMENT]| Self::from_bytes(bytes))
     }
 
-    fn from_hex(hex: &str) -> Result<Self, String> {
+    fn from_bytes_unchecked(bytes: &[u8]) -> Result<Self, Error> {
+        bytes
+            .try_into()
+            .map_err(|_| Error::InvalidLength {
+                expected: BYTES_PER_FIELD_ELEMENT,
+                actual: bytes.len(),
+            })
+            .and_then(|bytes: &[u8; BYTES_PER_FIELD_ELEMENT]| Self::from_bytes(bytes))
+    }
+
+    fn from_hex(hex: &str) -> Result<Self, Error> {
         let bytes = hex::decode(&hex[2..]).unwrap();
         Self::from_bytes(&bytes)
     }
@@ -106,7 +121,7 @@ impl CommonFr for Fr {
         Fr::pow(self, n)
     }
 
-    fn div(&self, b: &Self) -> Result<Self, String> {
+    fn div(&self, b: &Self) -> Result<Self, Error> {
         let mut res = Fr::zero();
         Fr::div(&mut res, self, b);
         Ok(res)
@@ -115,4 +130,28 @@ impl CommonFr for Fr {
     fn equals(&self, b: &Self) -> bool {
         Fr::eq(self, b)
     }
+
+    /// Montgomery's trick on mcl's copyable field elements, which multiply in place instead of
+    /// going through the trait's cloning arithmetic. Zeros are left as zero.
+    fn batch_inverse(values: &mut [Self]) {
+        // prefix[i] is the product of the nonzero values before i
+        let mut prefix = Vec::with_capacity(values.len());
+        let mut product = Fr::from_int(1);
+        for value in values.iter() {
+            prefix.push(product);
+            if !value.is_zero() {
+                product *= value;
+            }
+        }
+
+        let mut inverse = product.inverse();
+        for (value, prefix) in values.iter_mut().zip(prefix).rev() {
+            if value.is_zero() {
+                continue;
+            }
+            let next = inverse * *value;
+            *value = inverse * prefix;
+            inverse = next;
+        }
+    }
 }
@@ -1,9 +1,17 @@
-use crate::data_types::g1::is_valid_order;
-use crate::data_types::{fr::Fr, g1::G1};
+use crate::data_types::g1::{g1_linear_combination, is_valid_order};
+use crate::data_types::{fr::Fr, g1::G1, g2::G2};
 use crate::fk20_fft::{G1_GENERATOR, G1_NEGATIVE_GENERATOR};
+use crate::kzg10::Curve;
 use crate::mcl_methods::set_eth_serialization;
 use kzg::eip_4844::BYTES_PER_G1;
-use kzg::{G1Mul, G1 as CommonG1};
+use kzg::{Error, G1LinComb, G1Mul, PairingVerify, G1 as CommonG1};
+
+#[cfg(feature = "serde")]
+kzg::impl_serde_bytes!(
+    G1,
+    CommonG1::to_bytes,
+    kzg::serde_utils::g1_from_canonical_bytes::<G1>
+);
 
 impl CommonG1 for G1 {
     fn identity() -> Self {
@@ -22,27 +30,24 @@ impl CommonG1 for G1 {
         G1::random()
     }
 
-    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         bytes
             .try_into()
-            .map_err(|_| {
-                format!(
-                    "Invalid byte length. Expected {}, got {}",
-                    BYTES_PER_G1,
-                    bytes.len()
-                )
+            .map_err(|_| Error::InvalidLength {
+                expected: BYTES_PER_G1,
+                actual: bytes.len(),
             })
             .and_then(|bytes: &[u8; BYTES_PER_G1]| {
                 set_eth_serialization(1);
                 let mut g1 = G1::default();
                 if !G1::deserialize(&mut g1, bytes) {
-                    return Err("Failed to deserialize".to_string());
+                    return Err(Error::InvalidPoint);
                 }
                 Ok(g1)
             })
     }
 
-    fn from_hex(hex: &str) -> Result<Self, String> {
+    fn from_hex(hex: &str) -> Result<Self, Error> {
         let bytes = hex::decode(&hex[2..]).unwrap();
         Self::from_bytes(&bytes)
     }
@@ -100,3 +105,21 @@ impl G1Mul<Fr> for G1 {
         g1
     }
 }
+
+impl G1LinComb<Fr> for G1 {
+    fn g1_lincomb(points: &[Self], scalars: &[Fr], len: usize) -> Self {
+        let mut out = G1::default();
+        g1_linear_combination(&mut out, points, scalars, len);
+        out
+    }
+}
+
+impl PairingVerify<G1, G2> for G1 {
+    fn verify(a1: &G1, a2: &G2, b1: &G1, b2: &G2) -> bool {
+        Curve::verify_pairing(a1, a2, b1, b2)
+    }
+
+    fn verify_multi(pairs: &[(G1, G2)]) -> bool {
+        Curve::verify_pairings(pairs)
+    }
+}
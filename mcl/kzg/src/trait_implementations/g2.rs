@@ -1,7 +1,22 @@
+use crate::data_types::g2::g2_linear_combination;
+#[cfg(feature = "serde")]
+use crate::data_types::g2::is_valid_order;
 use crate::data_types::{fr::Fr, g2::G2};
 use crate::mcl_methods::set_eth_serialization;
 use kzg::eip_4844::BYTES_PER_G2;
-use kzg::{G2Mul, G2 as CommonG2};
+use kzg::{Error, G2LinComb, G2Mul, G2 as CommonG2};
+
+#[cfg(feature = "serde")]
+kzg::impl_serde_bytes!(G2, CommonG2::to_bytes, |bytes: &[u8]| {
+    let g2 = <G2 as CommonG2>::from_bytes(bytes)?;
+    if CommonG2::to_bytes(&g2)[..] != *bytes {
+        return Err(Error::InvalidPoint);
+    }
+    if !g2.is_valid() || !is_valid_order(&g2) {
+        return Err(Error::PointNotInSubgroup);
+    }
+    Ok(g2)
+});
 
 impl CommonG2 for G2 {
     fn generator() -> Self {
@@ -12,21 +27,18 @@ impl CommonG2 for G2 {
         G2::G2_NEGATIVE_GENERATOR
     }
 
-    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         bytes
             .try_into()
-            .map_err(|_| {
-                format!(
-                    "Invalid byte length. Expected {}, got {}",
-                    BYTES_PER_G2,
-                    bytes.len()
-                )
+            .map_err(|_| Error::InvalidLength {
+                expected: BYTES_PER_G2,
+                actual: bytes.len(),
             })
             .and_then(|bytes: &[u8; BYTES_PER_G2]| {
                 set_eth_serialization(1);
                 let mut g2 = G2::default();
                 if !G2::deserialize(&mut g2, bytes) {
-                    return Err("Failed to deserialize".to_string());
+                    return Err(Error::InvalidPoint);
                 }
                 Ok(g2)
             })
@@ -71,3 +83,11 @@ impl G2Mul<Fr> for G2 {
         g1
     }
 }
+
+impl G2LinComb<Fr> for G2 {
+    fn g2_lincomb(points: &[Self], scalars: &[Fr], len: usize) -> Self {
+        let mut out = G2::default();
+        g2_linear_combination(&mut out, points, scalars, len);
+        out
+    }
+}
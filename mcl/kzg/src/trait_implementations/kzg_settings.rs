@@ -2,31 +2,41 @@ use crate::data_types::{fr::Fr, g1::G1, g2::G2};
 use crate::fk20_fft::FFTSettings;
 use crate::kzg10::Polynomial;
 use crate::kzg_settings::KZGSettings;
+use kzg::eip_4844::BlobPreset;
+use kzg::trusted_setup::SetupBasis;
 use kzg::KZGSettings as CommonKZGSettings;
+use kzg::{Error, PolyEval};
 
 impl CommonKZGSettings<Fr, G1, G2, FFTSettings, Polynomial> for KZGSettings {
     fn new(
         secret_g1: &[G1],
         secret_g2: &[G2],
-        length: usize,
+        length_g1: usize,
+        length_g2: usize,
         fs: &FFTSettings,
-    ) -> Result<Self, String> {
-        KZGSettings::new(secret_g1, secret_g2, length, fs)
+    ) -> Result<Self, Error> {
+        KZGSettings::new(secret_g1, secret_g2, length_g1, length_g2, fs)
     }
 
-    fn commit_to_poly(&self, polynomial: &Polynomial) -> Result<G1, String> {
+    fn commit_to_poly(&self, polynomial: &Polynomial) -> Result<G1, Error> {
+        self.check_basis(SetupBasis::Monomial)?;
         polynomial.commit(&self.curve.g1_points)
     }
 
-    fn compute_proof_single(&self, polynomial: &Polynomial, x: &Fr) -> Result<G1, String> {
+    fn commit_to_poly_eval(&self, p: &PolyEval<Fr>) -> Result<G1, Error> {
+        KZGSettings::commit_to_poly_eval(self, p)
+    }
+
+    fn compute_proof_single(&self, polynomial: &Polynomial, x: &Fr) -> Result<G1, Error> {
+        self.check_basis(SetupBasis::Monomial)?;
         polynomial.gen_proof_at(&self.curve.g1_points, x)
     }
 
-    fn check_proof_single(&self, com: &G1, proof: &G1, x: &Fr, value: &Fr) -> Result<bool, String> {
+    fn check_proof_single(&self, com: &G1, proof: &G1, x: &Fr, value: &Fr) -> Result<bool, Error> {
         Ok(KZGSettings::check_proof_single(self, com, proof, x, value))
     }
 
-    fn compute_proof_multi(&self, p: &Polynomial, x: &Fr, n: usize) -> Result<G1, String> {
+    fn compute_proof_multi(&self, p: &Polynomial, x: &Fr, n: usize) -> Result<G1, Error> {
         KZGSettings::compute_proof_multi(self, p, x, n)
     }
 
@@ -37,7 +47,7 @@ impl CommonKZGSettings<Fr, G1, G2, FFTSettings, Polynomial> for KZGSettings {
         x: &Fr,
         values: &[Fr],
         n: usize,
-    ) -> Result<bool, String> {
+    ) -> Result<bool, Error> {
         KZGSettings::check_proof_multi(self, com, proof, x, values, n)
     }
 
@@ -48,4 +58,32 @@ impl CommonKZGSettings<Fr, G1, G2, FFTSettings, Polynomial> for KZGSettings {
     fn get_roots_of_unity_at(&self, i: usize) -> Fr {
         self.fft_settings.roots_of_unity[i]
     }
+
+    fn get_fft_settings(&self) -> &FFTSettings {
+        &self.fft_settings
+    }
+
+    fn get_g1_secret(&self) -> &[G1] {
+        &self.curve.g1_points
+    }
+
+    fn get_g2_secret(&self) -> &[G2] {
+        &self.curve.g2_points
+    }
+
+    fn get_blob_preset(&self) -> &BlobPreset {
+        &self.blob_preset
+    }
+
+    fn get_basis(&self) -> SetupBasis {
+        self.basis
+    }
+
+    fn to_lagrange(&self) -> Result<Self, Error> {
+        KZGSettings::to_lagrange(self)
+    }
+
+    fn to_monomial(&self) -> Result<Self, Error> {
+        KZGSettings::to_monomial(self)
+    }
 }
@@ -1,12 +1,15 @@
 use crate::data_types::fr::Fr;
 use crate::fk20_fft::FFTSettings;
 use crate::kzg10::Polynomial;
+use kzg::reed_solomon::correct_errors;
+use kzg::Error;
 use kzg::FFTSettingsPoly;
 use kzg::Poly;
+use kzg::PolyErrorCorrect;
 use kzg::PolyRecover;
 
 impl Poly<Fr> for Polynomial {
-    fn new(size: usize) -> Result<Self, String> {
+    fn new(size: usize) -> Result<Self, Error> {
         Ok(Polynomial::new(size))
     }
 
@@ -38,23 +41,23 @@ impl Poly<Fr> for Polynomial {
         todo!()
     }
 
-    fn inverse(&mut self, new_len: usize) -> Result<Self, String> {
+    fn inverse(&mut self, new_len: usize) -> Result<Self, Error> {
         Polynomial::inverse(self, new_len)
     }
 
-    fn div(&mut self, x: &Self) -> Result<Self, String> {
+    fn div(&mut self, x: &Self) -> Result<Self, Error> {
         Polynomial::div(self, &x.coeffs)
     }
 
-    fn long_div(&mut self, x: &Self) -> Result<Self, String> {
+    fn long_div(&mut self, x: &Self) -> Result<Self, Error> {
         Polynomial::long_division(self, &x.coeffs)
     }
 
-    fn fast_div(&mut self, x: &Self) -> Result<Self, String> {
+    fn fast_div(&mut self, x: &Self) -> Result<Self, Error> {
         Polynomial::fast_div(self, &x.coeffs)
     }
 
-    fn mul_direct(&mut self, x: &Self, len: usize) -> Result<Self, String> {
+    fn mul_direct(&mut self, x: &Self, len: usize) -> Result<Self, Error> {
         Polynomial::mul_direct(self, x, len)
     }
 }
@@ -65,7 +68,7 @@ impl FFTSettingsPoly<Fr, Polynomial, FFTSettings> for FFTSettings {
         x: &Polynomial,
         len: usize,
         fs: Option<&FFTSettings>,
-    ) -> Result<Polynomial, String> {
+    ) -> Result<Polynomial, Error> {
         Polynomial::mul_fft(a, x, fs, len)
     }
 }
@@ -74,10 +77,21 @@ impl PolyRecover<Fr, Polynomial, FFTSettings> for Polynomial {
     fn recover_poly_coeffs_from_samples(
         samples: &[Option<Fr>],
         fs: &FFTSettings,
-    ) -> Result<Self, String> {
+    ) -> Result<Self, Error> {
         Polynomial::recover_coeffs_from_samples(fs, samples)
     }
-    fn recover_poly_from_samples(samples: &[Option<Fr>], fs: &FFTSettings) -> Result<Self, String> {
+    fn recover_poly_from_samples(samples: &[Option<Fr>], fs: &FFTSettings) -> Result<Self, Error> {
         Polynomial::recover_from_samples(fs, samples)
     }
 }
+
+impl PolyErrorCorrect<Fr, Polynomial, FFTSettings> for Polynomial {
+    fn correct_poly_from_samples(
+        samples: &[Option<Fr>],
+        data_len: usize,
+        fs: &FFTSettings,
+    ) -> Result<(Self, Vec<usize>), Error> {
+        let (coeffs, corrected) = correct_errors(samples, data_len, fs)?;
+        Ok((Polynomial { coeffs }, corrected))
+    }
+}
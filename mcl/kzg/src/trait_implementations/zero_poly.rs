@@ -1,14 +1,11 @@
 use crate::data_types::fr::Fr;
 use crate::fk20_fft::FFTSettings;
 use crate::kzg10::Polynomial;
+use kzg::Error;
 use kzg::ZeroPoly;
 
 impl ZeroPoly<Fr, Polynomial> for FFTSettings {
-    fn do_zero_poly_mul_partial(
-        &self,
-        idxs: &[usize],
-        stride: usize,
-    ) -> Result<Polynomial, String> {
+    fn do_zero_poly_mul_partial(&self, idxs: &[usize], stride: usize) -> Result<Polynomial, Error> {
         self.do_zero_poly_mul_partial(idxs, stride)
     }
 
@@ -16,7 +13,7 @@ impl ZeroPoly<Fr, Polynomial> for FFTSettings {
         &self,
         domain_size: usize,
         partials: &[Polynomial],
-    ) -> Result<Polynomial, String> {
+    ) -> Result<Polynomial, Error> {
         self.reduce_partials(domain_size, partials)
     }
 
@@ -24,7 +21,7 @@ impl ZeroPoly<Fr, Polynomial> for FFTSettings {
         &self,
         domain_size: usize,
         missing_idxs: &[usize],
-    ) -> Result<(Vec<Fr>, Polynomial), String> {
+    ) -> Result<(Vec<Fr>, Polynomial), Error> {
         self.zero_poly_via_multiplication(domain_size, missing_idxs)
     }
 }
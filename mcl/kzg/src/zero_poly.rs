@@ -2,12 +2,13 @@ use crate::data_types::fr::Fr;
 use crate::fk20_fft::FFTSettings;
 use crate::kzg10::Polynomial;
 use crate::utilities::{is_power_of_2, next_pow_of_2};
+use kzg::Error;
 use std::cmp::min;
 
 ///  Copy all of the coefficients of polynomial @p p to @p out, padding to length @p p_len with zeros.
-pub fn pad_poly(new_length: usize, poly: &Polynomial) -> Result<Vec<Fr>, String> {
+pub fn pad_poly(new_length: usize, poly: &Polynomial) -> Result<Vec<Fr>, Error> {
     if new_length < poly.order() {
-        return Err(String::from(
+        return Err(Error::InvalidArgument(
             "new_length must not be shorter then given poly's length",
         ));
     }
@@ -31,9 +32,11 @@ impl FFTSettings {
         &self,
         indices: &[usize],
         stride: usize,
-    ) -> Result<Polynomial, String> {
+    ) -> Result<Polynomial, Error> {
         if indices.is_empty() {
-            return Err(String::from("Missing indices array must not be empty"));
+            return Err(Error::InvalidArgument(
+                "Missing indices array must not be empty",
+            ));
         }
 
         let mut poly = Polynomial::from_fr(vec![Fr::one(); indices.len() + 1]);
@@ -59,9 +62,9 @@ impl FFTSettings {
         &self,
         len_out: usize,
         partials: &[Polynomial],
-    ) -> Result<Polynomial, String> {
+    ) -> Result<Polynomial, Error> {
         if !is_power_of_2(len_out) {
-            return Err(String::from("len out must be a power of two"));
+            return Err(Error::NotPowerOfTwo("output length"));
         }
 
         // The degree of the output polynomial is the sum of the degrees of the input polynomials.
@@ -71,7 +74,7 @@ impl FFTSettings {
         }
 
         if out_degree + 1 > len_out {
-            return Err(String::from("Out degree is longer than out len"));
+            return Err(Error::InvalidArgument("Out degree is longer than out len"));
         }
 
         // Do the last partial first: it is no longer than the others and the padding can remain in place for the rest.
@@ -102,7 +105,7 @@ impl FFTSettings {
         &self,
         length: usize,
         missing_indices: &[usize],
-    ) -> Result<(Vec<Fr>, Polynomial), String> {
+    ) -> Result<(Vec<Fr>, Polynomial), Error> {
         let zero_eval: Vec<Fr>;
         let mut zero_poly: Polynomial;
 
@@ -113,13 +116,17 @@ impl FFTSettings {
         }
 
         if missing_indices.len() >= length {
-            return Err(String::from("Missing indice count is bigger than length"));
+            return Err(Error::InvalidArgument(
+                "Missing indice count is bigger than length",
+            ));
         }
         if length > self.max_width {
-            return Err(String::from("Length is bigger than fft_settings.max_width"));
+            return Err(Error::InvalidArgument(
+                "Length is bigger than fft_settings.max_width",
+            ));
         }
         if !is_power_of_2(length) {
-            return Err(String::from("Length must be a power of 2"));
+            return Err(Error::NotPowerOfTwo("length"));
         }
 
         let degree_of_partial = 256; // Tunable parameter. Must be a power of two.
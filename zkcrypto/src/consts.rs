@@ -2,6 +2,7 @@ use crate::zkfr::blsScalar;
 // use blst::blst_fr as BlstFr;
 // use blst::{blst_fr_from_uint64, blst_fr_mul};
 // use crate::Fr;
+use kzg::Error;
 use kzg::Fr;
 
 pub const SCALE2_ROOT_OF_UNITY: [[u64; 4]; 32] = [
@@ -203,13 +204,15 @@ pub const SCALE_FACTOR: u64 = 5;
 
 pub const NUM_ROOTS: usize = 32;
 
-pub fn expand_root_of_unity(root: &blsScalar, width: usize) -> Result<Vec<blsScalar>, String> {
+pub fn expand_root_of_unity(root: &blsScalar, width: usize) -> Result<Vec<blsScalar>, Error> {
     // let ret = <blsScalar as Fr>::one(); // blsScalar::default()
     let mut generated_powers = vec![blsScalar::one(), *root];
 
     while !(generated_powers.last().unwrap().is_one()) {
         if generated_powers.len() > width {
-            return Err(String::from("Root of unity multiplied for too long"));
+            return Err(Error::InvalidArgument(
+                "Root of unity multiplied for too long",
+            ));
         }
         generated_powers.push(generated_powers.last().unwrap().mul(root));
     }
@@ -1,5 +1,6 @@
 //! Multiscalar multiplication implementation using pippenger algorithm.
-use crate::curve::{g1::G1Projective, scalar::Scalar};
+use crate::curve::{g1::G1Projective, g2::G2Projective, scalar::Scalar};
+use core::ops::{Add, AddAssign};
 // use dusk_bytes::Serializable;
 
 // use alloc::vec::*;
@@ -180,9 +181,44 @@ fn to_radix_2w(scalar: &Scalar, w: usize) -> [i8; 43] {
     digits
 }
 
+/// Projective points that [`msm_variable_base`] can combine.
+pub trait MsmPoint:
+    Copy
+    + Send
+    + Sync
+    + AddAssign
+    + for<'a> AddAssign<&'a Self>
+    + for<'a> Add<&'a Self, Output = Self>
+    + Add<Output = Self>
+{
+    fn identity() -> Self;
+
+    fn double(&self) -> Self;
+}
+
+impl MsmPoint for G1Projective {
+    fn identity() -> Self {
+        G1Projective::identity()
+    }
+
+    fn double(&self) -> Self {
+        G1Projective::double(self)
+    }
+}
+
+impl MsmPoint for G2Projective {
+    fn identity() -> Self {
+        G2Projective::identity()
+    }
+
+    fn double(&self) -> Self {
+        G2Projective::double(self)
+    }
+}
+
 /// Performs a Variable Base Multiscalar Multiplication.
 #[allow(clippy::needless_collect)]
-pub fn msm_variable_base(points: &[G1Projective], scalars: &[Scalar]) -> G1Projective {
+pub fn msm_variable_base<P: MsmPoint>(points: &[P], scalars: &[Scalar]) -> P {
     #[cfg(feature = "parallel")]
     use rayon::prelude::*;
 
@@ -195,7 +231,7 @@ pub fn msm_variable_base(points: &[G1Projective], scalars: &[Scalar]) -> G1Proje
     let num_bits = 255usize;
     let fr_one = Scalar::one();
 
-    let zero = G1Projective::identity();
+    let zero = P::identity();
     let window_starts: Vec<_> = (0..num_bits).step_by(c).collect();
 
     #[cfg(feature = "parallel")]
@@ -241,7 +277,7 @@ pub fn msm_variable_base(points: &[G1Projective], scalars: &[Scalar]) -> G1Proje
                     }
                 });
 
-            let mut running_sum = G1Projective::identity();
+            let mut running_sum = P::identity();
             for b in buckets.into_iter().rev() {
                 running_sum += b;
                 res += &running_sum;
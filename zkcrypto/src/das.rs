@@ -1,16 +1,16 @@
 use crate::fftsettings::ZkFFTSettings;
 use crate::zkfr::blsScalar;
-use kzg::{Fr, DAS};
+use kzg::{Error, Fr, DAS};
 
 impl DAS<blsScalar> for ZkFFTSettings {
-    fn das_fft_extension(&self, val: &[blsScalar]) -> Result<Vec<blsScalar>, String> {
+    fn das_fft_extension(&self, val: &[blsScalar]) -> Result<Vec<blsScalar>, Error> {
         if val.is_empty() {
             // !(val.len() > 0)
-            return Err(String::from("The list cannot be empty"));
+            return Err(Error::InvalidArgument("The list cannot be empty"));
         } else if !(val.len().is_power_of_two()) {
-            return Err(String::from("The list must be power of two"));
+            return Err(Error::NotPowerOfTwo("list length"));
         } else if val.len() * 2 > self.max_width {
-            return Err(String::from("The list is too long"));
+            return Err(Error::InvalidArgument("The list is too long"));
         }
 
         // assert!(val.len() > 0);
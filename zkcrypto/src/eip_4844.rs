@@ -1,144 +1,161 @@
-use std::convert::TryInto;
 use std::fs::File;
 use std::io::Read;
-use std::ops::Sub;
 
 use crate::fk20::reverse_bit_order;
-use crate::kzg_proofs::{check_proof_single, KZGSettings};
-use crate::kzg_types::{pairings_verify, ZkG1Projective, ZkG2Projective};
+use crate::kzg_proofs::KZGSettings;
+use crate::kzg_types::{ZkG1Projective, ZkG2Projective};
 use crate::poly::KzgPoly;
 use crate::zkfr::blsScalar;
 use kzg::eip_4844::{
-    bytes_of_uint64, hash, load_trusted_setup_string, BYTES_PER_BLOB, BYTES_PER_COMMITMENT,
-    BYTES_PER_FIELD_ELEMENT, BYTES_PER_G1, BYTES_PER_G2, BYTES_PER_PROOF, CHALLENGE_INPUT_SIZE,
-    FIAT_SHAMIR_PROTOCOL_DOMAIN, FIELD_ELEMENTS_PER_BLOB, RANDOM_CHALLENGE_KZG_BATCH_DOMAIN,
-    TRUSTED_SETUP_NUM_G2_POINTS,
+    load_trusted_setup_string, BlobPreset, ProofUpdateTerms, BYTES_PER_FIELD_ELEMENT, BYTES_PER_G1,
+    BYTES_PER_G2, TRUSTED_SETUP_NUM_G2_POINTS,
 };
-use kzg::{cfg_into_iter, FFTSettings, Fr, Poly, FFTG1, G1, G2};
+use kzg::trusted_setup::{verify_lagrange_setup, verify_monomial_setup, SetupBasis};
+use kzg::{Error, FFTSettings, PolyEval, FFTG1, G1, G2};
 
-use crate::curve::multiscalar_mul::msm_variable_base;
 use crate::fftsettings::ZkFFTSettings;
 
-#[cfg(feature = "parallel")]
-use rayon::prelude::*;
-
 pub fn hash_to_bls_field(x: &[u8; BYTES_PER_FIELD_ELEMENT]) -> blsScalar {
-    Fr::from_bytes(x).unwrap()
+    kzg::eip_4844::hash_to_bls_field(x)
 }
 
-#[allow(clippy::useless_conversion)]
-pub fn bytes_to_blob(bytes: &[u8]) -> Result<Vec<blsScalar>, String> {
-    if bytes.len() != BYTES_PER_BLOB {
-        return Err(format!(
-            "Invalid byte length. Expected {} got {}",
-            BYTES_PER_BLOB,
-            bytes.len(),
-        ));
-    }
-
-    bytes
-        .chunks(BYTES_PER_FIELD_ELEMENT)
-        .map(|chunk| {
-            chunk
-                .try_into()
-                .map_err(|_| "Chunked into incorrect number of bytes".to_string())
-                .and_then(Fr::from_bytes)
-        })
-        .collect()
+pub fn bytes_to_blob(bytes: &[u8]) -> Result<Vec<blsScalar>, Error> {
+    kzg::eip_4844::bytes_to_blob(bytes)
 }
 
-#[allow(clippy::useless_conversion)]
-fn load_trusted_setup_rust(g1_bytes: &[u8], g2_bytes: &[u8]) -> KZGSettings {
-    let num_g1_points = g1_bytes.len() / BYTES_PER_G1;
-
-    assert_eq!(g1_bytes.len() / BYTES_PER_G1, FIELD_ELEMENTS_PER_BLOB);
-    assert_eq!(g2_bytes.len() / BYTES_PER_G2, TRUSTED_SETUP_NUM_G2_POINTS);
-
-    let g1_projectives: Vec<ZkG1Projective> = g1_bytes
-        .chunks(BYTES_PER_G1)
-        .map(|chunk| {
-            G1::from_bytes(
-                chunk
-                    .try_into()
-                    .expect("Chunked into incorrect number of bytes"),
-            )
-            .unwrap()
-        })
-        .collect();
-
-    let g2_values: Vec<ZkG2Projective> = g2_bytes
-        .chunks(BYTES_PER_G2)
-        .map(|chunk| {
-            G2::from_bytes(
-                chunk
-                    .try_into()
-                    .expect("Chunked into incorrect number of bytes"),
-            )
-            .unwrap()
-        })
-        .collect();
+pub fn bytes_to_blob_with_preset(
+    bytes: &[u8],
+    preset: &BlobPreset,
+) -> Result<Vec<blsScalar>, Error> {
+    kzg::eip_4844::bytes_to_blob_with_preset(bytes, preset)
+}
 
-    let mut max_scale: usize = 0;
-    while (1 << max_scale) < num_g1_points {
-        max_scale += 1;
+/// Loads a trusted setup from the concatenated compressed G1 and G2 points, with the G1 points
+/// in monomial form. With `verify`, also checks that the points are powers of one secret with
+/// [`verify_monomial_setup`], which rejects a corrupted or altered setup at the cost of a few
+/// multi-scalar multiplications and pairings.
+pub fn load_trusted_setup_rust(
+    g1_bytes: &[u8],
+    g2_bytes: &[u8],
+    verify: bool,
+) -> Result<KZGSettings, Error> {
+    let (blob_preset, g1_projectives, g2_values, fs) = decode_trusted_setup(g1_bytes, g2_bytes)?;
+
+    if verify {
+        verify_monomial_setup(&g1_projectives, &g2_values)?;
     }
 
-    let fs = ZkFFTSettings::new(max_scale).unwrap();
-    let mut g1_values = fs.fft_g1(&g1_projectives, true).unwrap();
+    let mut g1_values = fs.fft_g1(&g1_projectives, true)?;
     reverse_bit_order(&mut g1_values);
 
-    KZGSettings {
+    Ok(KZGSettings {
         secret_g1: g1_values,
         secret_g2: g2_values,
         fs,
-        length: num_g1_points as u64,
-    }
+        length: g1_projectives.len() as u64,
+        blob_preset,
+        basis: SetupBasis::Lagrange,
+    })
 }
 
-pub fn load_trusted_setup(filepath: &str) -> KZGSettings {
-    let mut file = File::open(filepath).expect("Unable to open file");
-    let mut contents = String::new();
-    file.read_to_string(&mut contents)
-        .expect("Unable to read file");
+/// Loads a trusted setup whose G1 points are already in bit-reversed Lagrange form, like the
+/// `g1_lagrange` array of the ceremony's `trusted_setup.json`. With `verify`, the points are
+/// checked with [`verify_lagrange_setup`].
+pub fn load_trusted_setup_lagrange_rust(
+    g1_bytes: &[u8],
+    g2_bytes: &[u8],
+    verify: bool,
+) -> Result<KZGSettings, Error> {
+    let (blob_preset, g1_values, g2_values, fs) = decode_trusted_setup(g1_bytes, g2_bytes)?;
+
+    if verify {
+        verify_lagrange_setup(&g1_values, &g2_values, &fs)?;
+    }
 
-    let (g1_bytes, g2_bytes) = load_trusted_setup_string(&contents);
-    load_trusted_setup_rust(g1_bytes.as_slice(), g2_bytes.as_slice())
+    Ok(KZGSettings {
+        length: g1_values.len() as u64,
+        secret_g1: g1_values,
+        secret_g2: g2_values,
+        fs,
+        blob_preset,
+        basis: SetupBasis::Lagrange,
+    })
 }
 
-fn fr_batch_inv(out: &mut [blsScalar], a: &[blsScalar], len: usize) {
-    assert!(len > 0);
-
-    let mut accumulator = blsScalar::one();
+/// Decodes the points of a trusted setup and creates FFT settings wide enough for its G1 points.
+fn decode_trusted_setup(
+    g1_bytes: &[u8],
+    g2_bytes: &[u8],
+) -> Result<
+    (
+        BlobPreset,
+        Vec<ZkG1Projective>,
+        Vec<ZkG2Projective>,
+        ZkFFTSettings,
+    ),
+    Error,
+> {
+    let num_g1_points = g1_bytes.len() / BYTES_PER_G1;
 
-    for i in 0..len {
-        out[i] = accumulator;
-        accumulator = accumulator.mul(&a[i]);
+    let blob_preset = BlobPreset::new(num_g1_points)?;
+    if g2_bytes.len() / BYTES_PER_G2 != TRUSTED_SETUP_NUM_G2_POINTS {
+        return Err(Error::InvalidLength {
+            expected: TRUSTED_SETUP_NUM_G2_POINTS,
+            actual: g2_bytes.len() / BYTES_PER_G2,
+        });
     }
 
-    accumulator = accumulator.eucl_inverse();
+    let g1_values = g1_bytes
+        .chunks(BYTES_PER_G1)
+        .map(ZkG1Projective::from_bytes)
+        .collect::<Result<Vec<ZkG1Projective>, Error>>()?;
+    let g2_values = g2_bytes
+        .chunks(BYTES_PER_G2)
+        .map(ZkG2Projective::from_bytes)
+        .collect::<Result<Vec<ZkG2Projective>, Error>>()?;
 
-    for i in (0..len).rev() {
-        out[i] = out[i].mul(&accumulator);
-        accumulator = accumulator.mul(&a[i]);
+    let mut max_scale: usize = 0;
+    while (1 << max_scale) < num_g1_points {
+        max_scale += 1;
     }
+
+    let fs = ZkFFTSettings::new(max_scale)?;
+    Ok((blob_preset, g1_values, g2_values, fs))
 }
 
-fn g1_lincomb(points: &[ZkG1Projective], scalars: &[blsScalar], _length: usize) -> ZkG1Projective {
-    msm_variable_base(points, scalars)
+fn read_trusted_setup_file(filepath: &str) -> Result<String, Error> {
+    let mut file = File::open(filepath)?;
+    let mut contents = String::new();
+    file.read_to_string(&mut contents)?;
+    Ok(contents)
+}
+
+pub fn load_trusted_setup(filepath: &str) -> Result<KZGSettings, Error> {
+    let contents = read_trusted_setup_file(filepath)?;
+    let (g1_bytes, g2_bytes) = load_trusted_setup_string(&contents)?;
+    load_trusted_setup_rust(g1_bytes.as_slice(), g2_bytes.as_slice(), false)
+}
+
+/// Loads a trusted setup from the `trusted_setup.json` file published by the KZG ceremony.
+pub fn load_trusted_setup_json(filepath: &str) -> Result<KZGSettings, Error> {
+    let contents = read_trusted_setup_file(filepath)?;
+    let (g1_bytes, g2_bytes) = kzg::eip_4844::load_trusted_setup_json(&contents)?;
+    load_trusted_setup_lagrange_rust(g1_bytes.as_slice(), g2_bytes.as_slice(), false)
 }
 
 pub fn compute_powers(base: &blsScalar, num_powers: usize) -> Vec<blsScalar> {
-    let mut powers: Vec<blsScalar> = vec![blsScalar::default(); num_powers];
-    powers[0] = blsScalar::one();
-    for i in 1..num_powers {
-        powers[i] = powers[i - 1].mul(base);
-    }
-    powers
+    kzg::eip_4844::compute_powers(base, num_powers)
 }
 
 pub fn blob_to_kzg_commitment(blob: &[blsScalar], s: &KZGSettings) -> ZkG1Projective {
-    let p = blob_to_polynomial(blob);
-    poly_to_kzg_commitment(&p, s)
+    kzg::eip_4844::blob_to_kzg_commitment_rust::<
+        blsScalar,
+        ZkG1Projective,
+        ZkG2Projective,
+        KzgPoly,
+        ZkFFTSettings,
+        _,
+    >(blob, s)
 }
 
 pub fn verify_kzg_proof(
@@ -147,15 +164,15 @@ pub fn verify_kzg_proof(
     y: &blsScalar,
     proof: &ZkG1Projective,
     s: &KZGSettings,
-) -> Result<bool, String> {
-    if !commitment.is_valid() {
-        return Err("Invalid commitment".to_string());
-    }
-    if !proof.is_valid() {
-        return Err("Invalid proof".to_string());
-    }
-
-    Ok(check_proof_single(commitment, proof, z, y, s).unwrap_or(false))
+) -> Result<bool, Error> {
+    kzg::eip_4844::verify_kzg_proof_rust::<
+        blsScalar,
+        ZkG1Projective,
+        ZkG2Projective,
+        KzgPoly,
+        ZkFFTSettings,
+        _,
+    >(commitment, z, y, proof, s)
 }
 
 pub fn verify_kzg_proof_batch(
@@ -165,40 +182,14 @@ pub fn verify_kzg_proof_batch(
     proofs_g1: &[ZkG1Projective],
     ts: &KZGSettings,
 ) -> bool {
-    let n = commitments_g1.len();
-    let mut c_minus_y: Vec<ZkG1Projective> = Vec::new();
-    let mut r_times_z: Vec<blsScalar> = Vec::new();
-
-    // Compute the random lincomb challenges
-    let r_powers = compute_r_powers(commitments_g1, zs_fr, ys_fr, proofs_g1);
-
-    // Compute \sum r^i * Proof_i
-    let proof_lincomb = g1_lincomb(proofs_g1, &r_powers, n);
-
-    for i in 0..n {
-        // Get [y_i]
-        let ys_encrypted = ZkG1Projective::generator().mul(&ys_fr[i]);
-        // Get C_i - [y_i]
-        c_minus_y.push(commitments_g1[i].sub(&ys_encrypted));
-        // Get r^i * z_i
-        r_times_z.push(r_powers[i].mul(&zs_fr[i]));
-    }
-
-    // Get \sum r^i z_i Proof_i
-    let proof_z_lincomb = g1_lincomb(proofs_g1, &r_times_z, n);
-    // Get \sum r^i (C_i - [y_i])
-    let mut c_minus_y_lincomb = g1_lincomb(&c_minus_y, &r_powers, n);
-
-    // Get C_minus_y_lincomb + proof_z_lincomb
-    let rhs_g1 = c_minus_y_lincomb.add_or_dbl(&proof_z_lincomb);
-
-    // Do the pairing check!
-    pairings_verify(
-        &proof_lincomb,
-        &ts.secret_g2[1],
-        &rhs_g1,
-        &ZkG2Projective::generator(),
-    )
+    kzg::eip_4844::verify_kzg_proof_batch::<
+        blsScalar,
+        ZkG1Projective,
+        ZkG2Projective,
+        KzgPoly,
+        ZkFFTSettings,
+        _,
+    >(commitments_g1, zs_fr, ys_fr, proofs_g1, ts)
 }
 
 pub fn compute_kzg_proof(
@@ -206,210 +197,94 @@ pub fn compute_kzg_proof(
     z: &blsScalar,
     s: &KZGSettings,
 ) -> (ZkG1Projective, blsScalar) {
-    assert_eq!(blob.len(), FIELD_ELEMENTS_PER_BLOB);
-
-    let polynomial = blob_to_polynomial(blob);
-    let y = evaluate_polynomial_in_evaluation_form(&polynomial, z, s);
-
-    let mut tmp: blsScalar;
-    let roots_of_unity: &Vec<blsScalar> = &s.fs.roots_of_unity;
-
-    let mut m: usize = 0;
-    let mut q: KzgPoly = KzgPoly::new(FIELD_ELEMENTS_PER_BLOB).unwrap();
-
-    let mut inverses_in: Vec<blsScalar> = vec![blsScalar::default(); FIELD_ELEMENTS_PER_BLOB];
-    let mut inverses: Vec<blsScalar> = vec![blsScalar::default(); FIELD_ELEMENTS_PER_BLOB];
-
-    for i in 0..FIELD_ELEMENTS_PER_BLOB {
-        if z.equals(&roots_of_unity[i]) {
-            // We are asked to compute a KZG proof inside the domain
-            m = i + 1;
-            inverses_in[i] = blsScalar::one();
-            continue;
-        }
-        // (p_i - y) / (ω_i - z)
-        q.coeffs[i] = polynomial.coeffs[i].sub(&y);
-        inverses_in[i] = roots_of_unity[i].sub(z);
-    }
-
-    fr_batch_inv(&mut inverses, &inverses_in, FIELD_ELEMENTS_PER_BLOB);
-
-    for (i, inverse) in inverses.iter().enumerate().take(FIELD_ELEMENTS_PER_BLOB) {
-        q.coeffs[i] = q.coeffs[i].mul(inverse);
-    }
-
-    if m != 0 {
-        // ω_{m-1} == z
-        m -= 1;
-        q.coeffs[m] = blsScalar::zero();
-        for i in 0..FIELD_ELEMENTS_PER_BLOB {
-            if i == m {
-                continue;
-            }
-            // Build denominator: z * (z - ω_i)
-            tmp = z.sub(&roots_of_unity[i]);
-            inverses_in[i] = tmp.mul(z);
-        }
-
-        fr_batch_inv(&mut inverses, &inverses_in, FIELD_ELEMENTS_PER_BLOB);
-
-        for i in 0..FIELD_ELEMENTS_PER_BLOB {
-            if i == m {
-                continue;
-            }
-            // Build numerator: ω_i * (p_i - y)
-            tmp = polynomial.coeffs[i].sub(&y);
-            tmp = tmp.mul(&roots_of_unity[i]);
-            // Do the division: (p_i - y) * ω_i / (z * (z - ω_i))
-            tmp = tmp.mul(&inverses[i]);
-            q.coeffs[m] = q.coeffs[m].add(&tmp);
-        }
-    }
-
-    let proof = g1_lincomb(&s.secret_g1, &q.coeffs, FIELD_ELEMENTS_PER_BLOB);
-    (proof, y)
+    kzg::eip_4844::compute_kzg_proof_rust::<
+        blsScalar,
+        ZkG1Projective,
+        ZkG2Projective,
+        KzgPoly,
+        ZkFFTSettings,
+        _,
+    >(blob, z, s)
 }
 
-pub fn evaluate_polynomial_in_evaluation_form(
-    p: &KzgPoly,
-    x: &blsScalar,
+pub fn update_kzg_commitment(
+    commitment: &ZkG1Projective,
+    changes: &[(usize, blsScalar, blsScalar)],
     s: &KZGSettings,
-) -> blsScalar {
-    assert_eq!(p.coeffs.len(), FIELD_ELEMENTS_PER_BLOB);
-
-    let roots_of_unity: &Vec<blsScalar> = &s.fs.roots_of_unity;
-    let mut inverses_in: Vec<blsScalar> = vec![blsScalar::default(); FIELD_ELEMENTS_PER_BLOB];
-    let mut inverses: Vec<blsScalar> = vec![blsScalar::default(); FIELD_ELEMENTS_PER_BLOB];
-
-    for i in 0..FIELD_ELEMENTS_PER_BLOB {
-        if x.equals(&roots_of_unity[i]) {
-            return p.get_coeff_at(i);
-        }
-        inverses_in[i] = x.sub(&roots_of_unity[i]);
-    }
-
-    fr_batch_inv(&mut inverses, &inverses_in, FIELD_ELEMENTS_PER_BLOB);
-
-    let mut tmp: blsScalar;
-    let mut out = blsScalar::zero();
-
-    for i in 0..FIELD_ELEMENTS_PER_BLOB {
-        tmp = inverses[i].mul(&roots_of_unity[i]);
-        tmp = tmp.mul(&p.coeffs[i]);
-        out = out.add(&tmp);
-    }
-
-    let arr: [u64; 4] = [FIELD_ELEMENTS_PER_BLOB as u64, 0, 0, 0];
-    tmp = blsScalar::from_u64_arr(&arr);
-    out = out.div(&tmp).unwrap();
-    tmp = x.pow(&arr);
-    tmp = tmp.sub(&blsScalar::one());
-    out = out.mul(&tmp);
-    out
+) -> Result<ZkG1Projective, Error> {
+    kzg::eip_4844::update_kzg_commitment_rust::<
+        blsScalar,
+        ZkG1Projective,
+        ZkG2Projective,
+        KzgPoly,
+        ZkFFTSettings,
+        _,
+    >(commitment, changes, s)
 }
 
-fn compute_challenge(blob: &[blsScalar], commitment: &ZkG1Projective) -> blsScalar {
-    let mut bytes: Vec<u8> = vec![0; CHALLENGE_INPUT_SIZE];
-
-    // Copy domain separator
-    bytes[..16].copy_from_slice(&FIAT_SHAMIR_PROTOCOL_DOMAIN);
-    bytes_of_uint64(&mut bytes[16..24], FIELD_ELEMENTS_PER_BLOB as u64);
-    // Set all other bytes of this 16-byte (little-endian) field to zero
-    bytes_of_uint64(&mut bytes[24..32], 0);
-
-    // Copy blob
-    for i in 0..blob.len() {
-        let v = Fr::to_bytes(&blob[i]);
-        bytes[(32 + i * BYTES_PER_FIELD_ELEMENT)..(32 + (i + 1) * BYTES_PER_FIELD_ELEMENT)]
-            .copy_from_slice(&v);
-    }
-
-    // Copy commitment
-    let v = G1::to_bytes(commitment);
-    for i in 0..v.len() {
-        bytes[32 + BYTES_PER_BLOB + i] = v[i];
-    }
-
-    // Now let's create the challenge!
-    let eval_challenge = hash(&bytes);
-    hash_to_bls_field(&eval_challenge)
+pub fn precompute_proof_update_terms(
+    z: &blsScalar,
+    s: &KZGSettings,
+) -> Result<ProofUpdateTerms<blsScalar, ZkG1Projective>, Error> {
+    kzg::eip_4844::precompute_proof_update_terms_rust::<
+        blsScalar,
+        ZkG1Projective,
+        ZkG2Projective,
+        KzgPoly,
+        ZkFFTSettings,
+        _,
+    >(z, s)
 }
 
-fn compute_r_powers(
-    commitments_g1: &[ZkG1Projective],
-    zs_fr: &[blsScalar],
-    ys_fr: &[blsScalar],
-    proofs_g1: &[ZkG1Projective],
-) -> Vec<blsScalar> {
-    let n = commitments_g1.len();
-    let input_size =
-        32 + n * (BYTES_PER_COMMITMENT + 2 * BYTES_PER_FIELD_ELEMENT + BYTES_PER_PROOF);
-
-    #[allow(unused_assignments)]
-    let mut offset = 0;
-    let mut bytes: Vec<u8> = vec![0; input_size];
-
-    // Copy domain separator
-    bytes[..16].copy_from_slice(&RANDOM_CHALLENGE_KZG_BATCH_DOMAIN);
-    bytes_of_uint64(&mut bytes[16..24], FIELD_ELEMENTS_PER_BLOB as u64);
-    bytes_of_uint64(&mut bytes[24..32], n as u64);
-    offset = 32;
-
-    for i in 0..n {
-        // Copy commitment
-        let v = G1::to_bytes(&commitments_g1[i]);
-        bytes[offset..(v.len() + offset)].copy_from_slice(&v[..]);
-        offset += BYTES_PER_COMMITMENT;
-
-        // Copy evaluation challenge
-        let v = Fr::to_bytes(&zs_fr[i]);
-        bytes[offset..(v.len() + offset)].copy_from_slice(&v[..]);
-        offset += BYTES_PER_FIELD_ELEMENT;
-
-        // Copy polynomial's evaluation value
-        let v = Fr::to_bytes(&ys_fr[i]);
-        bytes[offset..(v.len() + offset)].copy_from_slice(&v[..]);
-        offset += BYTES_PER_FIELD_ELEMENT;
-
-        // Copy proof
-        let v = G1::to_bytes(&proofs_g1[i]);
-        bytes[offset..(v.len() + offset)].copy_from_slice(&v[..]);
-        offset += BYTES_PER_PROOF;
-    }
-
-    // Make sure we wrote the entire buffer
-    assert_eq!(offset, input_size);
-
-    // Now let's create the challenge!
-    let eval_challenge = hash(&bytes);
-    let r = hash_to_bls_field(&eval_challenge);
-    compute_powers(&r, n)
+pub fn update_kzg_proof(
+    proof: &ZkG1Projective,
+    y: &blsScalar,
+    changes: &[(usize, blsScalar, blsScalar)],
+    terms: &ProofUpdateTerms<blsScalar, ZkG1Projective>,
+    s: &KZGSettings,
+) -> Result<(ZkG1Projective, blsScalar), Error> {
+    kzg::eip_4844::update_kzg_proof_rust::<
+        blsScalar,
+        ZkG1Projective,
+        ZkG2Projective,
+        KzgPoly,
+        ZkFFTSettings,
+        _,
+    >(proof, y, changes, terms, s)
 }
 
-pub fn blob_to_polynomial(blob: &[blsScalar]) -> KzgPoly {
-    assert_eq!(blob.len(), FIELD_ELEMENTS_PER_BLOB);
-    let mut p: KzgPoly = KzgPoly::new(FIELD_ELEMENTS_PER_BLOB).unwrap();
-    p.coeffs = blob.to_vec();
-    p
+pub fn evaluate_polynomial_in_evaluation_form(
+    p: &PolyEval<blsScalar>,
+    x: &blsScalar,
+    s: &KZGSettings,
+) -> blsScalar {
+    kzg::eip_4844::evaluate_polynomial_in_evaluation_form_rust::<
+        blsScalar,
+        ZkG1Projective,
+        ZkG2Projective,
+        KzgPoly,
+        ZkFFTSettings,
+        _,
+    >(p, x, s)
 }
 
-fn poly_to_kzg_commitment(p: &KzgPoly, s: &KZGSettings) -> ZkG1Projective {
-    assert_eq!(p.coeffs.len(), FIELD_ELEMENTS_PER_BLOB);
-    g1_lincomb(&s.secret_g1, &p.coeffs, FIELD_ELEMENTS_PER_BLOB)
+pub fn blob_to_polynomial(blob: &[blsScalar]) -> PolyEval<blsScalar> {
+    kzg::eip_4844::blob_to_polynomial_rust(blob)
 }
 
 pub fn compute_blob_kzg_proof(
     blob: &[blsScalar],
     commitment: &ZkG1Projective,
     ts: &KZGSettings,
-) -> Result<ZkG1Projective, String> {
-    if !commitment.is_valid() {
-        return Err("Invalid commitment".to_string());
-    }
-
-    let evaluation_challenge_fr = compute_challenge(blob, commitment);
-    let (proof, _) = compute_kzg_proof(blob, &evaluation_challenge_fr, ts);
-    Ok(proof)
+) -> Result<ZkG1Projective, Error> {
+    kzg::eip_4844::compute_blob_kzg_proof_rust::<
+        blsScalar,
+        ZkG1Projective,
+        ZkG2Projective,
+        KzgPoly,
+        ZkFFTSettings,
+        _,
+    >(blob, commitment, ts)
 }
 
 pub fn verify_blob_kzg_proof(
@@ -417,56 +292,15 @@ pub fn verify_blob_kzg_proof(
     commitment_g1: &ZkG1Projective,
     proof_g1: &ZkG1Projective,
     ts: &KZGSettings,
-) -> Result<bool, String> {
-    if !commitment_g1.is_valid() {
-        return Err("Invalid commitment".to_string());
-    }
-    if !proof_g1.is_valid() {
-        return Err("Invalid proof".to_string());
-    }
-
-    let polynomial = blob_to_polynomial(blob);
-    let evaluation_challenge_fr = compute_challenge(blob, commitment_g1);
-    let y_fr = evaluate_polynomial_in_evaluation_form(&polynomial, &evaluation_challenge_fr, ts);
-    verify_kzg_proof(commitment_g1, &evaluation_challenge_fr, &y_fr, proof_g1, ts)
-}
-
-fn compute_challenges_and_evaluate_polynomial(
-    blobs: &[Vec<blsScalar>],
-    commitments_g1: &[ZkG1Projective],
-    ts: &KZGSettings,
-) -> (Vec<blsScalar>, Vec<blsScalar>) {
-    let mut evaluation_challenges_fr = Vec::new();
-    let mut ys_fr = Vec::new();
-
-    for i in 0..blobs.len() {
-        let polynomial = blob_to_polynomial(&blobs[i]);
-        let evaluation_challenge_fr = compute_challenge(&blobs[i], &commitments_g1[i]);
-        let y_fr =
-            evaluate_polynomial_in_evaluation_form(&polynomial, &evaluation_challenge_fr, ts);
-
-        evaluation_challenges_fr.push(evaluation_challenge_fr);
-        ys_fr.push(y_fr);
-    }
-
-    (evaluation_challenges_fr, ys_fr)
-}
-
-fn validate_batched_input(
-    commitments: &[ZkG1Projective],
-    proofs: &[ZkG1Projective],
-) -> Result<(), String> {
-    let invalid_commitment = cfg_into_iter!(commitments).any(|&commitment| !commitment.is_valid());
-    let invalid_proof = cfg_into_iter!(proofs).any(|&proof| !proof.is_valid());
-
-    if invalid_commitment {
-        return Err("Invalid commitment".to_string());
-    }
-    if invalid_proof {
-        return Err("Invalid proof".to_string());
-    }
-
-    Ok(())
+) -> Result<bool, Error> {
+    kzg::eip_4844::verify_blob_kzg_proof_rust::<
+        blsScalar,
+        ZkG1Projective,
+        ZkG2Projective,
+        KzgPoly,
+        ZkFFTSettings,
+        _,
+    >(blob, commitment_g1, proof_g1, ts)
 }
 
 pub fn verify_blob_kzg_proof_batch(
@@ -474,79 +308,13 @@ pub fn verify_blob_kzg_proof_batch(
     commitments_g1: &[ZkG1Projective],
     proofs_g1: &[ZkG1Projective],
     ts: &KZGSettings,
-) -> Result<bool, String> {
-    // Exit early if we are given zero blobs
-    if blobs.is_empty() {
-        return Ok(true);
-    }
-
-    // For a single blob, just do a regular single verification
-    if blobs.len() == 1 {
-        return verify_blob_kzg_proof(&blobs[0], &commitments_g1[0], &proofs_g1[0], ts);
-    }
-
-    if blobs.len() != commitments_g1.len() || blobs.len() != proofs_g1.len() {
-        return Err("Invalid amount of arguments".to_string());
-    }
-
-    #[cfg(feature = "parallel")]
-    {
-        let num_blobs = blobs.len();
-        let num_cores = num_cpus::get_physical();
-
-        return if num_blobs > num_cores {
-            validate_batched_input(commitments_g1, proofs_g1)?;
-
-            // Process blobs in parallel subgroups
-            let blobs_per_group = num_blobs / num_cores;
-
-            Ok(blobs
-                .par_chunks(blobs_per_group)
-                .enumerate()
-                .all(|(i, blob_group)| {
-                    let num_blobs_in_group = blob_group.len();
-                    let commitment_group = &commitments_g1
-                        [blobs_per_group * i..blobs_per_group * i + num_blobs_in_group];
-                    let proof_group =
-                        &proofs_g1[blobs_per_group * i..blobs_per_group * i + num_blobs_in_group];
-                    let (evaluation_challenges_fr, ys_fr) =
-                        compute_challenges_and_evaluate_polynomial(
-                            blob_group,
-                            commitment_group,
-                            ts,
-                        );
-
-                    verify_kzg_proof_batch(
-                        commitment_group,
-                        &evaluation_challenges_fr,
-                        &ys_fr,
-                        proof_group,
-                        ts,
-                    )
-                }))
-        } else {
-            // Each group contains either one or zero blobs, so iterate
-            // over the single blob verification function in parallel
-            Ok((blobs, commitments_g1, proofs_g1).into_par_iter().all(
-                |(blob, commitment, proof)| {
-                    verify_blob_kzg_proof(blob, commitment, proof, ts).unwrap()
-                },
-            ))
-        };
-    }
-
-    #[cfg(not(feature = "parallel"))]
-    {
-        validate_batched_input(commitments_g1, proofs_g1)?;
-        let (evaluation_challenges_fr, ys_fr) =
-            compute_challenges_and_evaluate_polynomial(blobs, commitments_g1, ts);
-
-        Ok(verify_kzg_proof_batch(
-            commitments_g1,
-            &evaluation_challenges_fr,
-            &ys_fr,
-            proofs_g1,
-            ts,
-        ))
-    }
+) -> Result<bool, Error> {
+    kzg::eip_4844::verify_blob_kzg_proof_batch_rust::<
+        blsScalar,
+        ZkG1Projective,
+        ZkG2Projective,
+        KzgPoly,
+        ZkFFTSettings,
+        _,
+    >(blobs, commitments_g1, proofs_g1, ts)
 }
@@ -1,6 +1,7 @@
 use crate::fftsettings::ZkFFTSettings;
 use crate::utils::is_power_of_two;
 use crate::zkfr::blsScalar;
+use kzg::Error;
 use kzg::Fr;
 
 pub fn fft_fr_slow(
@@ -25,11 +26,13 @@ pub fn fft_fr(
     data: &[blsScalar],
     inverse: bool,
     fft_settings: &ZkFFTSettings,
-) -> Result<Vec<blsScalar>, String> {
+) -> Result<Vec<blsScalar>, Error> {
     if data.len() > fft_settings.max_width {
-        return Err(String::from("Given data is longer than allowed max width"));
+        return Err(Error::InvalidArgument(
+            "Given data is longer than allowed max width",
+        ));
     } else if !is_power_of_two(data.len()) {
-        return Err(String::from("Given data is not power-of-two length"));
+        return Err(Error::NotPowerOfTwo("data length"));
     }
 
     let stride = fft_settings.max_width / data.len();
@@ -45,12 +48,7 @@ pub fn fft_fr(
     if inverse {
         let mut inv_len: blsScalar = blsScalar::from(data.len() as u64);
         inv_len = inv_len.inverse();
-        for i in ret.iter_mut().take(data.len())
-        /*0..data.len()*/
-        {
-            *i = i.mul(&inv_len);
-            //ret[i] = ret[i].mul(&inv_len);
-        }
+        blsScalar::batch_mul_scalar(&mut ret[..data.len()], &inv_len);
     }
 
     Ok(ret)
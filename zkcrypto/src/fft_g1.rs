@@ -2,7 +2,7 @@ use crate::fftsettings::ZkFFTSettings;
 use crate::kzg_types::ZkG1Projective;
 use crate::utils::is_power_of_two;
 use crate::zkfr::blsScalar;
-use kzg::{Fr, FFTG1, G1};
+use kzg::{Error, Fr, FFTG1, G1};
 
 pub fn fft_g1_slow(
     ret: &mut [ZkG1Projective],
@@ -22,15 +22,13 @@ pub fn fft_g1_slow(
 }
 
 impl FFTG1<ZkG1Projective> for ZkFFTSettings {
-    fn fft_g1(
-        &self,
-        data: &[ZkG1Projective],
-        inverse: bool,
-    ) -> Result<Vec<ZkG1Projective>, String> {
+    fn fft_g1(&self, data: &[ZkG1Projective], inverse: bool) -> Result<Vec<ZkG1Projective>, Error> {
         if data.len() > self.max_width {
-            return Err(String::from("Given data is longer than allowed max width"));
+            return Err(Error::InvalidArgument(
+                "Given data is longer than allowed max width",
+            ));
         } else if !is_power_of_two(data.len()) {
-            return Err(String::from("Given data is not power-of-two length"));
+            return Err(Error::NotPowerOfTwo("data length"));
         }
 
         let stride = self.max_width / data.len();
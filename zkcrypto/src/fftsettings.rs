@@ -6,7 +6,7 @@ use crate::zkfr::blsScalar;
 use std::cmp::Ordering;
 
 use crate::fk20::reverse_bit_order;
-use kzg::{FFTFr, FFTSettings, FFTSettingsPoly, Fr};
+use kzg::{Error, FFTFr, FFTSettings, FFTSettingsPoly, Fr};
 
 #[derive(Debug, Clone, Default)]
 pub struct ZkFFTSettings {
@@ -78,19 +78,19 @@ impl FFTSettingsPoly<blsScalar, ZPoly, ZkFFTSettings> for ZkFFTSettings {
         b: &ZPoly,
         len: usize,
         _fs: Option<&ZkFFTSettings>,
-    ) -> Result<ZPoly, String> {
+    ) -> Result<ZPoly, Error> {
         poly_mul_fft(len, a, b)
     }
 }
 
 impl FFTFr<blsScalar> for ZkFFTSettings {
-    fn fft_fr(&self, data: &[blsScalar], inverse: bool) -> Result<Vec<blsScalar>, String> {
+    fn fft_fr(&self, data: &[blsScalar], inverse: bool) -> Result<Vec<blsScalar>, Error> {
         if data.len() > self.max_width {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "The supplied list is longer than the available max width",
             ));
         } else if !is_power_of_two(data.len()) {
-            return Err(String::from("A list with power-of-two length is expected"));
+            return Err(Error::NotPowerOfTwo("list length"));
         }
 
         // In case more roots are provided with fft_settings, use a larger stride
@@ -109,9 +109,7 @@ impl FFTFr<blsScalar> for ZkFFTSettings {
         if inverse {
             let mut inv_len: blsScalar = blsScalar::from_u64(data.len() as u64);
             inv_len = inv_len.inverse();
-            for i in ret.iter_mut().take(data.len()) {
-                *i = i.mul(&inv_len);
-            }
+            blsScalar::batch_mul_scalar(&mut ret[..data.len()], &inv_len);
         }
 
         Ok(ret)
@@ -119,9 +117,9 @@ impl FFTFr<blsScalar> for ZkFFTSettings {
 }
 
 impl FFTSettings<blsScalar> for ZkFFTSettings {
-    fn new(scale: usize) -> Result<Self, String> {
+    fn new(scale: usize) -> Result<Self, Error> {
         if scale >= SCALE2_ROOT_OF_UNITY.len() {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "Scale is expected to be within root of unity matrix row size",
             ));
         }
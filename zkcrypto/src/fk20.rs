@@ -4,7 +4,8 @@ use crate::kzg_types::{ZkG1Projective, ZkG2Projective};
 use crate::poly::ZPoly;
 use crate::utils::*;
 use crate::zkfr::blsScalar;
-use kzg::{FFTFr, FK20MultiSettings, FK20SingleSettings, Poly, FFTG1, G1};
+use kzg::trusted_setup::SetupBasis;
+use kzg::{Error, FFTFr, FK20MultiSettings, FK20SingleSettings, Poly, FFTG1, G1};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
@@ -19,17 +20,24 @@ impl
     FK20SingleSettings<blsScalar, ZkG1Projective, ZkG2Projective, ZkFFTSettings, ZPoly, KZGSettings>
     for ZkFK20SingleSettings
 {
-    fn new(kzg_settings: &KZGSettings, n: usize) -> Result<Self, String> {
+    fn new(kzg_settings: &KZGSettings, n: usize) -> Result<Self, Error> {
         let n2 = n / 2;
 
         if n > kzg_settings.fs.max_width {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "n must be less than or equal to kzg settings max width",
             ));
         } else if !is_power_of_two(n) {
-            return Err(String::from("n must be a power of two"));
+            return Err(Error::NotPowerOfTwo("n"));
         } else if n < 2 {
-            return Err(String::from("n must be greater than or equal to 2"));
+            return Err(Error::InvalidArgument(
+                "n must be greater than or equal to 2",
+            ));
+        } else if kzg_settings.basis != SetupBasis::Monomial {
+            return Err(Error::SetupBasisMismatch {
+                expected: SetupBasis::Monomial,
+                actual: kzg_settings.basis,
+            });
         }
 
         let mut x = Vec::new();
@@ -49,16 +57,16 @@ impl
         Ok(out)
     }
 
-    fn data_availability(&self, p: &ZPoly) -> Result<Vec<ZkG1Projective>, String> {
+    fn data_availability(&self, p: &ZPoly) -> Result<Vec<ZkG1Projective>, Error> {
         let n = p.len();
         let n2 = n * 2;
 
         if n2 > self.kzg_settings.fs.max_width {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "n2 must be less than or equal to kzg settings max width",
             ));
         } else if !is_power_of_two(n2) {
-            return Err(String::from("n2 must be a power of two"));
+            return Err(Error::NotPowerOfTwo("n2"));
         }
 
         let mut out = self.data_availability_optimized(p).unwrap();
@@ -67,16 +75,16 @@ impl
         Ok(out)
     }
 
-    fn data_availability_optimized(&self, p: &ZPoly) -> Result<Vec<ZkG1Projective>, String> {
+    fn data_availability_optimized(&self, p: &ZPoly) -> Result<Vec<ZkG1Projective>, Error> {
         let n = p.len();
         let n2 = n * 2;
 
         if n2 > self.kzg_settings.fs.max_width {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "n2 must be less than or equal to kzg settings max width",
             ));
         } else if !is_power_of_two(n2) {
-            return Err(String::from("n2 must be a power of two"));
+            return Err(Error::NotPowerOfTwo("n2"));
         }
 
         let toeplitz_coeffs = toeplitz_coeffs_step(p);
@@ -109,19 +117,28 @@ impl FK20MultiSettings<blsScalar, ZkG1Projective, ZkG2Projective, ZkFFTSettings,
     for ZkFK20MultiSettings
 {
     #[allow(clippy::many_single_char_names)]
-    fn new(ks: &KZGSettings, n: usize, chunk_len: usize) -> Result<Self, String> {
+    fn new(ks: &KZGSettings, n: usize, chunk_len: usize) -> Result<Self, Error> {
         if n > ks.fs.max_width {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "n must be less than or equal to kzg settings max width",
             ));
         } else if !is_power_of_two(n) {
-            return Err(String::from("n must be a power of two"));
+            return Err(Error::NotPowerOfTwo("n"));
         } else if n < 2 {
-            return Err(String::from("n must be greater than or equal to 2"));
+            return Err(Error::InvalidArgument(
+                "n must be greater than or equal to 2",
+            ));
+        } else if ks.basis != SetupBasis::Monomial {
+            return Err(Error::SetupBasisMismatch {
+                expected: SetupBasis::Monomial,
+                actual: ks.basis,
+            });
         } else if chunk_len > n / 2 {
-            return Err(String::from("chunk_len must be greater or equal to n / 2"));
+            return Err(Error::InvalidArgument(
+                "chunk_len must be greater or equal to n / 2",
+            ));
         } else if !is_power_of_two(chunk_len) {
-            return Err(String::from("chunk_len must be a power of two"));
+            return Err(Error::NotPowerOfTwo("chunk_len"));
         }
 
         let n2 = n / 2;
@@ -166,16 +183,16 @@ impl FK20MultiSettings<blsScalar, ZkG1Projective, ZkG2Projective, ZkFFTSettings,
         Ok(out)
     }
 
-    fn data_availability(&self, p: &ZPoly) -> Result<Vec<ZkG1Projective>, String> {
+    fn data_availability(&self, p: &ZPoly) -> Result<Vec<ZkG1Projective>, Error> {
         let n = p.len();
         let n2 = n * 2;
 
         if n2 > self.kzg_settings.fs.max_width {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "n2 must be less than or equal to kzg settings max width",
             ));
         } else if !is_power_of_two(n2) {
-            return Err(String::from("n2 must be a power of two"));
+            return Err(Error::NotPowerOfTwo("n2"));
         }
 
         let mut out = self.data_availability_optimized(p).unwrap();
@@ -184,16 +201,16 @@ impl FK20MultiSettings<blsScalar, ZkG1Projective, ZkG2Projective, ZkFFTSettings,
         Ok(out)
     }
 
-    fn data_availability_optimized(&self, p: &ZPoly) -> Result<Vec<ZkG1Projective>, String> {
+    fn data_availability_optimized(&self, p: &ZPoly) -> Result<Vec<ZkG1Projective>, Error> {
         let n = p.len();
         let n2 = n * 2;
 
         if n2 > self.kzg_settings.fs.max_width {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "n2 must be less than or equal to kzg settings max width",
             ));
         } else if !is_power_of_two(n2) {
-            return Err(String::from("n2 must be a power of two"));
+            return Err(Error::NotPowerOfTwo("n2"));
         }
 
         let n = n2 / 2;
@@ -8,9 +8,12 @@ use crate::kzg_types::{
     pairings_verify, ZkG1Projective as G1, ZkG2Projective as G2, G1_GENERATOR, G2_GENERATOR,
 };
 
-use kzg::{FFTFr, Fr, Poly as OtherPoly, G1 as _G1, G2 as _G2};
-
-use crate::curve::multiscalar_mul::msm_variable_base;
+use kzg::eip_4844::BlobPreset;
+use kzg::trusted_setup::{
+    lagrange_to_monomial, monomial_to_lagrange, verify_lagrange_setup, verify_monomial_setup,
+    SetupBasis,
+};
+use kzg::{Error, FFTFr, Fr, G1LinComb, Poly as OtherPoly, PolyEval, G1 as _G1, G2 as _G2};
 
 #[derive(Debug, Clone, Default)]
 pub struct KZGSettings {
@@ -18,6 +21,9 @@ pub struct KZGSettings {
     pub secret_g1: Vec<G1>,
     pub secret_g2: Vec<G2>,
     pub length: u64,
+    pub blob_preset: BlobPreset,
+    /// Basis of `secret_g1`.
+    pub basis: SetupBasis,
 }
 
 pub(crate) fn new_kzg_settings(
@@ -31,7 +37,64 @@ pub(crate) fn new_kzg_settings(
         secret_g1: _secret_g1,
         secret_g2: _secret_g2,
         length: secrets_len,
+        blob_preset: BlobPreset::default(),
+        basis: SetupBasis::Monomial,
+    }
+}
+
+impl KZGSettings {
+    /// Checks that the points are powers of one secret, as described in [`kzg::trusted_setup`].
+    pub fn verify_trusted_setup(&self) -> Result<(), Error> {
+        match self.basis {
+            SetupBasis::Monomial => verify_monomial_setup(&self.secret_g1, &self.secret_g2),
+            SetupBasis::Lagrange => {
+                verify_lagrange_setup(&self.secret_g1, &self.secret_g2, &self.fs)
+            }
+        }
+    }
+
+    pub(crate) fn check_basis(&self, expected: SetupBasis) -> Result<(), Error> {
+        if self.basis != expected {
+            return Err(Error::SetupBasisMismatch {
+                expected,
+                actual: self.basis,
+            });
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn to_lagrange(ks: &KZGSettings) -> Result<KZGSettings, Error> {
+    if ks.basis == SetupBasis::Lagrange {
+        return Ok(ks.clone());
     }
+    if ks.secret_g1.len() < ks.fs.max_width {
+        return Err(Error::SetupTooSmall);
+    }
+
+    Ok(KZGSettings {
+        fs: ks.fs.clone(),
+        secret_g1: monomial_to_lagrange(&ks.secret_g1[..ks.fs.max_width], &ks.fs)?,
+        secret_g2: ks.secret_g2.clone(),
+        length: ks.fs.max_width as u64,
+        blob_preset: ks.blob_preset,
+        basis: SetupBasis::Lagrange,
+    })
+}
+
+pub(crate) fn to_monomial(ks: &KZGSettings) -> Result<KZGSettings, Error> {
+    if ks.basis == SetupBasis::Monomial {
+        return Ok(ks.clone());
+    }
+
+    Ok(KZGSettings {
+        fs: ks.fs.clone(),
+        secret_g1: lagrange_to_monomial(&ks.secret_g1, &ks.fs)?,
+        secret_g2: ks.secret_g2.clone(),
+        length: ks.secret_g1.len() as u64,
+        blob_preset: ks.blob_preset,
+        basis: SetupBasis::Monomial,
+    })
 }
 
 pub fn generate_trusted_setup(n: usize, secret: [u8; 32usize]) -> (Vec<G1>, Vec<G2>) {
@@ -50,17 +113,27 @@ pub fn generate_trusted_setup(n: usize, secret: [u8; 32usize]) -> (Vec<G1>, Vec<
     (s1, s2)
 }
 
-pub(crate) fn commit_to_poly(p: &Poly, ks: &KZGSettings) -> Result<G1, String> {
+pub(crate) fn commit_to_poly(p: &Poly, ks: &KZGSettings) -> Result<G1, Error> {
+    ks.check_basis(SetupBasis::Monomial)?;
     if p.coeffs.len() > ks.secret_g1.len() {
-        Err(String::from("Poly given is too long"))
+        Err(Error::SetupTooSmall)
     } else if p.is_zero() {
         Ok(G1::identity())
     } else {
-        Ok(msm_variable_base(&ks.secret_g1, &p.coeffs))
+        Ok(G1::g1_lincomb(&ks.secret_g1, &p.coeffs, p.coeffs.len()))
+    }
+}
+
+pub(crate) fn commit_to_poly_eval(p: &PolyEval<Scalar>, ks: &KZGSettings) -> Result<G1, Error> {
+    ks.check_basis(SetupBasis::Lagrange)?;
+    if p.evals().len() > ks.secret_g1.len() {
+        Err(Error::SetupTooSmall)
+    } else {
+        Ok(G1::g1_lincomb(&ks.secret_g1, p.evals(), p.evals().len()))
     }
 }
 
-pub(crate) fn compute_proof_single(p: &Poly, x: &Scalar, ks: &KZGSettings) -> Result<G1, String> {
+pub(crate) fn compute_proof_single(p: &Poly, x: &Scalar, ks: &KZGSettings) -> Result<G1, Error> {
     compute_proof_multi(p, x, 1, ks)
 }
 
@@ -70,7 +143,7 @@ pub(crate) fn check_proof_single(
     x: &Scalar,
     value: &Scalar,
     ks: &KZGSettings,
-) -> Result<bool, String> {
+) -> Result<bool, Error> {
     let x_g2: G2 = G2_GENERATOR.mul(x);
     let s_minus_x: G2 = ks.secret_g2[1].sub(&x_g2);
     let y_g1 = G1_GENERATOR.mul(value);
@@ -93,9 +166,9 @@ pub(crate) fn compute_proof_multi(
     x: &Scalar,
     n: usize,
     ks: &KZGSettings,
-) -> Result<G1, String> {
+) -> Result<G1, Error> {
     if !is_power_of_two(n) {
-        return Err(String::from("n has to be power of two"));
+        return Err(Error::NotPowerOfTwo("n"));
     }
 
     let mut p2: Poly = Poly { coeffs: Vec::new() };
@@ -111,7 +184,7 @@ pub(crate) fn compute_proof_multi(
 
     let mut p = p.clone();
     let q = p.div(&p2).unwrap();
-    Ok(commit_to_poly(&q, ks).unwrap())
+    commit_to_poly(&q, ks)
 }
 
 pub(crate) fn check_proof_multi(
@@ -121,9 +194,11 @@ pub(crate) fn check_proof_multi(
     values: &[Scalar],
     n: usize,
     ks: &KZGSettings,
-) -> Result<bool, String> {
+) -> Result<bool, Error> {
     if !is_power_of_two(n) {
-        return Err(String::from("n has to be power of two"));
+        return Err(Error::NotPowerOfTwo("n"));
+    } else if n >= ks.secret_g2.len() {
+        return Err(Error::SetupTooSmall);
     }
 
     let mut poly = Poly {
@@ -141,7 +216,7 @@ pub(crate) fn check_proof_multi(
     let xn2 = G2_GENERATOR.mul(&x_power);
     let xn_minus_yn = ks.secret_g2[n].sub(&xn2);
 
-    let g1 = commit_to_poly(&poly, ks).unwrap();
+    let g1 = commit_to_poly(&poly, ks)?;
     let commit_minus_interp = com.sub(&g1);
 
     Ok(pairings_verify(
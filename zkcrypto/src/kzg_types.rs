@@ -1,10 +1,13 @@
 // pub use super::{ZPoly, BlsScalar};
-use kzg::{Fr, G1Mul, G2Mul, KZGSettings, G1, G2};
+use kzg::trusted_setup::SetupBasis;
+use kzg::{
+    Error, Fr, G1LinComb, G1Mul, G2LinComb, G2Mul, KZGSettings, PairingVerify, PolyEval, G1, G2,
+};
 // use ff::{Field, PrimeField};
 
 // use std::ptr;
 
-use kzg::eip_4844::{BYTES_PER_G1, BYTES_PER_G2};
+use kzg::eip_4844::{BlobPreset, BYTES_PER_G1, BYTES_PER_G2};
 use std::ops::{Add, Neg};
 // use std::convert::TryInto;
 
@@ -34,14 +37,17 @@ pub use crate::curve::pairings::{multi_miller_loop, G2Prepared, MillerLoopResult
 use crate::zkfr::blsScalar;
 
 use crate::curve::g2::G2Affine;
+use crate::curve::multiscalar_mul::msm_variable_base;
 use crate::fftsettings::ZkFFTSettings;
 use crate::poly::ZPoly;
 use kzg::FFTSettings;
 
 use crate::kzg_proofs::{
     check_proof_multi as check_multi, check_proof_single as check_single,
-    commit_to_poly as poly_commit, compute_proof_multi as open_multi,
-    compute_proof_single as open_single, new_kzg_settings, KZGSettings as LKZGSettings,
+    commit_to_poly as poly_commit, commit_to_poly_eval as poly_eval_commit,
+    compute_proof_multi as open_multi, compute_proof_single as open_single, new_kzg_settings,
+    to_lagrange as settings_to_lagrange, to_monomial as settings_to_monomial,
+    KZGSettings as LKZGSettings,
 };
 
 pub const G1_GENERATOR: ZkG1Projective = ZkG1Projective {
@@ -218,6 +224,13 @@ pub const G2_NEGATIVE_GENERATOR: ZkG2Projective = ZkG2Projective {
     },
 };
 
+#[cfg(feature = "serde")]
+kzg::impl_serde_bytes!(
+    ZkG1Projective,
+    G1::to_bytes,
+    kzg::serde_utils::g1_from_canonical_bytes::<ZkG1Projective>
+);
+
 impl G1 for ZkG1Projective {
     fn identity() -> Self {
         G1_IDENTITY
@@ -236,24 +249,21 @@ impl G1 for ZkG1Projective {
         result.mul(&blsScalar::rand())
     }
 
-    #[allow(clippy::bind_instead_of_map)]
-    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         bytes
             .try_into()
-            .map_err(|_| {
-                format!(
-                    "Invalid byte length. Expected {}, got {}",
-                    BYTES_PER_G1,
-                    bytes.len()
-                )
+            .map_err(|_| Error::InvalidLength {
+                expected: BYTES_PER_G1,
+                actual: bytes.len(),
             })
             .and_then(|bytes: &[u8; BYTES_PER_G1]| {
-                let affine: ZkG1Affine = ZkG1Affine::from_compressed(bytes).unwrap();
-                Ok(ZkG1Projective::from(affine))
+                Option::<ZkG1Affine>::from(ZkG1Affine::from_compressed(bytes))
+                    .map(ZkG1Projective::from)
+                    .ok_or(Error::InvalidPoint)
             })
     }
 
-    fn from_hex(hex: &str) -> Result<Self, String> {
+    fn from_hex(hex: &str) -> Result<Self, Error> {
         let bytes = hex::decode(&hex[2..]).unwrap();
         G1::from_bytes(&bytes)
     }
@@ -302,6 +312,20 @@ impl G1Mul<blsScalar> for ZkG1Projective {
     }
 }
 
+impl G1LinComb<blsScalar> for ZkG1Projective {
+    fn g1_lincomb(points: &[Self], scalars: &[blsScalar], len: usize) -> Self {
+        msm_variable_base(&points[..len], &scalars[..len])
+    }
+}
+
+// `from_compressed` already rejects points outside the subgroup
+#[cfg(feature = "serde")]
+kzg::impl_serde_bytes!(
+    ZkG2Projective,
+    G2::to_bytes,
+    <ZkG2Projective as G2>::from_bytes
+);
+
 impl G2 for ZkG2Projective {
     fn generator() -> Self {
         G2_GENERATOR
@@ -311,20 +335,17 @@ impl G2 for ZkG2Projective {
         G2_NEGATIVE_GENERATOR
     }
 
-    #[allow(clippy::bind_instead_of_map)]
-    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         bytes
             .try_into()
-            .map_err(|_| {
-                format!(
-                    "Invalid byte length. Expected {}, got {}",
-                    BYTES_PER_G2,
-                    bytes.len()
-                )
+            .map_err(|_| Error::InvalidLength {
+                expected: BYTES_PER_G2,
+                actual: bytes.len(),
             })
             .and_then(|bytes: &[u8; BYTES_PER_G2]| {
-                let affine: G2Affine = G2Affine::from_compressed(bytes).unwrap();
-                Ok(ZkG2Projective::from(affine))
+                Option::<G2Affine>::from(G2Affine::from_compressed(bytes))
+                    .map(ZkG2Projective::from)
+                    .ok_or(Error::InvalidPoint)
             })
     }
 
@@ -360,6 +381,12 @@ impl G2Mul<blsScalar> for ZkG2Projective {
     }
 }
 
+impl G2LinComb<blsScalar> for ZkG2Projective {
+    fn g2_lincomb(points: &[Self], scalars: &[blsScalar], len: usize) -> Self {
+        msm_variable_base(&points[..len], &scalars[..len])
+    }
+}
+
 pub fn pairings_verify(
     a1: &ZkG1Projective,
     a2: &ZkG2Projective,
@@ -388,26 +415,69 @@ pub fn pairings_verify(
     ZkFp12::eq(&ZkFp12::one(), &new_point.0)
 }
 
+pub fn pairings_verify_multi(pairs: &[(ZkG1Projective, ZkG2Projective)]) -> bool {
+    let prepared: Vec<(ZkG1Affine, G2Prepared)> = pairs
+        .iter()
+        .map(|(p, q)| (ZkG1Affine::from(p), G2Prepared::from(ZkG2Affine::from(q))))
+        .collect();
+    let terms: Vec<(&ZkG1Affine, &G2Prepared)> = prepared.iter().map(|(p, q)| (p, q)).collect();
+
+    let new_point = MillerLoopResult::final_exponentiation(&multi_miller_loop(&terms));
+
+    ZkFp12::eq(&ZkFp12::one(), &new_point.0)
+}
+
+impl PairingVerify<ZkG1Projective, ZkG2Projective> for ZkG1Projective {
+    fn verify(
+        a1: &ZkG1Projective,
+        a2: &ZkG2Projective,
+        b1: &ZkG1Projective,
+        b2: &ZkG2Projective,
+    ) -> bool {
+        pairings_verify(a1, a2, b1, b2)
+    }
+
+    fn verify_multi(pairs: &[(ZkG1Projective, ZkG2Projective)]) -> bool {
+        pairings_verify_multi(pairs)
+    }
+}
+
 impl KZGSettings<blsScalar, ZkG1Projective, ZkG2Projective, ZkFFTSettings, ZPoly> for LKZGSettings {
     fn new(
         secret_g1: &[ZkG1Projective],
         secret_g2: &[ZkG2Projective],
-        length: usize,
+        length_g1: usize,
+        length_g2: usize,
         fs: &ZkFFTSettings,
-    ) -> Result<LKZGSettings, String> {
+    ) -> Result<LKZGSettings, Error> {
+        if secret_g1.len() < length_g1 || secret_g2.len() < length_g2 {
+            return Err(Error::SetupTooSmall);
+        } else if length_g1 < fs.max_width {
+            return Err(Error::InvalidArgument(
+                "length_g1 must be equal to or greater than number of fft_settings roots",
+            ));
+        } else if length_g2 < 2 {
+            return Err(Error::InvalidArgument("length_g2 must be at least 2"));
+        }
+
         Ok(new_kzg_settings(
-            secret_g1.to_vec(),
-            secret_g2.to_vec(),
-            length as u64,
+            secret_g1[..length_g1].to_vec(),
+            secret_g2[..length_g2].to_vec(),
+            length_g1 as u64,
             fs,
         ))
     }
 
-    fn commit_to_poly(&self, p: &ZPoly) -> Result<ZkG1Projective, String> {
+    fn commit_to_poly(&self, p: &ZPoly) -> Result<ZkG1Projective, Error> {
+        self.check_basis(SetupBasis::Monomial)?;
         Ok(poly_commit(p, self).unwrap())
     }
 
-    fn compute_proof_single(&self, p: &ZPoly, x: &blsScalar) -> Result<ZkG1Projective, String> {
+    fn commit_to_poly_eval(&self, p: &PolyEval<blsScalar>) -> Result<ZkG1Projective, Error> {
+        poly_eval_commit(p, self)
+    }
+
+    fn compute_proof_single(&self, p: &ZPoly, x: &blsScalar) -> Result<ZkG1Projective, Error> {
         open_single(p, x, self)
     }
 
@@ -417,7 +487,7 @@ impl KZGSettings<blsScalar, ZkG1Projective, ZkG2Projective, ZkFFTSettings, ZPoly
         proof: &ZkG1Projective,
         x: &blsScalar,
         value: &blsScalar,
-    ) -> Result<bool, String> {
+    ) -> Result<bool, Error> {
         check_single(com, proof, x, value, self)
     }
 
@@ -426,7 +496,7 @@ impl KZGSettings<blsScalar, ZkG1Projective, ZkG2Projective, ZkFFTSettings, ZPoly
         p: &ZPoly,
         x: &blsScalar,
         n: usize,
-    ) -> Result<ZkG1Projective, String> {
+    ) -> Result<ZkG1Projective, Error> {
         open_multi(p, x, n, self)
     }
 
@@ -437,7 +507,7 @@ impl KZGSettings<blsScalar, ZkG1Projective, ZkG2Projective, ZkFFTSettings, ZPoly
         x: &blsScalar,
         values: &[blsScalar],
         n: usize,
-    ) -> Result<bool, String> {
+    ) -> Result<bool, Error> {
         check_multi(com, proof, x, values, n, self)
     }
 
@@ -448,4 +518,32 @@ impl KZGSettings<blsScalar, ZkG1Projective, ZkG2Projective, ZkFFTSettings, ZPoly
     fn get_roots_of_unity_at(&self, i: usize) -> blsScalar {
         self.fs.get_roots_of_unity_at(i)
     }
+
+    fn get_fft_settings(&self) -> &ZkFFTSettings {
+        &self.fs
+    }
+
+    fn get_g1_secret(&self) -> &[ZkG1Projective] {
+        &self.secret_g1
+    }
+
+    fn get_g2_secret(&self) -> &[ZkG2Projective] {
+        &self.secret_g2
+    }
+
+    fn get_blob_preset(&self) -> &BlobPreset {
+        &self.blob_preset
+    }
+
+    fn get_basis(&self) -> SetupBasis {
+        self.basis
+    }
+
+    fn to_lagrange(&self) -> Result<Self, Error> {
+        settings_to_lagrange(self)
+    }
+
+    fn to_monomial(&self) -> Result<Self, Error> {
+        settings_to_monomial(self)
+    }
 }
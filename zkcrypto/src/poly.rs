@@ -6,6 +6,7 @@ pub use kzg::{FFTFr, FFTSettings, Fr, Poly};
 use crate::consts::*;
 use crate::fftsettings::ZkFFTSettings;
 use crate::utils::*;
+use kzg::Error;
 // use crate::fft_fr::*;
 // use std::convert::TryInto;
 
@@ -93,7 +94,7 @@ impl ZPoly {
 }
 
 impl Poly<blsScalar> for ZPoly {
-    fn new(size: usize) -> Result<Self, String> {
+    fn new(size: usize) -> Result<Self, Error> {
         Ok(Self {
             coeffs: vec![blsScalar::default(); size],
         })
@@ -161,15 +162,15 @@ impl Poly<blsScalar> for ZPoly {
         }
     }
 
-    fn inverse(&mut self, new_len: usize) -> Result<Self, String> {
+    fn inverse(&mut self, new_len: usize) -> Result<Self, Error> {
         // +
 
         if self.coeffs.is_empty()
         /*.len() == 0*/
         {
-            return Err(String::from("Can't inverse a zero-length poly"));
+            return Err(Error::InvalidArgument("Can't inverse a zero-length poly"));
         } else if self.coeffs[0].is_zero() {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "First coefficient of polynomial mustn't be zero",
             ));
         }
@@ -212,40 +213,40 @@ impl Poly<blsScalar> for ZPoly {
         }
 
         if d + 1 != new_len {
-            return Err(String::from("d+1 is bad"));
+            return Err(Error::Internal("d+1 is bad"));
         }
 
         Ok(ret)
     }
 
-    fn div(&mut self, x: &Self) -> Result<Self, String> {
+    fn div(&mut self, x: &Self) -> Result<Self, Error> {
         // let ret = new_poly_div(&self, &x);
         // return ret;
         new_poly_div(self, x)
     }
 
-    fn long_div(&mut self, x: &Self) -> Result<Self, String> {
+    fn long_div(&mut self, x: &Self) -> Result<Self, Error> {
         poly_long_div(self, x)
     }
 
-    fn fast_div(&mut self, x: &Self) -> Result<Self, String> {
+    fn fast_div(&mut self, x: &Self) -> Result<Self, Error> {
         poly_fast_div(self, x)
     }
 
-    fn mul_direct(&mut self, x: &Self, len: usize) -> Result<Self, String> {
+    fn mul_direct(&mut self, x: &Self, len: usize) -> Result<Self, Error> {
         poly_mul_direct(self, x, len)
     }
 }
 
-pub fn poly_long_div(dividend: &ZPoly, divisor: &ZPoly) -> Result<ZPoly, String> {
+pub fn poly_long_div(dividend: &ZPoly, divisor: &ZPoly) -> Result<ZPoly, Error> {
     // +
 
     if divisor.coeffs.is_empty() {
         //.len() == 0
-        return Err(String::from("Can't divide by zero"));
+        return Err(Error::DivisionByZero);
     }
     // else if divisor.coeffs[divisor.coeffs.len() - 1].is_zero() {
-    // return Err(String::from("Highest coefficient must be non-zero"));
+    // return Err(Error::InvalidArgument("Highest coefficient must be non-zero"));
     // }
 
     let mut a_pos = dividend.coeffs.len() - 1;
@@ -290,15 +291,15 @@ pub fn poly_long_div(dividend: &ZPoly, divisor: &ZPoly) -> Result<ZPoly, String>
     Ok(out)
 }
 
-pub fn poly_fast_div(dividend: &ZPoly, divisor: &ZPoly) -> Result<ZPoly, String> {
+pub fn poly_fast_div(dividend: &ZPoly, divisor: &ZPoly) -> Result<ZPoly, Error> {
     // +
 
     if divisor.coeffs.is_empty() {
         // .len() == 0
-        return Err(String::from("Cant divide by zero"));
+        return Err(Error::DivisionByZero);
     }
     // else if divisor.coeffs[divisor.coeffs.len() - 1].is_zero() {
-    // return Err(String::from("Highest coefficient must be non-zero"));
+    // return Err(Error::InvalidArgument("Highest coefficient must be non-zero"));
     // }
 
     let m: usize = dividend.coeffs.len() - 1;
@@ -329,7 +330,7 @@ pub fn poly_fast_div(dividend: &ZPoly, divisor: &ZPoly) -> Result<ZPoly, String>
     Ok(out)
 }
 
-pub fn poly_flip(out: &ZPoly) -> Result<ZPoly, String> {
+pub fn poly_flip(out: &ZPoly) -> Result<ZPoly, Error> {
     // +
     let mut ret = ZPoly {
         coeffs: vec![<blsScalar as Default>::default(); out.len()],
@@ -342,7 +343,7 @@ pub fn poly_flip(out: &ZPoly) -> Result<ZPoly, String> {
     Ok(ret)
 }
 
-pub fn new_poly_div(dividend_: &ZPoly, divisor_: &ZPoly) -> Result<ZPoly, String> {
+pub fn new_poly_div(dividend_: &ZPoly, divisor_: &ZPoly) -> Result<ZPoly, Error> {
     // +
 
     let result = poly_norm(dividend_);
@@ -365,7 +366,7 @@ pub fn new_poly_div(dividend_: &ZPoly, divisor_: &ZPoly) -> Result<ZPoly, String
     }
 }
 
-pub fn poly_norm(p: &ZPoly) -> Result<ZPoly, String> {
+pub fn poly_norm(p: &ZPoly) -> Result<ZPoly, Error> {
     // +
     let mut ret = p.clone();
     let mut temp_len: usize = ret.coeffs.len();
@@ -380,7 +381,7 @@ pub fn poly_norm(p: &ZPoly) -> Result<ZPoly, String> {
     Ok(ret)
 }
 
-pub fn poly_quotient_length(dividend: &ZPoly, divisor: &ZPoly) -> Result<usize, String> {
+pub fn poly_quotient_length(dividend: &ZPoly, divisor: &ZPoly) -> Result<usize, Error> {
     // +
     if dividend.coeffs.len() >= divisor.coeffs.len() {
         Ok(dividend.coeffs.len() - divisor.coeffs.len() + 1)
@@ -421,11 +422,11 @@ pub fn pad_(input: &ZPoly, length: usize) -> ZPoly {
 
     out
 }
-pub fn pad_poly(input: &ZPoly, length: usize) -> Result<Vec<blsScalar>, String> {
+pub fn pad_poly(input: &ZPoly, length: usize) -> Result<Vec<blsScalar>, Error> {
     // +
 
     if length < input.coeffs.len() {
-        return Err(String::from(
+        return Err(Error::InvalidArgument(
             "new length must be longer or equal to poly length",
         ));
     }
@@ -441,7 +442,7 @@ pub fn pad_poly(input: &ZPoly, length: usize) -> Result<Vec<blsScalar>, String>
     Ok(out)
 }
 
-pub fn poly_mul_fft(out: usize, a: &ZPoly, b: &ZPoly) -> Result<ZPoly, String> {
+pub fn poly_mul_fft(out: usize, a: &ZPoly, b: &ZPoly) -> Result<ZPoly, Error> {
     // +
 
     let length = next_power_of_two(a.len() + b.len() - 1);
@@ -518,7 +519,7 @@ pub fn poly_mul_fft(out: usize, a: &ZPoly, b: &ZPoly) -> Result<ZPoly, String> {
     }
 }
 
-pub fn poly_mul_direct(a: &ZPoly, b: &ZPoly, output_len: usize) -> Result<ZPoly, String> {
+pub fn poly_mul_direct(a: &ZPoly, b: &ZPoly, output_len: usize) -> Result<ZPoly, Error> {
     // +
 
     if a.len() == 0 || b.len() == 0 {
@@ -545,7 +546,7 @@ pub fn poly_mul_direct(a: &ZPoly, b: &ZPoly, output_len: usize) -> Result<ZPoly,
     Ok(output)
 }
 
-pub fn poly_mul(a: &ZPoly, b: &ZPoly, output_len: usize) -> Result<ZPoly, String> {
+pub fn poly_mul(a: &ZPoly, b: &ZPoly, output_len: usize) -> Result<ZPoly, Error> {
     // +
     if (a.len() < 64) || (b.len() < 64) || (output_len < 128) {
         poly_mul_direct(a, b, output_len)
@@ -2,13 +2,13 @@ use crate::fftsettings::ZkFFTSettings;
 use crate::poly::{pad, ZPoly};
 use crate::utils::is_power_of_two;
 use crate::zkfr::blsScalar;
-use kzg::{FFTFr, ZeroPoly};
+use kzg::{Error, FFTFr, ZeroPoly};
 use std::cmp::min;
 use std::ops::Neg;
 
-// pub(crate) fn pad_poly(poly: &ZPoly, new_length: usize) -> Result<Vec<blsScalar>, String> {
+// pub(crate) fn pad_poly(poly: &ZPoly, new_length: usize) -> Result<Vec<blsScalar>, Error> {
 // if new_length < poly.coeffs.len() {
-// return Err(String::from("Wanted length less than current"));
+// return Err(Error::InvalidArgument("Wanted length less than current"));
 // }
 
 // let mut out = poly.coeffs.to_vec();
@@ -22,10 +22,10 @@ use std::ops::Neg;
 
 impl ZeroPoly<blsScalar, ZPoly> for ZkFFTSettings {
     #[allow(clippy::needless_range_loop)]
-    fn do_zero_poly_mul_partial(&self, indices: &[usize], stride: usize) -> Result<ZPoly, String> {
+    fn do_zero_poly_mul_partial(&self, indices: &[usize], stride: usize) -> Result<ZPoly, Error> {
         if indices.is_empty() {
             //  == 0
-            return Err(String::from("index array length mustnt be zero"));
+            return Err(Error::InvalidArgument("index array length mustnt be zero"));
         }
         let mut poly = ZPoly {
             coeffs: vec![blsScalar::one(); indices.len() + 1],
@@ -51,7 +51,7 @@ impl ZeroPoly<blsScalar, ZPoly> for ZkFFTSettings {
         Ok(poly)
     }
     #[allow(clippy::needless_range_loop)]
-    fn reduce_partials(&self, length: usize, partials: &[ZPoly]) -> Result<ZPoly, String> {
+    fn reduce_partials(&self, length: usize, partials: &[ZPoly]) -> Result<ZPoly, Error> {
         let mut out_degree: usize = 0;
         for partial in partials {
             // 0..partials.len()
@@ -59,11 +59,11 @@ impl ZeroPoly<blsScalar, ZPoly> for ZkFFTSettings {
         }
 
         if !is_power_of_two(length) {
-            return Err(String::from("Expected length to be power of two"));
+            return Err(Error::NotPowerOfTwo("length"));
         }
 
         if out_degree + 1 > length {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "Expected out_degree to be within possible polynomial size",
             ));
         }
@@ -93,7 +93,7 @@ impl ZeroPoly<blsScalar, ZPoly> for ZkFFTSettings {
         &self,
         length: usize,
         missing_indices: &[usize],
-    ) -> Result<(Vec<blsScalar>, ZPoly), String> {
+    ) -> Result<(Vec<blsScalar>, ZPoly), Error> {
         let zero_eval: Vec<blsScalar>;
         let mut zero_poly: ZPoly;
 
@@ -105,13 +105,15 @@ impl ZeroPoly<blsScalar, ZPoly> for ZkFFTSettings {
         }
 
         if missing_indices.len() >= length {
-            return Err(String::from("Missing indexes are greater than domain size"));
+            return Err(Error::InvalidArgument(
+                "Missing indexes are greater than domain size",
+            ));
         } else if length > self.max_width as usize {
-            return Err(String::from(
+            return Err(Error::InvalidArgument(
                 "Domain size is greater than fft_settings.max_width",
             ));
         } else if !length.is_power_of_two() {
-            return Err(String::from("Domain size must be a power of 2"));
+            return Err(Error::NotPowerOfTwo("domain size"));
         }
 
         let degree_of_partial = 256;
@@ -1,14 +1,23 @@
 // Adds implementation for blsScalar (Fr)
 
 pub use super::{BlsScalar, ZPoly};
+use kzg::Error;
 use kzg::Fr;
 
 use crate::curve::scalar::{sbb, Scalar, MODULUS, R2};
+use ff::BatchInverter;
 use kzg::eip_4844::BYTES_PER_FIELD_ELEMENT;
 use std::convert::TryInto;
 
 pub use crate::curve::scalar::Scalar as blsScalar;
 
+#[cfg(feature = "serde")]
+kzg::impl_serde_bytes!(
+    blsScalar,
+    Fr::to_bytes,
+    kzg::serde_utils::fr_from_canonical_bytes::<blsScalar>
+);
+
 impl Fr for blsScalar {
     fn null() -> Self {
         blsScalar::null()
@@ -27,15 +36,12 @@ impl Fr for blsScalar {
     }
 
     #[allow(clippy::bind_instead_of_map)]
-    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         bytes
             .try_into()
-            .map_err(|_| {
-                format!(
-                    "Invalid byte length. Expected {}, got {}",
-                    BYTES_PER_FIELD_ELEMENT,
-                    bytes.len()
-                )
+            .map_err(|_| Error::InvalidLength {
+                expected: BYTES_PER_FIELD_ELEMENT,
+                actual: bytes.len(),
             })
             .and_then(|bytes: &[u8; BYTES_PER_FIELD_ELEMENT]| {
                 let mut tmp = Scalar([0, 0, 0, 0]);
@@ -58,7 +64,11 @@ impl Fr for blsScalar {
             })
     }
 
-    fn from_hex(hex: &str) -> Result<Self, String> {
+    fn from_bytes_unchecked(bytes: &[u8]) -> Result<Self, Error> {
+        Fr::from_bytes(bytes)
+    }
+
+    fn from_hex(hex: &str) -> Result<Self, Error> {
         let bytes = hex::decode(&hex[2..]).unwrap();
         Fr::from_bytes(&bytes)
     }
@@ -143,7 +153,7 @@ impl Fr for blsScalar {
         out
     }
 
-    fn div(&self, b: &Self) -> Result<Self, String> {
+    fn div(&self, b: &Self) -> Result<Self, Error> {
         if <blsScalar as Fr>::is_zero(b) {
             return Ok(blsScalar::zero());
         }
@@ -155,9 +165,14 @@ impl Fr for blsScalar {
     fn equals(&self, other: &Self) -> bool {
         self.eq(other)
     }
+
+    fn batch_inverse(values: &mut [Self]) {
+        let mut scratch = vec![blsScalar::zero(); values.len()];
+        BatchInverter::invert_with_external_scratch(values, &mut scratch);
+    }
 }
 
-pub fn fr_div(a: &blsScalar, b: &blsScalar) -> Result<blsScalar, String> {
+pub fn fr_div(a: &blsScalar, b: &blsScalar) -> Result<blsScalar, Error> {
     if b.is_zero() {
         return Ok(blsScalar::zero());
     }
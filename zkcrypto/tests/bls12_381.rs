@@ -60,6 +60,16 @@ mod tests {
         fr_uint64s_roundtrip::<blsScalar>();
     }
 
+    #[test]
+    pub fn fr_batch_inverse_works_() {
+        fr_batch_inverse_works::<blsScalar>();
+    }
+
+    #[test]
+    pub fn fr_batch_mul_and_inner_product_work_() {
+        fr_batch_mul_and_inner_product_work::<blsScalar>();
+    }
+
     #[test]
     pub fn p1_mul_works_() {
         p1_mul_works::<blsScalar, ZkG1Projective>();
@@ -106,8 +116,23 @@ mod tests {
     // g1_random_linear_combination::<blsScalar, ZkG1Projective>(&g1_linear_combination);
     // }
 
+    #[test]
+    pub fn g1_lincomb_matches_naive_() {
+        g1_lincomb_matches_naive::<blsScalar, ZkG1Projective>();
+    }
+
+    #[test]
+    pub fn g2_lincomb_matches_naive_() {
+        g2_lincomb_matches_naive::<blsScalar, ZkG2Projective>();
+    }
+
     #[test]
     pub fn pairings_work_() {
         pairings_work::<blsScalar, ZkG1Projective, ZkG2Projective>(&pairings_verify);
     }
+
+    #[test]
+    pub fn pairings_verify_multi_works_() {
+        pairings_verify_multi_works::<blsScalar, ZkG1Projective, ZkG2Projective>();
+    }
 }
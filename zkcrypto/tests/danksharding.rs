@@ -0,0 +1,33 @@
+#[cfg(test)]
+mod tests {
+    use kzg_bench::tests::danksharding::*;
+    use rust_kzg_zkcrypto::fftsettings::ZkFFTSettings;
+    use rust_kzg_zkcrypto::fk20::ZkFK20MultiSettings;
+    use rust_kzg_zkcrypto::kzg_proofs::{generate_trusted_setup, KZGSettings};
+    use rust_kzg_zkcrypto::kzg_types::{ZkG1Projective, ZkG2Projective};
+    use rust_kzg_zkcrypto::poly::ZPoly;
+    use rust_kzg_zkcrypto::zkfr::blsScalar;
+
+    #[test]
+    fn test_extend_matrix() {
+        extend_matrix_test::<blsScalar, ZkFFTSettings>();
+    }
+
+    #[test]
+    fn test_sample_proofs() {
+        sample_proofs_test::<
+            blsScalar,
+            ZkG1Projective,
+            ZkG2Projective,
+            ZPoly,
+            ZkFFTSettings,
+            KZGSettings,
+            ZkFK20MultiSettings,
+        >(&generate_trusted_setup);
+    }
+
+    #[test]
+    fn test_recover_matrix() {
+        recover_matrix_test::<blsScalar, ZPoly, ZkFFTSettings>();
+    }
+}
@@ -3,17 +3,26 @@ mod tests {
     #[cfg(not(feature = "minimal-spec"))]
     use kzg_bench::tests::eip_4844::compute_and_verify_kzg_proof_within_domain_test;
     use kzg_bench::tests::eip_4844::{
-        blob_to_kzg_commitment_test, bytes_to_bls_field_test,
-        compute_and_verify_blob_kzg_proof_fails_with_incorrect_proof_test,
+        blob_to_kzg_commitment_test, bytes_to_blob_rejects_invalid_length_test,
+        bytes_to_bls_field_test, compute_and_verify_blob_kzg_proof_fails_with_incorrect_proof_test,
         compute_and_verify_blob_kzg_proof_test,
+        compute_and_verify_blob_kzg_proof_with_runtime_preset_test,
         compute_and_verify_kzg_proof_fails_with_incorrect_proof_test,
         compute_and_verify_kzg_proof_round_trip_test, compute_kzg_proof_test, compute_powers_test,
+        decode_payload_rejects_malformed_blobs_test, encode_payload_round_trip_test,
+        load_trusted_setup_json_reports_line_test, load_trusted_setup_json_test,
+        load_trusted_setup_lagrange_test, load_trusted_setup_string_reports_line_test,
+        update_kzg_commitment_and_proof_test, update_kzg_proof_cost_test,
         verify_kzg_proof_batch_fails_with_incorrect_proof_test, verify_kzg_proof_batch_test,
+        verify_trusted_setup_test,
     };
     use rust_kzg_zkcrypto::eip_4844::{
-        blob_to_kzg_commitment, blob_to_polynomial, bytes_to_blob, compute_blob_kzg_proof,
-        compute_kzg_proof, compute_powers, evaluate_polynomial_in_evaluation_form,
-        load_trusted_setup, verify_blob_kzg_proof, verify_blob_kzg_proof_batch, verify_kzg_proof,
+        blob_to_kzg_commitment, blob_to_polynomial, bytes_to_blob, bytes_to_blob_with_preset,
+        compute_blob_kzg_proof, compute_kzg_proof, compute_powers,
+        evaluate_polynomial_in_evaluation_form, load_trusted_setup, load_trusted_setup_json,
+        load_trusted_setup_lagrange_rust, load_trusted_setup_rust, precompute_proof_update_terms,
+        update_kzg_commitment, update_kzg_proof, verify_blob_kzg_proof,
+        verify_blob_kzg_proof_batch, verify_kzg_proof,
     };
     use rust_kzg_zkcrypto::fftsettings::ZkFFTSettings;
     use rust_kzg_zkcrypto::kzg_proofs::KZGSettings;
@@ -27,6 +36,21 @@ mod tests {
         bytes_to_bls_field_test::<blsScalar>();
     }
 
+    #[test]
+    pub fn encode_payload_round_trip_test_() {
+        encode_payload_round_trip_test::<blsScalar>();
+    }
+
+    #[test]
+    pub fn decode_payload_rejects_malformed_blobs_test_() {
+        decode_payload_rejects_malformed_blobs_test::<blsScalar>();
+    }
+
+    #[test]
+    pub fn bytes_to_blob_rejects_invalid_length_test_() {
+        bytes_to_blob_rejects_invalid_length_test::<blsScalar>(&bytes_to_blob);
+    }
+
     #[test]
     pub fn compute_powers_test_() {
         compute_powers_test::<blsScalar>(&compute_powers);
@@ -158,6 +182,108 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn compute_and_verify_blob_kzg_proof_with_runtime_preset_test_() {
+        compute_and_verify_blob_kzg_proof_with_runtime_preset_test::<
+            blsScalar,
+            ZkG1Projective,
+            ZkG2Projective,
+            KzgPoly,
+            ZkFFTSettings,
+            KZGSettings,
+        >(
+            &load_trusted_setup,
+            &blob_to_kzg_commitment,
+            &bytes_to_blob_with_preset,
+            &compute_blob_kzg_proof,
+            &verify_blob_kzg_proof,
+        );
+    }
+
+    #[test]
+    pub fn load_trusted_setup_string_reports_line_test_() {
+        load_trusted_setup_string_reports_line_test();
+    }
+
+    #[test]
+    pub fn load_trusted_setup_json_reports_line_test_() {
+        load_trusted_setup_json_reports_line_test();
+    }
+
+    #[test]
+    pub fn load_trusted_setup_json_test_() {
+        load_trusted_setup_json_test::<
+            blsScalar,
+            ZkG1Projective,
+            ZkG2Projective,
+            KzgPoly,
+            ZkFFTSettings,
+            KZGSettings,
+        >(&load_trusted_setup, &load_trusted_setup_json);
+    }
+
+    #[test]
+    pub fn verify_trusted_setup_test_() {
+        verify_trusted_setup_test::<
+            blsScalar,
+            ZkG1Projective,
+            ZkG2Projective,
+            KzgPoly,
+            ZkFFTSettings,
+            KZGSettings,
+        >(&load_trusted_setup_rust, &KZGSettings::verify_trusted_setup);
+    }
+
+    #[test]
+    pub fn load_trusted_setup_lagrange_test_() {
+        load_trusted_setup_lagrange_test::<
+            blsScalar,
+            ZkG1Projective,
+            ZkG2Projective,
+            KzgPoly,
+            ZkFFTSettings,
+            KZGSettings,
+        >(&load_trusted_setup_rust, &load_trusted_setup_lagrange_rust);
+    }
+
+    #[test]
+    pub fn update_kzg_commitment_and_proof_test_() {
+        update_kzg_commitment_and_proof_test::<
+            blsScalar,
+            ZkG1Projective,
+            ZkG2Projective,
+            KzgPoly,
+            ZkFFTSettings,
+            KZGSettings,
+        >(
+            &load_trusted_setup,
+            &blob_to_kzg_commitment,
+            &bytes_to_blob,
+            &compute_kzg_proof,
+            &update_kzg_commitment,
+            &precompute_proof_update_terms,
+            &update_kzg_proof,
+        );
+    }
+
+    #[test]
+    pub fn update_kzg_proof_cost_test_() {
+        update_kzg_proof_cost_test::<
+            blsScalar,
+            ZkG1Projective,
+            ZkG2Projective,
+            KzgPoly,
+            ZkFFTSettings,
+            KZGSettings,
+        >(
+            &load_trusted_setup,
+            &bytes_to_blob,
+            &compute_kzg_proof,
+            &precompute_proof_update_terms,
+            &update_kzg_proof,
+        );
+    }
+
     #[test]
     pub fn verify_kzg_proof_batch_test_() {
         verify_kzg_proof_batch_test::<
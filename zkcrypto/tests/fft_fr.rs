@@ -25,4 +25,9 @@ mod tests {
     fn stride_fft_() {
         stride_fft::<blsScalar, ZkFFTSettings>();
     }
+
+    #[test]
+    fn coset_fft_() {
+        coset_fft::<blsScalar, ZkFFTSettings>();
+    }
 }
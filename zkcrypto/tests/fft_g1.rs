@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
     use kzg::G1;
-    use kzg_bench::tests::fft_g1::{compare_ft_fft, roundtrip_fft, stride_fft};
+    use kzg_bench::tests::fft_g1::{compare_ft_fft, coset_fft, roundtrip_fft, stride_fft};
     use rust_kzg_zkcrypto::fft_g1::{fft_g1_fast, fft_g1_slow};
     use rust_kzg_zkcrypto::fftsettings::ZkFFTSettings;
     use rust_kzg_zkcrypto::kzg_types::ZkG1Projective;
@@ -38,4 +38,9 @@ mod tests {
             &make_data,
         );
     }
+
+    #[test]
+    fn coset_fft_() {
+        coset_fft::<blsScalar, ZkG1Projective, ZkFFTSettings>();
+    }
 }
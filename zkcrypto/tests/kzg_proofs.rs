@@ -27,7 +27,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "Poly given is too long")]
+    #[should_panic(expected = "SetupTooSmall")]
     fn test_commit_to_too_long_poly() {
         commit_to_too_long_poly::<
             blsScalar,
@@ -50,4 +50,28 @@ mod tests {
             &generate_trusted_setup,
         );
     }
+
+    #[test]
+    fn test_proof_multi_with_few_g2_points() {
+        proof_multi_with_few_g2_points::<
+            blsScalar,
+            ZkG1Projective,
+            ZkG2Projective,
+            ZPoly,
+            ZkFFTSettings,
+            KZGSettings,
+        >(&generate_trusted_setup);
+    }
+
+    #[test]
+    fn test_setup_basis_conversion() {
+        setup_basis_conversion::<
+            blsScalar,
+            ZkG1Projective,
+            ZkG2Projective,
+            ZPoly,
+            ZkFFTSettings,
+            KZGSettings,
+        >(&generate_trusted_setup);
+    }
 }
@@ -74,4 +74,44 @@ mod tests {
     fn poly_div_fast_test_() {
         poly_div_fast_test::<blsScalar, ZPoly>()
     }
+
+    #[test]
+    fn poly_add_sub_test_() {
+        poly_add_sub_test::<blsScalar, ZPoly>();
+    }
+
+    #[test]
+    fn poly_derivative_test_() {
+        poly_derivative_test::<blsScalar, ZPoly>();
+    }
+
+    #[test]
+    fn poly_compose_x_pow_test_() {
+        poly_compose_x_pow_test::<blsScalar, ZPoly>();
+    }
+
+    #[test]
+    fn poly_div_rem_test_() {
+        poly_div_rem_test::<blsScalar, ZPoly>();
+    }
+
+    #[test]
+    fn poly_eval_multi_test_() {
+        poly_eval_multi_test::<blsScalar, ZPoly>();
+    }
+
+    #[test]
+    fn poly_interpolate_test_() {
+        poly_interpolate_test::<blsScalar, ZPoly>();
+    }
+
+    #[test]
+    fn poly_eval_form_test_() {
+        poly_eval_form_test::<blsScalar, ZPoly, ZkFFTSettings>();
+    }
+
+    #[test]
+    fn poly_eval_form_div_test_() {
+        poly_eval_form_div_test::<blsScalar, ZPoly, ZkFFTSettings>();
+    }
 }
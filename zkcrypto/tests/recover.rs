@@ -19,4 +19,14 @@ mod recover_tests {
     fn more_than_half_missing_() {
         more_than_half_missing::<blsScalar, ZkFFTSettings, ZPoly, ZPoly>();
     }
+
+    #[test]
+    fn correct_errors_simple_() {
+        correct_errors_simple::<blsScalar, ZkFFTSettings, ZPoly, ZPoly>();
+    }
+
+    #[test]
+    fn correct_errors_too_many_() {
+        correct_errors_too_many::<blsScalar, ZkFFTSettings, ZPoly, ZPoly>();
+    }
 }
@@ -0,0 +1,34 @@
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use kzg_bench::tests::serialization::{
+        fr_serde_rejects_invalid_test, fr_serde_round_trip_test, g1_serde_rejects_invalid_test,
+        g1_serde_round_trip_test, g2_serde_round_trip_test,
+    };
+    use rust_kzg_zkcrypto::kzg_types::{ZkG1Projective, ZkG2Projective};
+    use rust_kzg_zkcrypto::zkfr::blsScalar;
+
+    #[test]
+    fn fr_serde_round_trip_test_() {
+        fr_serde_round_trip_test::<blsScalar>();
+    }
+
+    #[test]
+    fn fr_serde_rejects_invalid_test_() {
+        fr_serde_rejects_invalid_test::<blsScalar>();
+    }
+
+    #[test]
+    fn g1_serde_round_trip_test_() {
+        g1_serde_round_trip_test::<ZkG1Projective>();
+    }
+
+    #[test]
+    fn g1_serde_rejects_invalid_test_() {
+        g1_serde_rejects_invalid_test::<ZkG1Projective>();
+    }
+
+    #[test]
+    fn g2_serde_round_trip_test_() {
+        g2_serde_round_trip_test::<ZkG2Projective>();
+    }
+}
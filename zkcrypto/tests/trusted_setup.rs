@@ -0,0 +1,28 @@
+#[cfg(test)]
+mod tests {
+    use kzg_bench::tests::trusted_setup::{
+        powers_of_tau_ceremony_test, powers_of_tau_tampered_transcript_test,
+    };
+    use rust_kzg_zkcrypto::fftsettings::ZkFFTSettings;
+    use rust_kzg_zkcrypto::kzg_proofs::KZGSettings;
+    use rust_kzg_zkcrypto::kzg_types::{ZkG1Projective, ZkG2Projective};
+    use rust_kzg_zkcrypto::poly::ZPoly;
+    use rust_kzg_zkcrypto::zkfr::blsScalar;
+
+    #[test]
+    fn powers_of_tau_ceremony_test_() {
+        powers_of_tau_ceremony_test::<
+            blsScalar,
+            ZkG1Projective,
+            ZkG2Projective,
+            ZPoly,
+            ZkFFTSettings,
+            KZGSettings,
+        >();
+    }
+
+    #[test]
+    fn powers_of_tau_tampered_transcript_test_() {
+        powers_of_tau_tampered_transcript_test::<blsScalar, ZkG1Projective, ZkG2Projective>();
+    }
+}